@@ -1,52 +1,261 @@
+use crate::cache;
 use crate::dxenv::DxEnvironment;
 use crate::{
-    AnalysisDescribeOptions, AnalysisDescribeResult, AppDescribeOptions,
-    AppDescribeResult, AppletDescribeOptions, AppletDescribeResult,
-    AuthToken, ContainerDescribeOptions, ContainerDescribeResult,
+    emit_progress, quiet, AddTagsOptions, AnalysisDescribeOptions,
+    AnalysisDescribeResult, AppDescribeOptions, AppDescribeResult, AppUserList,
+    AppletDescribeOptions, AppletDescribeResult, AuthToken, CloneOptions,
+    CloneResult, ContainerDescribeOptions, ContainerDescribeResult,
     Credentials, DatabaseDescribeOptions, DatabaseDescribeResult,
-    DownloadOptions, DownloadResponse, DxErrorResponse, FileCloseOptions,
-    FileCloseResponse, FileDescribeOptions, FileDescribeResult,
-    FileNewOptions, FileNewResponse, FileUploadOptions, FileUploadResponse,
-    FindAppsOptions, FindAppsResponse, FindAppsResult, FindDataOptions,
-    FindDataResponse, FindDataResult, FindProjectsOptions,
+    DescribeDataObjectsOptions, DescribeDataObjectsResponse,
+    DescribeDataObjectsResult, DownloadOptions, DownloadResponse,
+    DxErrorResponse, FileCloseOptions, FileCloseResponse, FileDescribeOptions,
+    FileDescribeResult, FileNewOptions, FileNewResponse, FileUploadOptions,
+    FileUploadResponse, FindAppsOptions, FindAppsResponse, FindAppsResult,
+    FindDataOptions, FindDataResponse, FindDataResult, FindExecutionsOptions,
+    FindExecutionsResponse, FindExecutionsResult, FindOrgsOptions,
+    FindOrgsResponse, FindOrgsResult, FindProjectsOptions,
     FindProjectsResponse, FindProjectsResult, JobDescribeOptions,
-    JobDescribeResult, ListFolderOptions, ListFolderResult,
-    MakeFolderOptions, MakeFolderResult, NewProjectOptions, NewProjectResult,
-    ProjectDescribeOptions, ProjectDescribeResult, RecordDescribeOptions,
-    RecordDescribeResult, RmOptions, RmProjectOptions, RmProjectResult,
-    RmResult, RmdirOptions, RmdirResult, WatchOptions, WhoAmIOptions,
-    WhoAmIResult,
+    JobDescribeResult, ListFolderOptions, ListFolderResult, MakeFolderOptions,
+    MakeFolderResult, MoveOptions, MoveResult, NewProjectOptions,
+    NewProjectResult, NewTokenOptions, OrgDescribe, ProgressEvent,
+    ProgressFormat, ProjectDescribeOptions, ProjectDescribeResult,
+    RecordDescribeOptions, RecordDescribeResult, RecordNewOptions,
+    RecordNewResponse, RecordVisualizeOptions, RecordVisualizeResult,
+    RmOptions, RmProjectOptions, RmProjectResult, RmResult, RmdirOptions,
+    RmdirResult, RunOptions, RunResult, TokenDescribeResult,
+    UserDescribeOptions, UserDescribeResult, WatchMessage, WatchOptions,
+    WhoAmIOptions, WhoAmIResult,
 };
 
-//WatchResult,
-
 use anyhow::{anyhow, bail, Result};
-use futures_util::StreamExt;
+use futures_util::{future::BoxFuture, SinkExt, StreamExt};
 use indicatif::{ProgressBar, ProgressStyle};
-use log::debug;
+use log::{debug, trace};
+use once_cell::sync::OnceCell;
 use reqwest::{
-    header::{HeaderMap, HeaderName, HeaderValue},
-    Client, StatusCode,
+    header::{HeaderMap, HeaderName, HeaderValue, RANGE, RETRY_AFTER},
+    Client, Request, RequestBuilder, Response, StatusCode,
 };
 use serde::Serialize;
 use sha256::digest;
-use std::io::Write;
+use std::{
+    cell::RefCell,
+    io::Write,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
 //use textnonce::TextNonce;
 
-//use futures_util::{SinkExt, StreamExt};
-//use http::Uri;
-//use tokio::net::TcpListener;
-//use tokio_websockets::{ClientBuilder, Error, Message, ServerBuilder};
-
+/// Names a token by its SHA-256 digest rather than the token itself
 #[derive(Debug, Serialize)]
-struct LogoutPayload {
+struct TokenPayload {
     #[serde(rename = "tokenSignature")]
     token_signature: String,
 }
 
-const API_SERVER_PROTOCOL: &str = "https";
-const API_SERVER: &str = "api.dnanexus.com";
-const AUTH_SERVER: &str = "https://auth.dnanexus.com";
+const PROGRESS_EVENT_BYTES: u64 = 1024 * 1024;
+
+/// Times a throttled (429/503) request is sent again before giving up
+const MAX_RETRIES: u32 = 5;
+
+/// Requests allowed per second, unlimited until "set_rate_limit"
+static RATE_LIMITER: OnceCell<Mutex<TokenBucket>> = OnceCell::new();
+
+/// Log each request's route, status, latency, and retries to STDERR
+static VERBOSE_HTTP: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Replaces the network for requests made on this thread, see
+    /// "with_transport"
+    static TRANSPORT: RefCell<Option<Rc<dyn Transport>>> =
+        const { RefCell::new(None) };
+}
+
+/// Sends a built request and yields the response, which lets tests
+/// answer requests without a server
+pub trait Transport {
+    fn execute(
+        &self,
+        client: Client,
+        request: Request,
+    ) -> BoxFuture<'static, reqwest::Result<Response>>;
+}
+
+/// Puts the previous transport back even if the caller panics
+struct TransportGuard(Option<Rc<dyn Transport>>);
+
+impl Drop for TransportGuard {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        TRANSPORT.with(|transport| *transport.borrow_mut() = previous);
+    }
+}
+
+/// Allows bursts of up to one second's worth of requests, then spaces
+/// them out to the configured rate
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: f64) -> Self {
+        TokenBucket {
+            rate,
+            tokens: rate.max(1.0),
+            updated: Instant::now(),
+        }
+    }
+
+    // Takes a token if one is available, else says how long until one is
+    fn take(&mut self, now: Instant) -> Option<Duration> {
+        let elapsed = now.saturating_duration_since(self.updated);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.rate)
+            .min(self.rate.max(1.0));
+        self.updated = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - self.tokens) / self.rate))
+        }
+    }
+}
+
+// --------------------------------------------------
+/// Limits API requests to a number per second for the life of the
+/// process, e.g., to stay under the platform's throttling on big batches
+pub fn set_rate_limit(requests_per_second: f64) {
+    if requests_per_second > 0.0 {
+        let _ =
+            RATE_LIMITER.set(Mutex::new(TokenBucket::new(requests_per_second)));
+    }
+}
+
+// --------------------------------------------------
+/// Runs "f" with the requests it makes on this thread going to
+/// "transport" rather than the network. Requests on other threads,
+/// e.g., in a rayon pool, are not affected.
+pub fn with_transport<R>(
+    transport: impl Transport + 'static,
+    f: impl FnOnce() -> R,
+) -> R {
+    let previous = TRANSPORT
+        .with(|current| current.borrow_mut().replace(Rc::new(transport)));
+    let _guard = TransportGuard(previous);
+    f()
+}
+
+// --------------------------------------------------
+fn execute(
+    client: Client,
+    request: Request,
+) -> BoxFuture<'static, reqwest::Result<Response>> {
+    match TRANSPORT.with(|transport| transport.borrow().clone()) {
+        Some(transport) => transport.execute(client, request),
+        _ => Box::pin(async move { client.execute(request).await }),
+    }
+}
+
+// --------------------------------------------------
+pub fn set_verbose_http(verbose: bool) {
+    VERBOSE_HTTP.store(verbose, Ordering::Relaxed);
+}
+
+// --------------------------------------------------
+async fn wait_for_rate_limit() {
+    let Some(bucket) = RATE_LIMITER.get() else {
+        return;
+    };
+
+    loop {
+        let wait = match bucket.lock() {
+            Ok(mut bucket) => bucket.take(Instant::now()),
+            _ => None,
+        };
+
+        match wait {
+            Some(wait) => tokio::time::sleep(wait).await,
+            _ => return,
+        }
+    }
+}
+
+// --------------------------------------------------
+// Honors the server's "Retry-After" seconds, else backs off exponentially
+fn retry_delay(res: &Response, attempt: u32) -> Duration {
+    res.headers()
+        .get(RETRY_AFTER)
+        .and_then(|val| val.to_str().ok())
+        .and_then(|val| val.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or_else(|| {
+            Duration::from_secs(2u64.saturating_pow(attempt).min(30))
+        })
+}
+
+// --------------------------------------------------
+// Sends every request through the rate limiter and resends those the
+// server throttled, which needs a body that can be cloned
+async fn send(mut req: RequestBuilder) -> Result<Response> {
+    let mut attempt = 0;
+    loop {
+        let retry = req.try_clone();
+        let (client, request) = req.build_split();
+        let request = request?;
+        let route = format!("{} {}", request.method(), request.url().path());
+        debug!(
+            "request {} {} headers={:?}",
+            request.method(),
+            request.url(),
+            request.headers()
+        );
+        if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+            trace!("request body {}", String::from_utf8_lossy(body));
+        }
+
+        wait_for_rate_limit().await;
+        let start = Instant::now();
+        let res = execute(client, request).await;
+
+        let status = match &res {
+            Ok(res) => res.status().as_u16().to_string(),
+            Err(e) => e.to_string(),
+        };
+        let millis = start.elapsed().as_millis();
+        debug!(
+            "response {route} status={status} ms={millis} retries={attempt} \
+            headers={:?}",
+            res.as_ref().map(|res| res.headers()).ok()
+        );
+        if VERBOSE_HTTP.load(Ordering::Relaxed) {
+            eprintln!("{route} {status} {millis}ms retries={attempt}");
+        }
+
+        let res = res?;
+        let throttled = matches!(
+            res.status(),
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        match retry {
+            Some(next) if throttled && attempt < MAX_RETRIES => {
+                attempt += 1;
+                tokio::time::sleep(retry_delay(&res, attempt)).await;
+                req = next;
+            }
+            _ => return Ok(res),
+        }
+    }
+}
 
 // --------------------------------------------------
 #[tokio::main]
@@ -55,28 +264,31 @@ pub async fn describe_analysis(
     analysis_id: &str,
     options: &AnalysisDescribeOptions,
 ) -> Result<AnalysisDescribeResult> {
-    let url = format!(
-        "{}://{}/{}/describe",
-        API_SERVER_PROTOCOL, API_SERVER, analysis_id
-    );
+    let url = format!("{}/{}/describe", dx_env.api_url(), analysis_id);
+
+    if let Some(t) = cache::get(dx_env, analysis_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
 
     let client = Client::new();
     let req = client
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::put(dx_env, analysis_id, "describe", options, t);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -90,28 +302,31 @@ pub async fn describe_app(
     app_id: &str,
     options: &AppDescribeOptions,
 ) -> Result<AppDescribeResult> {
-    let url = format!(
-        "{}://{}/{}/describe",
-        API_SERVER_PROTOCOL, API_SERVER, app_id
-    );
+    let url = format!("{}/{}/describe", dx_env.api_url(), app_id);
+
+    if let Some(t) = cache::get(dx_env, app_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
 
     let client = Client::new();
     let req = client
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::put(dx_env, app_id, "describe", options, t);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -125,29 +340,32 @@ pub async fn describe_applet(
     applet_id: &str,
     options: &AppletDescribeOptions,
 ) -> Result<AppletDescribeResult> {
-    let url = format!(
-        "{}://{}/{}/describe",
-        API_SERVER_PROTOCOL, API_SERVER, applet_id
-    );
+    let url = format!("{}/{}/describe", dx_env.api_url(), applet_id);
+
+    if let Some(t) = cache::get(dx_env, applet_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
 
     let client = Client::new();
     let req = client
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         //StatusCode::OK => Ok(res.json::<AppletDescribeResult>().await?),
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::put(dx_env, applet_id, "describe", options, t);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -163,7 +381,7 @@ pub async fn describe_applet(
 //) -> Result<R> {
 //    let client = Client::new();
 //    let req = client.post(url).bearer_auth(&auth_token).json(&options);
-//    let res = req.send().await?;
+//    let res = send(req).await?;
 
 //    match res.status() {
 //        StatusCode::OK => {
@@ -174,7 +392,7 @@ pub async fn describe_applet(
 //        _ => {
 //            let text = res.text().await?;
 //            match serde_json::from_str::<DxErrorResponse>(&text) {
-//                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+//                Ok(e) => bail!(e),
 //                _ => bail!("{text}"),
 //            }
 //        }
@@ -188,30 +406,33 @@ pub async fn describe_container(
     container_id: &str,
     options: &ContainerDescribeOptions,
 ) -> Result<ContainerDescribeResult> {
-    let url = format!(
-        "{}://{}/{}/describe",
-        API_SERVER_PROTOCOL, API_SERVER, container_id
-    );
+    let url = format!("{}/{}/describe", dx_env.api_url(), container_id);
 
     //describe(&url, &dx_env.auth_token, &options)
 
+    if let Some(t) = cache::get(dx_env, container_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
+
     let client = Client::new();
     let req = client
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::put(dx_env, container_id, "describe", options, t);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -225,28 +446,62 @@ pub async fn describe_database(
     database_id: &str,
     options: &DatabaseDescribeOptions,
 ) -> Result<DatabaseDescribeResult> {
-    let url = format!(
-        "{}://{}/{}/describe",
-        API_SERVER_PROTOCOL, API_SERVER, database_id
-    );
+    let url = format!("{}/{}/describe", dx_env.api_url(), database_id);
+
+    if let Some(t) = cache::get(dx_env, database_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
 
     let client = Client::new();
     let req = client
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::put(dx_env, database_id, "describe", options, t);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn describe_data_objects(
+    dx_env: &DxEnvironment,
+    options: &DescribeDataObjectsOptions,
+) -> Result<Vec<DescribeDataObjectsResult>> {
+    // https://documentation.dnanexus.com/developer/api/
+    // system-methods#api-method-system-describedataobjects
+    let url = format!("{}/system/describeDataObjects", dx_env.api_url());
+    let client = Client::new();
+    let req = client
+        .post(&url)
+        .bearer_auth(&dx_env.auth_token)
+        .json(&options);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let response = res.json::<DescribeDataObjectsResponse>().await?;
+            Ok(response.results)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -260,28 +515,31 @@ pub async fn describe_file(
     file_id: &str,
     options: &FileDescribeOptions,
 ) -> Result<FileDescribeResult> {
-    let url = format!(
-        "{}://{}/{}/describe",
-        API_SERVER_PROTOCOL, API_SERVER, file_id
-    );
+    let url = format!("{}/{}/describe", dx_env.api_url(), file_id);
+
+    if let Some(t) = cache::get(dx_env, file_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
 
     let client = Client::new();
     let req = client
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::put(dx_env, file_id, "describe", options, t);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -295,28 +553,69 @@ pub async fn describe_job(
     job_id: &str,
     options: &JobDescribeOptions,
 ) -> Result<JobDescribeResult> {
-    let url = format!(
-        "{}://{}/{}/describe",
-        API_SERVER_PROTOCOL, API_SERVER, job_id
-    );
+    let url = format!("{}/{}/describe", dx_env.api_url(), job_id);
+
+    if let Some(t) = cache::get(dx_env, job_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
+
+    let client = Client::new();
+    let req = client
+        .post(&url)
+        .bearer_auth(&dx_env.auth_token)
+        .json(&options);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            cache::put(dx_env, job_id, "describe", options, t);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn describe_org(
+    dx_env: &DxEnvironment,
+    org_id: &str,
+) -> Result<OrgDescribe> {
+    let url = format!("{}/{}/describe", dx_env.api_url(), org_id);
+    let options = serde_json::json!({});
+
+    if let Some(t) = cache::get(dx_env, org_id, "describe", &options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
 
     let client = Client::new();
     let req = client
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::put(dx_env, org_id, "describe", &options, t);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -332,29 +631,32 @@ pub async fn describe_project(
 ) -> Result<ProjectDescribeResult> {
     // https://documentation.dnanexus.com/developer/api/data-containers/
     // projects#api-method-project-xxxx-describe
-    let url = format!(
-        "{}://{}/{}/describe",
-        API_SERVER_PROTOCOL, API_SERVER, project_id
-    );
+    let url = format!("{}/{}/describe", dx_env.api_url(), project_id);
+
+    if let Some(t) = cache::get(dx_env, project_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
 
     let client = Client::new();
     let req = client
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         //StatusCode::OK => Ok(res.json::<ProjectDescribeResult>().await?),
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::put(dx_env, project_id, "describe", options, t);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -368,28 +670,69 @@ pub async fn describe_record(
     record_id: &str,
     options: &RecordDescribeOptions,
 ) -> Result<RecordDescribeResult> {
-    let url = format!(
-        "{}://{}/{}/describe",
-        API_SERVER_PROTOCOL, API_SERVER, record_id
-    );
+    let url = format!("{}/{}/describe", dx_env.api_url(), record_id);
+
+    if let Some(t) = cache::get(dx_env, record_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
+
+    let client = Client::new();
+    let req = client
+        .post(&url)
+        .bearer_auth(&dx_env.auth_token)
+        .json(&options);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            cache::put(dx_env, record_id, "describe", options, t);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn describe_user(
+    dx_env: &DxEnvironment,
+    user_id: &str,
+    options: &UserDescribeOptions,
+) -> Result<UserDescribeResult> {
+    let url = format!("{}/{}/describe", dx_env.api_url(), user_id);
+
+    if let Some(t) = cache::get(dx_env, user_id, "describe", options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
 
     let client = Client::new();
     let req = client
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::put(dx_env, user_id, "describe", options, t);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -403,16 +746,14 @@ pub async fn download(
     file_id: &str,
     options: &DownloadOptions,
 ) -> Result<DownloadResponse> {
-    let url =
-        format!("{API_SERVER_PROTOCOL}://{API_SERVER}/{file_id}/download");
+    let url = format!("{}/{file_id}/download", dx_env.api_url());
 
     let client = Client::new();
-    let res = client
+    let req = client
         .post(url)
         .json(&options)
-        .bearer_auth(&dx_env.auth_token)
-        .send()
-        .await?;
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
@@ -423,7 +764,50 @@ pub async fn download(
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn download_range(
+    options: &DownloadResponse,
+    start: u64,
+    end: u64,
+) -> Result<Vec<u8>> {
+    let client = Client::new();
+    let mut headers = HeaderMap::new();
+    for (key, val) in &options.headers {
+        headers.insert(
+            HeaderName::from_bytes(key.as_bytes())?,
+            HeaderValue::from_str(val)?,
+        );
+    }
+    headers.insert(
+        RANGE,
+        HeaderValue::from_str(&format!("bytes={start}-{end}"))?,
+    );
+
+    let req = client.get(options.url.clone()).headers(headers);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::PARTIAL_CONTENT => Ok(res.bytes().await?.to_vec()),
+        // The server may ignore the range and send the whole file
+        StatusCode::OK => {
+            let bytes = res.bytes().await?;
+            let start = (start as usize).min(bytes.len());
+            let end = (end as usize + 1).min(bytes.len());
+            Ok(bytes[start..end].to_vec())
+        }
+        StatusCode::RANGE_NOT_SATISFIABLE => Ok(vec![]),
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -436,7 +820,7 @@ pub async fn download_file(
     options: &DownloadResponse,
     mut file: impl Write,
     filename: &str,
-    quiet: bool,
+    progress_format: ProgressFormat,
 ) -> Result<()> {
     let client = Client::new();
     let mut headers = HeaderMap::new();
@@ -447,18 +831,23 @@ pub async fn download_file(
         );
     }
 
-    let res = client
-        .get(options.url.clone())
-        .headers(headers)
-        .send()
-        .await?;
+    let req = client.get(options.url.clone()).headers(headers);
+    let res = send(req).await?;
 
     let total_size = res.content_length().ok_or(anyhow!(
         "Failed to get content length from '{}'",
         &options.url
     ))?;
 
-    let progress = if quiet {
+    let json = progress_format == ProgressFormat::Json;
+    if json {
+        emit_progress(&ProgressEvent::Started {
+            file: filename,
+            size: total_size,
+        });
+    }
+
+    let progress = if progress_format != ProgressFormat::Bar || quiet() {
         None
     } else {
         let pb = ProgressBar::new(total_size);
@@ -478,6 +867,7 @@ pub async fn download_file(
     match res.status() {
         StatusCode::OK => {
             let mut downloaded: u64 = 0;
+            let mut reported: u64 = 0;
             let mut stream = res.bytes_stream();
 
             while let Some(item) = stream.next().await {
@@ -495,17 +885,37 @@ pub async fn download_file(
                 if let Some(pb) = progress.as_ref() {
                     pb.set_position(new)
                 }
+
+                // Network chunks are small, so report at most once per MiB
+                if json
+                    && (new - reported >= PROGRESS_EVENT_BYTES
+                        || new == total_size)
+                {
+                    reported = new;
+                    emit_progress(&ProgressEvent::Bytes {
+                        file: filename,
+                        bytes: new,
+                        total: total_size,
+                    });
+                }
             }
 
             if let Some(pb) = progress {
                 pb.finish_with_message("Finished")
             }
+
+            if json {
+                emit_progress(&ProgressEvent::Finished {
+                    file: filename,
+                    id: None,
+                });
+            }
             Ok(())
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -521,8 +931,7 @@ pub async fn find_apps(
     // https://documentation.dnanexus.com/developer/api/search#
     // api-method-system-findapps
 
-    let url =
-        format!("{}://{}/system/findApps", API_SERVER_PROTOCOL, API_SERVER);
+    let url = format!("{}/system/findApps", dx_env.api_url());
     let client = Client::new();
     let mut apps: Vec<FindAppsResult> = vec![];
 
@@ -531,7 +940,7 @@ pub async fn find_apps(
             .post(&url)
             .bearer_auth(&dx_env.auth_token)
             .json(&options);
-        let res = req.send().await?;
+        let res = send(req).await?;
 
         match res.status() {
             StatusCode::OK => {
@@ -555,9 +964,7 @@ pub async fn find_apps(
             _ => {
                 let text = res.text().await?;
                 match serde_json::from_str::<DxErrorResponse>(&text) {
-                    Ok(e) => {
-                        bail!("{}: {}", e.error.error_type, e.error.message)
-                    }
+                    Ok(e) => bail!(e),
                     _ => {
                         bail!("{text}")
                     }
@@ -578,10 +985,7 @@ pub async fn find_data(
     // https://documentation.dnanexus.com/developer/api/search#
     // api-method-system-finddataobjects
 
-    let url = format!(
-        "{}://{}/system/findDataObjects",
-        API_SERVER_PROTOCOL, API_SERVER
-    );
+    let url = format!("{}/system/findDataObjects", dx_env.api_url());
     let client = Client::new();
     let mut apps: Vec<FindDataResult> = vec![];
 
@@ -590,7 +994,7 @@ pub async fn find_data(
             .post(&url)
             .bearer_auth(&dx_env.auth_token)
             .json(&options);
-        let res = req.send().await?;
+        let res = send(req).await?;
 
         match res.status() {
             StatusCode::OK => {
@@ -614,9 +1018,7 @@ pub async fn find_data(
             _ => {
                 let text = res.text().await?;
                 match serde_json::from_str::<DxErrorResponse>(&text) {
-                    Ok(e) => {
-                        bail!("{}: {}", e.error.error_type, e.error.message)
-                    }
+                    Ok(e) => bail!(e),
                     _ => {
                         bail!("{text}")
                     }
@@ -630,40 +1032,34 @@ pub async fn find_data(
 
 // --------------------------------------------------
 #[tokio::main]
-pub async fn find_projects(
+pub async fn find_executions(
     dx_env: &DxEnvironment,
-    mut options: FindProjectsOptions,
-) -> Result<Vec<FindProjectsResult>> {
+    options: &mut FindExecutionsOptions,
+) -> Result<Vec<FindExecutionsResult>> {
     // https://documentation.dnanexus.com/developer/api/search#
-    // api-method-system-findprojects
+    // api-method-system-findexecutions
 
-    let url = format!(
-        "{}://{}/system/findProjects",
-        API_SERVER_PROTOCOL, API_SERVER
-    );
+    let url = format!("{}/system/findExecutions", dx_env.api_url());
     let client = Client::new();
-    let mut projects: Vec<FindProjectsResult> = vec![];
+    let mut executions: Vec<FindExecutionsResult> = vec![];
 
     loop {
         let req = client
             .post(&url)
             .bearer_auth(&dx_env.auth_token)
             .json(&options);
-        let res = req.send().await?;
+        let res = send(req).await?;
 
         match res.status() {
             StatusCode::OK => {
-                let response = res.json::<FindProjectsResponse>().await?;
-
-                let mut data: Vec<FindProjectsResult> =
-                    response.results.into_iter().collect();
-
-                if !data.is_empty() {
-                    projects.append(&mut data);
-                }
+                let text = &res.text().await?;
+                debug!("{}", &text);
+                let mut response: FindExecutionsResponse =
+                    serde_json::from_str(text)?;
+                executions.append(&mut response.results);
 
                 if response.next.is_some() {
-                    options.starting = response.next.clone()
+                    options.starting = response.next
                 } else {
                     break;
                 }
@@ -671,9 +1067,7 @@ pub async fn find_projects(
             _ => {
                 let text = res.text().await?;
                 match serde_json::from_str::<DxErrorResponse>(&text) {
-                    Ok(e) => {
-                        bail!("{}: {}", e.error.error_type, e.error.message)
-                    }
+                    Ok(e) => bail!(e),
                     _ => {
                         bail!("{text}")
                     }
@@ -682,41 +1076,139 @@ pub async fn find_projects(
         }
     }
 
-    Ok(projects)
+    Ok(executions)
 }
 
 // --------------------------------------------------
 #[tokio::main]
-pub async fn ls(
+pub async fn find_orgs(
     dx_env: &DxEnvironment,
-    project_id: &str,
-    options: ListFolderOptions,
-) -> Result<ListFolderResult> {
-    // https://documentation.dnanexus.com/developer/api/data-containers/
-    // folders-and-deletion#api-method-class-xxxx-listfolder
-    //println!("{}", serde_json::to_string(&options)?);
-    let url = format!(
-        "{}://{}/{}/listFolder",
-        API_SERVER_PROTOCOL, API_SERVER, project_id
-    );
+    mut options: FindOrgsOptions,
+) -> Result<Vec<FindOrgsResult>> {
+    // https://documentation.dnanexus.com/developer/api/search#
+    // api-method-system-findorgs
+    let url = format!("{}/system/findOrgs", dx_env.api_url());
     let client = Client::new();
-    let req = client
-        .post(&url)
-        .bearer_auth(&dx_env.auth_token)
-        .json(&options);
-    let res = req.send().await?;
+    let mut orgs: Vec<FindOrgsResult> = vec![];
 
-    match res.status() {
-        StatusCode::OK => {
-            let t = &res.text().await?;
-            debug!("{}", &t);
-            Ok(serde_json::from_str(t)?)
-        }
-        _ => {
-            let text = res.text().await?;
-            match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
-                _ => bail!("{text}"),
+    loop {
+        let req = client
+            .post(&url)
+            .bearer_auth(&dx_env.auth_token)
+            .json(&options);
+        let res = send(req).await?;
+
+        match res.status() {
+            StatusCode::OK => {
+                let mut response = res.json::<FindOrgsResponse>().await?;
+                orgs.append(&mut response.results);
+
+                if response.next.is_some() {
+                    options.starting = response.next
+                } else {
+                    break;
+                }
+            }
+            _ => {
+                let text = res.text().await?;
+                match serde_json::from_str::<DxErrorResponse>(&text) {
+                    Ok(e) => bail!(e),
+                    _ => bail!("{text}"),
+                }
+            }
+        }
+    }
+
+    Ok(orgs)
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn find_projects(
+    dx_env: &DxEnvironment,
+    mut options: FindProjectsOptions,
+) -> Result<Vec<FindProjectsResult>> {
+    // https://documentation.dnanexus.com/developer/api/search#
+    // api-method-system-findprojects
+
+    let url = format!("{}/system/findProjects", dx_env.api_url());
+    let client = Client::new();
+    let mut projects: Vec<FindProjectsResult> = vec![];
+
+    loop {
+        let req = client
+            .post(&url)
+            .bearer_auth(&dx_env.auth_token)
+            .json(&options);
+        let res = send(req).await?;
+
+        match res.status() {
+            StatusCode::OK => {
+                let response = res.json::<FindProjectsResponse>().await?;
+
+                let mut data: Vec<FindProjectsResult> =
+                    response.results.into_iter().collect();
+
+                if !data.is_empty() {
+                    projects.append(&mut data);
+                }
+
+                if response.next.is_some() {
+                    options.starting = response.next.clone()
+                } else {
+                    break;
+                }
+            }
+            _ => {
+                let text = res.text().await?;
+                match serde_json::from_str::<DxErrorResponse>(&text) {
+                    Ok(e) => bail!(e),
+                    _ => {
+                        bail!("{text}")
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(projects)
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn ls(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    options: ListFolderOptions,
+) -> Result<ListFolderResult> {
+    // https://documentation.dnanexus.com/developer/api/data-containers/
+    // folders-and-deletion#api-method-class-xxxx-listfolder
+    //println!("{}", serde_json::to_string(&options)?);
+    let url = format!("{}/{}/listFolder", dx_env.api_url(), project_id);
+    if let Some(t) = cache::get(dx_env, project_id, "listFolder", &options) {
+        debug!("cached {}", &t);
+        return Ok(serde_json::from_str(&t)?);
+    }
+
+    let client = Client::new();
+    let req = client
+        .post(&url)
+        .bearer_auth(&dx_env.auth_token)
+        .json(&options);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            cache::put(dx_env, project_id, "listFolder", &options, t);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
             }
         }
     }
@@ -725,24 +1217,23 @@ pub async fn ls(
 // --------------------------------------------------
 #[tokio::main]
 pub async fn logout(dx_env: &DxEnvironment) -> Result<()> {
-    let url = format!("{AUTH_SERVER}/system/destroyAuthToken");
+    let url = format!("{}/system/destroyAuthToken", dx_env.auth_url());
     let client = Client::new();
-    let payload = LogoutPayload {
+    let payload = TokenPayload {
         token_signature: digest(&dx_env.auth_token),
     };
-    let res = client
+    let req = client
         .post(url)
         .bearer_auth(&dx_env.auth_token)
-        .json(&payload)
-        .send()
-        .await?;
+        .json(&payload);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => Ok(()),
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -752,21 +1243,81 @@ pub async fn logout(dx_env: &DxEnvironment) -> Result<()> {
 // --------------------------------------------------
 #[tokio::main]
 pub async fn login(
+    auth_url: &str,
     username: &str,
     password: &str,
     _token: Option<String>,
 ) -> Result<AuthToken> {
-    let url = format!("{AUTH_SERVER}/system/newAuthToken");
+    let url = format!("{auth_url}/system/newAuthToken");
     let client = Client::new();
     let cred = Credentials {
         username: username.to_string(),
         password: password.to_string(),
     };
-    let res = client.post(url).json(&cred).send().await?;
+    let res = send(client.post(url).json(&cred)).await?;
     let token = res.json::<AuthToken>().await?;
     Ok(token)
 }
 
+// --------------------------------------------------
+#[tokio::main]
+pub async fn describe_token(
+    dx_env: &DxEnvironment,
+) -> Result<TokenDescribeResult> {
+    let url = format!("{}/system/describeAuthToken", dx_env.auth_url());
+    let client = Client::new();
+    let payload = TokenPayload {
+        token_signature: digest(&dx_env.auth_token),
+    };
+    let req = client
+        .post(url)
+        .bearer_auth(&dx_env.auth_token)
+        .json(&payload);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn new_token(
+    dx_env: &DxEnvironment,
+    options: &NewTokenOptions,
+) -> Result<AuthToken> {
+    // The current token vouches for the new one instead of a password
+    let url = format!("{}/system/newAuthToken", dx_env.auth_url());
+    let client = Client::new();
+    let req = client
+        .post(url)
+        .bearer_auth(&dx_env.auth_token)
+        .json(&options);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => Ok(res.json::<AuthToken>().await?),
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
 // --------------------------------------------------
 #[tokio::main]
 pub async fn mkdir(
@@ -774,10 +1325,73 @@ pub async fn mkdir(
     project_id: &str,
     options: MakeFolderOptions,
 ) -> Result<MakeFolderResult> {
-    let url = format!(
-        "{}://{}/{}/newFolder",
-        API_SERVER_PROTOCOL, API_SERVER, project_id
-    );
+    let url = format!("{}/{}/newFolder", dx_env.api_url(), project_id);
+    debug!("{}", &url);
+
+    let client = Client::new();
+    let req = client
+        .post(&url)
+        .bearer_auth(&dx_env.auth_token)
+        .json(&options);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            cache::invalidate(&[project_id]);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn clone(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    options: &CloneOptions,
+) -> Result<CloneResult> {
+    let url = format!("{}/{}/clone", dx_env.api_url(), project_id);
+    let client = Client::new();
+    let req = client
+        .post(&url)
+        .bearer_auth(&dx_env.auth_token)
+        .json(&options);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            cache::invalidate(&[&options.project]);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn mv(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    options: &MoveOptions,
+) -> Result<MoveResult> {
+    let url = format!("{}/{}/move", dx_env.api_url(), project_id);
     debug!("{}", &url);
 
     let client = Client::new();
@@ -785,18 +1399,20 @@ pub async fn mkdir(
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::invalidate(&[project_id]);
+            cache::invalidate(&options.objects);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -809,7 +1425,7 @@ pub async fn new_project(
     dx_env: &DxEnvironment,
     options: NewProjectOptions,
 ) -> Result<NewProjectResult> {
-    let url = format!("{}://{}/project/new", API_SERVER_PROTOCOL, API_SERVER);
+    let url = format!("{}/project/new", dx_env.api_url());
     debug!("{}", &url);
 
     let client = Client::new();
@@ -817,7 +1433,41 @@ pub async fn new_project(
         .post(&url)
         .bearer_auth(&dx_env.auth_token)
         .json(&options);
-    let res = req.send().await?;
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn run(
+    dx_env: &DxEnvironment,
+    executable_id: &str,
+    options: &RunOptions,
+) -> Result<RunResult> {
+    // https://documentation.dnanexus.com/developer/api/running-analyses/
+    // applets-and-entry-points#api-method-applet-xxxx-run
+    let url = format!("{}/{}/run", dx_env.api_url(), executable_id);
+
+    let client = Client::new();
+    let req = client
+        .post(url)
+        .json(&options)
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
@@ -828,7 +1478,7 @@ pub async fn new_project(
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -838,72 +1488,39 @@ pub async fn new_project(
 // --------------------------------------------------
 #[tokio::main]
 pub async fn watch(
-    _dx_env: &DxEnvironment,
-    _job_id: &str,
-    _options: &WatchOptions,
+    dx_env: &DxEnvironment,
+    job_id: &str,
+    options: &WatchOptions,
+    mut on_message: impl FnMut(WatchMessage) -> Result<bool>,
 ) -> Result<()> {
-    //) -> Result<WatchResult> {
-    //let url = format!("wss://{API_SERVER}:443/{job_id}/getLog/websocket");
-
-    //let listener = TcpListener::bind("127.0.0.1:3000").await?;
-
-    //tokio::spawn(async move {
-    //    while let Ok((stream, _)) = listener.accept().await {
-    //        let mut ws_stream = ServerBuilder::new().accept(stream).await?;
-
-    //        tokio::spawn(async move {
-    //            // Just an echo server, really
-    //            while let Some(Ok(msg)) = ws_stream.next().await {
-    //                if msg.is_text() || msg.is_binary() {
-    //                    ws_stream.send(msg).await?;
-    //                }
-    //            }
-
-    //            Ok::<_, Error>(())
-    //        });
-    //    }
-
-    //    Ok::<_, Error>(())
-    //});
-
-    //let uri = Uri::from_static("ws://127.0.0.1:3000");
-    //let (mut client, _) = ClientBuilder::from_uri(uri).connect().await?;
-
-    //client.send(Message::text("Hello world!")).await?;
-
-    //while let Some(Ok(msg)) = client.next().await {
-    //    if let Some(text) = msg.as_text() {
-    //        assert_eq!(text, "Hello world!");
-    //        // We got one message, just stop now
-    //        client.close().await?;
-    //    }
-    //}
-
-    //let client = Client::new();
-    //let res = client
-    //    .post(url)
-    //    .json(&options)
-    //    .bearer_auth(&dx_env.auth_token)
-    //    .send()
-    //    .await?;
-
-    //dbg!(&res);
-    //match res.status() {
-    //    StatusCode::OK => {
-    //        let t = &res.text().await?;
-    //        debug!("{}", &t);
-    //        Ok(serde_json::from_str(t)?)
-    //    }
-    //    _ => {
-    //        let text = res.text().await?;
-    //        dbg!(&text);
-    //        match serde_json::from_str::<DxErrorResponse>(&text) {
-    //            Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
-    //            _ => bail!("Error: {text}"),
-    //        }
-    //    }
-    //}
-    //
+    let url = format!(
+        "{}/{job_id}/getLog/websocket",
+        dx_env.api_url().replacen("http", "ws", 1)
+    );
+    let (mut socket, _) = connect_async(&url).await?;
+
+    let mut payload = serde_json::to_value(options)?;
+    payload["access_token"] = dx_env.auth_token.clone().into();
+    payload["token_type"] = dx_env.auth_token_type.clone().into();
+    socket.send(Message::Text(payload.to_string())).await?;
+
+    while let Some(msg) = socket.next().await {
+        let text = match msg? {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+        debug!("{}", &text);
+        let message: WatchMessage = serde_json::from_str(&text)?;
+        if message.source == "SYSTEM" && message.msg == "END_LOG" {
+            break;
+        }
+        if !on_message(message)? {
+            break;
+        }
+    }
+
+    socket.close(None).await.ok();
     Ok(())
 }
 
@@ -913,15 +1530,13 @@ pub async fn whoami(
     dx_env: &DxEnvironment,
     options: &WhoAmIOptions,
 ) -> Result<WhoAmIResult> {
-    let url =
-        format!("{}://{}/system/whoami", API_SERVER_PROTOCOL, API_SERVER);
+    let url = format!("{}/system/whoami", dx_env.api_url());
     let client = Client::new();
-    let res = client
+    let req = client
         .post(url)
         .json(&options)
-        .bearer_auth(&dx_env.auth_token)
-        .send()
-        .await?;
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
@@ -932,7 +1547,7 @@ pub async fn whoami(
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -945,25 +1560,25 @@ pub async fn file_new(
     dx_env: &DxEnvironment,
     options: &FileNewOptions,
 ) -> Result<FileNewResponse> {
-    let url = format!("{}://{}/file/new", API_SERVER_PROTOCOL, API_SERVER);
+    let url = format!("{}/file/new", dx_env.api_url());
     let client = Client::new();
-    let res = client
+    let req = client
         .post(url)
         .json(&options)
-        .bearer_auth(&dx_env.auth_token)
-        .send()
-        .await?;
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::invalidate(&[&options.project]);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -977,17 +1592,13 @@ pub async fn file_upload(
     file_id: &str,
     options: &FileUploadOptions,
 ) -> Result<FileUploadResponse> {
-    let url = format!(
-        "{}://{}/{}/upload",
-        API_SERVER_PROTOCOL, API_SERVER, file_id
-    );
+    let url = format!("{}/{}/upload", dx_env.api_url(), file_id);
     let client = Client::new();
-    let res = client
+    let req = client
         .post(url)
         .json(&options)
-        .bearer_auth(&dx_env.auth_token)
-        .send()
-        .await?;
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
@@ -998,7 +1609,7 @@ pub async fn file_upload(
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -1020,19 +1631,15 @@ pub async fn file_upload_part(
         );
     }
 
-    let res = client
-        .put(options.url.clone())
-        .headers(headers)
-        .body(data)
-        .send()
-        .await?;
+    let req = client.put(options.url.clone()).headers(headers).body(data);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => Ok(()),
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -1046,15 +1653,179 @@ pub async fn file_close(
     file_id: &str,
     options: &FileCloseOptions,
 ) -> Result<FileCloseResponse> {
+    let url = format!("{}/{}/close", dx_env.api_url(), file_id);
+    let client = Client::new();
+    let req = client
+        .post(url)
+        .json(&options)
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            cache::invalidate(&[file_id]);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn add_tags(
+    dx_env: &DxEnvironment,
+    object_id: &str,
+    options: &AddTagsOptions,
+) -> Result<()> {
+    let url = format!("{}/{}/addTags", dx_env.api_url(), object_id);
+    let client = Client::new();
+    let req = client
+        .post(url)
+        .json(&options)
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            debug!("{}", &res.text().await?);
+            cache::invalidate(&[object_id]);
+            Ok(())
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn app_users(
+    dx_env: &DxEnvironment,
+    app_id: &str,
+    list: AppUserList,
+) -> Result<Vec<String>> {
+    // https://documentation.dnanexus.com/developer/api/running-analyses/
+    // apps#api-method-app-xxxx-yyyy-listauthorizedusers
+    let url = format!("{}/{}/list{}", dx_env.api_url(), app_id, list.route());
+    let client = Client::new();
+    let req = client
+        .post(url)
+        .json(&serde_json::json!({}))
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            let mut res: serde_json::Value = serde_json::from_str(t)?;
+            Ok(serde_json::from_value(res[list.key()].take())?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn update_app_users(
+    dx_env: &DxEnvironment,
+    app_id: &str,
+    list: AppUserList,
+    add: bool,
+    users: &[String],
+) -> Result<()> {
+    // https://documentation.dnanexus.com/developer/api/running-analyses/
+    // apps#api-method-app-xxxx-yyyy-addauthorizedusers
+    let action = if add { "add" } else { "remove" };
     let url =
-        format!("{}://{}/{}/close", API_SERVER_PROTOCOL, API_SERVER, file_id);
+        format!("{}/{}/{action}{}", dx_env.api_url(), app_id, list.route());
+    let client = Client::new();
+    let req = client
+        .post(url)
+        .json(&serde_json::json!({ list.key(): users }))
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            debug!("{}", &res.text().await?);
+            cache::invalidate(&[app_id]);
+            Ok(())
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn record_new(
+    dx_env: &DxEnvironment,
+    options: &RecordNewOptions,
+) -> Result<RecordNewResponse> {
+    let url = format!("{}/record/new", dx_env.api_url());
     let client = Client::new();
-    let res = client
+    let req = client
         .post(url)
         .json(&options)
-        .bearer_auth(&dx_env.auth_token)
-        .send()
-        .await?;
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            cache::invalidate(&[&options.project]);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn record_visualize(
+    dx_env: &DxEnvironment,
+    record_id: &str,
+    options: &RecordVisualizeOptions,
+) -> Result<RecordVisualizeResult> {
+    // Dataset and cohort records name the vizserver that queries them
+    let url = format!("{}/{}/visualize", dx_env.api_url(), record_id);
+    let client = Client::new();
+    let req = client
+        .post(url)
+        .json(&options)
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
@@ -1065,7 +1836,7 @@ pub async fn file_close(
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -1079,29 +1850,27 @@ pub async fn rm(
     project_id: &str,
     options: &RmOptions,
 ) -> Result<RmResult> {
-    let url = format!(
-        "{}://{}/{}/removeObjects",
-        API_SERVER_PROTOCOL, API_SERVER, project_id
-    );
+    let url = format!("{}/{}/removeObjects", dx_env.api_url(), project_id);
 
     let client = Client::new();
-    let res = client
+    let req = client
         .post(url)
         .json(&options)
-        .bearer_auth(&dx_env.auth_token)
-        .send()
-        .await?;
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::invalidate(&[project_id]);
+            cache::invalidate(&options.objects);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -1115,29 +1884,26 @@ pub async fn rmdir(
     project_id: &str,
     options: &RmdirOptions,
 ) -> Result<RmdirResult> {
-    let url = format!(
-        "{}://{}/{}/removeFolder",
-        API_SERVER_PROTOCOL, API_SERVER, project_id
-    );
+    let url = format!("{}/{}/removeFolder", dx_env.api_url(), project_id);
 
     let client = Client::new();
-    let res = client
+    let req = client
         .post(url)
         .json(&options)
-        .bearer_auth(&dx_env.auth_token)
-        .send()
-        .await?;
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
             let t = &res.text().await?;
             debug!("{}", &t);
+            cache::invalidate(&[project_id]);
             Ok(serde_json::from_str(t)?)
         }
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -1151,18 +1917,89 @@ pub async fn rm_project(
     project_id: &str,
     options: &RmProjectOptions,
 ) -> Result<RmProjectResult> {
-    let url = format!(
-        "{}://{}/{}/destroy",
-        API_SERVER_PROTOCOL, API_SERVER, project_id
-    );
+    let url = format!("{}/{}/destroy", dx_env.api_url(), project_id);
 
     let client = Client::new();
-    let res = client
+    let req = client
         .post(url)
         .json(&options)
-        .bearer_auth(&dx_env.auth_token)
-        .send()
-        .await?;
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            let t = &res.text().await?;
+            debug!("{}", &t);
+            cache::invalidate(&[project_id]);
+            Ok(serde_json::from_str(t)?)
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn post_webhook(url: &str, payload: &impl Serialize) -> Result<()> {
+    // Not a platform route, so no token, rate limit, or retries
+    let res = Client::new().post(url).json(payload).send().await?;
+    if !res.status().is_success() {
+        bail!("Webhook returned {}: {}", res.status(), res.text().await?)
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn terminate_job(dx_env: &DxEnvironment, job_id: &str) -> Result<()> {
+    // https://documentation.dnanexus.com/developer/api/running-analyses/
+    // applets-and-entry-points#api-method-job-xxxx-terminate
+
+    let url = format!("{}/{}/terminate", dx_env.api_url(), job_id);
+    let client = Client::new();
+    let req = client
+        .post(url)
+        .json(&serde_json::json!({}))
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
+
+    match res.status() {
+        StatusCode::OK => {
+            debug!("{}", &res.text().await?);
+            cache::invalidate(&[job_id]);
+            Ok(())
+        }
+        _ => {
+            let text = res.text().await?;
+            match serde_json::from_str::<DxErrorResponse>(&text) {
+                Ok(e) => bail!(e),
+                _ => bail!("{text}"),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+#[tokio::main]
+pub async fn vizserver_raw(
+    dx_env: &DxEnvironment,
+    vizserver_url: &str,
+    dataset_id: &str,
+    payload: &serde_json::Value,
+) -> Result<serde_json::Value> {
+    // The vizserver takes the platform token but is not the API server
+    let url = format!("{vizserver_url}/data/3.0/{dataset_id}/raw");
+    let client = Client::new();
+    let req = client
+        .post(url)
+        .json(payload)
+        .bearer_auth(&dx_env.auth_token);
+    let res = send(req).await?;
 
     match res.status() {
         StatusCode::OK => {
@@ -1173,7 +2010,7 @@ pub async fn rm_project(
         _ => {
             let text = res.text().await?;
             match serde_json::from_str::<DxErrorResponse>(&text) {
-                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+                Ok(e) => bail!(e),
                 _ => bail!("{text}"),
             }
         }
@@ -1205,11 +2042,454 @@ pub async fn rm_project(
 //        }
 //        _ => {
 //            let text = res.text().await?;
-//            dbg!(&text);
 //            match serde_json::from_str::<DxErrorResponse>(&text) {
-//                Ok(e) => bail!("{}: {}", e.error.error_type, e.error.message),
+//                Ok(e) => bail!(e),
 //                _ => bail!("{text}"),
 //            }
 //        }
 //    }
 //}
+
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::{with_transport, TokenBucket, Transport};
+    use crate::{
+        dxenv::DxEnvironment, exit, DownloadOptions, FileCloseOptions,
+        FileDescribeOptions, FileNewOptions, FileUploadOptions,
+        FindDataOptions, FindDataScope, FindDescribe,
+        ListFolderOptionOnlyValue, ListFolderOptions, ObjectType,
+        ProgressFormat,
+    };
+    use futures_util::future::BoxFuture;
+    use reqwest::{Client, Method, Request, Response};
+    use std::{
+        cell::RefCell,
+        collections::VecDeque,
+        panic::AssertUnwindSafe,
+        rc::Rc,
+        time::{Duration, Instant},
+    };
+
+    /// What the mock saw of a request
+    #[derive(Debug)]
+    struct Sent {
+        method: Method,
+        url: String,
+        auth: Option<String>,
+        body: Vec<u8>,
+    }
+
+    impl Sent {
+        fn json(&self) -> serde_json::Value {
+            serde_json::from_slice(&self.body).unwrap()
+        }
+    }
+
+    /// A canned response to a request for a method and path
+    struct Expected {
+        method: Method,
+        path: String,
+        status: u16,
+        body: String,
+    }
+
+    /// Answers each request with the first expected response for its
+    /// method and path, and remembers the requests
+    #[derive(Clone, Default)]
+    struct MockServer {
+        expected: Rc<RefCell<VecDeque<Expected>>>,
+        sent: Rc<RefCell<Vec<Sent>>>,
+    }
+
+    impl MockServer {
+        fn expect(&self, method: Method, path: &str, status: u16, body: &str) {
+            self.expected.borrow_mut().push_back(Expected {
+                method,
+                path: path.to_string(),
+                status,
+                body: body.to_string(),
+            });
+        }
+
+        fn sent(&self) -> std::cell::Ref<'_, Vec<Sent>> {
+            self.sent.borrow()
+        }
+    }
+
+    impl Transport for MockServer {
+        fn execute(
+            &self,
+            _client: Client,
+            request: Request,
+        ) -> BoxFuture<'static, reqwest::Result<Response>> {
+            let path = request.url().path().to_string();
+            let mut expected = self.expected.borrow_mut();
+            let found = expected
+                .iter()
+                .position(|e| e.method == request.method() && e.path == path);
+            let (status, body) = match found.and_then(|i| expected.remove(i)) {
+                Some(Expected { status, body, .. }) => (status, body),
+                _ => (
+                    500,
+                    format!(
+                        r#"{{"error": {{"type": "Unexpected", "message":
+                        "{} {path}"}}}}"#,
+                        request.method()
+                    ),
+                ),
+            };
+
+            self.sent.borrow_mut().push(Sent {
+                method: request.method().clone(),
+                url: request.url().to_string(),
+                auth: request
+                    .headers()
+                    .get("authorization")
+                    .and_then(|val| val.to_str().ok())
+                    .map(|val| val.to_string()),
+                body: request
+                    .body()
+                    .and_then(|body| body.as_bytes())
+                    .map_or(vec![], |body| body.to_vec()),
+            });
+
+            let response = http::Response::builder()
+                .status(status)
+                .header("retry-after", "0")
+                .body(body)
+                .unwrap();
+            Box::pin(async move { Ok(Response::from(response)) })
+        }
+    }
+
+    fn test_env() -> DxEnvironment {
+        DxEnvironment {
+            apiserver_host: "api.test".to_string(),
+            auth_token: "secret".to_string(),
+            ..DxEnvironment::default()
+        }
+    }
+
+    const PROJECT_ID: &str = "project-G0000000000000000000000P";
+
+    const FILE_ID: &str = "file-G00000000000000000000001";
+
+    #[test]
+    fn test_ls() {
+        let server = MockServer::default();
+        server.expect(
+            Method::POST,
+            &format!("/{PROJECT_ID}/listFolder"),
+            200,
+            r#"{"objects": [{"id": "file-G00000000000000000000001"}],
+               "folders": [["/data", true]]}"#,
+        );
+
+        let options = ListFolderOptions {
+            folder: "/",
+            only: Some(ListFolderOptionOnlyValue::All),
+            describe: false,
+            has_subfolder_flags: true,
+            include_hidden: false,
+        };
+        let res = with_transport(server.clone(), || {
+            super::ls(&test_env(), PROJECT_ID, options)
+        })
+        .unwrap();
+
+        assert_eq!(res.objects.unwrap()[0].id, FILE_ID);
+        assert_eq!(res.folders.unwrap(), [("/data".to_string(), true)]);
+
+        let sent = server.sent();
+        assert_eq!(
+            sent[0].url,
+            format!("https://api.test/{PROJECT_ID}/listFolder")
+        );
+        assert_eq!(sent[0].auth.as_deref(), Some("Bearer secret"));
+        assert_eq!(sent[0].json()["folder"], "/");
+    }
+
+    #[test]
+    fn test_find_data_pages() {
+        let server = MockServer::default();
+        let next =
+            format!(r#"{{"project": "{PROJECT_ID}", "id": "{FILE_ID}"}}"#);
+        server.expect(
+            Method::POST,
+            "/system/findDataObjects",
+            200,
+            &format!(r#"{{"results": [{next}], "next": {next}}}"#),
+        );
+        server.expect(
+            Method::POST,
+            "/system/findDataObjects",
+            200,
+            &format!(r#"{{"results": [{next}], "next": null}}"#),
+        );
+
+        let mut options = FindDataOptions {
+            class: Some(ObjectType::File),
+            state: None,
+            name: None,
+            visibility: None,
+            id: vec![],
+            object_type: None,
+            tags: vec![],
+            region: vec![],
+            properties: None,
+            link: None,
+            scope: Some(FindDataScope {
+                project: Some(PROJECT_ID.to_string()),
+                folder: Some("/".to_string()),
+                recurse: Some(true),
+            }),
+            sort_by: None,
+            level: None,
+            modified: None,
+            created: None,
+            describe: Some(FindDescribe::Boolean(false)),
+            starting: None,
+            limit: None,
+            archival_state: None,
+        };
+        let res = with_transport(server.clone(), || {
+            super::find_data(&test_env(), &mut options)
+        })
+        .unwrap();
+
+        // The second page starts where the first said to
+        assert_eq!(res.len(), 2);
+        let sent = server.sent();
+        assert_eq!(sent.len(), 2);
+        assert!(sent[0].json().get("starting").is_none());
+        assert_eq!(sent[1].json()["starting"]["id"], FILE_ID);
+    }
+
+    #[test]
+    fn test_describe_file() {
+        let server = MockServer::default();
+        server.expect(
+            Method::POST,
+            &format!("/{FILE_ID}/describe"),
+            200,
+            r#"{"id": "file-G00000000000000000000001", "name": "a.txt",
+               "state": "closed", "size": 3}"#,
+        );
+
+        let options = FileDescribeOptions {
+            project: Some(PROJECT_ID.to_string()),
+            fields: None,
+            properties: false,
+            details: false,
+        };
+        let desc = with_transport(server.clone(), || {
+            super::describe_file(&test_env(), FILE_ID, &options)
+        })
+        .unwrap();
+
+        assert_eq!(desc.name.as_deref(), Some("a.txt"));
+        assert_eq!(desc.state.as_deref(), Some("closed"));
+        assert_eq!(server.sent()[0].json()["project"], PROJECT_ID);
+    }
+
+    #[test]
+    fn test_upload() {
+        let server = MockServer::default();
+        server.expect(
+            Method::POST,
+            "/file/new",
+            200,
+            r#"{"id": "file-G00000000000000000000001"}"#,
+        );
+        server.expect(
+            Method::POST,
+            &format!("/{FILE_ID}/upload"),
+            200,
+            r#"{"url": "https://upload.test/part/1", "expires": 0,
+               "headers": {"content-md5": "abc"}}"#,
+        );
+        server.expect(Method::PUT, "/part/1", 200, "");
+        server.expect(
+            Method::POST,
+            &format!("/{FILE_ID}/close"),
+            200,
+            r#"{"id": "file-G00000000000000000000001"}"#,
+        );
+
+        let dx_env = test_env();
+        let new_options = FileNewOptions {
+            project: PROJECT_ID.to_string(),
+            name: Some("a.txt".to_string()),
+            tags: vec![],
+            types: vec![],
+            hidden: None,
+            details: None,
+            properties: None,
+            folder: Some("/".to_string()),
+            parents: Some(true),
+            media: None,
+            nonce: None,
+        };
+        with_transport(server.clone(), || -> anyhow::Result<()> {
+            let file = super::file_new(&dx_env, &new_options)?;
+            let upload_options = FileUploadOptions {
+                size: 3,
+                md5: "abc".to_string(),
+                index: 1,
+            };
+            let upload =
+                super::file_upload(&dx_env, &file.id, &upload_options)?;
+            super::file_upload_part(upload, b"abc".to_vec())?;
+            let close_options = FileCloseOptions {
+                id: file.id.clone(),
+            };
+            super::file_close(&dx_env, &file.id, &close_options)?;
+            Ok(())
+        })
+        .unwrap();
+
+        let sent = server.sent();
+        assert_eq!(sent.len(), 4);
+        assert_eq!(sent[0].json()["name"], "a.txt");
+        assert_eq!(sent[1].json()["index"], 1);
+
+        // The part goes to the upload URL with its data, not the token
+        assert_eq!(sent[2].method, Method::PUT);
+        assert_eq!(sent[2].url, "https://upload.test/part/1");
+        assert_eq!(sent[2].auth, None);
+        assert_eq!(sent[2].body, b"abc");
+    }
+
+    #[test]
+    fn test_download() {
+        let server = MockServer::default();
+        server.expect(
+            Method::POST,
+            &format!("/{FILE_ID}/download"),
+            200,
+            r#"{"url": "https://download.test/a.txt", "headers": {}}"#,
+        );
+        server.expect(Method::GET, "/a.txt", 200, "hello");
+
+        let options = DownloadOptions {
+            duration: None,
+            filename: None,
+            project: None,
+            preauthenticated: None,
+            sticky_ip: None,
+        };
+        let mut contents = vec![];
+        with_transport(server.clone(), || -> anyhow::Result<()> {
+            let download = super::download(&test_env(), FILE_ID, &options)?;
+            super::download_file(
+                &download,
+                &mut contents,
+                "a.txt",
+                ProgressFormat::None_,
+            )
+        })
+        .unwrap();
+
+        assert_eq!(contents, b"hello");
+        assert_eq!(server.sent()[1].url, "https://download.test/a.txt");
+    }
+
+    #[test]
+    fn test_error_payloads() {
+        let options = FileDescribeOptions {
+            project: None,
+            fields: None,
+            properties: false,
+            details: false,
+        };
+        let describe_error = |status: u16, error_type: &str| {
+            let server = MockServer::default();
+            server.expect(
+                Method::POST,
+                &format!("/{FILE_ID}/describe"),
+                status,
+                &format!(
+                    r#"{{"error": {{"type": "{error_type}",
+                       "message": "Nope"}}}}"#
+                ),
+            );
+            with_transport(server, || {
+                super::describe_file(&test_env(), FILE_ID, &options)
+            })
+            .unwrap_err()
+        };
+
+        let err = describe_error(404, "ResourceNotFound");
+        assert!(err.to_string().contains("Nope"));
+        assert_eq!(exit::exit_code(&err), exit::NOT_FOUND);
+        assert_eq!(
+            exit::exit_code(&describe_error(401, "InvalidAuthentication")),
+            exit::PERMISSION
+        );
+        assert_eq!(
+            exit::exit_code(&describe_error(422, "InvalidInput")),
+            exit::USAGE
+        );
+    }
+
+    #[test]
+    fn test_throttled_retry() {
+        let server = MockServer::default();
+        let path = format!("/{FILE_ID}/describe");
+        server.expect(Method::POST, &path, 429, "");
+        server.expect(
+            Method::POST,
+            &path,
+            200,
+            r#"{"id": "file-G00000000000000000000001"}"#,
+        );
+
+        let options = FileDescribeOptions {
+            project: None,
+            fields: None,
+            properties: false,
+            details: false,
+        };
+        let desc = with_transport(server.clone(), || {
+            super::describe_file(&test_env(), FILE_ID, &options)
+        })
+        .unwrap();
+
+        assert_eq!(desc.id, FILE_ID);
+        assert_eq!(server.sent().len(), 2);
+    }
+
+    #[test]
+    fn test_with_transport_restores() {
+        let server = MockServer::default();
+        let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            with_transport(server, || panic!("in the test"))
+        }));
+        assert!(res.is_err());
+        super::TRANSPORT
+            .with(|transport| assert!(transport.borrow().is_none()));
+    }
+
+    #[test]
+    fn test_token_bucket() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(2.0);
+        bucket.updated = start;
+
+        // A burst of one second's worth, then a wait for the next token
+        assert_eq!(bucket.take(start), None);
+        assert_eq!(bucket.take(start), None);
+        assert_eq!(bucket.take(start), Some(Duration::from_millis(500)));
+
+        let later = start + Duration::from_millis(500);
+        assert_eq!(bucket.take(later), None);
+        assert!(bucket.take(later).is_some());
+
+        // Idle time never saves up more than the burst
+        let much_later = later + Duration::from_secs(60);
+        assert_eq!(bucket.take(much_later), None);
+        assert_eq!(bucket.take(much_later), None);
+        assert!(bucket.take(much_later).is_some());
+    }
+}
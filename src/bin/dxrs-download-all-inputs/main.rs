@@ -3,7 +3,7 @@ use clap::Parser;
 use dxrs::{
     api,
     dxenv::{get_dx_env, DxEnvironment},
-    {DownloadOptions, FileDescribeField, FileDescribeOptions},
+    {DownloadOptions, FileDescribeField, FileDescribeOptions, ProgressFormat},
 };
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -68,12 +68,14 @@ struct InputFile {
 fn main() {
     if let Err(e) = run(Args::parse()) {
         eprintln!("{e}");
-        std::process::exit(1);
+        std::process::exit(dxrs::exit::exit_code(&e));
     }
 }
 
 // --------------------------------------------------
 fn run(args: Args) -> Result<()> {
+    dxrs::logging::init(None, None)?;
+
     // Optionally set num of threads, default will use all available
     if let Some(num) = args.threads {
         rayon::ThreadPoolBuilder::new()
@@ -130,12 +132,12 @@ fn run(args: Args) -> Result<()> {
     let results: Vec<_> = if args.parallel {
         downloads
             .par_iter()
-            .map(|file| download_file(&dx_env, &file))
+            .map(|file| download_file(&dx_env, file))
             .collect()
     } else {
         downloads
             .iter()
-            .map(|file| download_file(&dx_env, &file))
+            .map(|file| download_file(&dx_env, file))
             .collect()
     };
 
@@ -154,14 +156,12 @@ pub fn get_filename(dx_env: &DxEnvironment, file_id: &str) -> Result<String> {
         details: true,
         properties: true,
     };
-    let file = api::describe_file(dx_env, &file_id, &options)?;
+    let file = api::describe_file(dx_env, file_id, &options)?;
     Ok(file.name.unwrap_or(file_id.to_string()))
 }
 
 // --------------------------------------------------
-pub fn parse_json<T: for<'a> Deserialize<'a>>(
-    filename: &PathBuf,
-) -> Result<T> {
+pub fn parse_json<T: for<'a> Deserialize<'a>>(filename: &PathBuf) -> Result<T> {
     match File::open(filename) {
         Err(e) => bail!("{}: {e}", filename.display()),
 
@@ -191,7 +191,12 @@ fn download_file(dx_env: &DxEnvironment, file: &DownloadFile) -> Result<()> {
     println!("Starting {} => {}", file.filename, out_path.display());
     let out_file = File::create(out_path)?;
     let download = api::download(dx_env, &file.file_id, &dl_opts)?;
-    api::download_file(&download, out_file, &file.filename, true)?;
+    api::download_file(
+        &download,
+        out_file,
+        &file.filename,
+        ProgressFormat::None_,
+    )?;
 
     println!("Finished {}", file.filename);
 
@@ -0,0 +1,157 @@
+use crate::dxenv::{self, DxEnvironment};
+use anyhow::Result;
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use sha256::digest;
+use std::{
+    fs,
+    io::Write,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+/// Seconds a cached response stays fresh unless "--cache-ttl" says
+/// otherwise
+pub const DEFAULT_TTL: u64 = 30;
+
+/// States after which an object no longer changes, so responses in any
+/// other state (e.g., an open file or a running job) are never cached
+const SETTLED_STATES: &[&str] = &["closed", "done", "failed", "terminated"];
+
+/// Freshness of cached responses, unset (i.e., no caching) until
+/// "configure" is called
+static CACHE_TTL: OnceCell<Option<Duration>> = OnceCell::new();
+
+// --------------------------------------------------
+/// Turns the cache on or off for the life of the process
+pub fn configure(enabled: bool, ttl: u64) {
+    let _ = CACHE_TTL.set(enabled.then(|| Duration::from_secs(ttl)));
+}
+
+// --------------------------------------------------
+fn ttl() -> Option<Duration> {
+    CACHE_TTL.get().copied().flatten()
+}
+
+// --------------------------------------------------
+// Responses are grouped by object so that a change to a project or file
+// can drop every entry for it, whatever fields were requested
+fn entry_path(
+    dx_env: &DxEnvironment,
+    object_id: &str,
+    route: &str,
+    payload: &impl Serialize,
+) -> Result<PathBuf> {
+    let key = format!(
+        "{}\n{}\n{route}\n{}",
+        dx_env.api_url(),
+        digest(&dx_env.auth_token),
+        serde_json::to_string(payload)?
+    );
+    Ok(dxenv::cache_dir()?
+        .join(object_id)
+        .join(format!("{}.json", digest(key))))
+}
+
+// --------------------------------------------------
+/// The cached response body for a route on an object, if still fresh
+pub fn get(
+    dx_env: &DxEnvironment,
+    object_id: &str,
+    route: &str,
+    payload: &impl Serialize,
+) -> Option<String> {
+    let ttl = ttl()?;
+    let path = entry_path(dx_env, object_id, route, payload).ok()?;
+    let age = fs::metadata(&path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())?;
+
+    if age > ttl {
+        return None;
+    }
+
+    fs::read_to_string(path).ok()
+}
+
+// --------------------------------------------------
+/// Saves a response body unless it describes an object still changing.
+/// Failures are ignored as the cache is only an optimization.
+pub fn put(
+    dx_env: &DxEnvironment,
+    object_id: &str,
+    route: &str,
+    payload: &impl Serialize,
+    body: &str,
+) {
+    if ttl().is_none() || !is_settled(body) {
+        return;
+    }
+
+    let _ = entry_path(dx_env, object_id, route, payload).and_then(|path| {
+        let dir = path.parent().expect("cache entry has a parent");
+        fs::create_dir_all(dir)?;
+        let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
+        tmp.write_all(body.as_bytes())?;
+        tmp.persist(&path)?;
+        Ok(())
+    });
+}
+
+// --------------------------------------------------
+fn is_settled(body: &str) -> bool {
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(value) => match value.get("state").and_then(|s| s.as_str()) {
+            Some(state) => SETTLED_STATES.contains(&state),
+            _ => true,
+        },
+        _ => false,
+    }
+}
+
+// --------------------------------------------------
+/// Drops every cached response for the objects, e.g., after a change
+pub fn invalidate<S: AsRef<str>>(object_ids: &[S]) {
+    if let Ok(dir) = dxenv::cache_dir() {
+        for object_id in object_ids {
+            let _ = fs::remove_dir_all(dir.join(object_id.as_ref()));
+        }
+    }
+}
+
+// --------------------------------------------------
+/// Removes the whole cache and returns the number of entries removed
+pub fn clear() -> Result<usize> {
+    let dir = dxenv::cache_dir()?;
+    if !dir.is_dir() {
+        return Ok(0);
+    }
+
+    let mut num_entries = 0;
+    for object_dir in fs::read_dir(&dir)? {
+        let object_dir = object_dir?.path();
+        if object_dir.is_dir() {
+            num_entries += fs::read_dir(&object_dir)?.count();
+        }
+    }
+
+    fs::remove_dir_all(dir)?;
+    Ok(num_entries)
+}
+
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::is_settled;
+
+    #[test]
+    fn test_is_settled() {
+        assert!(is_settled(r#"{"id": "project-1", "name": "foo"}"#));
+        assert!(is_settled(r#"{"id": "file-1", "state": "closed"}"#));
+        assert!(is_settled(r#"{"id": "job-1", "state": "done"}"#));
+        assert!(!is_settled(r#"{"id": "file-1", "state": "open"}"#));
+        assert!(!is_settled(r#"{"id": "job-1", "state": "running"}"#));
+        assert!(!is_settled("not json"));
+    }
+}
@@ -0,0 +1,226 @@
+use crate::dxenv::get_dx_env;
+use crate::exit::CliError;
+use crate::{
+    api, entity_id, print_record, print_table, resolve_named_objects,
+    AppAccess, AppDescribeField, AppDescribeOptions, AppUserList,
+    AppletDescribeField, AppletDescribeOptions, ObjectType,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use std::collections::HashMap;
+use tabular::{Row, Table};
+
+#[derive(Clone, Parser, Debug)]
+pub struct AccessArgs {
+    /// App or applet name or ID
+    #[arg()]
+    executable: String,
+
+    /// Authorize a user, org, or "PUBLIC" to run the app
+    #[arg(long, value_name = "USER")]
+    add_user: Vec<String>,
+
+    /// Revoke a user's or org's authorization to run the app
+    #[arg(long, value_name = "USER")]
+    remove_user: Vec<String>,
+
+    /// Make a user or org a developer of the app
+    #[arg(long, value_name = "USER")]
+    add_developer: Vec<String>,
+
+    /// Remove a user or org from the app's developers
+    #[arg(long, value_name = "USER")]
+    remove_developer: Vec<String>,
+
+    /// Output JSON representation
+    #[arg(long, default_value = "false")]
+    json: bool,
+}
+
+// --------------------------------------------------
+pub fn access(args: AccessArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let executable = if entity_id::EXECUTABLE_ID.is_match(&args.executable) {
+        args.executable.clone()
+    } else {
+        resolve_named_objects(
+            &dx_env,
+            &args.executable,
+            &[ObjectType::Applet],
+            true,
+            false,
+        )?
+        .remove(0)
+    };
+    let (project_id, object_id) = match executable.split_once(':') {
+        Some((project_id, object_id)) => {
+            (Some(project_id.to_string()), object_id.to_string())
+        }
+        _ => (None, executable),
+    };
+
+    let changes = [
+        (AppUserList::AuthorizedUsers, true, &args.add_user),
+        (AppUserList::AuthorizedUsers, false, &args.remove_user),
+        (AppUserList::Developers, true, &args.add_developer),
+        (AppUserList::Developers, false, &args.remove_developer),
+    ];
+    let changing = changes.iter().any(|(_, _, users)| !users.is_empty());
+
+    if !object_id.starts_with("app-") {
+        if changing {
+            bail!(CliError::Usage(format!(
+                "Only apps have authorized users and developers, \
+                and {object_id} is not an app"
+            )));
+        }
+
+        let options = AppletDescribeOptions {
+            project: project_id,
+            fields: Some(HashMap::from([
+                (AppletDescribeField::Id, true),
+                (AppletDescribeField::Name, true),
+                (AppletDescribeField::Access, true),
+            ])),
+        };
+        let applet = api::describe_applet(&dx_env, &object_id, &options)?;
+        return print_access(
+            &applet.id,
+            applet.name.as_deref(),
+            applet.access,
+            None,
+            None,
+            args.json,
+        );
+    }
+
+    for (list, add, users) in changes {
+        if users.is_empty() {
+            continue;
+        }
+        let users: Vec<_> = users.iter().map(|u| app_user_id(u)).collect();
+        api::update_app_users(&dx_env, &object_id, list, add, &users)?;
+        note!(
+            "{} {} {} the {} of {object_id}",
+            if add { "Added" } else { "Removed" },
+            users.join(", "),
+            if add { "to" } else { "from" },
+            match list {
+                AppUserList::AuthorizedUsers => "authorized users",
+                AppUserList::Developers => "developers",
+            }
+        );
+    }
+
+    let options = AppDescribeOptions {
+        fields: HashMap::from([
+            (AppDescribeField::Id, true),
+            (AppDescribeField::Name, true),
+            (AppDescribeField::Version, true),
+            (AppDescribeField::Access, true),
+            (AppDescribeField::AuthorizedUsers, true),
+            (AppDescribeField::IsDeveloperFor, true),
+        ]),
+    };
+    let app = api::describe_app(&dx_env, &object_id, &options)?;
+
+    // Only developers may see who else develops the app
+    let developers = match app.is_developer_for {
+        Some(true) => {
+            Some(api::app_users(&dx_env, &app.id, AppUserList::Developers)?)
+        }
+        _ => None,
+    };
+    let name = match (&app.name, &app.version) {
+        (Some(name), Some(version)) => Some(format!("{name}/{version}")),
+        (name, _) => name.clone(),
+    };
+    print_access(
+        &app.id,
+        name.as_deref(),
+        app.access,
+        app.authorized_users,
+        developers,
+        args.json,
+    )
+}
+
+// --------------------------------------------------
+// Users are "user-" IDs unless they name an org or everyone
+pub(crate) fn app_user_id(user: &str) -> String {
+    if user == "PUBLIC" || user.starts_with("user-") || user.starts_with("org-")
+    {
+        user.to_string()
+    } else {
+        format!("user-{user}")
+    }
+}
+
+// --------------------------------------------------
+fn print_access(
+    id: &str,
+    name: Option<&str>,
+    access: Option<AppAccess>,
+    authorized_users: Option<Vec<String>>,
+    developers: Option<Vec<String>>,
+    show_json: bool,
+) -> Result<()> {
+    if show_json {
+        return print_record(&serde_json::json!({
+            "id": id,
+            "name": name,
+            "access": access,
+            "authorizedUsers": authorized_users,
+            "developers": developers,
+        }));
+    }
+
+    let list = |v: Option<Vec<String>>| {
+        v.and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+            .unwrap_or("-".to_string())
+    };
+    let flag = |v: Option<bool>| v.map_or("-".to_string(), |v| v.to_string());
+    let access = access.unwrap_or_default();
+    let mut table = Table::new("{:<}    {:<}");
+    table.add_row(Row::new().with_cell("ID").with_cell(id));
+    table.add_row(Row::new().with_cell("Name").with_cell(name.unwrap_or("NA")));
+    table.add_row(
+        Row::new()
+            .with_cell("Network")
+            .with_cell(list(Some(access.network))),
+    );
+    table.add_row(
+        Row::new()
+            .with_cell("Project")
+            .with_cell(access.project.unwrap_or("-".to_string())),
+    );
+    table.add_row(
+        Row::new()
+            .with_cell("All Projects")
+            .with_cell(access.all_projects.unwrap_or("-".to_string())),
+    );
+    table.add_row(
+        Row::new()
+            .with_cell("Developer")
+            .with_cell(flag(access.developer)),
+    );
+    table.add_row(
+        Row::new()
+            .with_cell("Project Creation")
+            .with_cell(flag(access.project_creation)),
+    );
+    if id.starts_with("app-") {
+        table.add_row(
+            Row::new()
+                .with_cell("Authorized Users")
+                .with_cell(list(authorized_users)),
+        );
+        table.add_row(
+            Row::new()
+                .with_cell("Developers")
+                .with_cell(list(developers)),
+        );
+    }
+    print_table(&table, true);
+    Ok(())
+}
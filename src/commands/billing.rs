@@ -0,0 +1,192 @@
+use crate::dxenv::get_dx_env;
+use crate::{
+    api, cost_breakdown, csv_quote, format_price, make_table, new_progress_bar,
+    output_format, print_records, print_table, search_time, theme, AccessLevel,
+    ExecutionCost, FindExecutionsOptions, FindProjectsDescribe,
+    FindProjectsOptions, OutputFormat, ProjectDescribeField,
+    UserDescribeOptions,
+};
+use anyhow::{anyhow, bail, Result};
+use chrono::Utc;
+use clap::Parser;
+use serde::Serialize;
+use size::Size;
+use std::{collections::HashMap, fs, path::PathBuf};
+
+#[derive(Clone, Parser, Debug)]
+pub struct BillingArgs {
+    /// Org or user billed for the projects, e.g., "org-xxxx"
+    #[arg()]
+    bill_to: String,
+
+    /// Count executions created at or after this time, e.g.,
+    /// "2024-01-01" or "-30d"
+    #[arg(long, value_name = "TIME")]
+    from: Option<String>,
+
+    /// Count executions created before this time
+    #[arg(long, value_name = "TIME")]
+    to: Option<String>,
+
+    /// Also write the report to a CSV file
+    #[arg(long, value_name = "FILE")]
+    csv: Option<PathBuf>,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct BillingRow {
+    pub(crate) project: String,
+    pub(crate) name: String,
+    pub(crate) executions: usize,
+    pub(crate) compute: f64,
+    pub(crate) egress: u64,
+}
+
+// --------------------------------------------------
+pub fn billing(args: BillingArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let payer = if args.bill_to.starts_with("org-") {
+        api::describe_org(&dx_env, &args.bill_to)?.name
+    } else if args.bill_to.starts_with("user-") {
+        let user = api::describe_user(
+            &dx_env,
+            &args.bill_to,
+            &UserDescribeOptions::default(),
+        )?;
+        user.handle
+    } else {
+        bail!(r#""{}" is not an org or user ID"#, args.bill_to)
+    };
+    let created = search_time(&args.from, &args.to, Utc::now())?;
+
+    let options = FindProjectsOptions {
+        name: None,
+        id: vec![],
+        level: Some(AccessLevel::View),
+        bill_to: Some(args.bill_to.clone()),
+        starting: None,
+        describe: Some(FindProjectsDescribe {
+            fields: HashMap::from([(ProjectDescribeField::Name, true)]),
+        }),
+    };
+    let projects = api::find_projects(&dx_env, options)?;
+
+    let pb = new_progress_bar(projects.len() as u64);
+    let mut rows = vec![];
+    let mut currency = None;
+    for project in &projects {
+        pb.inc(1);
+        let mut options = FindExecutionsOptions {
+            project: Some(project.id.clone()),
+            root_execution: None,
+            include_subjobs: false,
+            created: created.clone(),
+            class: None,
+            state: None,
+            describe: serde_json::json!({
+                "fields": {
+                    "id": true,
+                    "rootExecution": true,
+                    "totalPrice": true,
+                    "totalEgress": true,
+                    "currency": true,
+                }
+            }),
+            starting: None,
+        };
+        let executions: Vec<ExecutionCost> =
+            api::find_executions(&dx_env, &mut options)?
+                .into_iter()
+                .filter_map(|res| res.describe)
+                .collect();
+        if currency.is_none() {
+            currency = executions.iter().find_map(|e| e.currency.clone());
+        }
+
+        let costs = cost_breakdown(&executions, None);
+        rows.push(BillingRow {
+            project: project.id.clone(),
+            name: project.describe.get("name").cloned().unwrap_or_default(),
+            executions: costs.iter().map(|row| row.executions).sum(),
+            compute: costs.iter().map(|row| row.price).sum(),
+            egress: costs.iter().map(|row| row.egress).sum(),
+        });
+    }
+    pb.finish_and_clear();
+    rows.sort_by(|a, b| b.compute.total_cmp(&a.compute));
+
+    if let Some(path) = &args.csv {
+        fs::write(path, billing_csv(&rows))
+            .map_err(|e| anyhow!("{}: {e}", path.display()))?;
+        note!("Wrote {} projects to \"{}\"", rows.len(), path.display());
+    }
+
+    if output_format() != OutputFormat::Table {
+        return print_records(
+            &rows,
+            &["project", "name", "executions", "compute", "egress"],
+        );
+    }
+
+    println!(
+        "{}",
+        theme::heading(match payer {
+            Some(name) => format!("{name} ({})", args.bill_to),
+            _ => args.bill_to.clone(),
+        })
+    );
+    let mut table_rows = vec![vec![
+        "Project".to_string(),
+        "Name".to_string(),
+        "Executions".to_string(),
+        "Compute".to_string(),
+        "Egress".to_string(),
+    ]];
+    table_rows.extend(rows.iter().map(|row| {
+        vec![
+            row.project.clone(),
+            row.name.clone(),
+            row.executions.to_string(),
+            format_price(Some(row.compute), &currency),
+            Size::from_bytes(row.egress).to_string(),
+        ]
+    }));
+    table_rows.push(vec![
+        "Total".to_string(),
+        "".to_string(),
+        rows.iter()
+            .map(|row| row.executions)
+            .sum::<usize>()
+            .to_string(),
+        format_price(Some(rows.iter().map(|row| row.compute).sum()), &currency),
+        Size::from_bytes(rows.iter().map(|row| row.egress).sum::<u64>())
+            .to_string(),
+    ]);
+    print_table(
+        &make_table("{:<} {:<} {:>} {:>} {:>}", table_rows, 1, args.wide),
+        args.wide,
+    );
+    Ok(())
+}
+
+// --------------------------------------------------
+// Egress is in bytes and compute has no currency symbol so that
+// spreadsheets can sum the columns
+pub(crate) fn billing_csv(rows: &[BillingRow]) -> String {
+    let mut csv = "project,name,executions,compute,egress_bytes\n".to_string();
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{:.2},{}\n",
+            row.project,
+            csv_quote(&row.name),
+            row.executions,
+            row.compute,
+            row.egress
+        ));
+    }
+    csv
+}
@@ -0,0 +1,751 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::exit::CliError;
+use crate::json_parser::{
+    lint_app_https_app, DxApp, HttpsApp, RegionalOptions, SystemRequirements,
+    VALID_REGION,
+};
+use crate::{
+    api, check_instance_type, dxenv, entity_id, format_watch_message,
+    json_parser, local_md5s, upload_source, AppHttpsApp, ChunkReader,
+    ChunkWriter, FileDescribeField, FileDescribeOptions, FindDataOptions,
+    FindDataScope, FindDescribe, FindName, ObjectType, ProgressFormat,
+    ProjectDescribeField, ProjectDescribeOptions, ProjectPath, RunOptions,
+    UploadProgress, UploadSource, WatchOptions, ARCHIVE_CHUNKS,
+    ARCHIVE_CHUNK_SIZE,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{self, BufWriter},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+};
+use tar::Builder;
+
+const REMOTE_BUILDER_APP: &str = "app-tarball_applet_builder";
+
+/// Name of the uploaded tarball of an applet's "resources" directory
+const RESOURCES_ARCHIVE: &str = "resources.tar.gz";
+
+#[derive(Clone, Parser, Debug)]
+pub struct BuildArgs {
+    /// Directory name of applet or asset resources
+    #[arg(default_value = ".")]
+    src: String,
+
+    /// Destination for built applet
+    #[arg(short, long)]
+    destination: Option<String>,
+
+    /// Overwrite an existing applet
+    #[arg(short, long, default_value = "false")]
+    force: bool,
+
+    /// Build on the platform using the builder app
+    #[arg(long, default_value = "false")]
+    remote: bool,
+
+    /// Region to build for, repeat for several, defaults to those in
+    /// "regionalOptions" or else the destination project's region
+    #[arg(long, value_name = "REGION")]
+    region: Vec<String>,
+
+    /// Project for the resources of a region other than the
+    /// destination's, e.g., "aws:eu-central-1=project-xxxx"
+    #[arg(long, value_name = "REGION=PROJECT")]
+    region_project: Vec<String>,
+}
+
+/// The fingerprint of an applet's "resources" and the bundle of them
+/// uploaded to each project, so that a rebuild skips only the upload
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct BuildCache {
+    resources: String,
+
+    bundles: BTreeMap<String, String>,
+}
+
+// --------------------------------------------------
+pub fn build(args: BuildArgs) -> Result<()> {
+    let src_dir = Path::new(&args.src);
+    if !src_dir.is_dir() {
+        bail!(r#""{}" is not a directory"#, src_dir.display());
+    }
+
+    let app_json = src_dir.join("dxapp.json");
+    if !app_json.is_file() {
+        bail!(CliError::NotFound(format!(
+            r#"Cannot find "{}""#,
+            app_json.display()
+        )))
+    }
+
+    let dx_env = get_dx_env()?;
+    let mut app: DxApp = json_parser::parse(&app_json.display().to_string())?;
+    let destination = &args
+        .destination
+        .clone()
+        .unwrap_or(dx_env.cli_wd.to_string());
+    // A destination of only "project-xxxx:" is the root of that project
+    let caps = entity_id::OPTIONAL_PROJECT_PATH.captures(destination);
+    let project_id = caps
+        .as_ref()
+        .and_then(|caps| caps.get(1))
+        .map_or(dx_env.project_context_id.as_str(), |val| val.as_str());
+    let mut folder = caps
+        .as_ref()
+        .and_then(|caps| caps.get(2))
+        .map_or(destination.as_str(), |val| val.as_str())
+        .to_string();
+
+    if !folder.starts_with('/') {
+        folder = format!("/{folder}")
+    }
+
+    let project_opts = ProjectDescribeOptions {
+        fields: Some(HashMap::from([(ProjectDescribeField::Region, true)])),
+    };
+    let project_region =
+        api::describe_project(&dx_env, project_id, &project_opts)?
+            .region
+            .unwrap_or_default();
+    let regions = build_regions(&args.region, &app, &project_region);
+    let mut problems: Vec<_> = regions
+        .iter()
+        .flat_map(|region| app_region_problems(&app, region))
+        .collect();
+    if let Some(https_app) = &app.https_app {
+        problems.extend(lint_app_https_app(https_app));
+    }
+    if !problems.is_empty() {
+        bail!(problems.join("\n"))
+    }
+
+    // Resources live in a project in each region
+    let mut region_projects =
+        HashMap::from([(project_region.clone(), project_id.to_string())]);
+    for val in &args.region_project {
+        match val.split_once('=') {
+            Some((region, project))
+                if entity_id::PROJECT_ID.is_match(project) =>
+            {
+                region_projects.insert(region.to_string(), project.to_string());
+            }
+            _ => bail!(r#"Expected "REGION=PROJECT", got "{val}""#),
+        }
+    }
+    if let Some(region) =
+        regions.iter().find(|r| !region_projects.contains_key(*r))
+    {
+        bail!(
+            r#"Use "--region-project {region}=PROJECT" for resources in "{region}""#
+        )
+    }
+
+    println!(
+        r#"Building from "{}" to "{project_id}:{folder}" for {}"#,
+        src_dir.display(),
+        regions.join(", ")
+    );
+
+    // Find if there is an existing applet
+    let mut options = FindDataOptions {
+        class: Some(ObjectType::Applet),
+        state: None,
+        name: Some(FindName::Regexp(app.name.clone().unwrap_or_default())),
+        visibility: None,
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(project_id.to_string()),
+            folder: Some("/".to_string()),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Boolean(true)),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+    let applets = api::find_data(&dx_env, &mut options)?;
+
+    if !applets.is_empty() && !args.force {
+        let applet_id = applets
+            .first()
+            .and_then(|a| a.describe.clone().map(|d| d.id))
+            .unwrap_or("NA".to_string());
+
+        bail!(
+            "Applet ({}) already exists. Use -f|--force to overwrite",
+            applet_id
+        );
+    }
+
+    if args.remote {
+        return build_remote(
+            &dx_env, src_dir, project_id, &folder, &regions, args.force,
+        );
+    }
+
+    // Tar "resources" directory
+    let resources_dir = src_dir.join("resources");
+    if resources_dir.is_dir()
+        && !resources_dir.read_dir()?.collect::<Vec<_>>().is_empty()
+    {
+        // Bundles from an earlier build of the same contents are reused
+        let ignore = DxIgnore::load(src_dir)?;
+        let resources_prefix = Path::new("resources");
+        let fingerprint =
+            dir_fingerprint(&resources_dir, resources_prefix, &ignore)?;
+        let mut build_cache = read_build_cache(src_dir)
+            .filter(|cache| cache.resources == fingerprint)
+            .unwrap_or(BuildCache {
+                resources: fingerprint,
+                bundles: BTreeMap::new(),
+            });
+        let reused: HashMap<&String, String> = regions
+            .iter()
+            .filter_map(|region| {
+                let project = &region_projects[region];
+                build_cache
+                    .bundles
+                    .get(project)
+                    .filter(|file_id| is_closed_file(&dx_env, project, file_id))
+                    .map(|file_id| (region, file_id.clone()))
+            })
+            .collect();
+
+        if reused.len() < regions.len() {
+            println!(r#"Adding "{}""#, resources_dir.display());
+        }
+
+        // Each region gets its own copy of the resources
+        let mut regional_options =
+            app.regional_options.take().unwrap_or_default();
+        let defaults = regional_options.remove("*");
+        let mut expanded = HashMap::new();
+        for region in &regions {
+            let file_id = match reused.get(region) {
+                Some(file_id) => {
+                    println!("{region}: resources unchanged, using {file_id}");
+                    file_id.clone()
+                }
+                _ => {
+                    let destination = ProjectPath {
+                        project_id: region_projects[region].clone(),
+                        path: "/".to_string().into(),
+                    };
+                    let file_id = upload_directory(
+                        &dx_env,
+                        &resources_dir,
+                        resources_prefix,
+                        &ignore,
+                        RESOURCES_ARCHIVE,
+                        &destination,
+                    )?;
+                    println!("{region}: {RESOURCES_ARCHIVE} => {file_id}");
+                    file_id
+                }
+            };
+            build_cache
+                .bundles
+                .insert(region_projects[region].clone(), file_id.clone());
+
+            let system_requirements = regional_options
+                .remove(region)
+                .map(|opts| opts.system_requirements)
+                .filter(|reqs| !reqs.is_empty())
+                .or_else(|| {
+                    defaults.as_ref().map(|opts| {
+                        opts.system_requirements
+                            .iter()
+                            .map(|(entry, req)| {
+                                (
+                                    entry.clone(),
+                                    SystemRequirements {
+                                        instance_type: req
+                                            .instance_type
+                                            .clone(),
+                                        cluster_spec: None,
+                                    },
+                                )
+                            })
+                            .collect()
+                    })
+                })
+                .unwrap_or_else(|| app.run_spec.system_requirements.clone());
+            expanded.insert(
+                region.clone(),
+                RegionalOptions {
+                    resources: Some(file_id),
+                    system_requirements,
+                },
+            );
+        }
+        println!(
+            "{}",
+            serde_json::to_string_pretty(
+                &serde_json::json!({ "regionalOptions": expanded })
+            )?
+        );
+
+        if let Err(e) = write_build_cache(src_dir, &build_cache) {
+            eprintln!("Cannot save the resources fingerprint: {e}");
+        }
+    } else {
+        println!("Nothing in resources")
+    }
+
+    if let Some(https_app) = &app.https_app {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(
+                &serde_json::json!({ "httpsApp": https_app_spec(https_app) })
+            )?
+        );
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// The "httpsApp" of dxapp.json as the platform takes it for a new applet
+fn https_app_spec(https_app: &HttpsApp) -> AppHttpsApp {
+    AppHttpsApp {
+        ports: Some(https_app.ports.clone()),
+        shared_access: Some(https_app.shared_access.clone()),
+        dns: https_app.dns.as_ref().map(|dns| {
+            HashMap::from([("hostname".to_string(), dns.hostname.clone())])
+        }),
+        enabled: None,
+    }
+}
+
+// --------------------------------------------------
+// An MD5 over the relative path, permissions, and contents of everything
+// under a directory that would be archived, visited in sorted order so
+// that it is stable
+pub(crate) fn dir_fingerprint(
+    dir: &Path,
+    prefix: &Path,
+    ignore: &DxIgnore,
+) -> Result<String> {
+    let mut fingerprint = md5::Context::new();
+    for entry in walk_unignored(dir, prefix, ignore) {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        let rel_path = entry.path().strip_prefix(dir)?;
+        fingerprint.consume(rel_path.to_string_lossy().as_bytes());
+        fingerprint.consume(format!("\0{:o}\0", file_mode(&metadata)));
+        if entry.path_is_symlink() {
+            let target = fs::read_link(entry.path())?;
+            fingerprint.consume(target.to_string_lossy().as_bytes());
+        } else if metadata.is_file() {
+            let (md5, _) = local_md5s(File::open(entry.path())?, &[])?;
+            fingerprint.consume(md5.as_bytes());
+        }
+        fingerprint.consume(b"\n");
+    }
+    Ok(format!("{:x}", fingerprint.compute()))
+}
+
+// --------------------------------------------------
+#[cfg(unix)]
+fn file_mode(metadata: &fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode()
+}
+
+// --------------------------------------------------
+#[cfg(not(unix))]
+fn file_mode(metadata: &fs::Metadata) -> u32 {
+    u32::from(metadata.permissions().readonly())
+}
+
+// --------------------------------------------------
+// Build caches are kept by source directory outside of it so that they
+// are never bundled or committed with the applet
+fn build_cache_path(src_dir: &Path) -> Result<PathBuf> {
+    let src_dir = fs::canonicalize(src_dir)?;
+    let key =
+        format!("{:x}", md5::compute(src_dir.to_string_lossy().as_bytes()));
+    Ok(dxenv::builds_dir()?.join(format!("{key}.json")))
+}
+
+// --------------------------------------------------
+fn read_build_cache(src_dir: &Path) -> Option<BuildCache> {
+    let path = build_cache_path(src_dir).ok()?;
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+// --------------------------------------------------
+fn write_build_cache(src_dir: &Path, cache: &BuildCache) -> Result<()> {
+    let path = build_cache_path(src_dir)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(cache)?)?;
+    Ok(())
+}
+
+// --------------------------------------------------
+// A cached bundle may have been removed since it was uploaded
+fn is_closed_file(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    file_id: &str,
+) -> bool {
+    let options = FileDescribeOptions {
+        project: Some(project_id.to_string()),
+        fields: Some(HashMap::from([(FileDescribeField::State, true)])),
+        properties: false,
+        details: false,
+    };
+    api::describe_file(dx_env, file_id, &options)
+        .is_ok_and(|desc| desc.state.as_deref() == Some("closed"))
+}
+
+// --------------------------------------------------
+// Regions named on the command line, else those configured for the
+// applet, else the one region of the destination project
+pub(crate) fn build_regions(
+    requested: &[String],
+    app: &DxApp,
+    project_region: &str,
+) -> Vec<String> {
+    let mut regions: Vec<String> = if !requested.is_empty() {
+        requested.to_vec()
+    } else {
+        app.regional_options
+            .iter()
+            .flat_map(|opts| opts.keys())
+            .filter(|region| *region != "*")
+            .cloned()
+            .collect()
+    };
+
+    if regions.is_empty() {
+        regions.push(project_region.to_string());
+    }
+    regions.sort();
+    regions.dedup();
+    regions
+}
+
+// --------------------------------------------------
+// Unknown regions and instance types not offered in the region, using
+// the "*" options for regions without their own, then those in runSpec
+pub(crate) fn app_region_problems(app: &DxApp, region: &str) -> Vec<String> {
+    if !VALID_REGION.contains(&region) || region == "*" {
+        return vec![format!(r#"Invalid region "{region}""#)];
+    }
+
+    let reqs = app
+        .regional_options
+        .as_ref()
+        .and_then(|opts| opts.get(region).or(opts.get("*")))
+        .map(|opts| &opts.system_requirements)
+        .filter(|reqs| !reqs.is_empty())
+        .unwrap_or(&app.run_spec.system_requirements);
+
+    let mut problems: Vec<_> = reqs
+        .iter()
+        .filter_map(|(entry_point, req)| {
+            check_instance_type(region, &req.instance_type)
+                .map(|problem| format!("{entry_point}: {problem}"))
+        })
+        .collect();
+    problems.sort();
+    problems
+}
+
+// --------------------------------------------------
+fn build_remote(
+    dx_env: &DxEnvironment,
+    src_dir: &Path,
+    project_id: &str,
+    folder: &str,
+    regions: &[String],
+    force: bool,
+) -> Result<()> {
+    // Ship the whole source directory to the builder app so that
+    // any resources are compiled on the target Ubuntu release
+    let basename = src_dir
+        .canonicalize()?
+        .file_name()
+        .map_or("applet".to_string(), |n| n.to_string_lossy().to_string());
+    let archive = format!("{basename}.tar.gz");
+    println!(r#"Archiving "{}""#, src_dir.display());
+    let ignore = DxIgnore::load(src_dir)?;
+    let destination = ProjectPath {
+        project_id: project_id.to_string(),
+        path: "/".to_string().into(),
+    };
+    let file_id = upload_directory(
+        dx_env,
+        src_dir,
+        Path::new(""),
+        &ignore,
+        &archive,
+        &destination,
+    )?;
+    println!("{archive} => {file_id}");
+
+    let mut build_options =
+        vec![format!("--destination={project_id}:{folder}")];
+    build_options.extend(regions.iter().map(|r| format!("--region={r}")));
+    if force {
+        build_options.push("--overwrite".to_string());
+    }
+
+    let run_opts = RunOptions {
+        input: serde_json::json!({
+            "input_file": { "$dnanexus_link": file_id },
+            "build_options": build_options.join(" "),
+        }),
+        project: Some(project_id.to_string()),
+        folder: None,
+        name: Some(format!("Remote build of {basename}")),
+        tags: vec![],
+        system_requirements: HashMap::new(),
+        allow_ssh: vec![],
+        stage_system_requirements: HashMap::new(),
+    };
+
+    let job = api::run(dx_env, REMOTE_BUILDER_APP, &run_opts)?;
+    println!("Started builder job {}", job.id);
+
+    let watch_opts = WatchOptions {
+        num_recent_messages: None,
+        recurse_jobs: Some(false),
+        tail: Some(true),
+        levels: vec![],
+    };
+    api::watch(dx_env, &job.id, &watch_opts, |msg| {
+        println!("{}", format_watch_message(&msg, true, false));
+        Ok(true)
+    })?;
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// Tars and gzips a directory straight into a new file's upload parts
+// from another thread, so no archive is written to disk
+fn upload_directory(
+    dx_env: &DxEnvironment,
+    dir: &Path,
+    prefix: &Path,
+    ignore: &DxIgnore,
+    name: &str,
+    destination: &ProjectPath,
+) -> Result<String> {
+    let (sender, chunks) = mpsc::sync_channel(ARCHIVE_CHUNKS);
+    thread::scope(|scope| {
+        scope.spawn(move || {
+            let writer = BufWriter::with_capacity(
+                ARCHIVE_CHUNK_SIZE,
+                ChunkWriter(sender.clone()),
+            );
+            if let Err(e) = tar_directory(dir, prefix, ignore, writer) {
+                // The reader may already be gone if the upload failed
+                let _ = sender.send(Err(io::Error::other(e.to_string())));
+            }
+        });
+
+        let source = UploadSource {
+            label: dir.display().to_string(),
+            name: name.to_string(),
+            size: None,
+            reader: ChunkReader {
+                chunks,
+                chunk: io::Cursor::new(vec![]),
+            },
+        };
+        upload_source(
+            dx_env,
+            source,
+            destination,
+            None,
+            &[],
+            &UploadProgress::new(ProgressFormat::Bar, 0, 1),
+            true,
+        )
+    })
+}
+
+// --------------------------------------------------
+// Archives everything under "dir" that the ".dxignore" patterns allow,
+// where "prefix" is the path of "dir" inside the applet directory
+pub(crate) fn tar_directory(
+    dir: &Path,
+    prefix: &Path,
+    ignore: &DxIgnore,
+    writer: impl io::Write,
+) -> Result<()> {
+    let enc = GzEncoder::new(writer, Compression::default());
+    let mut tarball = Builder::new(enc);
+    for entry in walk_unignored(dir, prefix, ignore).skip(1) {
+        let entry = entry?;
+        let rel_path = entry.path().strip_prefix(dir)?;
+        tarball.append_path_with_name(entry.path(), rel_path)?;
+    }
+    tarball.into_inner()?.finish()?.flush()?;
+    Ok(())
+}
+
+// --------------------------------------------------
+// Everything under "dir" in sorted order, starting with "dir" itself,
+// without descending into ignored directories
+fn walk_unignored<'a>(
+    dir: &Path,
+    prefix: &'a Path,
+    ignore: &'a DxIgnore,
+) -> impl Iterator<Item = walkdir::Result<walkdir::DirEntry>> + 'a {
+    let root = dir.to_path_buf();
+    walkdir::WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(move |entry| {
+            entry.depth() == 0
+                || entry.path().strip_prefix(&root).map_or(true, |rel_path| {
+                    !ignore.is_ignored(
+                        &prefix.join(rel_path),
+                        entry.file_type().is_dir(),
+                    )
+                })
+        })
+}
+
+/// Patterns in ".dxignore" in the applet directory, written like
+/// ".gitignore," for files such as virtualenvs, ".git," and test data
+/// that should not be archived and shipped to the platform
+#[derive(Debug, Default)]
+pub(crate) struct DxIgnore {
+    rules: Vec<IgnoreRule>,
+}
+
+#[derive(Debug)]
+struct IgnoreRule {
+    regex: Regex,
+    negate: bool,
+    dir_only: bool,
+}
+
+impl DxIgnore {
+    fn load(src_dir: &Path) -> Result<Self> {
+        let path = src_dir.join(".dxignore");
+        if !path.is_file() {
+            return Ok(DxIgnore::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| anyhow!("{}: {e}", path.display()))?;
+        DxIgnore::parse(&contents)
+            .map_err(|e| anyhow!("{}: {e}", path.display()))
+    }
+
+    pub(crate) fn parse(contents: &str) -> Result<Self> {
+        let mut rules = vec![];
+        for line in contents.lines() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (negate, pattern) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                _ => (false, line),
+            };
+            let (dir_only, pattern) = match pattern.strip_suffix('/') {
+                Some(rest) => (true, rest),
+                _ => (false, pattern),
+            };
+            rules.push(IgnoreRule {
+                regex: ignore_pattern_to_regex(pattern)?,
+                negate,
+                dir_only,
+            });
+        }
+        Ok(DxIgnore { rules })
+    }
+
+    // The last matching pattern wins so that "!" can bring back a path
+    pub(crate) fn is_ignored(&self, rel_path: &Path, is_dir: bool) -> bool {
+        let path = rel_path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| {
+                (is_dir || !rule.dir_only) && rule.regex.is_match(&path)
+            })
+            .is_some_and(|rule| !rule.negate)
+    }
+}
+
+// --------------------------------------------------
+// A pattern with a slash is relative to the applet directory; one
+// without matches a name at any depth. "**/" matches any number of
+// directories and a trailing "**" everything inside.
+fn ignore_pattern_to_regex(pattern: &str) -> Result<Regex> {
+    let anchored = pattern.contains('/');
+    let glob = pattern.strip_prefix('/').unwrap_or(pattern);
+    let mut regex = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.next_if_eq(&'*').is_some() => {
+                if chars.next_if_eq(&'/').is_some() {
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '[' => {
+                regex.push('[');
+                if chars.next_if_eq(&'!').is_some() {
+                    regex.push('^');
+                }
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    if c == '\\' {
+                        regex.push('\\');
+                    }
+                    regex.push(c);
+                }
+                regex.push(']');
+            }
+            '\\' => {
+                if let Some(c) = chars.next() {
+                    regex.push_str(&regex::escape(&c.to_string()));
+                }
+            }
+            _ => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+
+    Regex::new(&regex).map_err(|e| anyhow!(r#"Bad pattern "{pattern}": {e}"#))
+}
@@ -0,0 +1,12 @@
+use crate::cache;
+use anyhow::Result;
+
+// --------------------------------------------------
+pub fn cache_clear() -> Result<()> {
+    let num_entries = cache::clear()?;
+    println!(
+        "Removed {num_entries} cached response{}",
+        if num_entries == 1 { "" } else { "s" }
+    );
+    Ok(())
+}
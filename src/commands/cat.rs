@@ -0,0 +1,45 @@
+use crate::dxenv::get_dx_env;
+use crate::{api, resolve_file_id, DownloadOptions, ProgressFormat};
+use anyhow::Result;
+use clap::Parser;
+use flate2::write::MultiGzDecoder;
+use std::io;
+
+#[derive(Clone, Parser, Debug)]
+pub struct CatArgs {
+    /// File paths or IDs
+    #[arg(required = true)]
+    paths: Vec<String>,
+
+    /// Decompress gzip/BGZF files, e.g., ".gz" or ".bgz"
+    #[arg(short('z'), long)]
+    decompress: bool,
+}
+
+// --------------------------------------------------
+pub fn cat(args: CatArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    for path in &args.paths {
+        let file_id = resolve_file_id(&dx_env, path)?;
+        let download =
+            api::download(&dx_env, &file_id, &DownloadOptions::default())?;
+        if args.decompress {
+            let mut decoder = MultiGzDecoder::new(io::stdout());
+            api::download_file(
+                &download,
+                &mut decoder,
+                path,
+                ProgressFormat::None_,
+            )?;
+            decoder.try_finish()?;
+        } else {
+            api::download_file(
+                &download,
+                io::stdout(),
+                path,
+                ProgressFormat::None_,
+            )?;
+        }
+    }
+    Ok(())
+}
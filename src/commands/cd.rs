@@ -0,0 +1,100 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{
+    api, dxenv, normalize_remote_path, subfolders, ListFolderOptionOnlyValue,
+    ListFolderOptions,
+};
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use inquire::Text;
+use std::path::Path;
+
+#[derive(Clone, Parser, Debug)]
+pub struct CdArgs {
+    /// Directory name, "-" for the previous one, or "/" if omitted
+    #[arg()]
+    dirname: Option<String>,
+
+    /// Choose the directory at a prompt that completes names with Tab
+    #[arg(short, long, default_value = "false", conflicts_with = "dirname")]
+    interactive: bool,
+}
+
+// --------------------------------------------------
+pub fn cd(args: CdArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let dirname = if args.interactive {
+        let completer_env = dx_env.clone();
+        let name = Text::new("Directory:")
+            .with_help_message("Tab completes folder names")
+            .with_autocomplete(move |input: &str| {
+                Ok(folder_suggestions(&completer_env, input))
+            })
+            .prompt()?;
+        Some(name)
+    } else {
+        args.dirname.clone()
+    };
+    let folder = &cd_target(&dx_env, dirname.as_deref())?;
+
+    let options = ListFolderOptions {
+        folder,
+        only: Some(ListFolderOptionOnlyValue::All),
+        describe: false,
+        has_subfolder_flags: true,
+        include_hidden: false,
+    };
+
+    let _ = api::ls(&dx_env, &dx_env.project_context_id, options)?;
+    let new_env = DxEnvironment {
+        cli_wd: folder.clone(),
+        previous_wd: Some(dx_env.cli_wd.clone()),
+        ..dx_env
+    };
+
+    dxenv::save_dx_env(&new_env)?;
+    println!(
+        "Changed working directory to \"{}:{}\"",
+        &new_env.project_context_id, &new_env.cli_wd
+    );
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// The folder "cd" moves to, where "-" is the one before the last "cd"
+pub(crate) fn cd_target(
+    dx_env: &DxEnvironment,
+    dirname: Option<&str>,
+) -> Result<String> {
+    match dirname {
+        Some("-") => dx_env
+            .previous_wd
+            .clone()
+            .ok_or(anyhow!("No previous working directory")),
+        Some(name) => Ok(normalize_remote_path(&dx_env.cli_wd, name)),
+        _ => Ok("/".to_string()),
+    }
+}
+
+// --------------------------------------------------
+// Folders starting with the text typed so far, searched in the folder
+// named by the text when it ends at a "/" and its parent otherwise
+fn folder_suggestions(dx_env: &DxEnvironment, input: &str) -> Vec<String> {
+    let typed = normalize_remote_path(&dx_env.cli_wd, input);
+    let is_dir = input.is_empty()
+        || input.ends_with('/')
+        || matches!(input.rsplit('/').next(), Some(".") | Some(".."));
+    let parent = if is_dir {
+        typed.clone()
+    } else {
+        Path::new(&typed)
+            .parent()
+            .map_or("/".to_string(), |dir| dir.display().to_string())
+    };
+
+    subfolders(dx_env, &dx_env.project_context_id, &parent)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|folder| folder.starts_with(&typed))
+        .collect()
+}
@@ -0,0 +1,134 @@
+use crate::dxenv::get_dx_env;
+use crate::{
+    api, listing_table, parse_duration, print_table, FindDataOptions,
+    FindDataScope, FindDescribe, ListColumn, ListingRow, ObjectState,
+    ObjectType, RmOptions, SearchTime, Visibility, WhoAmIOptions,
+    WhoAmIOptionsFields,
+};
+use anyhow::{bail, Result};
+use chrono::Utc;
+use clap::Parser;
+use inquire::Confirm;
+use std::{collections::HashMap, path::Path};
+
+#[derive(Clone, Parser, Debug)]
+pub struct CleanArgs {
+    /// Remove files you left open by interrupted uploads
+    #[arg(long, default_value = "false")]
+    open_uploads: bool,
+
+    /// Only files last modified at least this long ago, e.g., "6h" or
+    /// "2d", so that uploads and job outputs still being written are kept
+    #[arg(long, value_name = "DURATION", default_value = "1d")]
+    older_than: String,
+
+    /// Project ID, defaults to the current project
+    #[arg(short, long)]
+    project: Option<String>,
+
+    /// Remove without prompting
+    #[arg(short, long, default_value = "false")]
+    yes: bool,
+}
+
+// --------------------------------------------------
+pub fn clean(args: CleanArgs) -> Result<()> {
+    if !args.open_uploads {
+        bail!("Nothing to clean, e.g., use --open-uploads")
+    }
+
+    let age = parse_duration(&args.older_than)?;
+    let dx_env = get_dx_env()?;
+    let project_id = args.project.unwrap_or(dx_env.project_context_id.clone());
+
+    // Other users' files are theirs to clean up
+    let options = WhoAmIOptions {
+        fields: Some(HashMap::from([(WhoAmIOptionsFields::ClientIp, true)])),
+    };
+    let user = api::whoami(&dx_env, &options)?;
+    let cutoff = Utc::now().timestamp_millis() - age as i64 * 1000;
+
+    let mut options = FindDataOptions {
+        class: Some(ObjectType::File),
+        state: Some(ObjectState::Open),
+        name: None,
+        visibility: Some(Visibility::Either),
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(project_id.clone()),
+            folder: Some("/".to_string()),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: Some(SearchTime {
+            after: None,
+            before: Some(cutoff),
+        }),
+        created: None,
+        describe: Some(FindDescribe::Boolean(true)),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+
+    let rows: Vec<ListingRow> = api::find_data(&dx_env, &mut options)?
+        .into_iter()
+        .filter_map(|file| file.describe)
+        .filter(|desc| {
+            desc.created_by
+                .as_ref()
+                .is_some_and(|created_by| created_by.user == user.id)
+        })
+        .map(|desc| {
+            let folder = desc.folder.unwrap_or("".to_string());
+            ListingRow {
+                name: Path::new(&folder)
+                    .join(desc.name.unwrap_or("".to_string()))
+                    .display()
+                    .to_string(),
+                id: desc.id,
+                state: "open".to_string(),
+                size: desc.size,
+                modified: desc.modified,
+            }
+        })
+        .collect();
+
+    if rows.is_empty() {
+        println!(
+            "No open uploads of yours older than {} in {project_id}",
+            args.older_than
+        );
+        return Ok(());
+    }
+
+    let columns = [ListColumn::Modified, ListColumn::Name, ListColumn::Id];
+    print_table(&listing_table(&rows, &columns, true, true, false), false);
+
+    let num = rows.len();
+    let consent = args.yes
+        || Confirm::new(&format!(
+            "Remove {num} open upload{}?",
+            if num == 1 { "" } else { "s" }
+        ))
+        .with_default(false)
+        .prompt()
+        .unwrap_or(false);
+
+    if consent {
+        let options = RmOptions {
+            objects: rows.into_iter().map(|row| row.id).collect(),
+            force: Some(true),
+        };
+        api::rm(&dx_env, &project_id, &options)?;
+        println!("Removed {num} file{}", if num == 1 { "" } else { "s" });
+    }
+
+    Ok(())
+}
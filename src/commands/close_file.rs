@@ -0,0 +1,105 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{
+    api, resolve_file_id, FileCloseOptions, FileDescribeField,
+    FileDescribeOptions,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use std::{
+    collections::HashMap,
+    thread,
+    time::{Duration, Instant},
+};
+
+const CLOSE_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Clone, Parser, Debug)]
+pub struct CloseFileArgs {
+    /// File paths or IDs
+    #[arg(required = true)]
+    paths: Vec<String>,
+
+    /// Wait until the files are closed
+    #[arg(long)]
+    wait: bool,
+}
+
+// --------------------------------------------------
+pub fn close_file(args: CloseFileArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    for path in &args.paths {
+        let file_id = resolve_file_id(&dx_env, path)?;
+        if file_state(&dx_env, &file_id)?.as_deref() == Some("open") {
+            close_remote_file(&dx_env, &file_id, args.wait)?;
+        } else if args.wait {
+            wait_on_close(&dx_env, &file_id)?;
+        }
+        println!("{} {file_id}", if args.wait { "Closed" } else { "Closing" });
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+/// Close a file, optionally waiting for it to reach the "closed" state
+/// so that later steps do not race against it
+pub fn close_remote_file(
+    dx_env: &DxEnvironment,
+    file_id: &str,
+    wait: bool,
+) -> Result<()> {
+    let close_opts = FileCloseOptions {
+        id: file_id.to_string(),
+    };
+    api::file_close(dx_env, file_id, &close_opts)?;
+
+    if wait {
+        wait_on_close(dx_env, file_id)?;
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+/// Poll a file until it is closed
+pub fn wait_on_close(dx_env: &DxEnvironment, file_id: &str) -> Result<()> {
+    let start = Instant::now();
+    for attempt in 0.. {
+        if file_is_closed(file_state(dx_env, file_id)?.as_deref())? {
+            break;
+        }
+
+        if start.elapsed() > CLOSE_WAIT_TIMEOUT {
+            bail!("Timed out waiting for {file_id} to close");
+        }
+        thread::sleep(close_poll_delay(attempt));
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+pub(crate) fn file_state(
+    dx_env: &DxEnvironment,
+    file_id: &str,
+) -> Result<Option<String>> {
+    let options = FileDescribeOptions {
+        project: None,
+        fields: Some(HashMap::from([(FileDescribeField::State, true)])),
+        details: false,
+        properties: false,
+    };
+    Ok(api::describe_file(dx_env, file_id, &options)?.state)
+}
+
+// --------------------------------------------------
+pub(crate) fn file_is_closed(state: Option<&str>) -> Result<bool> {
+    match state {
+        Some("closed") => Ok(true),
+        Some("open") | Some("closing") => Ok(false),
+        Some(other) => bail!(r#"Unexpected file state "{other}""#),
+        None => bail!("Missing file state"),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn close_poll_delay(attempt: u32) -> Duration {
+    Duration::from_secs(2u64.saturating_pow(attempt).min(10))
+}
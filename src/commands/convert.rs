@@ -0,0 +1,364 @@
+use crate::json_parser::{
+    DxApp, DxAsset, InputOutputClass, InputSpec, Interpreter,
+    LinuxDistribution, LinuxRelease, LinuxVersion, OutputSpec, RegionalOptions,
+    RunSpec, SystemRequirements,
+};
+use crate::{wdl_template, write_formatted, DXAPI_VERSION};
+use anyhow::{anyhow, bail, Result};
+use clap::{builder::PossibleValue, Parser, ValueEnum};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+#[derive(Clone, Parser, Debug)]
+pub struct ConvertArgs {
+    /// dxapp.json, dxasset.json or WDL file, or "-" for STDIN
+    #[arg()]
+    filename: String,
+
+    /// Output file
+    #[arg(short, long, default_value = "-")]
+    output: String,
+
+    /// Kind of input, guessed from the filename or contents if not given
+    #[arg(short, long, value_name = "KIND")]
+    from: Option<ConvertFrom>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConvertFrom {
+    /// dxapp.json to a WDL task
+    Dxapp,
+    /// dxasset.json to an execDepends snippet for a dxapp.json
+    Dxasset,
+    /// WDL task to a dxapp.json skeleton
+    Wdl,
+}
+
+impl ValueEnum for ConvertFrom {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[ConvertFrom::Dxapp, ConvertFrom::Dxasset, ConvertFrom::Wdl]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            ConvertFrom::Dxapp => PossibleValue::new("dxapp"),
+            ConvertFrom::Dxasset => PossibleValue::new("dxasset"),
+            ConvertFrom::Wdl => PossibleValue::new("wdl"),
+        })
+    }
+}
+
+// --------------------------------------------------
+pub fn convert(args: ConvertArgs) -> Result<()> {
+    let contents = if args.filename == "-" {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        contents
+    } else {
+        fs::read_to_string(&args.filename)?
+    };
+
+    let from = match args.from {
+        Some(from) => from,
+        _ => convert_source(&args.filename, &contents),
+    };
+
+    let converted = match from {
+        ConvertFrom::Dxapp => {
+            let app: DxApp = serde_json::from_str(&contents)?;
+            // The run file is relative to the dxapp.json
+            let app_dir = if args.filename == "-" {
+                None
+            } else {
+                Path::new(&args.filename).parent()
+            };
+            app_to_wdl(&app, app_dir)?
+        }
+        ConvertFrom::Dxasset => {
+            let asset: DxAsset = serde_json::from_str(&contents)?;
+            serde_json::to_string_pretty(&asset_exec_depends(&asset))?
+        }
+        ConvertFrom::Wdl => {
+            serde_json::to_string_pretty(&wdl_to_app(&contents)?)?
+        }
+    };
+
+    write_formatted(converted.trim_end(), &args.output)
+}
+
+// --------------------------------------------------
+// Guess the kind of file to convert from its name, then its contents
+pub(crate) fn convert_source(filename: &str, contents: &str) -> ConvertFrom {
+    let path = Path::new(filename);
+    if path.extension().is_some_and(|ext| ext == "wdl") {
+        return ConvertFrom::Wdl;
+    }
+
+    match path.file_name().and_then(|name| name.to_str()) {
+        Some("dxapp.json") => ConvertFrom::Dxapp,
+        Some("dxasset.json") => ConvertFrom::Dxasset,
+        _ => match serde_json::from_str::<serde_json::Value>(contents) {
+            Ok(json) if json.get("runSpec").is_some() => ConvertFrom::Dxapp,
+            Ok(_) => ConvertFrom::Dxasset,
+            _ => ConvertFrom::Wdl,
+        },
+    }
+}
+
+// --------------------------------------------------
+fn app_to_wdl(app: &DxApp, app_dir: Option<&Path>) -> Result<String> {
+    // WDL identifiers cannot have dashes or dots
+    let task_name = app
+        .name
+        .as_deref()
+        .unwrap_or("main")
+        .replace(['-', '.'], "_");
+    let run_file = app_dir
+        .zip(app.run_spec.file.as_ref())
+        .map(|(dir, file)| dir.join(file))
+        .filter(|path| path.is_file());
+
+    wdl_template(
+        &task_name,
+        &app.input_spec,
+        &app.output_spec,
+        run_file.as_ref(),
+    )
+}
+
+// --------------------------------------------------
+// The asset's dependencies to paste into the "runSpec" of a dxapp.json
+fn asset_exec_depends(asset: &DxAsset) -> serde_json::Value {
+    serde_json::json!({ "execDepends": asset.exec_depends })
+}
+
+// --------------------------------------------------
+// A dxapp.json skeleton for the first task in a WDL document. The command
+// becomes the inline bash code of "main" with "~{x}" rewritten as "${x}".
+pub(crate) fn wdl_to_app(wdl: &str) -> Result<DxApp> {
+    let mut task_name: Option<String> = None;
+    let mut input_spec: Vec<InputSpec> = vec![];
+    let mut output_spec: Vec<OutputSpec> = vec![];
+    let mut command: Vec<&str> = vec![];
+    let mut instance_type: Option<String> = None;
+
+    // The block being read inside the task and its brace depth
+    let mut section: Option<&str> = None;
+    let mut depth = 0;
+
+    for line in wdl.lines() {
+        let trimmed = line.trim();
+        if task_name.is_none() {
+            if let Some(rest) = trimmed.strip_prefix("task ") {
+                task_name = rest
+                    .trim_end_matches('{')
+                    .split_whitespace()
+                    .next()
+                    .map(|name| name.to_string());
+            }
+            continue;
+        };
+
+        match section {
+            Some(end @ (">>>" | "}")) if trimmed == end => section = None,
+            Some(">>>" | "}") => command.push(line),
+            Some(block) => {
+                // Only the top-level lines of a block are declarations
+                let decl = trimmed.split('#').next().unwrap_or("").trim();
+                if depth == 1 && !decl.is_empty() && decl != "}" {
+                    match block {
+                        "input" => input_spec.push(wdl_input(decl)?),
+                        "output" => output_spec.push(wdl_output(decl)?),
+                        "runtime" => {
+                            if let Some(val) =
+                                decl.strip_prefix("dx_instance_type:")
+                            {
+                                let val = val.trim().trim_matches('"');
+                                if !val.is_empty() {
+                                    instance_type = Some(val.to_string());
+                                }
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+
+                depth += trimmed.matches('{').count() as i32
+                    - trimmed.matches('}').count() as i32;
+                if depth <= 0 {
+                    section = None;
+                }
+            }
+            None => {
+                if trimmed == "}" {
+                    break;
+                }
+
+                let (keyword, rest) =
+                    trimmed.split_once(' ').unwrap_or((trimmed, ""));
+                section = match (keyword, rest.trim()) {
+                    ("command", "<<<") => Some(">>>"),
+                    ("command", "{") => Some("}"),
+                    (
+                        block @ ("input" | "output" | "runtime" | "meta"
+                        | "parameter_meta"),
+                        "{",
+                    ) => {
+                        depth = 1;
+                        Some(block)
+                    }
+                    _ => None,
+                };
+            }
+        }
+    }
+
+    let Some(task_name) = task_name else {
+        bail!("No task found in WDL")
+    };
+
+    // Dedent the command into the body of the bash "main" function
+    let indent = command
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut code = vec!["main() {".to_string()];
+    for line in command {
+        let line = line.get(indent..).unwrap_or("").trim_end();
+        if line.is_empty() {
+            code.push("".to_string());
+        } else {
+            code.push(format!("    {}", line.replace("~{", "${")));
+        }
+    }
+    code.push("}".to_string());
+    code.push("".to_string());
+
+    let regional_options = instance_type.map(|instance_type| {
+        HashMap::from([(
+            "*".to_string(),
+            RegionalOptions {
+                resources: None,
+                system_requirements: HashMap::from([(
+                    "*".to_string(),
+                    SystemRequirements {
+                        instance_type,
+                        cluster_spec: None,
+                    },
+                )]),
+            },
+        )])
+    });
+
+    Ok(DxApp {
+        name: Some(task_name.clone()),
+        title: task_name,
+        dxapi: Some(DXAPI_VERSION.to_string()),
+        summary: None,
+        description: None,
+        version: None,
+        developer_notes: None,
+        types: vec![],
+        bill_to: None,
+        open_source: None,
+        categories: vec![],
+        developers: vec![],
+        authorized_users: vec![],
+        input_spec,
+        output_spec,
+        run_spec: RunSpec {
+            interpreter: Some(Interpreter::Bash),
+            file: None,
+            distribution: LinuxDistribution::Ubuntu,
+            release: Some(LinuxRelease::V20_04),
+            version: Some(LinuxVersion::V0),
+            code: Some(code.join("\n")),
+            head_job_on_demand: None,
+            restartable_entry_points: None,
+            asset_depends: None,
+            exec_depends: vec![],
+            system_requirements: HashMap::new(),
+            timeout_policy: None,
+        },
+        https_app: None,
+        access: None,
+        regional_options,
+        details: None,
+        ignore_reuse: None,
+    })
+}
+
+// --------------------------------------------------
+// Splits a WDL declaration such as "Array[File]? reads = []" into its
+// class, whether it is optional, its name and any expression
+fn parse_wdl_declaration(
+    decl: &str,
+) -> Result<(InputOutputClass, bool, String, Option<String>)> {
+    let (decl, expr) = match decl.split_once('=') {
+        Some((decl, expr)) => (decl.trim(), Some(expr.trim().to_string())),
+        _ => (decl.trim(), None),
+    };
+
+    // Types such as "Map[String, Int]" may have spaces
+    let (wdl_type, name) = decl
+        .rsplit_once(char::is_whitespace)
+        .ok_or(anyhow!(r#"Cannot parse WDL declaration "{decl}""#))?;
+    let wdl_type = wdl_type.trim();
+    let optional = wdl_type.ends_with('?');
+    let class = InputOutputClass::from_wdl(wdl_type.trim_end_matches('?'))
+        .ok_or(anyhow!(r#"Unsupported WDL type "{wdl_type}" for "{name}""#))?;
+
+    Ok((class, optional, name.to_string(), expr))
+}
+
+// --------------------------------------------------
+fn wdl_input(decl: &str) -> Result<InputSpec> {
+    let (class, optional, name, expr) = parse_wdl_declaration(decl)?;
+
+    // Literals such as 1, true or "foo" are also JSON, but a default for
+    // a data object would have to be a link
+    let default = expr
+        .and_then(|expr| serde_json::from_str(&expr).ok())
+        .filter(|_| {
+            !matches!(
+                class.item_class(),
+                InputOutputClass::Applet
+                    | InputOutputClass::File
+                    | InputOutputClass::Record
+            )
+        });
+
+    Ok(InputSpec {
+        name,
+        label: None,
+        class,
+        optional: optional.then_some(true),
+        default,
+        patterns: vec![],
+        help: None,
+        choices: vec![],
+        input_type: None,
+        group: None,
+        suggestions: vec![],
+    })
+}
+
+// --------------------------------------------------
+fn wdl_output(decl: &str) -> Result<OutputSpec> {
+    let (class, optional, name, _) = parse_wdl_declaration(decl)?;
+
+    Ok(OutputSpec {
+        name,
+        class,
+        label: None,
+        help: None,
+        optional: optional.then_some(true),
+        patterns: vec![],
+    })
+}
@@ -0,0 +1,175 @@
+use crate::dxenv::get_dx_env;
+use crate::entity_id::EntityId;
+use crate::exit::CliError;
+use crate::{
+    api, format_price, make_table, output_format, print_records, print_table,
+    ExecutionCost, FindExecutionsOptions, OutputFormat,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use serde::Serialize;
+use size::Size;
+
+#[derive(Clone, Parser, Debug)]
+pub struct CostArgs {
+    /// Project ID or root job/analysis ID, defaults to current project
+    #[arg()]
+    id: Option<String>,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct CostRow {
+    pub(crate) name: String,
+    pub(crate) executions: usize,
+    pub(crate) price: f64,
+    pub(crate) egress: u64,
+}
+
+// --------------------------------------------------
+pub fn cost(args: CostArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let id = args.id.clone().unwrap_or(dx_env.project_context_id.clone());
+    let root = match EntityId::parse(&id) {
+        Some(EntityId::Project { .. }) => None,
+        Some(EntityId::Job { job_id }) => Some(job_id),
+        Some(EntityId::Analysis { analysis_id }) => Some(analysis_id),
+        _ => bail!(CliError::Usage(format!(
+            r#""{id}" is not a project, job or analysis ID"#
+        ))),
+    };
+
+    let mut options = FindExecutionsOptions {
+        project: root.is_none().then(|| id.clone()),
+        root_execution: root.clone(),
+        include_subjobs: root.is_some(),
+        created: None,
+        class: None,
+        state: None,
+        describe: serde_json::json!({
+            "fields": {
+                "id": true,
+                "name": true,
+                "executableName": true,
+                "parentJob": true,
+                "parentAnalysis": true,
+                "rootExecution": true,
+                "totalPrice": true,
+                "totalEgress": true,
+                "currency": true,
+            }
+        }),
+        starting: None,
+    };
+    let executions: Vec<ExecutionCost> =
+        api::find_executions(&dx_env, &mut options)?
+            .into_iter()
+            .filter_map(|res| res.describe)
+            .collect();
+
+    let rows = cost_breakdown(&executions, root.as_deref());
+    let total = match &root {
+        Some(root) => executions
+            .iter()
+            .find(|e| &e.id == root)
+            .and_then(|e| e.total_price),
+        _ => None,
+    }
+    .unwrap_or(rows.iter().map(|row| row.price).sum());
+
+    if output_format() != OutputFormat::Table {
+        return print_records(
+            &rows,
+            &["name", "executions", "price", "egress"],
+        );
+    }
+
+    let currency = executions.iter().find_map(|e| e.currency.clone());
+    let mut table_rows: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            vec![
+                row.name.clone(),
+                row.executions.to_string(),
+                format_price(Some(row.price), &currency),
+                Size::from_bytes(row.egress).to_string(),
+            ]
+        })
+        .collect();
+    table_rows.push(vec![
+        "Total".to_string(),
+        executions.len().to_string(),
+        format_price(Some(total), &currency),
+        Size::from_bytes(rows.iter().map(|row| row.egress).sum::<u64>())
+            .to_string(),
+    ]);
+    print_table(
+        &make_table("{:<} {:>} {:>} {:>}", table_rows, 0, args.wide),
+        args.wide,
+    );
+    Ok(())
+}
+
+// --------------------------------------------------
+// Prices include descendants, so only the top of each tree is summed:
+// the root executions of a project or the stages of a root execution
+pub(crate) fn cost_breakdown(
+    executions: &[ExecutionCost],
+    root: Option<&str>,
+) -> Vec<CostRow> {
+    let tops: Vec<_> = match root {
+        Some(root) => {
+            let children: Vec<_> = executions
+                .iter()
+                .filter(|e| {
+                    e.parent_job.as_deref() == Some(root)
+                        || e.parent_analysis.as_deref() == Some(root)
+                })
+                .collect();
+            if children.is_empty() {
+                executions.iter().filter(|e| e.id == root).collect()
+            } else {
+                children
+            }
+        }
+        _ => executions
+            .iter()
+            .filter(|e| e.root_execution.as_ref() == Some(&e.id))
+            .collect(),
+    };
+
+    let mut rows: Vec<CostRow> = vec![];
+    for execution in tops {
+        let name = match root {
+            Some(_) => execution.name.clone(),
+            _ => execution.executable_name.clone(),
+        }
+        .or(execution.name.clone())
+        .unwrap_or(execution.id.clone());
+        let price = execution.total_price.unwrap_or(0.);
+        let egress: u64 = execution
+            .total_egress
+            .as_ref()
+            .map_or(0, |egress| egress.values().sum());
+
+        match rows.iter_mut().find(|row| row.name == name) {
+            Some(row) => {
+                row.executions += 1;
+                row.price += price;
+                row.egress += egress;
+            }
+            _ => rows.push(CostRow {
+                name,
+                executions: 1,
+                price,
+                egress,
+            }),
+        }
+    }
+
+    rows.sort_by(|a, b| b.price.total_cmp(&a.price));
+    rows
+}
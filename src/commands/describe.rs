@@ -0,0 +1,2298 @@
+use crate::dxenv::DxEnvironment;
+use crate::entity_id::EntityId;
+use crate::exit::CliError;
+use crate::{
+    api, AnalysisDescribeField, AnalysisDescribeOptions, AppDescribeField,
+    AppDescribeOptions, AppletDescribeField, AppletDescribeOptions,
+    ContainerDescribeField, ContainerDescribeOptions, DatabaseDescribeField,
+    DatabaseDescribeOptions, Description, FileDescribeField,
+    FileDescribeOptions, JobDescribeField, JobDescribeOptions,
+    ProjectDescribeField, ProjectDescribeOptions, RecordDescribeField,
+    RecordDescribeOptions,
+};
+#[cfg(feature = "cli")]
+use crate::{
+    dataset_query, dxenv::get_dx_env, fan_out, format_price, format_properties,
+    incomplete, is_dataset_record, job_failure_with_stderr, limit_rows,
+    load_dataset, make_table, more_rows, output_format, print_record,
+    print_records, print_table, read_stdin_args, resolve_named_objects,
+    tsv_value, AnalysisDescribeResult, AppDescribeResult, AppletDescribeResult,
+    ContainerDescribeResult, DatabaseDescribeResult, DatasetSummary,
+    DescribeDataObjectsOptions, DescribeFields, DxFileDescriptorValue,
+    FileDescribeResult, FileDescriptor, JobDescribeResult, KitchenSink,
+    ObjectType, OutputFormat, ProjectDescribeResult, RecordDescribeResult,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use log::debug;
+#[cfg(feature = "cli")]
+use size::Size;
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+#[cfg(feature = "cli")]
+use tabular::{Row, Table};
+
+/// Most objects "system/describeDataObjects" takes in one request
+#[cfg(feature = "cli")]
+const DESCRIBE_BATCH_SIZE: usize = 1000;
+
+#[derive(Clone, Parser, Debug)]
+pub struct DescribeArgs {
+    /// Show linked objects by ID without looking up their names
+    #[arg(long, default_value = "false")]
+    no_resolve: bool,
+
+    /// Object identifier or the name of an applet, record, file or app
+    #[arg()]
+    ids: Vec<String>,
+
+    /// Describe every object with a name rather than choosing one
+    #[arg(short, long, default_value = "false")]
+    all: bool,
+
+    /// Output JSON representation
+    #[arg(long, default_value = "false")]
+    json: bool,
+
+    /// Attempt number for restarted job
+    #[arg(long = "try", value_name = "INT")]
+    try_number: Option<u64>,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+
+    /// Maximum number of rows to show for large sections
+    #[arg(long, value_name = "N", default_value = "20")]
+    max_rows: usize,
+
+    /// Show every row of large sections
+    #[arg(long, default_value = "false", conflicts_with = "max_rows")]
+    full: bool,
+
+    /// Read newline-separated IDs or paths from STDIN, same as "-"
+    #[arg(long, default_value = "false")]
+    stdin: bool,
+
+    /// Print each object with a template of JSON field names, e.g.,
+    /// "{id}\t{name}\t{size}" or "{properties.sample}"
+    #[arg(
+        long,
+        value_name = "TEMPLATE",
+        conflicts_with_all = ["json", "fields"]
+    )]
+    format: Option<String>,
+
+    /// Fields to print for each object, e.g., "id,name,size"
+    #[arg(long, value_delimiter = ',', value_name = "FIELDS")]
+    fields: Vec<String>,
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub fn describe(mut args: DescribeArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let max_rows = (!args.full).then_some(args.max_rows);
+    let plain_fields = !args.json && output_format() == OutputFormat::Table;
+    args.json = args.json || output_format() != OutputFormat::Table;
+    let (json, wide) = (&args.json, args.wide);
+
+    let (mut ids, mut errors) = (vec![], vec![]);
+    for id in read_stdin_args(&args.ids, args.stdin)? {
+        if EntityId::parse(&id).is_some() {
+            ids.push(id);
+            continue;
+        }
+
+        match resolve_named_objects(
+            &dx_env,
+            &id,
+            &[ObjectType::Applet, ObjectType::File, ObjectType::Record],
+            true,
+            args.all,
+        ) {
+            Ok(found) => ids.extend(found),
+            Err(e) => {
+                eprintln!("{e}");
+                errors.push(e);
+            }
+        }
+    }
+
+    // Several objects in pretty JSON make one array rather than many
+    // documents, while JSONL and TSV already print one per object
+    let json_array = *json
+        && ids.len() > 1
+        && matches!(output_format(), OutputFormat::Table | OutputFormat::Json);
+
+    let mut records = vec![];
+    let try_number = args.try_number;
+    let descriptions =
+        fan_out(&ids, |id| describe_object(&dx_env, id, try_number))?;
+
+    for desc in descriptions {
+        let desc = match desc {
+            Ok(desc) => desc,
+            Err(e) => {
+                eprintln!("{e}");
+                errors.push(e);
+                continue;
+            }
+        };
+
+        if let Some(template) = &args.format {
+            let val = serde_json::to_value(&desc)?;
+            println!("{}", format_description(template, &val)?);
+            continue;
+        } else if !args.fields.is_empty() {
+            let val = serde_json::to_value(&desc)?;
+            records.push(select_fields(&val, &args.fields));
+            continue;
+        } else if json_array {
+            let mut val = serde_json::to_value(&desc)?;
+            if let Description::Record(record) = &desc {
+                if let Some(dataset) = is_dataset_record(record)
+                    .then(|| describe_dataset(&dx_env, record))
+                    .and_then(|res| res.map_err(|e| eprintln!("{e}")).ok())
+                {
+                    val["dataset"] = serde_json::to_value(dataset)?;
+                }
+            }
+            records.push(val);
+            continue;
+        }
+
+        match desc {
+            Description::Analysis(analysis) => {
+                let names = if *json || args.no_resolve {
+                    HashMap::new()
+                } else {
+                    link_names(
+                        &dx_env,
+                        analysis.run_input.iter().chain(&analysis.output),
+                    )
+                };
+                print_analysis_description(
+                    *analysis, json, wide, max_rows, &names,
+                )?
+            }
+            Description::App(app) => print_app_description(*app, json, wide)?,
+            Description::Applet(applet) => {
+                print_applet_description(*applet, json, wide, max_rows)?
+            }
+            Description::Container(container) => {
+                print_container_description(*container, json, wide)?
+            }
+            Description::Database(db) => {
+                print_database_description(*db, json, wide)?
+            }
+            Description::File(file) => {
+                print_file_description(*file, json, wide, max_rows)?
+            }
+            Description::Job(job) => {
+                let names = if *json || args.no_resolve {
+                    HashMap::new()
+                } else {
+                    link_names(&dx_env, job.run_input.iter().chain(&job.output))
+                };
+                let failure = if *json {
+                    None
+                } else {
+                    job_failure_with_stderr(&dx_env, &job)
+                };
+                print_job_description(*job, json, wide, max_rows, &names)?;
+                if let Some(failure) = failure {
+                    print!("\n{failure}");
+                }
+            }
+            Description::Project(project) => {
+                print_project_description(*project, json, wide, max_rows)?
+            }
+            Description::Record(record) => {
+                let dataset = is_dataset_record(&record)
+                    .then(|| describe_dataset(&dx_env, &record))
+                    .and_then(|res| res.map_err(|e| eprintln!("{e}")).ok());
+                print_record_description(
+                    *record, dataset, json, wide, max_rows,
+                )?
+            }
+        }
+    }
+
+    if !args.fields.is_empty() {
+        if plain_fields {
+            for record in &records {
+                let cells: Vec<_> = args
+                    .fields
+                    .iter()
+                    .map(|field| tsv_value(&record[field]))
+                    .collect();
+                println!("{}", cells.join("\t"));
+            }
+        } else {
+            let fields: Vec<&str> =
+                args.fields.iter().map(|f| f.as_str()).collect();
+            print_records(&records, &fields)?;
+        }
+    } else if json_array {
+        println!("{}", serde_json::to_string_pretty(&records)?);
+    }
+
+    incomplete("Describe", errors)
+}
+
+// --------------------------------------------------
+// Fields are named as in the JSON output, and dots reach into objects
+// and arrays, e.g., "properties.sample" or "parts.1.md5"
+#[cfg(feature = "cli")]
+fn json_field<'a>(
+    val: &'a serde_json::Value,
+    field: &str,
+) -> Option<&'a serde_json::Value> {
+    field.split('.').try_fold(val, |acc, key| match acc {
+        serde_json::Value::Array(items) => {
+            key.parse::<usize>().ok().and_then(|i| items.get(i))
+        }
+        _ => acc.get(key),
+    })
+}
+
+// --------------------------------------------------
+// Keeps only the requested fields, with null for any the object lacks
+// so that every record has the same keys
+#[cfg(feature = "cli")]
+pub(crate) fn select_fields(
+    val: &serde_json::Value,
+    fields: &[String],
+) -> serde_json::Value {
+    serde_json::Value::Object(
+        fields
+            .iter()
+            .map(|field| {
+                (
+                    field.clone(),
+                    json_field(val, field)
+                        .cloned()
+                        .unwrap_or(serde_json::Value::Null),
+                )
+            })
+            .collect(),
+    )
+}
+
+// --------------------------------------------------
+// "{field}" is replaced by its value, "{{" and "}}" are literal braces,
+// and "\t" and "\n" are a tab and a newline
+#[cfg(feature = "cli")]
+pub(crate) fn format_description(
+    template: &str,
+    val: &serde_json::Value,
+) -> Result<String> {
+    let mut out = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '{' => {
+                let mut field = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => field.push(c),
+                        None => bail!(r#"Unclosed "{{{field}" in template"#),
+                    }
+                }
+                match json_field(val, field.trim()) {
+                    Some(serde_json::Value::String(s)) => out.push_str(s),
+                    Some(serde_json::Value::Null) | None => (),
+                    Some(other) => out.push_str(&other.to_string()),
+                }
+            }
+            '}' => bail!(r#"Unmatched "}}" in template"#),
+            '\\' => match chars.next() {
+                Some('t') => out.push('\t'),
+                Some('n') => out.push('\n'),
+                Some(other) => {
+                    out.push('\\');
+                    out.push(other);
+                }
+                None => out.push('\\'),
+            },
+            _ => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+// --------------------------------------------------
+/// Describe any object by ID with all of its fields
+pub fn describe_object(
+    dx_env: &DxEnvironment,
+    id: &str,
+    try_number: Option<u64>,
+) -> Result<Description> {
+    let desc = match EntityId::parse(id) {
+        Some(EntityId::Analysis { analysis_id }) => {
+            let options = AnalysisDescribeOptions {
+                fields: AnalysisDescribeField::iter()
+                    .map(|e| (e, true))
+                    .collect::<HashMap<_, _>>(),
+            };
+            Description::Analysis(Box::new(api::describe_analysis(
+                dx_env,
+                &analysis_id,
+                &options,
+            )?))
+        }
+        Some(EntityId::App { app_id }) => {
+            let options = AppDescribeOptions {
+                fields: AppDescribeField::iter()
+                    .map(|e| (e, true))
+                    .collect::<HashMap<_, _>>(),
+            };
+            Description::App(Box::new(api::describe_app(
+                dx_env, &app_id, &options,
+            )?))
+        }
+        Some(EntityId::Applet {
+            project_id,
+            applet_id,
+        }) => {
+            let options = AppletDescribeOptions {
+                project: project_id,
+                fields: Some(
+                    AppletDescribeField::iter()
+                        .map(|e| (e, true))
+                        .collect::<HashMap<_, _>>(),
+                ),
+            };
+            Description::Applet(Box::new(api::describe_applet(
+                dx_env, &applet_id, &options,
+            )?))
+        }
+        Some(EntityId::Container { container_id }) => {
+            let options = ContainerDescribeOptions {
+                fields: Some(
+                    ContainerDescribeField::iter()
+                        .map(|e| (e, true))
+                        .collect::<HashMap<_, _>>(),
+                ),
+            };
+            Description::Container(Box::new(api::describe_container(
+                dx_env,
+                &container_id,
+                &options,
+            )?))
+        }
+        Some(EntityId::Database {
+            project_id,
+            database_id,
+        }) => {
+            let options = DatabaseDescribeOptions {
+                project: project_id.map(|v| v.to_string()),
+                fields: Some(
+                    DatabaseDescribeField::iter()
+                        .map(|e| (e, true))
+                        .collect::<HashMap<_, _>>(),
+                ),
+                details: true,
+                properties: true,
+            };
+            Description::Database(Box::new(api::describe_database(
+                dx_env,
+                &database_id,
+                &options,
+            )?))
+        }
+        Some(EntityId::File {
+            project_id,
+            file_id,
+        }) => {
+            let options = FileDescribeOptions {
+                project: project_id.map(|v| v.to_string()),
+                fields: Some(
+                    FileDescribeField::iter()
+                        .map(|e| (e, true))
+                        .collect::<HashMap<_, _>>(),
+                ),
+                details: true,
+                properties: true,
+            };
+            Description::File(Box::new(api::describe_file(
+                dx_env, &file_id, &options,
+            )?))
+        }
+        Some(EntityId::Job { job_id }) => {
+            let options = JobDescribeOptions {
+                default_fields: None,
+                fields: Some(
+                    JobDescribeField::iter()
+                        .map(|e| (e, true))
+                        .collect::<HashMap<_, _>>(),
+                ),
+                try_number,
+            };
+            Description::Job(Box::new(api::describe_job(
+                dx_env, &job_id, &options,
+            )?))
+        }
+        Some(EntityId::Project { project_id }) => {
+            let options = ProjectDescribeOptions {
+                fields: Some(
+                    ProjectDescribeField::iter()
+                        .map(|e| (e, true))
+                        .collect::<HashMap<_, _>>(),
+                ),
+            };
+            Description::Project(Box::new(api::describe_project(
+                dx_env,
+                &project_id,
+                &options,
+            )?))
+        }
+        Some(EntityId::Record {
+            project_id,
+            record_id,
+        }) => {
+            let options = RecordDescribeOptions {
+                project: project_id.map(|v| v.to_string()),
+                fields: Some(
+                    RecordDescribeField::iter()
+                        .map(|e| (e, true))
+                        .collect::<HashMap<_, _>>(),
+                ),
+                details: true,
+                properties: true,
+            };
+            Description::Record(Box::new(api::describe_record(
+                dx_env, &record_id, &options,
+            )?))
+        }
+        _ => bail!(CliError::Usage(format!(r#"Cannot describe "{id}""#))),
+    };
+    debug!("{:#?}", &desc);
+    Ok(desc)
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_database_description(
+    db: DatabaseDescribeResult,
+    show_json: &bool,
+    wide: bool,
+) -> Result<()> {
+    if *show_json {
+        print_record(&db)?;
+    } else {
+        let fmt = "{:<}    {:<}";
+        let mut table = Table::new(fmt);
+        table.add_row(Row::new().with_cell("ID").with_cell(db.id));
+
+        // TODO: More rows
+        table.add_row(
+            Row::new()
+                .with_cell("Class")
+                .with_cell(db.class.unwrap_or("NA".to_string())),
+        );
+
+        print_table(&table, wide);
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_record_description(
+    record: RecordDescribeResult,
+    dataset: Option<DatasetSummary>,
+    show_json: &bool,
+    wide: bool,
+    max_rows: Option<usize>,
+) -> Result<()> {
+    if *show_json {
+        let mut val = serde_json::to_value(&record)?;
+        if let Some(dataset) = &dataset {
+            val["dataset"] = serde_json::to_value(dataset)?;
+        }
+        print_record(&val)?;
+    } else {
+        let fmt = "{:<}    {:<}";
+        let mut table = Table::new(fmt);
+        table.add_row(Row::new().with_cell("ID").with_cell(record.id));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Class")
+                .with_cell(record.class.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Name")
+                .with_cell(record.name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Project")
+                .with_cell(record.project.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Folder")
+                .with_cell(record.folder.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("State")
+                .with_cell(record.state.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Visibility").with_cell(
+            record.hidden.map_or(
+                "NA",
+                |v| {
+                    if v {
+                        "hidden"
+                    } else {
+                        "visible"
+                    }
+                },
+            ),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Tags").with_cell(
+                record
+                    .tags
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Properties").with_cell(
+                record.properties.map_or("-".to_string(), |p| {
+                    format_properties(&p, max_rows)
+                }),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Links").with_cell(
+                record
+                    .links
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(Row::new().with_cell("Created").with_cell(
+            record.created.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Created By").with_cell(
+                record.created_by.map_or("NA".to_string(), |c| c.user),
+            ),
+        );
+
+        table.add_row(Row::new().with_cell("Last Modified").with_cell(
+            record.modified.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Size").with_cell(
+                record.size.map_or("NA".to_string(), |s| {
+                    Size::from_bytes(s).to_string()
+                }),
+            ),
+        );
+
+        print_table(&table, wide);
+
+        if let Some(dataset) = &dataset {
+            println!("{}", dataset_lines(dataset, wide).join("\n"));
+        }
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// The dataset's schema and, for a cohort, the count of its members
+#[cfg(feature = "cli")]
+fn describe_dataset(
+    dx_env: &DxEnvironment,
+    record: &RecordDescribeResult,
+) -> Result<DatasetSummary> {
+    let (viz, mut summary) = load_dataset(dx_env, record)?;
+    if summary.record_type == "Cohort" {
+        if let Some(key) = &summary.primary_key {
+            let payload = dataset_query(&viz, &[key.to_string()]);
+            let res =
+                api::vizserver_raw(dx_env, &viz.url, &viz.dataset, &payload)?;
+            summary.count =
+                res["results"].as_array().map(|results| results.len());
+        }
+    }
+
+    Ok(summary)
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn dataset_lines(
+    dataset: &DatasetSummary,
+    wide: bool,
+) -> Vec<String> {
+    let mut rows = vec![
+        vec!["Record Type".to_string(), dataset.record_type.clone()],
+        vec!["Dataset".to_string(), dataset.dataset.clone()],
+        vec![
+            "Version".to_string(),
+            dataset.version.clone().unwrap_or("NA".to_string()),
+        ],
+        vec![
+            "Databases".to_string(),
+            if dataset.databases.is_empty() {
+                "-".to_string()
+            } else {
+                dataset.databases.join(", ")
+            },
+        ],
+        vec![
+            "Primary Key".to_string(),
+            dataset.primary_key.clone().unwrap_or("NA".to_string()),
+        ],
+    ];
+    if let Some(count) = dataset.count {
+        rows.push(vec!["Cohort Count".to_string(), count.to_string()]);
+    }
+    let mut lines = vec![make_table("{:<}    {:<}", rows, 1, wide).to_string()];
+
+    for entity in &dataset.entities {
+        lines.push(format!(
+            "Entity \"{}\" ({} field{})",
+            entity.name,
+            entity.fields.len(),
+            if entity.fields.len() == 1 { "" } else { "s" }
+        ));
+        let mut rows = vec![vec!["Field", "Type", "Column", "Title"]
+            .into_iter()
+            .map(str::to_string)
+            .collect()];
+        for field in &entity.fields {
+            rows.push(vec![
+                field.name.clone(),
+                field.field_type.clone(),
+                match (&field.table, &field.column) {
+                    (Some(table), Some(column)) => {
+                        format!("{table}.{column}")
+                    }
+                    _ => "-".to_string(),
+                },
+                field.title.clone().unwrap_or("-".to_string()),
+            ]);
+        }
+        lines.push(
+            make_table("{:<}  {:<}  {:<}  {:<}", rows, 3, wide).to_string(),
+        );
+    }
+
+    lines
+}
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_file_description(
+    file: FileDescribeResult,
+    show_json: &bool,
+    wide: bool,
+    max_rows: Option<usize>,
+) -> Result<()> {
+    if *show_json {
+        print_record(&file)?;
+    } else {
+        let fmt = "{:<}    {:<}";
+        let mut table = Table::new(fmt);
+        table.add_row(Row::new().with_cell("ID").with_cell(file.id));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Class")
+                .with_cell(file.class.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Name")
+                .with_cell(file.name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Project")
+                .with_cell(file.project.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Folder")
+                .with_cell(file.folder.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("State")
+                .with_cell(file.state.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Visibility")
+                .with_cell(file.hidden.map_or("NA", |v| {
+                    if v {
+                        "hidden"
+                    } else {
+                        "visible"
+                    }
+                })),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Types").with_cell(
+                file.types
+                    .and_then(|t| (!t.is_empty()).then(|| t.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Tags").with_cell(
+                file.tags
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Properties").with_cell(
+                file.properties.map_or("-".to_string(), |p| {
+                    format_properties(&p, max_rows)
+                }),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Outgoing Links").with_cell(
+                file.links
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(Row::new().with_cell("Created").with_cell(
+            file.created.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Created By").with_cell(
+                file.created_by.map_or("NA".to_string(), |c| c.user),
+            ),
+        );
+
+        table.add_row(Row::new().with_cell("Last Modified").with_cell(
+            file.modified.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Media Type")
+                .with_cell(file.media.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Size").with_cell(
+                file.size.map_or("NA".to_string(), |s| {
+                    Size::from_bytes(s).to_string()
+                }),
+            ),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Cloud Account")
+                .with_cell(file.cloud_account.unwrap_or("NA".to_string())),
+        );
+
+        print_table(&table, wide);
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// Names of the data objects linked from input or output values, by ID.
+// Names only make the output easier to read, so lookups that fail are
+// left out.
+#[cfg(feature = "cli")]
+fn link_names<'a>(
+    dx_env: &DxEnvironment,
+    values: impl Iterator<Item = &'a HashMap<String, KitchenSink>>,
+) -> HashMap<String, String> {
+    let mut ids = vec![];
+    for val in values.flat_map(|values| values.values()) {
+        linked_ids(val, &mut ids);
+    }
+    ids.sort();
+    ids.dedup();
+
+    let mut names = HashMap::new();
+    for chunk in ids.chunks(DESCRIBE_BATCH_SIZE) {
+        let options = DescribeDataObjectsOptions {
+            objects: chunk.to_vec(),
+            class_describe_options: HashMap::from([(
+                "*".to_string(),
+                DescribeFields {
+                    fields: HashMap::from([
+                        ("id".to_string(), true),
+                        ("name".to_string(), true),
+                    ]),
+                },
+            )]),
+        };
+
+        match api::describe_data_objects(dx_env, &options) {
+            Ok(results) => names.extend(
+                results
+                    .into_iter()
+                    .filter_map(|result| result.describe)
+                    .filter_map(|desc| desc.name.map(|name| (desc.id, name))),
+            ),
+            Err(e) => debug!("{e}"),
+        }
+    }
+
+    names
+}
+
+// --------------------------------------------------
+// The IDs of the data objects linked anywhere in a value
+#[cfg(feature = "cli")]
+pub(crate) fn linked_ids(val: &KitchenSink, ids: &mut Vec<String>) {
+    match val {
+        KitchenSink::FileValue(FileDescriptor::Dx(desc)) => {
+            if let Some(id) = link_id(&desc.dnanexus_link) {
+                ids.push(id.to_string());
+            }
+        }
+        KitchenSink::List(vals) => {
+            vals.iter().for_each(|val| linked_ids(val, ids))
+        }
+        KitchenSink::Mapping(vals) => {
+            vals.values().for_each(|val| linked_ids(val, ids))
+        }
+        _ => (),
+    }
+}
+
+// --------------------------------------------------
+// Links to analysis outputs name no data object until the analysis runs
+#[cfg(feature = "cli")]
+fn link_id(link: &DxFileDescriptorValue) -> Option<&str> {
+    match link {
+        DxFileDescriptorValue::FileId(id) => Some(id),
+        DxFileDescriptorValue::File(file) => Some(&file.id),
+        DxFileDescriptorValue::Analysis(_) => None,
+    }
+}
+
+// --------------------------------------------------
+// A value with each link whose name is known shown as "name (file-xxxx)"
+#[cfg(feature = "cli")]
+pub(crate) fn display_value(
+    val: &KitchenSink,
+    names: &HashMap<String, String>,
+) -> String {
+    match val {
+        KitchenSink::FileValue(FileDescriptor::Dx(desc)) => {
+            match link_id(&desc.dnanexus_link)
+                .and_then(|id| names.get(id).map(|name| (name, id)))
+            {
+                Some((name, id)) => format!("{name} ({id})"),
+                _ => val.to_string(),
+            }
+        }
+        KitchenSink::List(vals) => format!(
+            "[{}]",
+            vals.iter()
+                .map(|val| display_value(val, names))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        KitchenSink::Mapping(vals) => format!(
+            "{{{}}}",
+            vals.iter()
+                .map(|(key, val)| {
+                    format!("\"{key}\": {}", display_value(val, names))
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        _ => val.to_string(),
+    }
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_analysis_description(
+    analysis: AnalysisDescribeResult,
+    show_json: &bool,
+    wide: bool,
+    max_rows: Option<usize>,
+    names: &HashMap<String, String>,
+) -> Result<()> {
+    debug!("{:#?}", &analysis);
+
+    if *show_json {
+        print_record(&analysis)?;
+    } else {
+        let fmt = "{:<}    {:<}";
+        let mut table = Table::new(fmt);
+        let currency = analysis.currency;
+
+        // TODO: Add more rows
+        table.add_row(Row::new().with_cell("ID").with_cell(&analysis.id));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Class")
+                .with_cell(analysis.class.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Job Name")
+                .with_cell(analysis.name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Executable Name").with_cell(
+                analysis.executable_name.unwrap_or("NA".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Executable")
+                .with_cell(analysis.executable.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Project Context")
+                .with_cell(analysis.project.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Billed To")
+                .with_cell(analysis.bill_to.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Workspace")
+                .with_cell(analysis.workspace.unwrap_or("NA".to_string())),
+        );
+
+        if let Some(workflow) = analysis.workflow {
+            table.add_row(
+                Row::new().with_cell("Workflow").with_cell(&workflow.id),
+            );
+        }
+
+        table.add_row(
+            Row::new()
+                .with_cell("Output Folder")
+                .with_cell(analysis.folder.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Priority")
+                .with_cell(analysis.priority.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("State")
+                .with_cell(analysis.state.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Root Execution")
+                .with_cell(analysis.root_execution.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Parent Job")
+                .with_cell(analysis.parent_job.unwrap_or("-".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Preserve Job Outputs Folder")
+                .with_cell(
+                    analysis
+                        .preserve_job_outputs
+                        .map_or("-".to_string(), |v| v.to_string()),
+                ),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Launched By")
+                .with_cell(analysis.launched_by.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Created").with_cell(
+            analysis.created.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Last Modified").with_cell(
+            analysis.modified.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Depends On").with_cell(
+                analysis
+                    .depends_on
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Tags").with_cell(
+                analysis
+                    .tags
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Properties").with_cell(
+                analysis.properties.map_or("-".to_string(), |p| {
+                    format_properties(&p, max_rows)
+                }),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Total Price").with_cell(format_price(
+                analysis.total_price,
+                &currency.clone(),
+            )),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Tree TAT").with_cell(
+                analysis
+                    .tree_turnaround_time
+                    .map_or("-".to_string(), |v| v.to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Detached From")
+                .with_cell(analysis.detached_from.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Rank").with_cell(
+            analysis.rank.map_or("-".to_string(), |v| v.to_string()),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Detailed Job Metrics").with_cell(
+                analysis
+                    .detailed_job_metrics
+                    .map_or("-".to_string(), |v| v.to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Detached From Try").with_cell(
+                analysis
+                    .detached_from_try
+                    .map_or("NA".to_string(), |v| v.to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Currency").with_cell(
+                currency
+                    .clone()
+                    .map_or("-".to_string(), |v| v.code.to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Total Egress").with_cell(
+                analysis
+                    .total_egress
+                    .map_or("NA".to_string(), |v| format!("{:?}", v)),
+            ),
+        );
+
+        table.add_row(Row::new().with_cell("Egress Computed At").with_cell(
+            analysis.egress_computed_at.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Cost Limit")
+                .with_cell(format_price(analysis.cost_limit, &currency)),
+        );
+
+        if let Some(stages) = analysis.stages {
+            for (stage_num, stage) in stages.iter().enumerate() {
+                table.add_row(
+                    Row::new()
+                        .with_cell(format!("Stage {stage_num}"))
+                        .with_cell(&stage.id),
+                );
+
+                if let Some(execution) = &stage.execution {
+                    if let Some(executable) = &execution.executable {
+                        table.add_row(
+                            Row::new()
+                                .with_cell("  Executable")
+                                .with_cell(executable),
+                        );
+                    }
+
+                    if let Some(job_id) = &execution.origin_job {
+                        table.add_row(
+                            Row::new()
+                                .with_cell("  Execution")
+                                .with_cell(job_id),
+                        );
+                    }
+                }
+            }
+        }
+
+        for (title, values) in
+            [("Input", analysis.run_input), ("Output", analysis.output)]
+        {
+            if let Some(values) = values {
+                table.add_row(Row::new().with_cell(title).with_cell(""));
+                let mut values: Vec<_> = values.iter().collect();
+                values.sort_by_key(|(name, _)| *name);
+                let (shown, hidden) = limit_rows(values, max_rows);
+                for (name, val) in shown {
+                    table.add_row(
+                        Row::new()
+                            .with_cell(format!("  {name}"))
+                            .with_cell(display_value(val, names)),
+                    );
+                }
+                if hidden > 0 {
+                    table.add_row(
+                        Row::new().with_cell("").with_cell(more_rows(hidden)),
+                    );
+                }
+            }
+        }
+
+        print_table(&table, wide);
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_app_description(
+    app: AppDescribeResult,
+    show_json: &bool,
+    wide: bool,
+) -> Result<()> {
+    debug!("{:#?}", &app);
+
+    if *show_json {
+        print_record(&app)?;
+    } else {
+        // TODO: Add Details, Regional Options
+        let fmt = "{:<}    {:<}";
+        let mut table = Table::new(fmt);
+
+        table.add_row(Row::new().with_cell("ID").with_cell(app.id));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Title")
+                .with_cell(app.title.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Summary")
+                .with_cell(app.summary.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Categories").with_cell(
+                app.categories
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Class")
+                .with_cell(app.class.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Billed To")
+                .with_cell(app.bill_to.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Name")
+                .with_cell(app.name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Version")
+                .with_cell(app.version.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Created By")
+                .with_cell(app.created_by.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Created").with_cell(
+            app.created.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Published").with_cell(
+            app.published.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Last Modified").with_cell(
+            app.modified.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Created From")
+                .with_cell(app.applet.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Installed").with_cell(
+            app.installed.map_or(
+                "NA",
+                |v| {
+                    if v {
+                        "hidden"
+                    } else {
+                        "visible"
+                    }
+                },
+            ),
+        ));
+
+        table.add_row(Row::new().with_cell("Open Source").with_cell(
+            app.open_source.map_or(
+                "NA",
+                |v| {
+                    if v {
+                        "hidden"
+                    } else {
+                        "visible"
+                    }
+                },
+            ),
+        ));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Deleted")
+                .with_cell(app.deleted.map_or("NA", |v| {
+                    if v {
+                        "hidden"
+                    } else {
+                        "visible"
+                    }
+                })),
+        );
+
+        table.add_row(Row::new().with_cell("Input").with_cell(
+            app.input_spec.map_or("-".to_string(), |input| {
+                input
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Output").with_cell(
+            app.output_spec.map_or("-".to_string(), |output| {
+                output
+                    .iter()
+                    .map(|o| o.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }),
+        ));
+
+        if let Some(run_spec) = &app.run_spec {
+            table.add_row(
+                Row::new()
+                    .with_cell("Interpreter")
+                    .with_cell(&run_spec.interpreter),
+            );
+
+            if let Some(bundles) = &run_spec.bundled_depends {
+                table.add_row(
+                    Row::new().with_cell("Bundled Depends").with_cell(
+                        bundles
+                            .iter()
+                            .map(|b| {
+                                format!("{} ({})", b.name, b.id.dnanexus_link)
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                );
+            }
+
+            if let Some(reqs) = &run_spec.system_requirements {
+                table.add_row(
+                    Row::new().with_cell("System Requirements").with_cell(
+                        reqs.iter()
+                            .map(|(k, v)| format!("{{\"{k}\": {v}}}"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                );
+            }
+        }
+
+        table.add_row(
+            Row::new()
+                .with_cell("Resources")
+                .with_cell(app.resources.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Num. Installs")
+                .with_cell(app.installs.unwrap_or(0)),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Authorized Users").with_cell(
+                app.authorized_users
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Region")
+                .with_cell(app.region.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Ignore Reuse").with_cell(
+            app.ignore_reuse.map_or("NA".to_string(), |v| v.to_string()),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Is Developer For").with_cell(
+                app.is_developer_for
+                    .map_or("NA".to_string(), |v| v.to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Line Item Per Test").with_cell(
+                app.line_item_per_test
+                    .map_or("NA".to_string(), |v| v.to_string()),
+            ),
+        );
+
+        print_table(&table, wide);
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_applet_description(
+    applet: AppletDescribeResult,
+    show_json: &bool,
+    wide: bool,
+    max_rows: Option<usize>,
+) -> Result<()> {
+    debug!("{:#?}", &applet);
+
+    if *show_json {
+        print_record(&applet)?;
+    } else {
+        let fmt = "{:<}    {:<}";
+        let mut table = Table::new(fmt);
+        table.add_row(Row::new().with_cell("ID").with_cell(applet.id));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Class")
+                .with_cell(applet.class.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Name")
+                .with_cell(applet.name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Project")
+                .with_cell(applet.project.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Folder")
+                .with_cell(applet.folder.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("State")
+                .with_cell(applet.state.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Visibility").with_cell(
+            applet.hidden.map_or(
+                "NA",
+                |v| {
+                    if v {
+                        "hidden"
+                    } else {
+                        "visible"
+                    }
+                },
+            ),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Types").with_cell(
+                applet
+                    .types
+                    .and_then(|t| (!t.is_empty()).then(|| t.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Properties").with_cell(
+                applet.properties.map_or("-".to_string(), |p| {
+                    format_properties(&p, max_rows)
+                }),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Tags").with_cell(
+                applet
+                    .tags
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Outgoing Links").with_cell(
+                applet
+                    .links
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(Row::new().with_cell("Created").with_cell(
+            applet.created.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Created By").with_cell(
+                applet.created_by.map_or("NA".to_string(), |c| c.user),
+            ),
+        );
+
+        table.add_row(Row::new().with_cell("Last Modified").with_cell(
+            applet.modified.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Title")
+                .with_cell(applet.title.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Summary")
+                .with_cell(applet.summary.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Access").with_cell(
+            applet.access.map_or("-".to_string(), |a| {
+                format!(
+                    "network: {}",
+                    if a.network.is_empty() {
+                        "[]".to_string()
+                    } else {
+                        a.network.join(", ")
+                    }
+                )
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Input").with_cell(
+            applet.input_spec.map_or("-".to_string(), |input| {
+                input
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Output").with_cell(
+            applet.output_spec.map_or("-".to_string(), |output| {
+                output
+                    .iter()
+                    .map(|o| o.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }),
+        ));
+
+        table.add_row(
+            Row::new()
+                .with_cell("API Version")
+                .with_cell(applet.dx_api.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Ignore Reuse").with_cell(
+                applet
+                    .ignore_reuse
+                    .map_or("NA".to_string(), |v| v.to_string()),
+            ),
+        );
+
+        if let Some(run_spec) = &applet.run_spec {
+            table.add_row(
+                Row::new()
+                    .with_cell("Interpreter")
+                    .with_cell(&run_spec.interpreter),
+            );
+
+            if let Some(bundles) = &run_spec.bundled_depends {
+                table.add_row(
+                    Row::new().with_cell("Bundled Depends").with_cell(
+                        bundles
+                            .iter()
+                            .map(|b| {
+                                format!("{} ({})", b.name, b.id.dnanexus_link)
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                );
+            }
+
+            if let Some(reqs) = &run_spec.system_requirements {
+                table.add_row(
+                    Row::new().with_cell("System Requirements").with_cell(
+                        reqs.iter()
+                            .map(|(k, v)| format!("{{\"{k}\": {v}}}"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                );
+            }
+        }
+
+        print_table(&table, wide);
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_container_description(
+    container: ContainerDescribeResult,
+    show_json: &bool,
+    wide: bool,
+) -> Result<()> {
+    debug!("{:#?}", &container);
+
+    if *show_json {
+        print_record(&container)?;
+    } else {
+        let fmt = "{:<}    {:<}";
+        let mut table = Table::new(fmt);
+        table.add_row(Row::new().with_cell("ID").with_cell(container.id));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Class")
+                .with_cell(container.class.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Name")
+                .with_cell(container.name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Billed To")
+                .with_cell(container.bill_to.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Access Level").with_cell(
+            container.level.map_or("NA".to_string(), |l| l.to_string()),
+        ));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Region")
+                .with_cell(container.region.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Created").with_cell(
+            container.created.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Last Modified").with_cell(
+            container.modified.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Data Usage").with_cell(format!(
+            "{:0.02} GB",
+            container.data_usage.unwrap_or(0.0)
+        )));
+
+        table.add_row(Row::new().with_cell("Sponsored Data Usage").with_cell(
+            format!(
+                "{:0.02} GB",
+                container.sponsored_data_usage.unwrap_or(0.0)
+            ),
+        ));
+
+        table.add_row(Row::new().with_cell("Remove Data Usage").with_cell(
+            format!("{:0.02} GB", container.remote_data_usage.unwrap_or(0.0)),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Container Type").with_cell(
+                container.container_type.unwrap_or("NA".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Associated App ID")
+                .with_cell(container.app.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Associated App")
+                .with_cell(container.app_name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Cloud Account")
+                .with_cell(container.cloud_account.unwrap_or("NA".to_string())),
+        );
+
+        print_table(&table, wide);
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_project_description(
+    project: ProjectDescribeResult,
+    show_json: &bool,
+    wide: bool,
+    max_rows: Option<usize>,
+) -> Result<()> {
+    if *show_json {
+        print_record(&project)?;
+    } else {
+        let fmt = "{:<}    {:<}";
+        let mut table = Table::new(fmt);
+
+        table.add_row(Row::new().with_cell("ID").with_cell(project.id));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Class")
+                .with_cell(project.class.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Name")
+                .with_cell(project.name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Summary")
+                .with_cell(project.summary.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Description")
+                .with_cell(project.description.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Billed To")
+                .with_cell(project.bill_to.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Access Level").with_cell(
+            project.level.map_or("NA".to_string(), |l| l.to_string()),
+        ));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Region")
+                .with_cell(project.region.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Protected")
+                .with_cell(project.protected.unwrap()),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Contains PHI")
+                .with_cell(project.contains_phi.unwrap()),
+        );
+
+        table.add_row(Row::new().with_cell("Created").with_cell(
+            project.created.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Created By").with_cell(
+            project.created_by.map_or("NA".to_string(), |c| c.user),
+        ));
+
+        table.add_row(Row::new().with_cell("Last Modified").with_cell(
+            project.modified.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Data Usage").with_cell(format!(
+            "{:0.02} GB",
+            project.data_usage.unwrap_or(0.0)
+        )));
+
+        table.add_row(Row::new().with_cell("Storage Cost").with_cell(format!(
+            "{}{:0.03}/month",
+            project.currency.map_or("$".to_string(), |c| c.symbol),
+            project.storage_cost.unwrap_or(0.0)
+        )));
+
+        table.add_row(Row::new().with_cell("Sponsored Usage").with_cell(
+            format!("{:0.02} GB", project.sponsored_data_usage.unwrap_or(0.0)),
+        ));
+
+        table.add_row(Row::new().with_cell("Sponsored Egress").with_cell(
+            format!(
+                "{:0.02} GB",
+                project.total_sponsored_egress_bytes.unwrap_or(0.0)
+            ),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Tags").with_cell(
+                project
+                    .tags
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Properties").with_cell(
+                project.properties.map_or("-".to_string(), |p| {
+                    format_properties(&p, max_rows)
+                }),
+            ),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Cloud Account")
+                .with_cell(project.cloud_account.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Remote Data Usage")
+                .with_cell(project.remote_data_usage.unwrap_or(0.0)),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Download Restricted")
+                .with_cell(project.download_restricted.unwrap()),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Archived Data Usage")
+                .with_cell(project.archived_data_usage.unwrap_or(0.0)),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Preview Viewer Restricted")
+                .with_cell(project.preview_viewer_restricted.unwrap()),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Display Data Protection Notice")
+                .with_cell(project.display_data_protection_notice.unwrap()),
+        );
+
+        table.add_row(Row::new().with_cell("Default Instance Type").with_cell(
+            project.default_instance_type.unwrap_or("NA".to_string()),
+        ));
+
+        table.add_row(Row::new().with_cell("Provider").with_cell(
+            project.provider.map_or("-".to_string(), |p| {
+                if p.is_empty() {
+                    "-".to_string()
+                } else {
+                    let pairs: Vec<String> =
+                        p.iter().map(|(k, v)| format!("{k} = {v}")).collect();
+                    pairs.join(", ")
+                }
+            }),
+        ));
+
+        print_table(&table, wide);
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_job_description(
+    job: JobDescribeResult,
+    show_json: &bool,
+    wide: bool,
+    max_rows: Option<usize>,
+    names: &HashMap<String, String>,
+) -> Result<()> {
+    if *show_json {
+        print_record(&job)?;
+    } else {
+        let fmt = "{:<}    {:<}";
+        let mut table = Table::new(fmt);
+        table.add_row(Row::new().with_cell("ID").with_cell(&job.id));
+
+        table.add_row(Row::new().with_cell("Try").with_cell(
+            job.try_number.map_or("NA".to_string(), |v| v.to_string()),
+        ));
+
+        table.add_row(
+            Row::new()
+                .with_cell("Class")
+                .with_cell(job.class.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Job Name")
+                .with_cell(job.name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Executable Name")
+                .with_cell(job.executable_name.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Project Context")
+                .with_cell(job.project.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Region")
+                .with_cell(job.region.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Billed To")
+                .with_cell(job.bill_to.unwrap_or("NA".to_string())),
+        );
+
+        if let Some(applet) = job.applet {
+            table.add_row(Row::new().with_cell("Applet").with_cell(applet));
+        }
+
+        if let Some(app) = &job.app {
+            table.add_row(Row::new().with_cell("App").with_cell(app));
+
+            table.add_row(
+                Row::new()
+                    .with_cell("Resources")
+                    .with_cell(job.resources.unwrap_or("NA".to_string())),
+            );
+
+            table.add_row(
+                Row::new()
+                    .with_cell("Project Cache")
+                    .with_cell(job.project_cache.unwrap_or("NA".to_string())),
+            );
+        }
+
+        table.add_row(
+            Row::new()
+                .with_cell("Instance Type")
+                .with_cell(job.instance_type.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Priority")
+                .with_cell(job.priority.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("State")
+                .with_cell(job.state.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Root Execution")
+                .with_cell(job.root_execution.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Origin Job")
+                .with_cell(job.origin_job.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Parent Job")
+                .with_cell(job.parent_job.unwrap_or("-".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Parent Analysis")
+                .with_cell(job.parent_analysis.unwrap_or("-".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Analysis")
+                .with_cell(job.analysis.unwrap_or("-".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Stage")
+                .with_cell(job.stage.unwrap_or("-".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Function")
+                .with_cell(job.function.unwrap_or("-".to_string())),
+        );
+
+        for (title, values) in
+            [("Input", job.run_input), ("Output", job.output)]
+        {
+            if let Some(values) = values {
+                table.add_row(Row::new().with_cell(title).with_cell(""));
+                let mut values: Vec<_> = values.iter().collect();
+                values.sort_by_key(|(name, _)| *name);
+                let (shown, hidden) = limit_rows(values, max_rows);
+                for (name, val) in shown {
+                    table.add_row(
+                        Row::new()
+                            .with_cell(format!("  {name}"))
+                            .with_cell(display_value(val, names)),
+                    );
+                }
+                if hidden > 0 {
+                    table.add_row(
+                        Row::new().with_cell("").with_cell(more_rows(hidden)),
+                    );
+                }
+            }
+        }
+
+        table.add_row(
+            Row::new()
+                .with_cell("Output Folder")
+                .with_cell(job.folder.unwrap_or("-".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Preserve Job Outputs Folder")
+                .with_cell(
+                    job.preserve_job_outputs
+                        .map_or("-".to_string(), |v| v.to_string()),
+                ),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Launched By")
+                .with_cell(job.launched_by.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(Row::new().with_cell("Created").with_cell(
+            job.created.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Try Created").with_cell(
+            job.try_created.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Started Running").with_cell(
+            job.started_running.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Stopped Running").with_cell(
+            job.stopped_running.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(Row::new().with_cell("Last Modified").with_cell(
+            job.modified.map_or("NA".to_string(), |d| {
+                d.format("%Y-%m-%d %H:%M:%S").to_string()
+            }),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Depends On").with_cell(
+                job.depends_on
+                    .map_or("NA".to_string(), |vals| vals.join(", ")),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Tags").with_cell(
+                job.tags
+                    .and_then(|v| (!v.is_empty()).then(|| v.join(", ")))
+                    .unwrap_or("-".to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Properties").with_cell(
+                job.properties.map_or("-".to_string(), |p| {
+                    format_properties(&p, max_rows)
+                }),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Tree TAT").with_cell(
+                job.tree_turnaround_time
+                    .map_or("-".to_string(), |v| v.to_string()),
+            ),
+        );
+
+        let currency = job.currency;
+
+        if let Some(reqs) = &job.system_requirements {
+            table.add_row(
+                Row::new().with_cell("System Requirements").with_cell(
+                    reqs.iter()
+                        .map(|(k, v)| format!("{{\"{k}\": {v}}}"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+            );
+        }
+
+        table.add_row(
+            Row::new()
+                .with_cell("Cost Limit")
+                .with_cell(format_price(job.cost_limit, &currency)),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Detached From")
+                .with_cell(job.detached_from.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Output Reused From")
+                .with_cell(job.output_reused_from.unwrap_or("NA".to_string())),
+        );
+
+        table.add_row(
+            Row::new()
+                .with_cell("Worker Reused Deadline Run Time")
+                .with_cell(
+                    job.worker_reuse_deadline_run_time
+                        .map_or("NA".to_string(), |val| val.to_string()),
+                ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Single Context").with_cell(
+                job.single_context
+                    .map_or("NA".to_string(), |val| val.to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Failure Counts").with_cell(
+                job.failure_counts
+                    .map_or("NA".to_string(), |val| val.to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Ignore Reuse").with_cell(
+                job.ignore_reuse
+                    .map_or("NA".to_string(), |val| val.to_string()),
+            ),
+        );
+
+        if let Some(https_app) = &job.https_app {
+            table.add_row(
+                Row::new().with_cell("HTTPS App").with_cell(https_app),
+            );
+        }
+
+        table.add_row(Row::new().with_cell("Rank").with_cell(
+            job.rank.map_or("NA".to_string(), |val| val.to_string()),
+        ));
+
+        table.add_row(
+            Row::new().with_cell("Detached From Try").with_cell(
+                job.detached_from_try
+                    .map_or("NA".to_string(), |val| val.to_string()),
+            ),
+        );
+
+        table.add_row(
+            Row::new().with_cell("Execution Policy").with_cell(
+                job.execution_policy
+                    .map_or("NA".to_string(), |val| val.to_string()),
+            ),
+        );
+
+        print_table(&table, wide);
+    }
+
+    Ok(())
+}
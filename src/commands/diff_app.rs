@@ -0,0 +1,419 @@
+use crate::dxenv::get_dx_env;
+use crate::json_parser::{
+    AccessSpec, DxApp, InputSpec, OutputSpec, RegionalOptions, RunSpec,
+};
+use crate::{
+    api, make_table, object_class, output_format, print_records, print_table,
+    resolve_executable, AppletDescribeField, AppletDescribeOptions,
+    AppletDescribeResult, OutputFormat,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use serde::Serialize;
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    path::Path,
+};
+use strum::IntoEnumIterator;
+
+#[derive(Clone, Parser, Debug)]
+pub struct DiffAppArgs {
+    /// dxapp.json, its directory, or an applet ID or name
+    #[arg(value_name = "APP")]
+    source: String,
+
+    /// dxapp.json, its directory, or an applet ID or name
+    #[arg(value_name = "APP")]
+    target: String,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+// --------------------------------------------------
+pub fn diff_app(args: DiffAppArgs) -> Result<()> {
+    let (source, source_deployed) = load_app(&args.source)?;
+    let (target, target_deployed) = load_app(&args.target)?;
+    let ignore = if source_deployed || target_deployed {
+        APPLET_IGNORED_FIELDS
+    } else {
+        &[]
+    };
+    let diffs = diff_apps(&source, &target, ignore)?;
+
+    if output_format() != OutputFormat::Table {
+        print_records(&diffs, &["status", "path", "detail"])?;
+    } else if !diffs.is_empty() {
+        let rows = diffs
+            .iter()
+            .map(|diff| {
+                vec![
+                    diff.status.to_string(),
+                    diff.path.clone(),
+                    diff.detail.clone(),
+                ]
+            })
+            .collect();
+        print_table(
+            &make_table("{:<} {:<} {:<}", rows, 2, args.wide),
+            args.wide,
+        );
+    }
+
+    match diffs.len() {
+        0 => {
+            println!("No differences");
+            Ok(())
+        }
+        1 => bail!("Found 1 difference"),
+        n => bail!("Found {n} differences"),
+    }
+}
+
+// Fields a built applet does not keep, either because only apps have
+// them or because the build turns them into something else, e.g., the
+// run file becomes inline code
+pub(crate) const APPLET_IGNORED_FIELDS: &[&str] = &[
+    "authorizedUsers",
+    "billTo",
+    "categories",
+    "developers",
+    "httpsApp",
+    "openSource",
+    "version",
+    "runSpec.assetDepends",
+    "runSpec.code",
+    "runSpec.file",
+    "runSpec.restartableEntryPoints",
+];
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct AppDiff {
+    pub(crate) status: &'static str,
+    pub(crate) path: String,
+    pub(crate) detail: String,
+}
+
+// --------------------------------------------------
+// A dxapp.json or the directory holding one, otherwise a deployed applet,
+// along with whether it was deployed
+fn load_app(name: &str) -> Result<(DxApp, bool)> {
+    let path = Path::new(name);
+    let path = if path.is_dir() {
+        path.join("dxapp.json")
+    } else {
+        path.to_path_buf()
+    };
+
+    if path.is_file() {
+        let json = fs::read_to_string(&path)?;
+        let app = serde_json::from_str(&json).map_err(|e| {
+            anyhow!(r#"Cannot parse "{}": {e}"#, path.display())
+        })?;
+        return Ok((app, false));
+    }
+
+    let dx_env = get_dx_env()?;
+    let (project_id, applet_id) = resolve_executable(&dx_env, name)?;
+    if object_class(&applet_id) != "applet" {
+        bail!(r#""{name}" is neither a dxapp.json nor an applet"#)
+    }
+
+    let options = AppletDescribeOptions {
+        project: Some(project_id),
+        fields: Some(
+            AppletDescribeField::iter()
+                .map(|e| (e, true))
+                .collect::<HashMap<_, _>>(),
+        ),
+    };
+    let applet = api::describe_applet(&dx_env, &applet_id, &options)?;
+    Ok((applet_to_app(applet)?, true))
+}
+
+// --------------------------------------------------
+// The dxapp.json of an applet, as far as the platform keeps it
+pub(crate) fn applet_to_app(applet: AppletDescribeResult) -> Result<DxApp> {
+    let run_spec = applet
+        .run_spec
+        .ok_or(anyhow!("Applet {} has no runSpec", applet.id))?;
+
+    let regional_options =
+        run_spec.system_requirements_by_region.map(|by_region| {
+            by_region
+                .into_iter()
+                .filter_map(|(region, requirements)| {
+                    let system_requirements =
+                        serde_json::to_value(requirements)
+                            .ok()
+                            .and_then(|val| serde_json::from_value(val).ok())?;
+                    Some((
+                        region,
+                        RegionalOptions {
+                            resources: None,
+                            system_requirements,
+                        },
+                    ))
+                })
+                .collect()
+        });
+
+    let details = match applet.details {
+        Some(details) => {
+            Some(serde_json::from_value(serde_json::to_value(details)?)?)
+        }
+        _ => None,
+    };
+
+    Ok(DxApp {
+        name: applet.name,
+        title: applet.title.unwrap_or_default(),
+        dxapi: applet.dx_api,
+        summary: applet.summary,
+        description: applet.description,
+        version: None,
+        developer_notes: applet.developer_notes,
+        types: applet.types.unwrap_or_default(),
+        categories: vec![],
+        bill_to: None,
+        open_source: None,
+        developers: vec![],
+        authorized_users: vec![],
+        input_spec: applet
+            .input_spec
+            .unwrap_or_default()
+            .into_iter()
+            .map(|input| InputSpec {
+                name: input.name,
+                label: input.label,
+                class: input.class,
+                optional: input.optional,
+                default: input.default,
+                patterns: input.patterns.unwrap_or_default(),
+                help: input.help,
+                choices: input.choices.unwrap_or_default(),
+                input_type: None,
+                group: input.group,
+                suggestions: vec![],
+            })
+            .collect(),
+        output_spec: applet
+            .output_spec
+            .unwrap_or_default()
+            .into_iter()
+            .map(|output| OutputSpec {
+                name: output.name,
+                class: output.class,
+                label: output.label,
+                help: output.help,
+                optional: output.optional,
+                patterns: output.patterns.unwrap_or_default(),
+            })
+            .collect(),
+        run_spec: RunSpec {
+            interpreter: Some(run_spec.interpreter),
+            file: None,
+            distribution: run_spec.distribution,
+            release: Some(run_spec.release),
+            version: Some(run_spec.version),
+            code: None,
+            head_job_on_demand: run_spec.head_job_on_demand,
+            restartable_entry_points: None,
+            asset_depends: None,
+            exec_depends: run_spec
+                .exec_depends
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|dep| serde_json::from_value(dep).ok())
+                .collect(),
+            // Applets keep these by region, as in "regionalOptions"
+            system_requirements: HashMap::new(),
+            timeout_policy: run_spec.timeout_policy,
+        },
+        https_app: None,
+        access: applet.access.map(|access| AccessSpec {
+            network: access.network,
+            project: access.project,
+            all_projects: access.all_projects,
+            developer: access.developer,
+            project_creation: access.project_creation,
+        }),
+        regional_options,
+        details,
+        ignore_reuse: applet.ignore_reuse,
+    })
+}
+
+// --------------------------------------------------
+// Compares apps field by field, descending into sections such as
+// "runSpec", with inputs and outputs matched by name
+pub(crate) fn diff_apps(
+    source: &DxApp,
+    target: &DxApp,
+    ignore: &[&str],
+) -> Result<Vec<AppDiff>> {
+    let source = serde_json::to_value(source)?;
+    let target = serde_json::to_value(target)?;
+    let mut diffs = vec![];
+
+    for key in json_keys(Some(&source), Some(&target)) {
+        let (src, tgt) = (source.get(&key), target.get(&key));
+        match key.as_str() {
+            "inputSpec" | "outputSpec" => {
+                diffs.extend(diff_app_specs(&key, src, tgt))
+            }
+            _ => diff_app_value(&key, src, tgt, ignore, &mut diffs),
+        }
+    }
+
+    Ok(diffs)
+}
+
+// --------------------------------------------------
+fn diff_app_specs(
+    key: &str,
+    source: Option<&serde_json::Value>,
+    target: Option<&serde_json::Value>,
+) -> Vec<AppDiff> {
+    // Specs by name in their original order, where an unset "optional"
+    // means the same as false
+    let specs = |specs: Option<&serde_json::Value>| -> Vec<(String, _)> {
+        specs
+            .and_then(|val| val.as_array())
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mut spec| {
+                if let Some(obj) = spec.as_object_mut() {
+                    if obj.get("optional") == Some(&serde_json::json!(false)) {
+                        obj.remove("optional");
+                    }
+                }
+                let name = spec["name"].as_str().unwrap_or("").to_string();
+                (name, spec)
+            })
+            .collect()
+    };
+    let source = specs(source);
+    let target = specs(target);
+    let find = |specs: &[(String, serde_json::Value)], name: &str| {
+        specs
+            .iter()
+            .find(|(spec_name, _)| spec_name == name)
+            .map(|(_, spec)| spec.clone())
+    };
+
+    let mut diffs = vec![];
+    for (name, src) in &source {
+        let path = format!("{key}.{name}");
+        match find(&target, name) {
+            Some(tgt) => {
+                let details: Vec<_> = json_keys(Some(src), Some(&tgt))
+                    .into_iter()
+                    .filter(|field| src.get(field) != tgt.get(field))
+                    .map(|field| {
+                        format!(
+                            "{field} {} != {}",
+                            app_value_string(src.get(&field)),
+                            app_value_string(tgt.get(&field))
+                        )
+                    })
+                    .collect();
+                if !details.is_empty() {
+                    diffs.push(AppDiff {
+                        status: "differs",
+                        path,
+                        detail: details.join("; "),
+                    });
+                }
+            }
+            _ => diffs.push(AppDiff {
+                status: "source-only",
+                path,
+                detail: app_value_string(src.get("class")),
+            }),
+        }
+    }
+
+    for (name, tgt) in &target {
+        if find(&source, name).is_none() {
+            diffs.push(AppDiff {
+                status: "target-only",
+                path: format!("{key}.{name}"),
+                detail: app_value_string(tgt.get("class")),
+            });
+        }
+    }
+
+    diffs
+}
+
+// --------------------------------------------------
+fn diff_app_value(
+    path: &str,
+    source: Option<&serde_json::Value>,
+    target: Option<&serde_json::Value>,
+    ignore: &[&str],
+    diffs: &mut Vec<AppDiff>,
+) {
+    if ignore.contains(&path) {
+        return;
+    }
+
+    let status = match (source, target) {
+        (Some(_), None) => "source-only",
+        (None, Some(_)) => "target-only",
+        (Some(src), Some(tgt)) if src.is_object() && tgt.is_object() => {
+            for key in json_keys(source, target) {
+                diff_app_value(
+                    &format!("{path}.{key}"),
+                    src.get(&key),
+                    tgt.get(&key),
+                    ignore,
+                    diffs,
+                );
+            }
+            return;
+        }
+        (Some(src), Some(tgt)) if src != tgt => "differs",
+        _ => return,
+    };
+
+    diffs.push(AppDiff {
+        status,
+        path: path.to_string(),
+        detail: match status {
+            "differs" => format!(
+                "{} != {}",
+                app_value_string(source),
+                app_value_string(target)
+            ),
+            _ => app_value_string(source.or(target)),
+        },
+    });
+}
+
+// --------------------------------------------------
+// The sorted keys of either JSON object
+fn json_keys(
+    source: Option<&serde_json::Value>,
+    target: Option<&serde_json::Value>,
+) -> BTreeSet<String> {
+    [source, target]
+        .into_iter()
+        .flatten()
+        .filter_map(|val| val.as_object())
+        .flat_map(|obj| obj.keys().cloned())
+        .collect()
+}
+
+// --------------------------------------------------
+fn app_value_string(val: Option<&serde_json::Value>) -> String {
+    match val {
+        Some(serde_json::Value::String(val)) => val.to_string(),
+        Some(val) => val.to_string(),
+        _ => "NA".to_string(),
+    }
+}
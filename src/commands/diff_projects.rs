@@ -0,0 +1,269 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{
+    api, entity_id, make_table, output_format, print_records, print_table,
+    resolve_path, FindDataOptions, FindDataScope, FindDescribe, OutputFormat,
+    ProjectDescribeField, ProjectDescribeOptions,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    path::Path,
+};
+
+#[derive(Clone, Parser, Debug)]
+pub struct DiffProjectsArgs {
+    /// Source project ID, optionally with a folder
+    #[arg(value_name = "PROJECT[:FOLDER]")]
+    source: String,
+
+    /// Target project ID, optionally with a folder
+    #[arg(value_name = "PROJECT[:FOLDER]")]
+    target: String,
+
+    /// Do not compare tags
+    #[arg(long)]
+    ignore_tags: bool,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+// --------------------------------------------------
+pub fn diff_projects(args: DiffProjectsArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let source = project_inventory(&dx_env, &args.source, args.ignore_tags)?;
+    let target = project_inventory(&dx_env, &args.target, args.ignore_tags)?;
+    let diffs = diff_inventories(&source, &target);
+
+    if output_format() != OutputFormat::Table {
+        print_records(&diffs, &["status", "path", "detail"])?;
+    } else if !diffs.is_empty() {
+        let rows = diffs
+            .iter()
+            .map(|diff| {
+                vec![
+                    diff.status.to_string(),
+                    diff.path.clone(),
+                    diff.detail.clone(),
+                ]
+            })
+            .collect();
+        print_table(
+            &make_table("{:<} {:<} {:<}", rows, 2, args.wide),
+            args.wide,
+        );
+    }
+
+    match diffs.len() {
+        0 => {
+            println!(
+                "No differences in {} folders and {} objects",
+                source.folders.len(),
+                source.objects.values().map(Vec::len).sum::<usize>()
+            );
+            Ok(())
+        }
+        1 => bail!("Found 1 difference"),
+        n => bail!("Found {n} differences"),
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ProjectInventory {
+    pub(crate) folders: BTreeSet<String>,
+    pub(crate) objects: BTreeMap<String, Vec<InventoryObject>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct InventoryObject {
+    pub(crate) size: Option<u64>,
+    pub(crate) md5: Option<String>,
+    pub(crate) tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct ProjectDiff {
+    pub(crate) status: &'static str,
+    pub(crate) path: String,
+    pub(crate) detail: String,
+}
+
+// --------------------------------------------------
+// Folders and objects keyed by their path relative to the root
+fn project_inventory(
+    dx_env: &DxEnvironment,
+    project_path: &str,
+    ignore_tags: bool,
+) -> Result<ProjectInventory> {
+    // A bare project ID means the whole project, not the working folder
+    let project_path = if entity_id::PROJECT_ID.is_match(project_path) {
+        format!("{project_path}:/")
+    } else {
+        project_path.to_string()
+    };
+    let dx_path = resolve_path(dx_env, &project_path)?;
+    let root = dx_path.path.trim_end_matches('/').to_string();
+    let relative = |path: &str| -> Option<String> {
+        let rest = path.strip_prefix(&root)?;
+        (rest.is_empty() || rest.starts_with('/'))
+            .then(|| if rest.is_empty() { "/" } else { rest }.to_string())
+    };
+
+    let desc_opts = ProjectDescribeOptions {
+        fields: Some(HashMap::from([(ProjectDescribeField::Folders, true)])),
+    };
+    let project =
+        api::describe_project(dx_env, &dx_path.project_id, &desc_opts)?;
+
+    let mut inventory = ProjectInventory {
+        folders: project
+            .folders
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|folder| relative(folder))
+            .collect(),
+        ..Default::default()
+    };
+
+    let mut options = FindDataOptions {
+        class: None,
+        state: None,
+        name: None,
+        visibility: None,
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(dx_path.project_id.clone()),
+            folder: Some(if root.is_empty() {
+                "/".to_string()
+            } else {
+                root.clone()
+            }),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Mapping(HashMap::from([(
+            "properties".to_string(),
+            true,
+        )]))),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+
+    for desc in api::find_data(dx_env, &mut options)?
+        .into_iter()
+        .filter_map(|row| row.describe)
+    {
+        let folder = desc.folder.clone().unwrap_or("/".to_string());
+        let path = Path::new(&folder)
+            .join(desc.name.clone().unwrap_or_default())
+            .display()
+            .to_string();
+        let Some(path) = relative(&path) else {
+            continue;
+        };
+
+        let mut tags = if ignore_tags { vec![] } else { desc.tags };
+        tags.sort();
+        inventory
+            .objects
+            .entry(path)
+            .or_default()
+            .push(InventoryObject {
+                size: desc.size,
+                md5: desc
+                    .properties
+                    .and_then(|props| props.get("md5").cloned()),
+                tags,
+            });
+    }
+
+    for objects in inventory.objects.values_mut() {
+        objects.sort();
+    }
+
+    Ok(inventory)
+}
+
+// --------------------------------------------------
+pub(crate) fn diff_inventories(
+    source: &ProjectInventory,
+    target: &ProjectInventory,
+) -> Vec<ProjectDiff> {
+    let diff = |status, path: &str, detail: String| ProjectDiff {
+        status,
+        path: path.to_string(),
+        detail,
+    };
+
+    let mut diffs = vec![];
+    for folder in source.folders.difference(&target.folders) {
+        diffs.push(diff("source-only", folder, "folder".to_string()));
+    }
+    for folder in target.folders.difference(&source.folders) {
+        diffs.push(diff("target-only", folder, "folder".to_string()));
+    }
+
+    let paths: BTreeSet<_> =
+        source.objects.keys().chain(target.objects.keys()).collect();
+    for path in paths {
+        match (source.objects.get(path), target.objects.get(path)) {
+            (Some(_), None) => {
+                diffs.push(diff("source-only", path, "object".to_string()))
+            }
+            (None, Some(_)) => {
+                diffs.push(diff("target-only", path, "object".to_string()))
+            }
+            (Some(src), Some(tgt)) if src.len() != tgt.len() => {
+                diffs.push(diff(
+                    "differs",
+                    path,
+                    format!("{} copies != {} copies", src.len(), tgt.len()),
+                ));
+            }
+            (Some(src), Some(tgt)) => {
+                for (a, b) in src.iter().zip(tgt) {
+                    let mut details = vec![];
+                    if a.size != b.size {
+                        details.push(format!(
+                            "size {} != {}",
+                            a.size.map_or("NA".to_string(), |s| s.to_string()),
+                            b.size.map_or("NA".to_string(), |s| s.to_string()),
+                        ));
+                    }
+                    if a.md5 != b.md5 {
+                        details.push(format!(
+                            "md5 {} != {}",
+                            a.md5.as_deref().unwrap_or("NA"),
+                            b.md5.as_deref().unwrap_or("NA"),
+                        ));
+                    }
+                    if a.tags != b.tags {
+                        details.push(format!(
+                            "tags [{}] != [{}]",
+                            a.tags.join(","),
+                            b.tags.join(","),
+                        ));
+                    }
+                    if !details.is_empty() {
+                        diffs.push(diff("differs", path, details.join("; ")));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    diffs
+}
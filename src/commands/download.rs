@@ -0,0 +1,555 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::exit::CliError;
+use crate::{
+    api, emit_progress, find_files_by_path, incomplete, open_outfile,
+    read_stdin_args, resolve_path, select_file_from_list, ChunkReader,
+    ChunkWriter, DownloadOptions, FileDescribeField, FileDescribeOptions,
+    FindDataDescribe, FindDataOptions, FindDataResult, FindDataScope,
+    FindDescribe, ListFolderOptionOnlyValue, ListFolderOptions,
+    ListFolderResult, ObjectState, ObjectType, ProgressEvent, ProgressFormat,
+    ARCHIVE_CHUNKS, ARCHIVE_CHUNK_SIZE,
+};
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rayon::prelude::*;
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, BufWriter, Write as _},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+};
+use tar::Builder;
+
+/// The extensions of archives that "download --archive" writes
+const ARCHIVE_EXTENSIONS: &[&str] = &[".tar.gz", ".tgz"];
+
+#[derive(Clone, Parser, Debug)]
+pub struct DownloadArgs {
+    /// Object identifier
+    #[arg()]
+    paths: Vec<String>,
+
+    /// Output directory
+    #[arg(short, long)]
+    dir: Option<String>,
+
+    /// Local filename, "-" for STDOUT
+    #[arg(short, long)]
+    output: Option<String>,
+
+    /// Overwrite local file
+    #[arg(short, long, default_value = "false")]
+    force: bool,
+
+    /// Upload directories recursively
+    #[arg(short, long, default_value = "false")]
+    recursive: bool,
+
+    /// Download all matching objects
+    #[arg(short, long, default_value = "false")]
+    all: bool,
+
+    /// Do not show a progress bar
+    #[arg(short, long, default_value = "false")]
+    quiet: bool,
+
+    /// Number of concurrent downloads for directories
+    #[arg(short('j'), long, value_name = "NUM", default_value = "1")]
+    threads: usize,
+
+    /// Skip local files with matching size and modification time
+    #[arg(long, alias = "sync", default_value = "false")]
+    skip_existing: bool,
+
+    /// Progress display, "json" writes events to STDERR
+    #[arg(long, value_name = "FORMAT", default_value = "bar")]
+    progress: ProgressFormat,
+
+    /// Read newline-separated IDs or paths from STDIN, same as "-"
+    #[arg(long, default_value = "false")]
+    stdin: bool,
+
+    /// Write a folder into one ".tar.gz" file, "-" for STDOUT
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["dir", "output", "skip_existing", "threads"]
+    )]
+    archive: Option<String>,
+}
+
+// --------------------------------------------------
+pub fn download(args: DownloadArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let paths = read_stdin_args(&args.paths, args.stdin)?;
+    if let Some(archive) = &args.archive {
+        return match paths.as_slice() {
+            [path] => download_archive(&dx_env, path, archive, &args),
+            _ => bail!(CliError::Usage(
+                "An archive takes exactly one folder".to_string()
+            )),
+        };
+    }
+
+    let outdir = &args.dir.clone().unwrap_or(".".to_string());
+    let outdir = PathBuf::from(&outdir);
+    if !outdir.is_dir() {
+        fs::create_dir_all(&outdir)?;
+    }
+
+    let mut errors = vec![];
+    for path in &paths {
+        match resolve_path(&dx_env, path) {
+            Err(e) => {
+                eprintln!("{e}");
+                errors.push(e);
+            }
+            Ok(dx_path) => {
+                // Handle folders
+                let parent = Path::new(&dx_path.path)
+                    .parent()
+                    .map_or(dx_env.cli_wd.to_string(), |val| {
+                        val.to_string_lossy().to_string()
+                    });
+
+                let options = ListFolderOptions {
+                    folder: &parent,
+                    only: Some(ListFolderOptionOnlyValue::Folders),
+                    describe: true,
+                    has_subfolder_flags: true,
+                    include_hidden: args.all,
+                };
+
+                let results: ListFolderResult =
+                    api::ls(&dx_env, &dx_path.project_id, options)?;
+
+                if let Some(folders) = results.folders {
+                    let matches: Vec<_> = folders
+                        .iter()
+                        // tuple with (dirname, has_subdir)
+                        .map(|t| t.0.clone())
+                        .filter(|name| name == &dx_path.path)
+                        .collect();
+
+                    if let Some(dir) = matches.first() {
+                        // TODO: make a separate "download-dir" action?
+                        if !args.recursive {
+                            bail!("Use recursive flag to download diretory");
+                        }
+
+                        let mut find_opts = FindDataOptions {
+                            class: Some(ObjectType::File),
+                            state: None,
+                            name: None,
+                            visibility: None,
+                            id: vec![],
+                            object_type: None,
+                            tags: vec![],
+                            region: vec![],
+                            properties: None,
+                            link: None,
+                            scope: Some(FindDataScope {
+                                project: Some(dx_path.project_id.clone()),
+                                folder: Some(dir.clone()),
+                                recurse: Some(true),
+                            }),
+                            sort_by: None,
+                            level: None,
+                            modified: None,
+                            created: None,
+                            describe: Some(FindDescribe::Boolean(true)),
+                            starting: None,
+                            limit: None,
+                            archival_state: None,
+                        };
+
+                        let outdir =
+                            &args.dir.clone().unwrap_or(".".to_string());
+                        let files = api::find_data(&dx_env, &mut find_opts)?;
+                        if let Err(e) =
+                            download_folder(&dx_env, files, outdir, path, &args)
+                        {
+                            eprintln!("{e}");
+                            errors.push(e);
+                        }
+                    }
+                }
+
+                // Handle file(s)
+                let files = find_files_by_path(
+                    &dx_env,
+                    &dx_path.path,
+                    &dx_path.project_id,
+                )?;
+
+                if let Some(file_id) = select_file_from_list(&files, false) {
+                    if let Err(e) =
+                        download_file(&dx_env, &file_id, &outdir, args.clone())
+                    {
+                        if args.progress == ProgressFormat::Json {
+                            emit_progress(&ProgressEvent::Failed {
+                                file: &file_id,
+                                error: e.to_string(),
+                            });
+                        }
+                        eprintln!("{e}");
+                        errors.push(e);
+                    }
+                }
+            }
+        }
+    }
+
+    incomplete("Download", errors)
+}
+
+// --------------------------------------------------
+fn download_folder(
+    dx_env: &DxEnvironment,
+    files: Vec<FindDataResult>,
+    outdir: &str,
+    path: &str,
+    args: &DownloadArgs,
+) -> Result<()> {
+    let mut downloads: Vec<(String, PathBuf, FindDataDescribe)> = vec![];
+    for file in files {
+        if let Some(desc) = file.describe {
+            let folder = desc.folder.clone().unwrap_or(path.to_string());
+            let folder = folder.strip_prefix('/').unwrap_or(&folder);
+            let local_dir = Path::new(&outdir).join(folder);
+            downloads.push((file.id, local_dir, desc));
+        }
+    }
+
+    let dl_args = DownloadArgs {
+        // Stale files must be replaced when syncing
+        force: args.force || args.skip_existing,
+        // Concurrent progress bars would trample each other
+        quiet: args.quiet
+            || (args.threads > 1 && args.progress == ProgressFormat::Bar),
+        ..args.clone()
+    };
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.threads.max(1))
+        .build()?;
+
+    let results: Vec<DownloadStatus> = pool.install(|| {
+        downloads
+            .par_iter()
+            .map(|(file_id, local_dir, desc)| {
+                let local_path = local_dir
+                    .join(desc.name.clone().unwrap_or(file_id.to_string()));
+                if args.skip_existing
+                    && is_up_to_date(&local_path, desc.size, desc.modified)
+                {
+                    return DownloadStatus::Skipped;
+                }
+
+                match download_file(dx_env, file_id, local_dir, dl_args.clone())
+                {
+                    Ok(_) => {
+                        if !args.quiet {
+                            println!("{}", local_path.display());
+                        }
+                        DownloadStatus::Downloaded
+                    }
+                    Err(e) => {
+                        let file = local_path.display().to_string();
+                        if args.progress == ProgressFormat::Json {
+                            emit_progress(&ProgressEvent::Failed {
+                                file: &file,
+                                error: e.to_string(),
+                            });
+                        }
+                        eprintln!("{file}: {e}");
+                        DownloadStatus::Failed
+                    }
+                }
+            })
+            .collect()
+    });
+
+    let count = |status: DownloadStatus| {
+        results.iter().filter(|&r| *r == status).count()
+    };
+    println!(
+        "Downloaded {}, skipped {}, failed {}",
+        count(DownloadStatus::Downloaded),
+        count(DownloadStatus::Skipped),
+        count(DownloadStatus::Failed),
+    );
+
+    if count(DownloadStatus::Failed) > 0 {
+        bail!(r#"Failed to download some files in "{path}""#)
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// Streams every closed file in a folder into a gzipped tarball, one
+// file at a time, so nothing is written to the local disk but the archive
+fn download_archive(
+    dx_env: &DxEnvironment,
+    path: &str,
+    archive: &str,
+    args: &DownloadArgs,
+) -> Result<()> {
+    if archive != "-"
+        && !ARCHIVE_EXTENSIONS.iter().any(|e| archive.ends_with(e))
+    {
+        bail!(CliError::Usage(format!(
+            r#"Archive "{archive}" must end with {}"#,
+            ARCHIVE_EXTENSIONS.join(" or ")
+        )));
+    }
+    if archive != "-" && Path::new(archive).exists() && !args.force {
+        bail!(r#"Use force to overwrite "{archive}""#);
+    }
+
+    let dx_path = resolve_path(dx_env, path)?;
+    let folder = dx_path.path.clone();
+    if folder != "/" {
+        let parent = Path::new(&folder)
+            .parent()
+            .map_or("/".to_string(), |p| p.display().to_string());
+        let options = ListFolderOptions {
+            folder: &parent,
+            only: Some(ListFolderOptionOnlyValue::Folders),
+            describe: false,
+            has_subfolder_flags: true,
+            include_hidden: args.all,
+        };
+        let listing = api::ls(dx_env, &dx_path.project_id, options)?;
+        if !listing
+            .folders
+            .unwrap_or_default()
+            .iter()
+            .any(|(name, _)| name == &folder)
+        {
+            bail!(CliError::NotFound(format!(
+                r#"Found no folder "{dx_path}""#
+            )));
+        }
+    }
+
+    let mut find_opts = FindDataOptions {
+        class: Some(ObjectType::File),
+        state: Some(ObjectState::Closed),
+        name: None,
+        visibility: None,
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(dx_path.project_id.clone()),
+            folder: Some(folder.clone()),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Boolean(true)),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+    let files = api::find_data(dx_env, &mut find_opts)?;
+
+    let result = write_archive(dx_env, &folder, files, archive, args);
+    if result.is_err() && archive != "-" {
+        // Leave no truncated archive that looks complete
+        let _ = fs::remove_file(archive);
+    }
+    let num_files = result?;
+    note!(
+        "Archived {num_files} file{} from {dx_path}",
+        if num_files == 1 { "" } else { "s" }
+    );
+    Ok(())
+}
+
+// --------------------------------------------------
+fn write_archive(
+    dx_env: &DxEnvironment,
+    folder: &str,
+    files: Vec<FindDataResult>,
+    archive: &str,
+    args: &DownloadArgs,
+) -> Result<usize> {
+    let progress = if args.quiet {
+        ProgressFormat::None_
+    } else {
+        args.progress
+    };
+    let dl_options = DownloadOptions {
+        duration: None,
+        filename: None,
+        project: None,
+        preauthenticated: None,
+        sticky_ip: None,
+    };
+
+    let enc = GzEncoder::new(open_outfile(archive)?, Compression::default());
+    let mut tarball = Builder::new(enc);
+    let mut num_files = 0;
+    for file in files {
+        let Some(desc) = file.describe else { continue };
+        let name = desc.name.clone().unwrap_or(file.id.clone());
+        let entry = archive_entry_path(
+            folder,
+            desc.folder.as_deref().unwrap_or(folder),
+            &name,
+        );
+
+        let mut header = tar::Header::new_gnu();
+        header.set_entry_type(tar::EntryType::Regular);
+        header.set_size(
+            desc.size.ok_or(anyhow!("Missing size for {}", file.id))?,
+        );
+        header.set_mode(0o644);
+        header.set_mtime(desc.modified.map_or(0, |t| t.timestamp() as u64));
+
+        let download = api::download(dx_env, &file.id, &dl_options)?;
+        let (sender, chunks) = mpsc::sync_channel(ARCHIVE_CHUNKS);
+        thread::scope(|scope| -> Result<()> {
+            scope.spawn(move || {
+                let writer = BufWriter::with_capacity(
+                    ARCHIVE_CHUNK_SIZE,
+                    ChunkWriter(sender.clone()),
+                );
+                if let Err(e) =
+                    api::download_file(&download, writer, &name, progress)
+                {
+                    // The reader may already be gone if the archive failed
+                    let _ = sender.send(Err(io::Error::other(e.to_string())));
+                }
+            });
+
+            let reader = ChunkReader {
+                chunks,
+                chunk: io::Cursor::new(vec![]),
+            };
+            tarball
+                .append_data(&mut header, &entry, reader)
+                .map_err(|e| anyhow!("{}: {e}", entry.display()))
+        })?;
+        num_files += 1;
+    }
+    tarball.into_inner()?.finish()?.flush()?;
+    Ok(num_files)
+}
+
+// --------------------------------------------------
+// Keeps the name of the downloaded folder at the top of the archive, as
+// "tar" would for a local one, unless it is the root of the project
+pub(crate) fn archive_entry_path(
+    folder: &str,
+    file_folder: &str,
+    name: &str,
+) -> PathBuf {
+    let top = Path::new(folder).parent().unwrap_or(Path::new("/"));
+    Path::new(file_folder)
+        .strip_prefix(top)
+        .unwrap_or(Path::new(""))
+        .join(name)
+}
+
+#[derive(Debug, PartialEq)]
+enum DownloadStatus {
+    Downloaded,
+    Skipped,
+    Failed,
+}
+
+// --------------------------------------------------
+pub(crate) fn is_up_to_date(
+    local_path: &Path,
+    size: Option<u64>,
+    modified: Option<DateTime<Utc>>,
+) -> bool {
+    // A local copy is current if it has the same size and
+    // was written after the remote file was last modified
+    match fs::metadata(local_path) {
+        Ok(meta) => {
+            let same_size = size.is_some_and(|s| s == meta.len());
+            let newer = match (modified, meta.modified()) {
+                (Some(remote), Ok(local)) => {
+                    DateTime::<Utc>::from(local) >= remote
+                }
+                _ => false,
+            };
+            same_size && newer
+        }
+        _ => false,
+    }
+}
+
+// --------------------------------------------------
+pub fn download_file(
+    dx_env: &DxEnvironment,
+    file_id: &str,
+    outdir: &PathBuf,
+    args: DownloadArgs,
+) -> Result<()> {
+    if !outdir.is_dir() {
+        fs::create_dir_all(outdir)?;
+    }
+
+    let dl_options = DownloadOptions {
+        duration: None,
+        filename: None,
+        project: None,
+        preauthenticated: None,
+        sticky_ip: None,
+    };
+
+    let desc_opts = FileDescribeOptions {
+        project: None,
+        fields: Some(HashMap::from([(FileDescribeField::Name, true)])),
+        details: true,
+        properties: true,
+    };
+
+    let desc = api::describe_file(dx_env, file_id, &desc_opts)?;
+    let filename = &desc.name.clone().unwrap_or(desc.id);
+    let local_path = match &args.output {
+        Some(val) => {
+            if val == "-" {
+                val.clone()
+            } else {
+                outdir.join(val.clone()).display().to_string()
+            }
+        }
+        _ => outdir
+            .join(desc.name.unwrap_or(file_id.to_string()))
+            .display()
+            .to_string(),
+    };
+
+    if local_path != "-" {
+        let path = Path::new(&local_path);
+        if path.exists() && !args.force {
+            bail!(r#"Use force to overwrite "{local_path}""#);
+        }
+    }
+
+    let download = api::download(dx_env, file_id, &dl_options)?;
+    let outfile = open_outfile(&local_path)?;
+    let progress = if args.quiet {
+        ProgressFormat::None_
+    } else {
+        args.progress
+    };
+    api::download_file(&download, outfile, filename, progress)?;
+    Ok(())
+}
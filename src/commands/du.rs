@@ -0,0 +1,178 @@
+use crate::dxenv::get_dx_env;
+use crate::{
+    api, make_table, output_format, print_records, print_table, resolve_path,
+    theme, FindDataOptions, FindDataScope, FindDescribe, ObjectType,
+    OutputFormat, ProjectDescribeField, ProjectDescribeOptions,
+};
+use anyhow::Result;
+use clap::Parser;
+use serde::Serialize;
+use size::Size;
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, Parser, Debug)]
+pub struct DuArgs {
+    /// Project or folder path, defaults to the current folder
+    #[arg()]
+    path: Option<String>,
+
+    /// Number of folder levels to summarize
+    #[arg(short, long, value_name = "N", default_value = "1")]
+    depth: usize,
+
+    /// Human-readable sizes
+    #[arg(short('H'), long)]
+    human: bool,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct DuRow {
+    pub(crate) folder: String,
+    pub(crate) files: usize,
+    pub(crate) size: u64,
+}
+
+// --------------------------------------------------
+pub fn du(args: DuArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let path = args.path.clone().unwrap_or(dx_env.cli_wd.clone());
+    let dx_path = resolve_path(&dx_env, &path)?;
+    let folder = if dx_path.path.is_empty() {
+        "/".to_string()
+    } else {
+        dx_path.path.clone()
+    };
+
+    let mut options = FindDataOptions {
+        class: Some(ObjectType::File),
+        state: None,
+        name: None,
+        visibility: None,
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(dx_path.project_id.clone()),
+            folder: Some(folder.clone()),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Boolean(true)),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+    let files: Vec<_> = api::find_data(&dx_env, &mut options)?
+        .into_iter()
+        .filter_map(|res| res.describe)
+        .map(|desc| {
+            (
+                desc.folder.unwrap_or("/".to_string()),
+                desc.size.unwrap_or_default(),
+            )
+        })
+        .collect();
+    let rows = du_rows(&files, &folder, args.depth);
+
+    if output_format() != OutputFormat::Table {
+        return print_records(&rows, &["folder", "files", "size"]);
+    }
+
+    let desc_opts = ProjectDescribeOptions {
+        fields: Some(HashMap::from([
+            (ProjectDescribeField::Name, true),
+            (ProjectDescribeField::DataUsage, true),
+            (ProjectDescribeField::ArchivedDataUsage, true),
+            (ProjectDescribeField::RemoteDataUsage, true),
+        ])),
+    };
+    let project =
+        api::describe_project(&dx_env, &dx_path.project_id, &desc_opts)?;
+
+    println!(
+        "{}",
+        theme::heading(format!(
+            "{} ({}):{folder}",
+            project.name.unwrap_or_default(),
+            dx_path.project_id,
+        ))
+    );
+    for (label, usage) in [
+        ("Data usage", project.data_usage),
+        ("Archived data usage", project.archived_data_usage),
+        ("Remote data usage", project.remote_data_usage),
+    ] {
+        if let Some(gb) = usage {
+            println!("{label}: {gb:.2} GB");
+        }
+    }
+
+    let size = |bytes: u64| {
+        if args.human {
+            Size::from_bytes(bytes).to_string()
+        } else {
+            bytes.to_string()
+        }
+    };
+    let mut table_rows: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            vec![row.folder.clone(), row.files.to_string(), size(row.size)]
+        })
+        .collect();
+    table_rows.push(vec![
+        "Total".to_string(),
+        files.len().to_string(),
+        size(files.iter().map(|(_, size)| size).sum()),
+    ]);
+    print_table(
+        &make_table("{:<} {:>} {:>}", table_rows, 0, args.wide),
+        args.wide,
+    );
+    Ok(())
+}
+
+// --------------------------------------------------
+// Totals the (folder, size) of each file under the folders at most
+// "depth" levels below the root
+pub(crate) fn du_rows(
+    files: &[(String, u64)],
+    root: &str,
+    depth: usize,
+) -> Vec<DuRow> {
+    let mut usage: BTreeMap<String, (usize, u64)> = BTreeMap::new();
+    for (folder, size) in files {
+        let Ok(relative) = Path::new(folder).strip_prefix(root) else {
+            continue;
+        };
+        let summary = relative
+            .components()
+            .take(depth)
+            .fold(PathBuf::from(root), |path, part| path.join(part));
+        let entry = usage.entry(summary.display().to_string()).or_default();
+        entry.0 += 1;
+        entry.1 += size;
+    }
+
+    usage
+        .into_iter()
+        .map(|(folder, (files, size))| DuRow {
+            folder,
+            files,
+            size,
+        })
+        .collect()
+}
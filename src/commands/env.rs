@@ -0,0 +1,62 @@
+use crate::dxenv;
+use crate::dxenv::get_dx_env;
+use anyhow::Result;
+use clap::Parser;
+
+#[derive(Clone, Parser, Debug)]
+pub struct EnvArgs {
+    /// Bash commands to export variables
+    #[arg(
+        long,
+        default_value = "false",
+        conflicts_with_all = ["json", "dxconfig"]
+    )]
+    bash: bool,
+
+    /// JSON of the current environment
+    #[arg(long, default_value = "false", conflicts_with = "dxconfig")]
+    json: bool,
+
+    /// JSON for the Python dx-toolkit's "environment.json"
+    #[arg(long, default_value = "false")]
+    dxconfig: bool,
+}
+
+// --------------------------------------------------
+pub fn print_env(args: EnvArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    if args.bash {
+        for (key, val) in dx_env.dx_toolkit_vars() {
+            println!("export {key}={}", shell_quote(&val));
+        }
+        return Ok(());
+    } else if args.json {
+        println!("{}", serde_json::to_string_pretty(&dx_env)?);
+        return Ok(());
+    } else if args.dxconfig {
+        let vars = dx_env.dx_toolkit_vars();
+        println!("{}", serde_json::to_string_pretty(&vars)?);
+        return Ok(());
+    }
+
+    println!("API server protocol   {}", dx_env.apiserver_protocol);
+    println!("API server host       {}", dx_env.apiserver_host);
+    println!("API server port       {}", dx_env.apiserver_port);
+    println!("Current workspace     {}", dx_env.project_context_name);
+    println!("Current workspace     {}", dx_env.project_context_id);
+    println!("Current folder        {}", dx_env.cli_wd);
+    println!("Current user          {}", dx_env.username);
+    if let Some(name) = dxenv::active_profile(&dxenv::get_profiles()?) {
+        println!("Current profile       {name}");
+    }
+    if let Some(job_id) = dxenv::job_id() {
+        println!("Current job           {job_id}");
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// Single-quotes a value for the shell
+pub(crate) fn shell_quote(val: &str) -> String {
+    format!("'{}'", val.replace('\'', r#"'\''"#))
+}
@@ -0,0 +1,494 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::entity_id::EntityId;
+use crate::exit::CliError;
+use crate::{
+    api, csv_quote, describe_object, link_ids, new_progress_bar, new_spinner,
+    open_outfile, resolve_named_objects, DatasetEntity, DatasetField,
+    DatasetSummary, Description, DownloadOptions, ObjectType, ProgressFormat,
+    RecordDescribeField, RecordDescribeOptions, RecordDescribeResult,
+    RecordVisualizeOptions, RecordVisualizeResult,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::{builder::PossibleValue, Parser, ValueEnum};
+use flate2::write::MultiGzDecoder;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::{
+    collections::{BTreeSet, HashMap},
+    io::{BufWriter, Write as _},
+    time::Duration,
+};
+
+/// Rows per record batch when writing Parquet
+#[cfg(feature = "parquet")]
+const PARQUET_BATCH_ROWS: usize = 10_000;
+
+#[derive(Clone, Parser, Debug)]
+pub struct ExtractDatasetArgs {
+    /// Dataset or cohort record path or ID
+    #[arg()]
+    dataset: String,
+
+    /// Fields to extract, e.g., "participant.eid,participant.p31"
+    #[arg(long, value_delimiter = ',', value_name = "ENTITY.FIELD")]
+    fields: Vec<String>,
+
+    /// Extract every field of these entities
+    #[arg(long, value_delimiter = ',', value_name = "ENTITY")]
+    entities: Vec<String>,
+
+    /// Show the fields that can be extracted
+    #[arg(
+        long,
+        default_value = "false",
+        conflicts_with_all = ["fields", "entities"]
+    )]
+    list_fields: bool,
+
+    /// Output format
+    #[arg(short, long, value_name = "FORMAT", default_value = "csv")]
+    format: ExtractFormat,
+
+    /// Output file, "-" for STDOUT [default: DATASET_NAME.FORMAT]
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<String>,
+
+    /// Do not show a progress bar
+    #[arg(short, long, default_value = "false")]
+    quiet: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExtractFormat {
+    Csv,
+    Parquet,
+}
+
+impl ValueEnum for ExtractFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[ExtractFormat::Csv, ExtractFormat::Parquet]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            ExtractFormat::Csv => PossibleValue::new("csv"),
+            ExtractFormat::Parquet => PossibleValue::new("parquet"),
+        })
+    }
+}
+
+// --------------------------------------------------
+// Apollo datasets and the cohorts saved from them are records typed
+// "Dataset" and "CohortBrowser"
+pub(crate) fn is_dataset_record(record: &RecordDescribeResult) -> bool {
+    record.types.as_ref().is_some_and(|types| {
+        types.iter().any(|t| t == "Dataset" || t == "CohortBrowser")
+    })
+}
+
+// --------------------------------------------------
+// Finds the vizserver for a dataset or cohort and reads the schema from
+// the dataset's descriptor
+pub(crate) fn load_dataset(
+    dx_env: &DxEnvironment,
+    record: &RecordDescribeResult,
+) -> Result<(RecordVisualizeResult, DatasetSummary)> {
+    let viz = api::record_visualize(
+        dx_env,
+        &record.id,
+        &RecordVisualizeOptions {
+            project: record.project.clone(),
+            cohort_browser: false,
+        },
+    )?;
+
+    // A cohort keeps no descriptor of its own
+    let details = if viz.dataset == record.id {
+        record.details.clone()
+    } else {
+        let options = RecordDescribeOptions {
+            project: viz.dataset_record_project.clone(),
+            fields: Some(HashMap::from([(RecordDescribeField::Details, true)])),
+            details: true,
+            properties: false,
+        };
+        api::describe_record(dx_env, &viz.dataset, &options)?.details
+    };
+    let details = serde_json::to_value(details)?;
+    let descriptor_id = link_ids(&details["descriptor"])
+        .pop()
+        .ok_or(anyhow!(r#"Dataset "{}" has no descriptor"#, viz.dataset))?;
+    let descriptor = read_dataset_descriptor(dx_env, &descriptor_id)?;
+    let summary = dataset_summary(&viz, &descriptor);
+
+    Ok((viz, summary))
+}
+
+// --------------------------------------------------
+// Descriptors are usually gzipped JSON
+fn read_dataset_descriptor(
+    dx_env: &DxEnvironment,
+    file_id: &str,
+) -> Result<serde_json::Value> {
+    let download = api::download(dx_env, file_id, &DownloadOptions::default())?;
+    let mut bytes = vec![];
+    api::download_file(&download, &mut bytes, file_id, ProgressFormat::None_)?;
+
+    if bytes.starts_with(&[0x1f, 0x8b]) {
+        let mut decoder = MultiGzDecoder::new(vec![]);
+        decoder.write_all(&bytes)?;
+        bytes = decoder.finish()?;
+    }
+
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+// --------------------------------------------------
+pub(crate) fn dataset_summary(
+    viz: &RecordVisualizeResult,
+    descriptor: &serde_json::Value,
+) -> DatasetSummary {
+    let model = &descriptor["model"];
+    let primary = &model["global_primary_key"];
+    let primary_entity = primary["entity"].as_str();
+    let primary_key = primary_entity
+        .zip(primary["field"].as_str())
+        .map(|(entity, field)| format!("{entity}.{field}"));
+
+    let mut entities: Vec<_> = model["entities"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(name, entity)| DatasetEntity {
+            name: name.to_string(),
+            fields: entity["fields"]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(name, field)| DatasetField {
+                    name: name.to_string(),
+                    field_type: field["type"]
+                        .as_str()
+                        .unwrap_or("NA")
+                        .to_string(),
+                    title: field["title"].as_str().map(str::to_string),
+                    table: field["mapping"]["table"]
+                        .as_str()
+                        .map(str::to_string),
+                    column: field["mapping"]["column"]
+                        .as_str()
+                        .map(str::to_string),
+                })
+                .collect(),
+        })
+        .collect();
+
+    // The entity holding the primary key (e.g., "participant") first
+    entities.sort_by_key(|entity| Some(entity.name.as_str()) != primary_entity);
+
+    DatasetSummary {
+        record_type: if viz.record_types.iter().any(|t| t == "CohortBrowser") {
+            "Cohort".to_string()
+        } else {
+            "Dataset".to_string()
+        },
+        dataset: viz.dataset.clone(),
+        version: viz
+            .dataset_version
+            .clone()
+            .or(descriptor["version"].as_str().map(str::to_string)),
+        databases: viz.databases.clone(),
+        primary_key,
+        count: None,
+        entities,
+    }
+}
+
+// --------------------------------------------------
+// Selects fields named like "participant.eid" as "participant$eid",
+// limited to the members of a cohort by its filters
+pub(crate) fn dataset_query(
+    viz: &RecordVisualizeResult,
+    fields: &[String],
+) -> serde_json::Value {
+    let fields: Vec<_> = fields
+        .iter()
+        .map(|field| serde_json::json!({ field: field.replacen('.', "$", 1) }))
+        .collect();
+    let mut payload = serde_json::json!({
+        "project_context": viz.dataset_record_project,
+        "fields": fields,
+    });
+    if let Some(filters) = &viz.filters {
+        payload["filters"] = filters.clone();
+    }
+    if let Some(sql) = &viz.base_sql {
+        payload["base_sql"] = serde_json::json!(sql);
+    }
+    payload
+}
+
+// --------------------------------------------------
+pub fn extract_dataset(args: ExtractDatasetArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let id = match EntityId::parse(&args.dataset) {
+        Some(_) => args.dataset.clone(),
+        _ => resolve_named_objects(
+            &dx_env,
+            &args.dataset,
+            &[ObjectType::Record],
+            false,
+            false,
+        )?
+        .remove(0),
+    };
+    let record = match describe_object(&dx_env, &id, None)? {
+        Description::Record(record) if is_dataset_record(&record) => record,
+        _ => bail!(CliError::Usage(format!(
+            r#""{}" is not a dataset or cohort"#,
+            args.dataset
+        ))),
+    };
+    let (viz, summary) = load_dataset(&dx_env, &record)?;
+
+    if args.list_fields {
+        for entity in &summary.entities {
+            for field in &entity.fields {
+                println!(
+                    "{}.{}\t{}",
+                    entity.name,
+                    field.name,
+                    field.title.as_deref().unwrap_or("")
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let fields = select_dataset_fields(&summary, &args.fields, &args.entities)?;
+    let out = args.output.clone().unwrap_or_else(|| {
+        format!(
+            "{}.{}",
+            record.name.clone().unwrap_or(record.id.clone()),
+            match args.format {
+                ExtractFormat::Csv => "csv",
+                ExtractFormat::Parquet => "parquet",
+            }
+        )
+    });
+    if out == "-" && args.format == ExtractFormat::Parquet {
+        bail!("Use --output to name the Parquet file");
+    }
+
+    let spinner = if args.quiet {
+        ProgressBar::hidden()
+    } else {
+        new_spinner()
+    };
+    spinner.set_message(format!(
+        "Querying {} field{}",
+        fields.len(),
+        if fields.len() == 1 { "" } else { "s" }
+    ));
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    let names: Vec<_> = fields.iter().map(|(name, _)| name.clone()).collect();
+    let mut res = api::vizserver_raw(
+        &dx_env,
+        &viz.url,
+        &viz.dataset,
+        &dataset_query(&viz, &names),
+    )?;
+    spinner.finish_and_clear();
+
+    let rows = match res["results"].take() {
+        serde_json::Value::Array(rows) => rows,
+        _ => bail!("Unexpected response from {}", viz.url),
+    };
+    let progress = if args.quiet {
+        ProgressBar::hidden()
+    } else {
+        new_progress_bar(rows.len() as u64)
+    };
+    progress.set_style(
+        ProgressStyle::default_bar()
+            .template("[{elapsed_precise}] [{wide_bar}] {pos}/{len} rows")?,
+    );
+
+    match args.format {
+        ExtractFormat::Csv => {
+            let mut writer = BufWriter::new(open_outfile(&out)?);
+            writeln!(
+                writer,
+                "{}",
+                names
+                    .iter()
+                    .map(|name| csv_quote(name))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )?;
+            for row in &rows {
+                writeln!(writer, "{}", dataset_csv_row(&names, row))?;
+                progress.inc(1);
+            }
+            writer.flush()?;
+        }
+        ExtractFormat::Parquet => {
+            write_dataset_parquet(&out, &fields, &rows, &progress)?
+        }
+    }
+    progress.finish_and_clear();
+
+    if out != "-" {
+        note!(
+            r#"Wrote {} row{} to "{out}""#,
+            rows.len(),
+            if rows.len() == 1 { "" } else { "s" }
+        );
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// The requested fields with their types, in the order given and each
+// only once, checked against the dataset's schema
+pub(crate) fn select_dataset_fields(
+    summary: &DatasetSummary,
+    fields: &[String],
+    entities: &[String],
+) -> Result<Vec<(String, String)>> {
+    if fields.is_empty() && entities.is_empty() {
+        bail!(
+            "Use --fields or --entities to choose what to extract, \
+            or --list-fields to see them"
+        );
+    }
+
+    let find_entity = |name: &str| {
+        summary
+            .entities
+            .iter()
+            .find(|entity| entity.name == name)
+            .ok_or(anyhow!(r#"Unknown entity "{name}""#))
+    };
+
+    let mut selected: Vec<(String, String)> = vec![];
+    for field in fields {
+        let (entity_name, field_name) = field
+            .split_once('.')
+            .ok_or(anyhow!(r#"Field "{field}" must be ENTITY.FIELD"#))?;
+        let found = find_entity(entity_name)?
+            .fields
+            .iter()
+            .find(|f| f.name == field_name)
+            .ok_or(anyhow!(r#"Unknown field "{field}""#))?;
+        selected.push((field.to_string(), found.field_type.clone()));
+    }
+
+    for name in entities {
+        for field in &find_entity(name)?.fields {
+            selected.push((
+                format!("{name}.{}", field.name),
+                field.field_type.clone(),
+            ));
+        }
+    }
+
+    let mut seen = BTreeSet::new();
+    selected.retain(|(name, _)| seen.insert(name.clone()));
+    Ok(selected)
+}
+
+// --------------------------------------------------
+// Multi-valued fields are written as JSON arrays
+pub(crate) fn dataset_csv_row(
+    fields: &[String],
+    row: &serde_json::Value,
+) -> String {
+    fields
+        .iter()
+        .map(|field| match &row[field] {
+            serde_json::Value::Null => "".to_string(),
+            serde_json::Value::String(val) => csv_quote(val),
+            other => csv_quote(&other.to_string()),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// --------------------------------------------------
+#[cfg(feature = "parquet")]
+pub(crate) fn write_dataset_parquet(
+    filename: &str,
+    fields: &[(String, String)],
+    rows: &[serde_json::Value],
+    progress: &ProgressBar,
+) -> Result<()> {
+    use arrow_array::{
+        ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray,
+    };
+    use arrow_schema::{DataType, Field, Schema};
+    use parquet::arrow::ArrowWriter;
+    use std::{fs::File, sync::Arc};
+
+    let data_type = |field_type: &str| match field_type {
+        "integer" => DataType::Int64,
+        "float" => DataType::Float64,
+        _ => DataType::Utf8,
+    };
+    let schema = Arc::new(Schema::new(
+        fields
+            .iter()
+            .map(|(name, field_type)| {
+                Field::new(name, data_type(field_type), true)
+            })
+            .collect::<Vec<_>>(),
+    ));
+
+    let mut writer =
+        ArrowWriter::try_new(File::create(filename)?, schema.clone(), None)?;
+    for batch in rows.chunks(PARQUET_BATCH_ROWS) {
+        let columns: Vec<ArrayRef> = fields
+            .iter()
+            .map(|(name, field_type)| -> ArrayRef {
+                let values = batch.iter().map(|row| &row[name]);
+                match data_type(field_type) {
+                    DataType::Int64 => Arc::new(
+                        values.map(|val| val.as_i64()).collect::<Int64Array>(),
+                    ),
+                    DataType::Float64 => Arc::new(
+                        values
+                            .map(|val| val.as_f64())
+                            .collect::<Float64Array>(),
+                    ),
+                    _ => Arc::new(
+                        values
+                            .map(|val| match val {
+                                serde_json::Value::Null => None,
+                                serde_json::Value::String(val) => {
+                                    Some(val.clone())
+                                }
+                                other => Some(other.to_string()),
+                            })
+                            .collect::<StringArray>(),
+                    ),
+                }
+            })
+            .collect();
+        writer.write(&RecordBatch::try_new(schema.clone(), columns)?)?;
+        progress.inc(batch.len() as u64);
+    }
+    writer.close()?;
+
+    Ok(())
+}
+
+// --------------------------------------------------
+#[cfg(not(feature = "parquet"))]
+pub(crate) fn write_dataset_parquet(
+    _filename: &str,
+    _fields: &[(String, String)],
+    _rows: &[serde_json::Value],
+    _progress: &ProgressBar,
+) -> Result<()> {
+    bail!(r#"Parquet output needs dxrs built with "--features parquet""#)
+}
@@ -0,0 +1,120 @@
+use crate::dxenv::get_dx_env;
+use crate::{
+    api, make_table, output_format, print_records, print_table, tsv_value,
+    AppDescribeField, FindAppsDescribe, FindAppsOptions, FindAppsResult,
+    FindName, OutputFormat,
+};
+use anyhow::Result;
+use clap::Parser;
+use log::debug;
+use std::collections::HashMap;
+
+#[derive(Clone, Parser, Debug)]
+pub struct FindAppsArgs {
+    /// App name
+    #[arg(short, long)]
+    name: Option<String>,
+
+    /// Apps in this category
+    #[arg(short, long)]
+    category: Option<String>,
+
+    /// Show every version of each app
+    #[arg(short, long, default_value = "false")]
+    all_versions: bool,
+
+    /// Only apps you have installed
+    #[arg(short, long, default_value = "false")]
+    installed: bool,
+
+    /// Output JSON representation
+    #[arg(long, default_value = "false")]
+    json: bool,
+
+    /// Show only app IDs
+    #[arg(long, default_value = "false")]
+    brief: bool,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+// --------------------------------------------------
+pub fn find_apps(args: FindAppsArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let mut options = FindAppsOptions {
+        name: None,
+        category: args.category.clone(),
+        all_versions: args.all_versions.then_some(true),
+        published: None,
+        bill_to: vec![],
+        created_by: None,
+        developer: None,
+        authorized_user: None,
+        installed: args.installed.then_some(true),
+        starting: None,
+        limit: None,
+        describe: Some(FindAppsDescribe {
+            fields: HashMap::from([
+                (AppDescribeField::Name, true),
+                (AppDescribeField::Title, true),
+                (AppDescribeField::Version, true),
+                (AppDescribeField::Published, true),
+                (AppDescribeField::BillTo, true),
+            ]),
+        }),
+    };
+
+    if let Some(name) = &args.name {
+        options.name = Some(FindName::Regexp(name.clone()));
+    } else {
+        options.name = Some(FindName::Glob("*".to_string()))
+    }
+
+    let apps = api::find_apps(&dx_env, &mut options)?;
+    debug!("{:#?}", &apps);
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&apps)?);
+        return Ok(());
+    }
+
+    if args.brief {
+        for app in apps {
+            println!("{}", app.id);
+        }
+        return Ok(());
+    }
+
+    let records: Vec<_> = apps.into_iter().map(find_apps_record).collect();
+    let fields = ["name", "title", "version", "published", "billTo", "id"];
+    if output_format() == OutputFormat::Table {
+        let rows = records
+            .iter()
+            .map(|app| fields.iter().map(|f| tsv_value(&app[f])).collect())
+            .collect();
+        print_table(
+            &make_table("{:<} {:<} {:<} {:<} {:<} {:<}", rows, 1, args.wide),
+            args.wide,
+        );
+        Ok(())
+    } else {
+        print_records(&records, &fields)
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn find_apps_record(app: FindAppsResult) -> serde_json::Value {
+    let desc = app.describe.unwrap_or_default();
+    serde_json::json!({
+        "name": desc.name,
+        "title": desc.title,
+        "version": desc.version,
+        "published": desc.published.map(|d| {
+            d.format("%Y-%m-%d %H:%M:%S").to_string()
+        }),
+        "billTo": desc.bill_to,
+        "id": app.id,
+    })
+}
@@ -0,0 +1,434 @@
+use crate::dxenv::DxEnvironment;
+use crate::{
+    api, normalize_remote_path, ArchivalState, FindDataOptions, FindDataResult,
+    FindDataScope, FindDescribe, FindName, KitchenSink, ListColumn, ListSortBy,
+    ObjectType, SearchTime,
+};
+#[cfg(feature = "cli")]
+use crate::{
+    column_names, dxenv::get_dx_env, output_format, print_listing,
+    print_records, sort_listing, theme, FindProjectsDescribe,
+    FindProjectsOptions, ListingRow, OutputFormat, ProjectDescribeField,
+};
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use log::debug;
+use regex::Regex;
+#[cfg(feature = "cli")]
+use std::path::Path;
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Clone, Parser, Debug)]
+pub struct FindDataArgs {
+    /// Data object name
+    #[arg(short, long)]
+    name: Option<String>,
+
+    /// Class
+    #[arg(short, long)]
+    class: Option<String>,
+
+    /// Search path
+    #[arg(short, long)]
+    path: Option<String>,
+
+    /// Search every project you can access, grouped by project
+    #[arg(short, long, default_value = "false", conflicts_with = "path")]
+    everywhere: bool,
+
+    /// Output JSON representation
+    #[arg(long, default_value = "false")]
+    json: bool,
+
+    /// Show only project-qualified IDs
+    #[arg(long, default_value = "false")]
+    brief: bool,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+
+    /// Columns to show, e.g., "name,size,id"
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "state,modified,size,name,id"
+    )]
+    columns: Vec<ListColumn>,
+
+    /// Sort results
+    #[arg(long)]
+    sort_by: Option<ListSortBy>,
+
+    /// Reverse the sort order
+    #[arg(short, long)]
+    reverse: bool,
+
+    /// Objects with this tag, may be repeated
+    #[arg(long, value_name = "TAG")]
+    tag: Vec<String>,
+
+    /// Objects with this property, as KEY=VALUE or just KEY
+    #[arg(long, value_name = "PROPERTY")]
+    property: Vec<String>,
+
+    /// Created after a date (e.g., "2024-01-01") or time ago ("-2d")
+    #[arg(long, value_name = "TIME")]
+    created_after: Option<String>,
+
+    /// Created before a date or time ago
+    #[arg(long, value_name = "TIME")]
+    created_before: Option<String>,
+
+    /// Modified after a date or time ago
+    #[arg(long, value_name = "TIME")]
+    modified_after: Option<String>,
+
+    /// Modified before a date or time ago
+    #[arg(long, value_name = "TIME")]
+    modified_before: Option<String>,
+
+    /// Archival state of files
+    #[arg(long, value_name = "STATE")]
+    archival_state: Option<ArchivalState>,
+
+    /// Files at least SIZE (e.g., 64K, 1G)
+    #[arg(long, value_name = "SIZE")]
+    min_size: Option<String>,
+
+    /// Files at most SIZE
+    #[arg(long, value_name = "SIZE")]
+    max_size: Option<String>,
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub fn find_data(args: FindDataArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let data = search_data(&dx_env, &args)?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else if args.brief {
+        for result in data {
+            println!("{}:{}", result.project, result.id);
+        }
+    } else if args.everywhere {
+        print_data_by_project(&dx_env, data, &args)?;
+    } else {
+        let mut rows = find_data_rows(data);
+        sort_listing(&mut rows, &args.sort_by, args.reverse);
+        print_listing(&rows, &args.columns, true, false, args.wide)?;
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+/// Data objects matching the "find data" options
+pub fn search_data(
+    dx_env: &DxEnvironment,
+    args: &FindDataArgs,
+) -> Result<Vec<FindDataResult>> {
+    let folder = normalize_remote_path(
+        &dx_env.cli_wd,
+        &args.path.clone().unwrap_or("".to_string()),
+    );
+
+    let mut options = FindDataOptions {
+        class: None,
+        state: None,
+        name: None,
+        visibility: None,
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: (!args.everywhere).then(|| FindDataScope {
+            // TODO: What if project_id is explicit in search path?
+            project: Some(dx_env.project_context_id.clone()),
+            folder: Some(folder),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Boolean(true)),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+
+    if let Some(val) = &args.class {
+        options.class = Some(ObjectType::from_str(val)?);
+    }
+
+    options.tags = args.tag.clone();
+    options.archival_state = args.archival_state.clone();
+    options.properties = property_filter(&args.property)?;
+    let now = Utc::now();
+    options.created =
+        search_time(&args.created_after, &args.created_before, now)?;
+    options.modified =
+        search_time(&args.modified_after, &args.modified_before, now)?;
+
+    if let Some(val) = &args.name {
+        options.name = Some(FindName::Regexp(val.clone()));
+    } else if args.everywhere {
+        bail!("Searching everywhere requires --name")
+    } else {
+        options.name = Some(FindName::Glob("*".to_string()))
+    }
+
+    debug!("{:#?}", &options);
+    let mut data = api::find_data(dx_env, &mut options)?;
+    debug!("{:#?}", &data);
+
+    // The API has no size filter, so apply it to the results
+    let min_size = args.min_size.as_deref().map(parse_size).transpose()?;
+    let max_size = args.max_size.as_deref().map(parse_size).transpose()?;
+    if min_size.is_some() || max_size.is_some() {
+        data.retain(|result| {
+            result
+                .describe
+                .as_ref()
+                .and_then(|desc| desc.size)
+                .is_some_and(|size| {
+                    min_size.is_none_or(|min| size >= min)
+                        && max_size.is_none_or(|max| size <= max)
+                })
+        });
+    }
+
+    Ok(data)
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn parse_properties(
+    properties: &[String],
+) -> Result<HashMap<String, String>> {
+    let mut map = HashMap::new();
+    for prop in properties {
+        match prop.split_once('=') {
+            Some((key, val)) if !key.is_empty() => {
+                map.insert(key.to_string(), val.to_string());
+            }
+            _ => bail!(r#"Property "{prop}" must be KEY=VALUE"#),
+        }
+    }
+    Ok(map)
+}
+
+// --------------------------------------------------
+pub(crate) fn property_filter(
+    properties: &[String],
+) -> Result<Option<KitchenSink>> {
+    if properties.is_empty() {
+        return Ok(None);
+    }
+
+    let mut filter = HashMap::new();
+    for prop in properties {
+        let (key, val) = match prop.split_once('=') {
+            Some((key, val)) => {
+                (key, KitchenSink::StringValue(val.to_string()))
+            }
+            _ => (prop.as_str(), KitchenSink::BooleanValue(true)),
+        };
+        if key.is_empty() {
+            bail!(r#"Invalid property "{prop}""#);
+        }
+        filter.insert(key.to_string(), val);
+    }
+    Ok(Some(KitchenSink::Mapping(filter)))
+}
+
+// --------------------------------------------------
+pub(crate) fn search_time(
+    after: &Option<String>,
+    before: &Option<String>,
+    now: DateTime<Utc>,
+) -> Result<Option<SearchTime>> {
+    if after.is_none() && before.is_none() {
+        return Ok(None);
+    }
+
+    let parse = |val: &Option<String>| {
+        val.as_deref().map(|v| parse_time(v, now)).transpose()
+    };
+    Ok(Some(SearchTime {
+        after: parse(after)?,
+        before: parse(before)?,
+    }))
+}
+
+// --------------------------------------------------
+/// Milliseconds since the epoch for a date ("2024-01-01"), a
+/// date and time ("2024-01-01T12:00:00Z"), a time ago ("-2d"),
+/// or a literal timestamp
+pub(crate) fn parse_time(val: &str, now: DateTime<Utc>) -> Result<i64> {
+    let val = val.trim();
+    if let Some(ago) = val.strip_prefix('-') {
+        let secs = parse_duration(ago)
+            .map_err(|_| anyhow!(r#"Invalid time "{val}""#))?;
+        return Ok(now.timestamp_millis() - secs as i64 * 1000);
+    }
+
+    if let Ok(ts) = val.parse::<i64>() {
+        return Ok(ts);
+    }
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(val) {
+        return Ok(dt.timestamp_millis());
+    }
+
+    if let Ok(dt) =
+        chrono::NaiveDateTime::parse_from_str(val, "%Y-%m-%d %H:%M:%S")
+    {
+        return Ok(dt.and_utc().timestamp_millis());
+    }
+
+    match chrono::NaiveDate::parse_from_str(val, "%Y-%m-%d") {
+        Ok(date) => Ok(date
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight")
+            .and_utc()
+            .timestamp_millis()),
+        _ => bail!(r#"Invalid time "{val}""#),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn parse_size(val: &str) -> Result<u64> {
+    let re = Regex::new(r"^(?i)(\d+)\s*([KMG]i?)?B?$").unwrap();
+    match re.captures(val.trim()) {
+        Some(caps) => {
+            let num: u64 = caps[1].parse()?;
+            let multiplier =
+                match caps.get(2).map(|m| m.as_str().chars().next().unwrap()) {
+                    Some('K' | 'k') => 1024,
+                    Some('M' | 'm') => 1024 * 1024,
+                    Some('G' | 'g') => 1024 * 1024 * 1024,
+                    _ => 1,
+                };
+            Ok(num * multiplier)
+        }
+        _ => bail!(r#"Invalid size "{val}""#),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn parse_duration(val: &str) -> Result<u64> {
+    let re = Regex::new(r"^(?i)(\d+)\s*([smhdw])?$").unwrap();
+    match re.captures(val.trim()) {
+        Some(caps) => {
+            let num: u64 = caps[1].parse()?;
+            let multiplier =
+                match caps.get(2).map(|m| m.as_str().chars().next().unwrap()) {
+                    Some('M' | 'm') => 60,
+                    Some('H' | 'h') => 60 * 60,
+                    Some('D' | 'd') => 24 * 60 * 60,
+                    Some('W' | 'w') => 7 * 24 * 60 * 60,
+                    _ => 1,
+                };
+            Ok(num * multiplier)
+        }
+        _ => bail!(r#"Invalid duration "{val}""#),
+    }
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn find_data_rows(data: Vec<FindDataResult>) -> Vec<ListingRow> {
+    data.into_iter()
+        .filter_map(|row| row.describe)
+        .map(|desc| {
+            let folder = desc.folder.unwrap_or("".to_string());
+            ListingRow {
+                name: Path::new(&folder)
+                    .join(desc.name.unwrap_or("".to_string()))
+                    .display()
+                    .to_string(),
+                id: desc.id,
+                state: desc.state.map_or("NA".to_string(), |s| s.to_string()),
+                size: desc.size,
+                modified: desc.modified,
+            }
+        })
+        .collect()
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_data_by_project(
+    dx_env: &DxEnvironment,
+    data: Vec<FindDataResult>,
+    args: &FindDataArgs,
+) -> Result<()> {
+    let mut by_project: HashMap<String, Vec<FindDataResult>> = HashMap::new();
+    for result in data {
+        by_project
+            .entry(result.project.clone())
+            .or_default()
+            .push(result);
+    }
+
+    // Look up all of the project names in one request
+    let names: HashMap<String, String> = if by_project.is_empty() {
+        HashMap::new()
+    } else {
+        let options = FindProjectsOptions {
+            name: None,
+            id: by_project.keys().cloned().collect(),
+            level: None,
+            bill_to: None,
+            starting: None,
+            describe: Some(FindProjectsDescribe {
+                fields: HashMap::from([(ProjectDescribeField::Name, true)]),
+            }),
+        };
+        api::find_projects(dx_env, options)?
+            .into_iter()
+            .filter_map(|p| p.describe.get("name").map(|n| (p.id, n.clone())))
+            .collect()
+    };
+
+    let mut projects: Vec<_> = by_project.into_iter().collect();
+    projects.sort_by_key(|(id, _)| (names.get(id).cloned(), id.clone()));
+
+    let machine = output_format() != OutputFormat::Table;
+    let mut records = vec![];
+    for (project_id, data) in projects {
+        let project_name = names.get(&project_id).cloned().unwrap_or_default();
+        let mut rows = find_data_rows(data);
+        sort_listing(&mut rows, &args.sort_by, args.reverse);
+
+        if machine {
+            for row in rows {
+                let mut record = serde_json::to_value(row)?;
+                record["project"] = serde_json::json!(project_id);
+                record["project_name"] = serde_json::json!(project_name);
+                records.push(record);
+            }
+        } else {
+            println!(
+                "{}",
+                theme::heading(format!("{project_name} ({project_id})"))
+            );
+            print_listing(&rows, &args.columns, true, false, args.wide)?;
+        }
+    }
+
+    if machine {
+        let mut fields = vec!["project".to_string()];
+        fields.extend(column_names(&args.columns));
+        let fields: Vec<_> = fields.iter().map(|f| f.as_str()).collect();
+        print_records(&records, &fields)?;
+    }
+
+    Ok(())
+}
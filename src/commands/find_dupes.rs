@@ -0,0 +1,326 @@
+use crate::dxenv::get_dx_env;
+use crate::{
+    api, entity_id, make_table, output_format, print_records, print_table,
+    resolve_path, AddTagsOptions, FileDescribeField, FileDescribeOptions,
+    FileDescribeResult, FindDataOptions, FindDataScope, FindDescribe,
+    ObjectState, ObjectType, OutputFormat, RmOptions, Visibility,
+    FOLDER_WALK_THREADS,
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use inquire::Confirm;
+use rayon::prelude::*;
+use serde::Serialize;
+use size::Size;
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+};
+
+#[derive(Clone, Parser, Debug)]
+pub struct FindDupesArgs {
+    /// Project or folder, defaults to the whole current project
+    #[arg()]
+    path: Option<String>,
+
+    /// Remove all but the oldest copy of each file
+    #[arg(long, default_value = "false", conflicts_with = "tag")]
+    rm: bool,
+
+    /// Tag all but the oldest copy of each file
+    #[arg(long, value_name = "TAG")]
+    tag: Option<String>,
+
+    /// Remove without prompting
+    #[arg(short, long, default_value = "false")]
+    yes: bool,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DupeCandidate {
+    pub(crate) id: String,
+
+    pub(crate) path: String,
+
+    pub(crate) size: u64,
+
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    // Unknown until the parts are described
+    pub(crate) checksum: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct DupeRow {
+    pub(crate) group: usize,
+
+    // "keep" for the oldest copy, else "duplicate"
+    pub(crate) status: &'static str,
+
+    size: u64,
+
+    pub(crate) path: String,
+
+    id: String,
+
+    checksum: String,
+}
+
+// --------------------------------------------------
+pub fn find_dupes(args: FindDupesArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+
+    // A bare project ID means the whole project, not the working folder
+    let path = match &args.path {
+        Some(path) if entity_id::PROJECT_ID.is_match(path) => {
+            format!("{path}:/")
+        }
+        Some(path) => path.clone(),
+        _ => format!("{}:/", dx_env.project_context_id),
+    };
+    let dx_path = resolve_path(&dx_env, &path)?;
+    let project_id = dx_path.project_id.clone();
+
+    // Hidden files such as applet resource bundles are left alone since
+    // removing one breaks the executable that links to it
+    let mut options = FindDataOptions {
+        class: Some(ObjectType::File),
+        state: Some(ObjectState::Closed),
+        name: None,
+        visibility: Some(Visibility::Visible),
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(project_id.clone()),
+            folder: Some(dx_path.path.clone()),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Boolean(true)),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+
+    let mut files: Vec<DupeCandidate> = api::find_data(&dx_env, &mut options)?
+        .into_iter()
+        .filter_map(|file| file.describe)
+        .map(|desc| DupeCandidate {
+            path: Path::new(&desc.folder.unwrap_or("/".to_string()))
+                .join(desc.name.unwrap_or_default())
+                .display()
+                .to_string(),
+            id: desc.id,
+            size: desc.size.unwrap_or_default(),
+            created: desc.created,
+            checksum: None,
+        })
+        .collect();
+
+    // Only files sharing a size with another need their parts described
+    let mut sizes: HashMap<u64, usize> = HashMap::new();
+    for file in &files {
+        *sizes.entry(file.size).or_default() += 1;
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(FOLDER_WALK_THREADS)
+        .build()?;
+    let desc_opts = FileDescribeOptions {
+        project: Some(project_id.clone()),
+        fields: Some(HashMap::from([(FileDescribeField::Parts, true)])),
+        properties: false,
+        details: false,
+    };
+    pool.install(|| {
+        files
+            .par_iter_mut()
+            .filter(|file| {
+                file.checksum.is_none()
+                    && file.size > 0
+                    && sizes.get(&file.size).is_some_and(|&n| n > 1)
+            })
+            .for_each(|file| {
+                match api::describe_file(&dx_env, &file.id, &desc_opts) {
+                    Ok(desc) => file.checksum = file_checksum(&desc),
+                    Err(e) => eprintln!("{}: {e}", file.id),
+                }
+            })
+    });
+
+    let unknown = files
+        .iter()
+        .filter(|file| {
+            file.checksum.is_none()
+                && file.size > 0
+                && sizes.get(&file.size).is_some_and(|&n| n > 1)
+        })
+        .count();
+    let groups = find_duplicates(files);
+    let rows = dupe_rows(&groups);
+
+    if output_format() != OutputFormat::Table {
+        print_records(
+            &rows,
+            &["group", "status", "size", "path", "id", "checksum"],
+        )?;
+    } else if !rows.is_empty() {
+        let table_rows = rows
+            .iter()
+            .map(|row| {
+                vec![
+                    row.group.to_string(),
+                    row.status.to_string(),
+                    Size::from_bytes(row.size).to_string(),
+                    row.path.clone(),
+                    row.id.clone(),
+                ]
+            })
+            .collect();
+        print_table(
+            &make_table("{:>} {:<} {:>} {:<} {:<}", table_rows, 3, args.wide),
+            args.wide,
+        );
+    }
+
+    let redundant: Vec<&DupeCandidate> = groups
+        .iter()
+        .flat_map(|group| group.iter().skip(1))
+        .collect();
+    let reclaimable: u64 = redundant.iter().map(|file| file.size).sum();
+    note!(
+        "Found {} group{} of duplicates, {} reclaimable",
+        groups.len(),
+        if groups.len() == 1 { "" } else { "s" },
+        Size::from_bytes(reclaimable)
+    );
+    if unknown > 0 {
+        note!(
+            "Skipped {unknown} file{} with no checksum to compare",
+            if unknown == 1 { "" } else { "s" }
+        );
+    }
+
+    if redundant.is_empty() {
+        return Ok(());
+    }
+
+    let num = redundant.len();
+    if let Some(tag) = &args.tag {
+        let options = AddTagsOptions {
+            project: project_id.clone(),
+            tags: vec![tag.clone()],
+        };
+        for file in &redundant {
+            api::add_tags(&dx_env, &file.id, &options)?;
+        }
+        println!(
+            r#"Tagged {num} duplicate{} "{tag}""#,
+            if num == 1 { "" } else { "s" }
+        );
+    } else if args.rm {
+        let consent = args.yes
+            || Confirm::new(&format!(
+                "Remove {num} duplicate{}?",
+                if num == 1 { "" } else { "s" }
+            ))
+            .with_default(false)
+            .prompt()
+            .unwrap_or(false);
+
+        if consent {
+            let options = RmOptions {
+                objects: redundant.iter().map(|file| file.id.clone()).collect(),
+                force: Some(true),
+            };
+            api::rm(&dx_env, &project_id, &options)?;
+            println!(
+                "Removed {num} duplicate{}",
+                if num == 1 { "" } else { "s" }
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// Files with the same size and checksum, the oldest of each first and
+// the groups with the most to reclaim first. Empty files and those with
+// no checksum are never grouped.
+pub(crate) fn find_duplicates(
+    files: Vec<DupeCandidate>,
+) -> Vec<Vec<DupeCandidate>> {
+    let mut groups: BTreeMap<(u64, String), Vec<DupeCandidate>> =
+        BTreeMap::new();
+    for file in files {
+        if let Some(checksum) = file.checksum.clone().filter(|_| file.size > 0)
+        {
+            groups.entry((file.size, checksum)).or_default().push(file);
+        }
+    }
+
+    let mut groups: Vec<_> = groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .map(|mut group| {
+            group.sort_by(|a, b| (a.created, &a.id).cmp(&(b.created, &b.id)));
+            group
+        })
+        .collect();
+    groups.sort_by_key(|group| {
+        std::cmp::Reverse(group[0].size * (group.len() as u64 - 1))
+    });
+    groups
+}
+
+// --------------------------------------------------
+pub(crate) fn dupe_rows(groups: &[Vec<DupeCandidate>]) -> Vec<DupeRow> {
+    groups
+        .iter()
+        .enumerate()
+        .flat_map(|(num, group)| {
+            group.iter().enumerate().map(move |(i, file)| DupeRow {
+                group: num + 1,
+                status: if i == 0 { "keep" } else { "duplicate" },
+                size: file.size,
+                path: file.path.clone(),
+                id: file.id.clone(),
+                checksum: file.checksum.clone().unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+// --------------------------------------------------
+// Computed from the MD5s the platform keeps for each part rather than
+// the user-editable "md5" property, so files match when all their parts
+// do, which holds for identical files uploaded in parts of the same size
+pub(crate) fn file_checksum(desc: &FileDescribeResult) -> Option<String> {
+    let mut parts: Vec<(u64, Option<String>)> = desc
+        .parts
+        .as_ref()?
+        .iter()
+        .map(|(index, part)| {
+            (index.parse().unwrap_or_default(), part.md5.clone())
+        })
+        .collect();
+    parts.sort();
+    let md5s: Option<Vec<String>> =
+        parts.into_iter().map(|(_, md5)| md5).collect();
+    match md5s?.as_slice() {
+        [md5] => Some(md5.clone()),
+        md5s => Some(format!("parts:{:x}", md5::compute(md5s.join(",")))),
+    }
+}
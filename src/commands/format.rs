@@ -0,0 +1,141 @@
+use crate::exit::CliError;
+use crate::json_parser::{DxApp, DxAsset};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+#[derive(Clone, Parser, Debug)]
+pub struct FormatArgs {
+    /// Filename, directory, or "-" for STDIN
+    #[arg()]
+    filename: String,
+
+    #[arg(short, long, default_value = "-")]
+    output: String,
+
+    /// Exit with an error if any file is not already formatted
+    #[arg(long)]
+    check: bool,
+}
+
+// --------------------------------------------------
+pub fn format(args: FormatArgs) -> Result<()> {
+    if args.filename == "-" {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        let formatted = format_json(&contents, None)?;
+        if args.check {
+            if !is_formatted(&contents, &formatted) {
+                bail!("STDIN is not formatted");
+            }
+        } else {
+            write_formatted(&formatted, &args.output)?;
+        }
+        return Ok(());
+    }
+
+    let path = Path::new(&args.filename);
+    if !path.is_dir() {
+        let basename = path.file_name().expect("filename").to_string_lossy();
+        if basename != "dxapp.json" && basename != "dxasset.json" {
+            println!("Input file must be dxapp.json or dxasset.json");
+            return Ok(());
+        }
+
+        let contents = fs::read_to_string(path)?;
+        let formatted = format_json(&contents, Some(&basename))?;
+        if args.check {
+            if !is_formatted(&contents, &formatted) {
+                bail!("{} is not formatted", args.filename);
+            }
+        } else {
+            write_formatted(&formatted, &args.output)?;
+        }
+        return Ok(());
+    }
+
+    if args.output != "-" {
+        bail!(CliError::Usage(
+            "Cannot use --output with a directory".to_string()
+        ));
+    }
+
+    let mut unformatted = 0;
+    for entry in walkdir::WalkDir::new(path)
+        .sort_by_file_name()
+        .into_iter()
+        .flatten()
+        .filter(|e| e.file_type().is_file())
+    {
+        let basename = entry.file_name().to_string_lossy();
+        if basename != "dxapp.json" && basename != "dxasset.json" {
+            continue;
+        }
+
+        let file = entry.path().display().to_string();
+        let contents = fs::read_to_string(entry.path())?;
+        let formatted = format_json(&contents, Some(&basename))
+            .map_err(|e| anyhow!("{file}: {e}"))?;
+        if is_formatted(&contents, &formatted) {
+            continue;
+        }
+
+        if args.check {
+            println!("Would reformat {file}");
+            unformatted += 1;
+        } else {
+            write_formatted(&formatted, &file)?;
+            println!("Formatted {file}");
+        }
+    }
+
+    match unformatted {
+        0 => Ok(()),
+        1 => bail!("1 file is not formatted"),
+        n => bail!("{n} files are not formatted"),
+    }
+}
+
+// --------------------------------------------------
+// Canonical JSON for a dxapp.json or dxasset.json, guessing the kind
+// from the contents when there is no filename
+pub(crate) fn format_json(
+    contents: &str,
+    basename: Option<&str>,
+) -> Result<String> {
+    let formatted = match basename {
+        Some("dxapp.json") => serde_json::to_string_pretty(
+            &serde_json::from_str::<DxApp>(contents)?,
+        )?,
+        Some("dxasset.json") => serde_json::to_string_pretty(
+            &serde_json::from_str::<DxAsset>(contents)?,
+        )?,
+        _ => match serde_json::from_str::<DxApp>(contents) {
+            Ok(app) => serde_json::to_string_pretty(&app)?,
+            Err(app_err) => match serde_json::from_str::<DxAsset>(contents) {
+                Ok(asset) => serde_json::to_string_pretty(&asset)?,
+                _ => bail!("Input is not a valid app or asset: {app_err}"),
+            },
+        },
+    };
+    Ok(formatted)
+}
+
+// --------------------------------------------------
+pub(crate) fn is_formatted(contents: &str, formatted: &str) -> bool {
+    contents.trim_end() == formatted.trim_end()
+}
+
+// --------------------------------------------------
+pub(crate) fn write_formatted(formatted: &str, filename: &str) -> Result<()> {
+    if filename == "-" {
+        println!("{formatted}");
+    } else {
+        fs::write(filename, format!("{formatted}\n"))?;
+    }
+    Ok(())
+}
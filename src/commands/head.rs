@@ -0,0 +1,95 @@
+use crate::dxenv::get_dx_env;
+use crate::{api, resolve_file_id, DownloadOptions};
+use anyhow::Result;
+use clap::Parser;
+use flate2::write::MultiGzDecoder;
+use std::io::{self, Write as _};
+
+const HEAD_CHUNK_BYTES: u64 = 64 * 1024;
+
+#[derive(Clone, Parser, Debug)]
+pub struct HeadArgs {
+    /// File path or ID
+    #[arg()]
+    path: String,
+
+    /// Number of lines
+    #[arg(short('n'), long, default_value = "10")]
+    lines: usize,
+
+    /// Decompress gzip/BGZF files, e.g., ".gz" or ".bgz"
+    #[arg(short('z'), long)]
+    decompress: bool,
+}
+
+// --------------------------------------------------
+pub fn head(args: HeadArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let file_id = resolve_file_id(&dx_env, &args.path)?;
+    let download =
+        api::download(&dx_env, &file_id, &DownloadOptions::default())?;
+
+    // Fetch ranges only until there are enough lines
+    let mut start = 0;
+    let chunks = std::iter::from_fn(|| {
+        let end = start + HEAD_CHUNK_BYTES - 1;
+        match api::download_range(&download, start, end) {
+            Ok(chunk) if chunk.is_empty() => None,
+            Ok(chunk) => {
+                start += chunk.len() as u64;
+                Some(Ok(chunk))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    });
+
+    let text = head_lines(chunks, args.lines, args.decompress)?;
+    io::stdout().write_all(&text)?;
+    Ok(())
+}
+
+// --------------------------------------------------
+// The first lines of the (possibly compressed) chunks, reading no
+// more chunks than needed
+pub(crate) fn head_lines(
+    chunks: impl Iterator<Item = Result<Vec<u8>>>,
+    lines: usize,
+    decompress: bool,
+) -> Result<Vec<u8>> {
+    let mut decoder = decompress.then(|| MultiGzDecoder::new(vec![]));
+    let mut raw = vec![];
+    for chunk in chunks {
+        let chunk = chunk?;
+        let text = match decoder.as_mut() {
+            Some(decoder) => {
+                decoder.write_all(&chunk)?;
+                decoder.flush()?;
+                decoder.get_ref()
+            }
+            _ => {
+                raw.extend(chunk);
+                &raw
+            }
+        };
+
+        if text.iter().filter(|&&b| b == b'\n').count() >= lines {
+            break;
+        }
+    }
+
+    let text = match decoder {
+        Some(decoder) => decoder.get_ref().clone(),
+        _ => raw,
+    };
+    let end = text
+        .iter()
+        .enumerate()
+        .filter(|(_, &b)| b == b'\n')
+        .nth(lines.saturating_sub(1))
+        .map_or(text.len(), |(i, _)| i + 1);
+    Ok(if lines == 0 {
+        vec![]
+    } else {
+        text[..end].to_vec()
+    })
+}
@@ -0,0 +1,129 @@
+use crate::dxenv::get_dx_env;
+use crate::json_parser::InputOutputClass;
+use crate::{
+    executable_input_spec, make_table, output_format, print_records,
+    print_table, resolve_executable, theme, tsv_value, AppInputSpec,
+    OutputFormat,
+};
+use anyhow::Result;
+use clap::Parser;
+
+#[derive(Clone, Parser, Debug)]
+pub struct InputsArgs {
+    /// App, applet or workflow ID, or the name of one
+    #[arg()]
+    executable: String,
+
+    /// Print a JSON skeleton of the inputs to edit and run with
+    #[arg(long, default_value = "false")]
+    template: bool,
+
+    /// Do not truncate help to fit the terminal
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+// --------------------------------------------------
+pub fn inputs(args: InputsArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let (project_id, executable_id) =
+        resolve_executable(&dx_env, &args.executable)?;
+    let spec = executable_input_spec(&dx_env, &project_id, &executable_id)?;
+
+    if args.template {
+        println!("{}", serde_json::to_string_pretty(&input_template(&spec))?);
+        return Ok(());
+    }
+
+    let records: Vec<_> = spec.iter().map(input_spec_record).collect();
+    let fields = [
+        "name", "class", "optional", "default", "choices", "patterns", "help",
+    ];
+    if output_format() != OutputFormat::Table {
+        let mut fields = fields.to_vec();
+        fields.insert(0, "group");
+        return print_records(&records, &fields);
+    }
+
+    // Inputs keep their order within groups shown in order of appearance
+    let mut groups: Vec<&str> = vec![];
+    for record in &records {
+        let group = record["group"].as_str().unwrap_or_default();
+        if !groups.contains(&group) {
+            groups.push(group);
+        }
+    }
+
+    for group in groups {
+        println!("{}", theme::heading(group));
+        let mut rows = vec![fields.iter().map(|f| f.to_string()).collect()];
+        rows.extend(
+            records
+                .iter()
+                .filter(|record| record["group"].as_str() == Some(group))
+                .map(|record| {
+                    fields.iter().map(|f| tsv_value(&record[f])).collect()
+                }),
+        );
+        print_table(
+            &make_table(
+                "{:<} {:<} {:<} {:<} {:<} {:<} {:<}",
+                rows,
+                6,
+                args.wide,
+            ),
+            args.wide,
+        );
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+pub(crate) fn input_spec_record(input: &AppInputSpec) -> serde_json::Value {
+    serde_json::json!({
+        "group": input.group.clone().unwrap_or("Common".to_string()),
+        "name": input.name,
+        "class": input.class.to_string(),
+        "optional": input.optional.unwrap_or(false),
+        "default": input.default,
+        "choices": input.choices,
+        "patterns": input.patterns,
+        "help": input.help.as_ref().or(input.label.as_ref()),
+    })
+}
+
+// --------------------------------------------------
+// Required inputs get a placeholder of their class and optional ones
+// their default so that the result is ready to edit and pass to "run"
+pub(crate) fn input_template(spec: &[AppInputSpec]) -> serde_json::Value {
+    let link = || serde_json::json!({ "$dnanexus_link": "" });
+    let mut inputs = serde_json::Map::new();
+    for input in spec {
+        let value = match (&input.default, input.optional.unwrap_or(false)) {
+            (Some(default), _) => default.clone(),
+            (_, true) => continue,
+            _ => match input.choices.as_ref().and_then(|c| c.first()) {
+                Some(choice) => choice.clone(),
+                _ => match input.class {
+                    InputOutputClass::Boolean => serde_json::json!(false),
+                    InputOutputClass::Float => serde_json::json!(0.0),
+                    InputOutputClass::Hash => serde_json::json!({}),
+                    InputOutputClass::Int => serde_json::json!(0),
+                    InputOutputClass::String => serde_json::json!(""),
+                    InputOutputClass::Applet
+                    | InputOutputClass::File
+                    | InputOutputClass::Record => link(),
+                    InputOutputClass::ArrayApplet
+                    | InputOutputClass::ArrayFile
+                    | InputOutputClass::ArrayRecord => {
+                        serde_json::json!([link()])
+                    }
+                    _ => serde_json::json!([]),
+                },
+            },
+        };
+        inputs.insert(input.name.clone(), value);
+    }
+    serde_json::Value::Object(inputs)
+}
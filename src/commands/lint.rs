@@ -0,0 +1,177 @@
+use crate::json_parser::{DxApp, DxAsset};
+use crate::{json_parser, normalize, theme, DXAPI_VERSION};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use std::{fs, path::Path};
+
+#[derive(Clone, Parser, Debug)]
+pub struct LintArgs {
+    /// Filename
+    #[arg()]
+    filename: String,
+
+    /// Apply safe corrections and rewrite the file
+    #[arg(long)]
+    fix: bool,
+}
+
+// --------------------------------------------------
+pub fn lint(args: LintArgs) -> Result<()> {
+    let basename = Path::new(&args.filename)
+        .file_name()
+        .expect("filename")
+        .to_string_lossy();
+
+    if basename == "dxapp.json" {
+        if args.fix {
+            fix_app_file(&args.filename)?;
+        }
+
+        let app: serde_json::Value = json_parser::parse(&args.filename)?;
+        let issues = json_parser::validate_app(&app);
+        if issues.is_empty() {
+            println!("No suggestions");
+        } else {
+            for issue in &issues {
+                println!("{issue}");
+            }
+
+            let num_errors = issues
+                .iter()
+                .filter(|i| i.severity == json_parser::Severity::Error)
+                .count();
+            if num_errors > 0 {
+                bail!(
+                    "Found {num_errors} error{}",
+                    if num_errors == 1 { "" } else { "s" }
+                )
+            }
+        }
+    } else if basename == "dxasset.json" {
+        let asset: DxAsset = json_parser::parse(&args.filename)?;
+        let suggestions = json_parser::lint_asset(&asset);
+        if suggestions.is_empty() {
+            println!("No suggestions");
+        } else {
+            println!("{}", suggestions.join("\n"));
+        }
+    } else {
+        println!("Input file must be dxapp.json or dxasset.json")
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// Rewrite a dxapp.json with safe fixes in canonical order, showing a diff
+fn fix_app_file(filename: &str) -> Result<()> {
+    let original = fs::read_to_string(filename)?;
+    let mut value: serde_json::Value = serde_json::from_str(&original)
+        .map_err(|e| anyhow!("Failed to parse {filename}: {e}"))?;
+    let fixes = fix_app(&mut value);
+    let app: DxApp = serde_json::from_value(value)
+        .map_err(|e| anyhow!("Cannot fix {filename}: {e}"))?;
+    json_parser::write_file(&app, filename)?;
+
+    for fix in &fixes {
+        println!("Fixed: {fix}");
+    }
+
+    let diff = line_diff(&original, &fs::read_to_string(filename)?);
+    if diff.is_empty() {
+        println!("No changes to {filename}");
+    } else {
+        for line in diff {
+            match line.chars().next() {
+                Some('-') => println!("{}", theme::removed(line)),
+                _ => println!("{}", theme::added(line)),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+pub(crate) fn fix_app(app: &mut serde_json::Value) -> Vec<String> {
+    let mut fixes = vec![];
+
+    if let Some(name) = app["name"].as_str().map(|s| s.to_string()) {
+        if let Ok(fixed) = normalize(name.clone()) {
+            if fixed != name {
+                fixes.push(format!(r#"Normalized name "{name}" to "{fixed}""#));
+                app["name"] = serde_json::json!(fixed);
+            }
+        }
+    }
+
+    if app.get("dxapi").is_none() {
+        fixes.push(format!(r#"Set dxapi to "{DXAPI_VERSION}""#));
+        app["dxapi"] = serde_json::json!(DXAPI_VERSION);
+    }
+
+    let Some(run_spec) = app.get_mut("runSpec") else {
+        return fixes;
+    };
+
+    if run_spec["release"] == "20.04" && run_spec["version"] == "1" {
+        fixes.push(r#"Set runSpec.version to "0" for release 20.04"#.into());
+        run_spec["version"] = serde_json::json!("0");
+    }
+
+    if let Some(deps) = run_spec
+        .get_mut("execDepends")
+        .and_then(|deps| deps.as_array_mut())
+    {
+        for (i, dep) in deps.iter_mut().enumerate() {
+            if let Some(map) = dep.as_object_mut() {
+                if let Some(manager) = map.remove("packageManager") {
+                    fixes.push(format!(
+                        "Renamed runSpec.execDepends[{i}].packageManager \
+                        to package_manager"
+                    ));
+                    map.entry("package_manager").or_insert(manager);
+                }
+            }
+        }
+    }
+
+    fixes
+}
+
+// --------------------------------------------------
+// Lines removed from ("-") and added to ("+") the old text, in order
+pub(crate) fn line_diff(old: &str, new: &str) -> Vec<String> {
+    let old: Vec<_> = old.lines().collect();
+    let new: Vec<_> = new.lines().collect();
+
+    // Longest common subsequence lengths of the suffixes
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut diff = vec![];
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if j == new.len()
+            || (i < old.len() && lcs[i + 1][j] >= lcs[i][j + 1])
+        {
+            diff.push(format!("-{}", old[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+{}", new[j]));
+            j += 1;
+        }
+    }
+
+    diff
+}
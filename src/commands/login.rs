@@ -0,0 +1,232 @@
+use crate::dxenv::{get_dx_env, save_dx_env, save_login, DxEnvironment};
+use crate::exit::CliError;
+use crate::select_project;
+use crate::{
+    api, dxenv, NewTokenOptions, SelectArgs, WhoAmIOptions, WhoAmIOptionsFields,
+};
+use anyhow::{anyhow, bail, Result};
+use chrono::{Local, Utc};
+use clap::Parser;
+use inquire::{Password, Text};
+use log::debug;
+use std::{collections::HashMap, env, fs, path::PathBuf};
+
+/// Days that a token from "login --refresh" lasts
+const TOKEN_LIFETIME_DAYS: i64 = 30;
+
+#[derive(Clone, Parser, Debug)]
+pub struct LoginArgs {
+    /// Login token
+    #[arg(short, long, conflicts_with = "token_file")]
+    token: Option<String>,
+
+    /// File containing a login token
+    #[arg(long, value_name = "PATH")]
+    token_file: Option<PathBuf>,
+
+    /// Fail rather than prompt when no token is given
+    #[arg(long)]
+    no_prompt: bool,
+
+    /// Save the token in the config file instead of the keychain
+    #[arg(long)]
+    plaintext_store: bool,
+
+    /// Exchange the current token for a new one with the same scope
+    #[arg(long, conflicts_with_all = ["token", "token_file"])]
+    refresh: bool,
+
+    /// API server host
+    #[arg(long)]
+    host: Option<String>,
+
+    /// API server port
+    #[arg(long)]
+    port: Option<u32>,
+
+    /// API server protocol
+    #[arg(long)]
+    protocol: Option<String>,
+}
+
+// --------------------------------------------------
+pub fn login(args: LoginArgs) -> Result<()> {
+    if args.refresh {
+        return login_refresh();
+    }
+
+    let token = match (&args.token, &args.token_file) {
+        (Some(token), _) => Some(token.clone()),
+        (_, Some(path)) => Some(
+            fs::read_to_string(path)
+                .map_err(|e| anyhow!("{}: {e}", path.display()))?
+                .trim()
+                .to_string(),
+        ),
+        // Saved like one given with --token, otherwise logging in would
+        // not outlast the environment
+        _ => dxenv::env_auth_token(),
+    };
+
+    if let Some(token) = token {
+        return login_with_token(token, &args);
+    }
+
+    if args.no_prompt {
+        bail!(
+            "No credentials found, use --token, --token-file, \
+            or set DX_API_TOKEN or DX_SECURITY_CONTEXT"
+        )
+    }
+
+    let dx_user: String = if let Ok(user) = env::var("DX_USERNAME") {
+        user
+    } else if let Some(user) = dxenv::get_dx_username() {
+        user
+    } else {
+        "".to_string()
+    };
+
+    let username = Text::new("Username:")
+        .with_initial_value(&dx_user)
+        .prompt()
+        .unwrap();
+
+    let password = Password::new("Password:")
+        .without_confirmation()
+        .prompt()
+        .unwrap();
+
+    // First time login there is no dx_env.json, but a profile
+    // may already name the server to log into
+    let cur_env = login_server(get_dx_env().unwrap_or_default(), &args);
+
+    let auth_token = api::login(
+        &cur_env.auth_url(),
+        &username,
+        &password,
+        args.token.clone(),
+    )?;
+
+    let dx_env = DxEnvironment {
+        username: username.clone(),
+        auth_token: auth_token.access_token.to_string(),
+        auth_token_type: "Bearer".to_string(),
+        plaintext_store: args.plaintext_store,
+        ..cur_env
+    };
+    let dx_env = DxEnvironment {
+        auth_token_expires: token_expires(&dx_env),
+        ..dx_env
+    };
+
+    save_dx_env(&dx_env)?;
+    select_project(SelectArgs {
+        project: None,
+        level: None,
+        current: false,
+    })?;
+    Ok(())
+}
+
+// --------------------------------------------------
+// Asks the auth server for a new token with the scope and label of the
+// current one, then revokes the current one
+fn login_refresh() -> Result<()> {
+    if dxenv::env_auth_token().is_some() {
+        bail!(CliError::Usage(
+            "The token is set in the environment, so replace it there"
+                .to_string()
+        ));
+    }
+
+    let dx_env = get_dx_env()?;
+    let info = api::describe_token(&dx_env)?;
+    let expires = Utc::now() + chrono::Duration::days(TOKEN_LIFETIME_DAYS);
+    let options = NewTokenOptions {
+        label: info.label,
+        scope: info.scope,
+        expires: expires.timestamp_millis(),
+    };
+    let new_token = api::new_token(&dx_env, &options)?;
+
+    let new_env = DxEnvironment {
+        auth_token: new_token.access_token,
+        auth_token_type: "Bearer".to_string(),
+        auth_token_expires: Some(options.expires),
+        ..dx_env.clone()
+    };
+    save_dx_env(&new_env)?;
+
+    // The new token is saved, so the old one is only a liability
+    if let Err(e) = api::logout(&dx_env) {
+        eprintln!("Could not revoke the old token: {e}");
+    }
+    println!(
+        "Refreshed the token for {}, which expires {}",
+        new_env.username,
+        expires.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")
+    );
+    Ok(())
+}
+
+// --------------------------------------------------
+// Best effort, as not every token may be described, e.g., a job's
+fn token_expires(dx_env: &DxEnvironment) -> Option<i64> {
+    match api::describe_token(dx_env) {
+        Ok(info) => info.expires.map(|t| t.timestamp_millis()),
+        Err(e) => {
+            debug!("Cannot describe token: {e}");
+            None
+        }
+    }
+}
+
+// --------------------------------------------------
+fn login_server(dx_env: DxEnvironment, args: &LoginArgs) -> DxEnvironment {
+    DxEnvironment {
+        apiserver_host: args.host.clone().unwrap_or(dx_env.apiserver_host),
+        apiserver_port: args.port.unwrap_or(dx_env.apiserver_port),
+        apiserver_protocol: args
+            .protocol
+            .clone()
+            .unwrap_or(dx_env.apiserver_protocol),
+        ..dx_env
+    }
+}
+
+// --------------------------------------------------
+fn login_with_token(token: String, args: &LoginArgs) -> Result<()> {
+    if token.is_empty() {
+        bail!("Token is empty")
+    }
+
+    let dx_env = DxEnvironment {
+        auth_token: token,
+        auth_token_type: "Bearer".to_string(),
+        plaintext_store: args.plaintext_store,
+        ..login_server(get_dx_env().unwrap_or_default(), args)
+    };
+
+    // Check the token and find out whose it is
+    let options = WhoAmIOptions {
+        fields: Some(HashMap::from([(WhoAmIOptionsFields::ClientIp, true)])),
+    };
+    let user = api::whoami(&dx_env, &options)?;
+    let dx_env = DxEnvironment {
+        username: user.id.trim_start_matches("user-").to_string(),
+        auth_token_expires: token_expires(&dx_env),
+        ..dx_env
+    };
+    save_login(&dx_env)?;
+    println!("Logged in as {}", dx_env.username);
+
+    if !args.no_prompt && dx_env.project_context_id.is_empty() {
+        select_project(SelectArgs {
+            project: None,
+            level: None,
+            current: false,
+        })?;
+    }
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use crate::api;
+use crate::dxenv::{get_dx_env, remove_backups, save_dx_env, DxEnvironment};
+use anyhow::Result;
+
+// --------------------------------------------------
+pub fn logout() -> Result<()> {
+    let dx_env = get_dx_env()?;
+    api::logout(&dx_env)?;
+    let new_env = DxEnvironment {
+        auth_token: "".to_string(),
+        auth_token_type: "".to_string(),
+        ..dx_env
+    };
+    save_dx_env(&new_env)?;
+    remove_backups()
+}
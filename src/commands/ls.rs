@@ -0,0 +1,435 @@
+use crate::dxenv::DxEnvironment;
+use crate::{
+    api, find_files_by_path, is_glob, resolve_path, sort_listing,
+    FindDataDescribe, ListColumn, ListFolderObject, ListFolderOptionOnlyValue,
+    ListFolderOptions, ListFolderResult, ListSortBy, ListingRow, PathListing,
+};
+#[cfg(feature = "cli")]
+use crate::{
+    dxenv::get_dx_env, fan_out, incomplete, listing_table, output_format,
+    print_listing, print_table, theme, OutputFormat, ProjectDescribeField,
+    ProjectDescribeOptions,
+};
+use anyhow::Result;
+use clap::Parser;
+use rayon::prelude::*;
+#[cfg(feature = "cli")]
+use std::collections::HashMap;
+use std::{collections::BTreeMap, path::Path};
+
+pub(crate) const FOLDER_WALK_THREADS: usize = 8;
+
+// How deep and what to list when walking a folder hierarchy
+#[derive(Debug)]
+pub(crate) struct FolderWalk {
+    pub(crate) depth: Option<usize>,
+    pub(crate) dirs_only: bool,
+    pub(crate) include_hidden: bool,
+}
+
+#[derive(Clone, Parser, Debug)]
+pub struct LsArgs {
+    /// Directory names or paths with globs, e.g., "/data/*.bam"
+    #[arg()]
+    paths: Vec<String>,
+
+    /// Long listing
+    #[arg(short, long)]
+    long: bool,
+
+    /// Show only file IDs
+    #[arg(short, long)]
+    brief: bool,
+
+    /// Show hidden
+    #[arg(short, long)]
+    all: bool,
+
+    /// Human-readable file sizes
+    #[arg(short('H'), long)]
+    human: bool,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long)]
+    wide: bool,
+
+    /// Columns to show, e.g., "name,size,id"
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "state,modified,size,name,id"
+    )]
+    columns: Vec<ListColumn>,
+
+    /// Sort results
+    #[arg(long)]
+    sort_by: Option<ListSortBy>,
+
+    /// Reverse the sort order
+    #[arg(short, long)]
+    reverse: bool,
+
+    /// List subfolders recursively
+    #[arg(short('R'), long)]
+    recursive: bool,
+
+    /// Maximum number of folder levels to descend with --recursive
+    #[arg(long, value_name = "N", requires = "recursive")]
+    depth: Option<usize>,
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub fn ls(args: LsArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let paths = if args.paths.is_empty() {
+        vec![dx_env.cli_wd.to_string()]
+    } else {
+        args.paths.clone()
+    };
+
+    // Machine-readable output gathers every path into one listing
+    let machine = output_format() != OutputFormat::Table;
+    let (mut rows, mut errors) = (vec![], vec![]);
+
+    let listings = fan_out(&paths, |path| list_path(&dx_env, path, &args))?;
+    for (path, listing) in paths.iter().zip(listings) {
+        let listing = match listing {
+            Ok(listing) => listing,
+            Err(e) => {
+                eprintln!("{e}");
+                errors.push(e);
+                continue;
+            }
+        };
+
+        if is_glob(&listing.path) && listing.matches.is_empty() {
+            eprintln!(r#"No matches for "{path}""#);
+        }
+
+        if machine {
+            rows.extend(listing.matches);
+            rows.extend(listing.contents.unwrap_or_default());
+        } else {
+            print_path_listing(&dx_env, listing, &args)?;
+        }
+    }
+
+    if machine {
+        print_listing(&rows, &args.columns, args.human, true, args.wide)?;
+    }
+
+    incomplete("Listing", errors)
+}
+
+// --------------------------------------------------
+/// List the objects matching a path and, when it is a folder, what it
+/// contains, recursively with "args.recursive"
+pub fn list_path(
+    dx_env: &DxEnvironment,
+    path: &str,
+    args: &LsArgs,
+) -> Result<PathListing> {
+    let dx_path = resolve_path(dx_env, path)?;
+    let files = find_files_by_path(dx_env, &dx_path.path, &dx_path.project_id)?;
+
+    // Globbed matches may span folders, so show full paths
+    let globbed = is_glob(&dx_path.path);
+    let display_name = |desc: &FindDataDescribe| {
+        let name = desc.name.clone().unwrap_or("".to_string());
+        match (globbed, &desc.folder) {
+            (true, Some(folder)) => {
+                Path::new(folder).join(name).to_string_lossy().to_string()
+            }
+            _ => name,
+        }
+    };
+
+    let mut matches: Vec<ListingRow> = files
+        .into_iter()
+        .filter_map(|file| file.describe)
+        .map(|desc| ListingRow {
+            name: display_name(&desc),
+            state: desc
+                .archival_state
+                .as_ref()
+                .map_or("".to_string(), |s| s.to_string()),
+            id: desc.id,
+            size: desc.size,
+            modified: desc.modified,
+        })
+        .collect();
+    sort_listing(&mut matches, &args.sort_by, args.reverse);
+
+    let contents = if dx_path.path.starts_with("/") && !globbed {
+        Some(if args.recursive {
+            recursive_listing(dx_env, &dx_path.project_id, &dx_path.path, args)?
+        } else {
+            folder_listing(dx_env, &dx_path.project_id, &dx_path.path, args)?
+        })
+    } else {
+        None
+    };
+
+    Ok(PathListing {
+        project_id: dx_path.project_id,
+        path: dx_path.path,
+        matches,
+        contents,
+    })
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+fn print_path_listing(
+    dx_env: &DxEnvironment,
+    listing: PathListing,
+    args: &LsArgs,
+) -> Result<()> {
+    if !listing.matches.is_empty() {
+        if args.long {
+            let table = listing_table(
+                &listing.matches,
+                &args.columns,
+                args.human,
+                true,
+                args.wide,
+            );
+            print_table(&table, args.wide);
+        } else {
+            for row in &listing.matches {
+                println!("{} : {}", row.name, row.id);
+            }
+        }
+    }
+
+    let Some(contents) = listing.contents else {
+        return Ok(());
+    };
+
+    let desc_opts = ProjectDescribeOptions {
+        fields: Some(HashMap::from([(ProjectDescribeField::Name, true)])),
+    };
+    let project =
+        api::describe_project(dx_env, &listing.project_id, &desc_opts)?;
+    println!(
+        "{}",
+        theme::heading(format!(
+            "{} ({}):{}",
+            project.name.unwrap_or("".to_string()),
+            listing.project_id,
+            listing.path
+        ))
+    );
+
+    if args.recursive {
+        if args.long {
+            let table = listing_table(
+                &contents,
+                &args.columns,
+                args.human,
+                true,
+                args.wide,
+            );
+            print_table(&table, args.wide);
+        } else {
+            for row in contents {
+                if args.brief && !row.id.is_empty() {
+                    println!("  {}", theme::id(&row.id));
+                } else if !args.brief {
+                    println!("  {}", row.name);
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let (folders, objects): (Vec<_>, Vec<_>) =
+        contents.into_iter().partition(|row| row.state == "folder");
+    for folder in &folders {
+        println!("{}", theme::folder(&folder.name));
+    }
+
+    if args.long {
+        if !objects.is_empty() {
+            let table = listing_table(
+                &objects,
+                &args.columns,
+                args.human,
+                true,
+                args.wide,
+            );
+            println!("{}:", theme::heading(listing.path));
+            print_table(&table, args.wide);
+        }
+    } else if objects.is_empty() && folders.is_empty() {
+        println!("Empty directory");
+    } else {
+        for row in objects {
+            if args.brief {
+                println!("  {}", theme::id(&row.id));
+            } else {
+                println!("  {}", row.name);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// Folders and objects in one directory as listing rows
+fn folder_listing(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    folder: &str,
+    args: &LsArgs,
+) -> Result<Vec<ListingRow>> {
+    let options = ListFolderOptions {
+        folder,
+        only: Some(ListFolderOptionOnlyValue::All),
+        describe: true,
+        has_subfolder_flags: true,
+        include_hidden: args.all,
+    };
+    let results: ListFolderResult = api::ls(dx_env, project_id, options)?;
+
+    let mut rows: Vec<ListingRow> = results
+        .folders
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, _has_subdir)| ListingRow {
+            name,
+            id: "".to_string(),
+            state: "folder".to_string(),
+            size: None,
+            modified: None,
+        })
+        .collect();
+
+    if let Some(objects) = results.objects {
+        rows.extend(folder_listing_rows(objects, args));
+    }
+
+    Ok(rows)
+}
+
+// --------------------------------------------------
+// Folders and objects below a directory with paths relative to it
+fn recursive_listing(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    folder: &str,
+    args: &LsArgs,
+) -> Result<Vec<ListingRow>> {
+    let walk = FolderWalk {
+        depth: args.depth,
+        dirs_only: false,
+        include_hidden: args.all,
+    };
+    let listings = walk_folders(dx_env, project_id, folder, &walk)?;
+
+    let mut rows = vec![];
+    for (dir, ls) in listings {
+        let relative = |name: &str| {
+            let path = Path::new(&dir).join(name);
+            path.strip_prefix(folder)
+                .unwrap_or(&path)
+                .display()
+                .to_string()
+        };
+
+        for (subdir, _) in ls.folders.unwrap_or_default() {
+            rows.push(ListingRow {
+                name: format!("{}/", relative(&subdir)),
+                id: "".to_string(),
+                state: "folder".to_string(),
+                size: None,
+                modified: None,
+            });
+        }
+
+        for mut row in folder_listing_rows(ls.objects.unwrap_or_default(), args)
+        {
+            row.name = relative(&row.name);
+            rows.push(row);
+        }
+    }
+
+    Ok(rows)
+}
+
+// --------------------------------------------------
+fn folder_listing_rows(
+    objects: Vec<ListFolderObject>,
+    args: &LsArgs,
+) -> Vec<ListingRow> {
+    let mut rows: Vec<ListingRow> = objects
+        .into_iter()
+        .filter_map(|obj| obj.describe)
+        .map(|desc| ListingRow {
+            name: desc.name,
+            id: desc.id,
+            state: desc.state.to_string(),
+            size: desc.size,
+            modified: desc.modified,
+        })
+        .collect();
+    sort_listing(&mut rows, &args.sort_by, args.reverse);
+    rows
+}
+
+// --------------------------------------------------
+// Lists a folder and its subfolders one level at a time, fetching each
+// level concurrently rather than walking the project one folder at a time
+pub(crate) fn walk_folders(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    folder: &str,
+    walk: &FolderWalk,
+) -> Result<BTreeMap<String, ListFolderResult>> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(FOLDER_WALK_THREADS)
+        .build()?;
+
+    let mut listings = BTreeMap::new();
+    let mut level = vec![folder.to_string()];
+    let mut depth = 0;
+    while !level.is_empty() {
+        let results: Vec<Result<ListFolderResult>> = pool.install(|| {
+            level
+                .par_iter()
+                .map(|folder| {
+                    let ls_opts = ListFolderOptions {
+                        folder,
+                        only: Some(if walk.dirs_only {
+                            ListFolderOptionOnlyValue::Folders
+                        } else {
+                            ListFolderOptionOnlyValue::All
+                        }),
+                        describe: !walk.dirs_only,
+                        has_subfolder_flags: true,
+                        include_hidden: walk.include_hidden,
+                    };
+                    api::ls(dx_env, project_id, ls_opts)
+                })
+                .collect()
+        });
+
+        depth += 1;
+        let descend = walk.depth.is_none_or(|max| depth < max);
+        let mut next = vec![];
+        for (folder, ls) in level.into_iter().zip(results) {
+            let ls = ls?;
+            if descend {
+                next.extend(
+                    ls.folders.iter().flatten().map(|(sub, _)| sub.clone()),
+                );
+            }
+            listings.insert(folder, ls);
+        }
+        level = next;
+    }
+
+    Ok(listings)
+}
@@ -0,0 +1,35 @@
+use crate::dxenv::get_dx_env;
+use crate::{api, parse_duration, resolve_link_target, DownloadOptions};
+use anyhow::Result;
+use clap::Parser;
+
+#[derive(Clone, Parser, Debug)]
+pub struct MakeDownloadUrlArgs {
+    /// File path or ID
+    #[arg()]
+    path: String,
+
+    /// How long the URL is valid, e.g., 30m, 12h, 7d
+    #[arg(short, long, default_value = "1d")]
+    duration: String,
+
+    /// Name the file is saved as when downloaded
+    #[arg(short, long)]
+    filename: Option<String>,
+}
+
+// --------------------------------------------------
+pub fn make_download_url(args: MakeDownloadUrlArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let (project_id, file_id) = resolve_link_target(&dx_env, &args.path)?;
+    let options = DownloadOptions {
+        duration: Some(parse_duration(&args.duration)?),
+        filename: args.filename.clone(),
+        project: project_id,
+        preauthenticated: Some(true),
+        sticky_ip: None,
+    };
+    let download = api::download(&dx_env, &file_id, &options)?;
+    println!("{}", download.url);
+    Ok(())
+}
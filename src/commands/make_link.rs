@@ -0,0 +1,92 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{
+    api, entity_id, resolve_file_id, resolve_path, DxFileDescriptor,
+    DxFileDescriptorProject, DxFileDescriptorValue, RecordNewOptions,
+};
+use anyhow::Result;
+use clap::Parser;
+
+#[derive(Clone, Parser, Debug)]
+pub struct MakeLinkArgs {
+    /// Object paths or IDs
+    #[arg(required = true)]
+    paths: Vec<String>,
+
+    /// Create a record with this name holding the links as details
+    #[arg(short, long, value_name = "NAME")]
+    record: Option<String>,
+
+    /// Leave the project out of the links
+    #[arg(long)]
+    no_project: bool,
+}
+
+// --------------------------------------------------
+pub fn make_link(args: MakeLinkArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let mut links = vec![];
+    for path in &args.paths {
+        let (project_id, object_id) = resolve_link_target(&dx_env, path)?;
+        let project_id = if args.no_project { None } else { project_id };
+        links.push(dx_link(project_id, object_id));
+    }
+
+    let value = if links.len() == 1 {
+        serde_json::to_value(&links[0])?
+    } else {
+        serde_json::to_value(&links)?
+    };
+
+    match &args.record {
+        Some(name) => {
+            let options = RecordNewOptions {
+                project: dx_env.project_context_id.clone(),
+                name: Some(name.to_string()),
+                folder: Some(dx_env.cli_wd.clone()),
+                parents: Some(true),
+                details: Some(value),
+                close: true,
+            };
+            let record = api::record_new(&dx_env, &options)?;
+            println!("{}", record.id);
+        }
+        _ => println!("{}", serde_json::to_string_pretty(&value)?),
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+pub(crate) fn resolve_link_target(
+    dx_env: &DxEnvironment,
+    path: &str,
+) -> Result<(Option<String>, String)> {
+    if let Some(caps) = entity_id::PROJECT_OBJECT_ID.captures(path) {
+        return Ok((
+            caps.get(1).map(|m| m.as_str().to_string()),
+            caps[2].to_string(),
+        ));
+    }
+
+    let dx_path = resolve_path(dx_env, path)?;
+    let file_id = resolve_file_id(dx_env, path)?;
+    Ok((Some(dx_path.project_id), file_id))
+}
+
+// --------------------------------------------------
+pub(crate) fn dx_link(
+    project_id: Option<String>,
+    object_id: String,
+) -> DxFileDescriptor {
+    DxFileDescriptor {
+        dnanexus_link: match project_id {
+            Some(project) => {
+                DxFileDescriptorValue::File(DxFileDescriptorProject {
+                    project,
+                    id: object_id,
+                })
+            }
+            _ => DxFileDescriptorValue::FileId(object_id),
+        },
+    }
+}
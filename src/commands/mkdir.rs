@@ -0,0 +1,50 @@
+use crate::dxenv::get_dx_env;
+use crate::{api, incomplete, resolve_path, MakeFolderOptions};
+use anyhow::Result;
+use clap::Parser;
+use log::debug;
+
+#[derive(Clone, Parser, Debug)]
+pub struct MkdirArgs {
+    /// Directory name, optionally prefixed with "project-xxxx:"
+    #[arg()]
+    paths: Vec<String>,
+
+    /// Create parent directories as needed
+    #[arg(short, long, default_value = "false")]
+    parents: bool,
+}
+
+// --------------------------------------------------
+pub fn mkdir(args: MkdirArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+
+    debug!("{:?}", &args);
+
+    let mut errors = vec![];
+    for path in &args.paths {
+        let dest = match resolve_path(&dx_env, path) {
+            Ok(dest) => dest,
+            Err(e) => {
+                eprintln!("{e}");
+                errors.push(e);
+                continue;
+            }
+        };
+
+        let options = MakeFolderOptions {
+            folder: dest.path.clone(),
+            parents: args.parents,
+        };
+
+        match api::mkdir(&dx_env, &dest.project_id, options) {
+            Ok(_) => println!(r#"Created folder "{dest}""#),
+            Err(e) => {
+                eprintln!("{e}");
+                errors.push(e);
+            }
+        }
+    }
+
+    incomplete("Mkdir", errors)
+}
@@ -0,0 +1,648 @@
+//! The subcommands, one module each, along with the helpers that
+//! several of them share
+#[cfg(feature = "cli")]
+mod access;
+#[cfg(feature = "cli")]
+mod billing;
+#[cfg(feature = "cli")]
+mod build;
+#[cfg(feature = "cli")]
+mod cache_clear;
+#[cfg(feature = "cli")]
+mod cat;
+#[cfg(feature = "cli")]
+mod cd;
+#[cfg(feature = "cli")]
+mod clean;
+#[cfg(feature = "cli")]
+mod close_file;
+#[cfg(feature = "cli")]
+mod convert;
+#[cfg(feature = "cli")]
+mod cost;
+mod describe;
+#[cfg(feature = "cli")]
+mod diff_app;
+#[cfg(feature = "cli")]
+mod diff_projects;
+#[cfg(feature = "cli")]
+mod download;
+#[cfg(feature = "cli")]
+mod du;
+#[cfg(feature = "cli")]
+mod env;
+#[cfg(feature = "cli")]
+mod extract_dataset;
+#[cfg(feature = "cli")]
+mod find_apps;
+mod find_data;
+#[cfg(feature = "cli")]
+mod find_dupes;
+#[cfg(feature = "cli")]
+mod format;
+#[cfg(feature = "cli")]
+mod head;
+#[cfg(feature = "cli")]
+mod inputs;
+#[cfg(feature = "cli")]
+mod lint;
+#[cfg(feature = "cli")]
+mod login;
+#[cfg(feature = "cli")]
+mod logout;
+mod ls;
+#[cfg(feature = "cli")]
+mod make_download_url;
+#[cfg(feature = "cli")]
+mod make_link;
+#[cfg(feature = "cli")]
+mod mkdir;
+#[cfg(feature = "cli")]
+mod new_project;
+#[cfg(feature = "cli")]
+mod preflight;
+#[cfg(feature = "cli")]
+mod profile;
+#[cfg(feature = "cli")]
+mod pwd;
+#[cfg(feature = "cli")]
+mod query;
+#[cfg(feature = "cli")]
+mod rerun;
+mod resolve;
+#[cfg(feature = "cli")]
+mod restore;
+#[cfg(feature = "cli")]
+mod rm;
+#[cfg(feature = "cli")]
+mod rm_project;
+#[cfg(feature = "cli")]
+mod rmdir;
+#[cfg(feature = "cli")]
+mod run;
+#[cfg(feature = "cli")]
+mod run_wdl;
+#[cfg(feature = "cli")]
+mod search;
+#[cfg(feature = "cli")]
+mod select;
+#[cfg(feature = "cli")]
+mod snapshot;
+#[cfg(feature = "cli")]
+mod ssh;
+#[cfg(feature = "cli")]
+mod stream;
+#[cfg(feature = "cli")]
+mod sync;
+#[cfg(feature = "cli")]
+mod test;
+#[cfg(feature = "cli")]
+mod token_info;
+#[cfg(feature = "cli")]
+mod top;
+#[cfg(feature = "cli")]
+mod tree;
+#[cfg(feature = "cli")]
+mod upload;
+#[cfg(feature = "cli")]
+mod verify;
+#[cfg(feature = "cli")]
+mod versions;
+#[cfg(feature = "cli")]
+mod wait;
+#[cfg(feature = "cli")]
+mod watch;
+#[cfg(feature = "cli")]
+mod whoami;
+#[cfg(feature = "cli")]
+mod wizard;
+
+#[cfg(feature = "cli")]
+pub use access::*;
+#[cfg(feature = "cli")]
+pub use billing::*;
+#[cfg(feature = "cli")]
+pub use build::*;
+#[cfg(feature = "cli")]
+pub use cache_clear::*;
+#[cfg(feature = "cli")]
+pub use cat::*;
+#[cfg(feature = "cli")]
+pub use cd::*;
+#[cfg(feature = "cli")]
+pub use clean::*;
+#[cfg(feature = "cli")]
+pub use close_file::*;
+#[cfg(feature = "cli")]
+pub use convert::*;
+#[cfg(feature = "cli")]
+pub use cost::*;
+pub use describe::*;
+#[cfg(feature = "cli")]
+pub use diff_app::*;
+#[cfg(feature = "cli")]
+pub use diff_projects::*;
+#[cfg(feature = "cli")]
+pub use download::*;
+#[cfg(feature = "cli")]
+pub use du::*;
+#[cfg(feature = "cli")]
+pub use env::*;
+#[cfg(feature = "cli")]
+pub use extract_dataset::*;
+#[cfg(feature = "cli")]
+pub use find_apps::*;
+pub use find_data::*;
+#[cfg(feature = "cli")]
+pub use find_dupes::*;
+#[cfg(feature = "cli")]
+pub use format::*;
+#[cfg(feature = "cli")]
+pub use head::*;
+#[cfg(feature = "cli")]
+pub use inputs::*;
+#[cfg(feature = "cli")]
+pub use lint::*;
+#[cfg(feature = "cli")]
+pub use login::*;
+#[cfg(feature = "cli")]
+pub use logout::*;
+pub use ls::*;
+#[cfg(feature = "cli")]
+pub use make_download_url::*;
+#[cfg(feature = "cli")]
+pub use make_link::*;
+#[cfg(feature = "cli")]
+pub use mkdir::*;
+#[cfg(feature = "cli")]
+pub use new_project::*;
+#[cfg(feature = "cli")]
+pub use preflight::*;
+#[cfg(feature = "cli")]
+pub use profile::*;
+#[cfg(feature = "cli")]
+pub use pwd::*;
+#[cfg(feature = "cli")]
+pub use query::*;
+#[cfg(feature = "cli")]
+pub use rerun::*;
+pub use resolve::*;
+#[cfg(feature = "cli")]
+pub use restore::*;
+#[cfg(feature = "cli")]
+pub use rm::*;
+#[cfg(feature = "cli")]
+pub use rm_project::*;
+#[cfg(feature = "cli")]
+pub use rmdir::*;
+#[cfg(feature = "cli")]
+pub use run::*;
+#[cfg(feature = "cli")]
+pub use run_wdl::*;
+#[cfg(feature = "cli")]
+pub use search::*;
+#[cfg(feature = "cli")]
+pub use select::*;
+#[cfg(feature = "cli")]
+pub use snapshot::*;
+#[cfg(feature = "cli")]
+pub use ssh::*;
+#[cfg(feature = "cli")]
+pub(crate) use stream::*;
+#[cfg(feature = "cli")]
+pub use sync::*;
+#[cfg(feature = "cli")]
+pub use test::*;
+#[cfg(feature = "cli")]
+pub use token_info::*;
+#[cfg(feature = "cli")]
+pub use top::*;
+#[cfg(feature = "cli")]
+pub use tree::*;
+#[cfg(feature = "cli")]
+pub use upload::*;
+#[cfg(feature = "cli")]
+pub use verify::*;
+#[cfg(feature = "cli")]
+pub use versions::*;
+#[cfg(feature = "cli")]
+pub use wait::*;
+#[cfg(feature = "cli")]
+pub use watch::*;
+#[cfg(feature = "cli")]
+pub use whoami::*;
+#[cfg(feature = "cli")]
+pub use wizard::*;
+
+#[cfg(feature = "cli")]
+use crate::{cache, ColorWhen, OutputFormat};
+#[cfg(feature = "cli")]
+use anyhow::Result;
+#[cfg(feature = "cli")]
+use clap::Parser;
+#[cfg(feature = "cli")]
+use rayon::prelude::*;
+#[cfg(feature = "cli")]
+use std::path::PathBuf;
+
+#[cfg(feature = "cli")]
+const FAN_OUT_THREADS: usize = 8;
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+#[derive(Parser, Debug)]
+#[command(arg_required_else_help = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Log debugging information, same as "--log-level debug"
+    #[arg(short, long, default_value = "false")]
+    pub debug: bool,
+
+    /// Log level, e.g., "debug", or "env_logger" directives, e.g.,
+    /// "info,dxrs::api=trace"
+    #[arg(long, global = true, value_name = "LEVEL")]
+    pub log_level: Option<String>,
+
+    /// Append logs to this file instead of STDERR, at "debug" unless
+    /// "--log-level" says otherwise
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Output format for listings and descriptions
+    #[arg(long, global = true, value_name = "FORMAT", default_value = "table")]
+    pub output_format: OutputFormat,
+
+    /// Named profile to use instead of the current one
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Seconds to reuse cached describe and folder listings
+    #[arg(
+        long,
+        global = true,
+        value_name = "SECONDS",
+        default_value_t = cache::DEFAULT_TTL
+    )]
+    pub cache_ttl: u64,
+
+    /// Always ask the API instead of reusing cached responses
+    #[arg(long, global = true, default_value = "false")]
+    pub no_cache: bool,
+
+    /// Most API requests to send per second, 0 for no limit
+    #[arg(long, global = true, value_name = "REQUESTS", default_value = "0")]
+    pub rate_limit: f64,
+
+    /// Log the route, status, latency, and retries of each request
+    #[arg(long, global = true, default_value = "false")]
+    pub verbose_http: bool,
+
+    /// Do not show progress bars or informational messages
+    #[arg(short, long, global = true, default_value = "false")]
+    pub quiet: bool,
+
+    /// When to color the output, "auto" unless NO_COLOR is set
+    #[arg(long, global = true, value_name = "WHEN", default_value = "auto")]
+    pub color: ColorWhen,
+
+    /// Do not color the output, same as "--color never"
+    #[arg(long, global = true, default_value = "false")]
+    pub no_color: bool,
+}
+
+#[cfg(feature = "cli")]
+#[derive(Parser, Debug)]
+pub enum Command {
+    /// Show or change who can run and develop an app
+    Access(AccessArgs),
+
+    /// Show compute and egress charges per project for an org or user
+    Billing(BillingArgs),
+
+    /// Build applet or asset
+    #[clap(alias = "bu")]
+    Build(BuildArgs),
+
+    /// Remove cached describe and folder listings
+    CacheClear {},
+
+    /// Print the contents of files
+    Cat(CatArgs),
+
+    /// Change directory
+    Cd(CdArgs),
+
+    /// Remove leftovers from interrupted operations
+    Clean(CleanArgs),
+
+    /// Close open files
+    CloseFile(CloseFileArgs),
+
+    /// Convert between dxapp.json, dxasset.json and WDL
+    Convert(ConvertArgs),
+
+    /// Show what a project or execution tree has cost
+    Cost(CostArgs),
+
+    /// Show object metadata
+    #[clap(alias = "desc", alias = "de")]
+    Describe(DescribeArgs),
+
+    /// Compare two dxapp.json files or a dxapp.json and an applet
+    DiffApp(DiffAppArgs),
+
+    /// Compare the folders and objects of two projects
+    DiffProjects(DiffProjectsArgs),
+
+    /// Download a file or directory
+    #[clap(alias = "dl")]
+    Download(DownloadArgs),
+
+    /// Show data usage per folder of a project
+    Du(DuArgs),
+
+    /// Environment listing
+    Env(EnvArgs),
+
+    /// Export fields of a dataset or cohort to CSV or Parquet
+    ExtractDataset(ExtractDatasetArgs),
+
+    /// Find apps
+    #[clap(alias = "fa")]
+    FindApps(FindAppsArgs),
+
+    /// Find data
+    #[clap(alias = "fd")]
+    FindData(FindDataArgs),
+
+    /// Find files with the same size and checksum in a project
+    FindDupes(FindDupesArgs),
+
+    /// Format app/asset JSON
+    #[clap(alias = "fmt")]
+    Format(FormatArgs),
+
+    /// Print the first lines of a file
+    Head(HeadArgs),
+
+    /// Show the inputs of an app or applet
+    Inputs(InputsArgs),
+
+    /// Lint app/asset JSON
+    Lint(LintArgs),
+
+    /// Login to platform
+    #[clap(alias = "li")]
+    Login(LoginArgs),
+
+    /// Logout of platform
+    #[clap(alias = "lo")]
+    Logout {},
+
+    /// Directory listing
+    Ls(LsArgs),
+
+    /// Create a shareable download URL for a file
+    MakeDownloadUrl(MakeDownloadUrlArgs),
+
+    /// Print link JSON for objects or save it as a record
+    MakeLink(MakeLinkArgs),
+
+    /// Create directory
+    Mkdir(MkdirArgs),
+
+    /// Create project
+    #[clap(alias = "newp")]
+    NewProject(NewProjectArgs),
+
+    /// Check that an applet and inputs are ready to run
+    Preflight(PreflightArgs),
+
+    /// List, add, switch or delete config profiles
+    Profile(ProfileArgs),
+
+    /// Print working directory
+    Pwd {},
+
+    /// Run SQL against a database with a Spark cluster job
+    Query(QueryArgs),
+
+    /// Rerun a job or analysis with the same inputs
+    Rerun(RerunArgs),
+
+    /// Move objects removed with "rm --trash" back to where they were,
+    /// or clone the objects of a snapshot into a project
+    Restore(RestoreArgs),
+
+    /// Remove a file, directory, applet, or workflow
+    Rm(RmArgs),
+
+    /// Remove a directory
+    #[clap(alias = "rmd")]
+    Rmdir(RmdirArgs),
+
+    /// Delete projects and all associated data
+    #[clap(alias = "rmp")]
+    RmProject(RmProjectArgs),
+
+    /// Run an applet, app or workflow
+    Run(RunArgs),
+
+    /// Compile a local WDL workflow with dxCompiler and optionally run it
+    RunWdl(RunWdlArgs),
+
+    /// Search file properties and record details
+    Search(SearchArgs),
+
+    /// Select working project
+    #[clap(alias = "se")]
+    Select(SelectArgs),
+
+    /// Export a project's folders and objects to a JSON manifest
+    Snapshot(SnapshotArgs),
+
+    /// Connect to a running job with SSH
+    Ssh(SshArgs),
+
+    /// Copy only the changed files between a local directory and a remote
+    /// folder
+    Sync(SyncArgs),
+
+    /// Run an applet locally in a Docker container
+    Test(TestArgs),
+
+    /// Describe the current auth token
+    TokenInfo(TokenInfoArgs),
+
+    /// Show the running jobs in a project, refreshing until quit
+    Top(TopArgs),
+
+    /// List folders and objects in a tree
+    #[clap(alias = "tr")]
+    Tree(TreeArgs),
+
+    /// Upload a local file to the platform
+    #[clap(alias = "up")]
+    Upload(UploadArgs),
+
+    /// Compare a local file with a remote file
+    Verify(VerifyArgs),
+
+    /// List every version of an app
+    Versions(VersionsArgs),
+
+    /// Wait for jobs and analyses to finish or files to close
+    Wait(WaitArgs),
+
+    /// Watch a job
+    #[clap(alias = "wa")]
+    Watch(WatchArgs),
+
+    /// Identify currently logged in user
+    #[clap(alias = "who")]
+    Whoami(WhoamiArgs),
+
+    /// Wizard for creating applets
+    #[clap(alias = "wiz")]
+    Wizard(WizardArgs),
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+impl Command {
+    /// Whether the subcommand talks to the API server, as those that
+    /// work only with local files or the config have no use for a token
+    pub fn calls_api(&self) -> bool {
+        !matches!(
+            self,
+            Command::CacheClear {}
+                | Command::Convert(_)
+                | Command::Env(_)
+                | Command::Format(_)
+                | Command::Lint(_)
+                | Command::Login(_)
+                | Command::Logout {}
+                | Command::Profile(_)
+                | Command::Pwd {}
+                | Command::Test(_)
+                | Command::TokenInfo(_)
+                | Command::Wizard(_)
+        )
+    }
+}
+
+// --------------------------------------------------
+/// Runs the function behind a subcommand
+#[cfg(feature = "cli")]
+pub fn dispatch(command: &Command) -> Result<()> {
+    match command {
+        Command::Access(args) => access(args.clone()),
+        Command::Billing(args) => billing(args.clone()),
+        Command::Build(args) => build(args.clone()),
+        Command::CacheClear {} => cache_clear(),
+        Command::Cat(args) => cat(args.clone()),
+        Command::Cd(args) => cd(args.clone()),
+        Command::Clean(args) => clean(args.clone()),
+        Command::CloseFile(args) => close_file(args.clone()),
+        Command::Convert(args) => convert(args.clone()),
+        Command::Cost(args) => cost(args.clone()),
+        Command::Describe(args) => describe(args.clone()),
+        Command::DiffApp(args) => diff_app(args.clone()),
+        Command::DiffProjects(args) => diff_projects(args.clone()),
+        Command::Download(args) => download(args.clone()),
+        Command::Du(args) => du(args.clone()),
+        Command::Env(args) => print_env(args.clone()),
+        Command::ExtractDataset(args) => extract_dataset(args.clone()),
+        Command::FindApps(args) => find_apps(args.clone()),
+        Command::FindData(args) => find_data(args.clone()),
+        Command::FindDupes(args) => find_dupes(args.clone()),
+        Command::Format(args) => format(args.clone()),
+        Command::Head(args) => head(args.clone()),
+        Command::Inputs(args) => inputs(args.clone()),
+        Command::Lint(args) => lint(args.clone()),
+        Command::Login(args) => {
+            login(args.clone())?;
+            println!("Login successful");
+            Ok(())
+        }
+        Command::Logout {} => {
+            logout()?;
+            println!("Logout successful");
+            Ok(())
+        }
+        Command::Ls(args) => ls(args.clone()),
+        Command::MakeDownloadUrl(args) => make_download_url(args.clone()),
+        Command::MakeLink(args) => make_link(args.clone()),
+        Command::Mkdir(args) => mkdir(args.clone()),
+        Command::NewProject(args) => {
+            new_project(args.clone())?;
+            Ok(())
+        }
+        Command::Preflight(args) => preflight(args.clone()),
+        Command::Profile(args) => profile(args.clone()),
+        Command::Pwd {} => pwd(),
+        Command::Query(args) => query(args.clone()),
+        Command::RmProject(args) => rm_project(args.clone()),
+        Command::Rerun(args) => rerun(args.clone()),
+        Command::Restore(args) => restore(args.clone()),
+        Command::Rm(args) => rm(args.clone()),
+        Command::Rmdir(args) => rmdir(args.clone()),
+        Command::Run(args) => run(args.clone()),
+        Command::RunWdl(args) => run_wdl(args.clone()),
+        Command::Search(args) => search(args.clone()),
+        Command::Select(args) => select_project(args.clone()),
+        Command::Snapshot(args) => snapshot(args.clone()),
+        Command::Ssh(args) => ssh(args.clone()),
+        Command::Sync(args) => sync(args.clone()),
+        Command::Test(args) => test(args.clone()),
+        Command::TokenInfo(args) => token_info(args.clone()),
+        Command::Top(args) => top(args.clone()),
+        Command::Tree(args) => tree(args.clone()),
+        Command::Upload(args) => upload(args.clone()),
+        Command::Verify(args) => verify(args.clone()),
+        Command::Versions(args) => versions(args.clone()),
+        Command::Wait(args) => wait(args.clone()),
+        Command::Watch(args) => watch(args.clone()),
+        Command::Whoami(args) => whoami(args.clone()),
+        Command::Wizard(args) => wizard(args.clone()),
+    }
+}
+
+// --------------------------------------------------
+// Calls the API for many paths or IDs at once on up to FAN_OUT_THREADS
+// threads, returning the results in the order given
+#[cfg(feature = "cli")]
+pub(crate) fn fan_out<T: Sync, R: Send>(
+    items: &[T],
+    f: impl Fn(&T) -> R + Sync + Send,
+) -> Result<Vec<R>> {
+    if items.len() < 2 {
+        return Ok(items.iter().map(f).collect());
+    }
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(FAN_OUT_THREADS.min(items.len()))
+        .build()?;
+    Ok(pool.install(|| items.par_iter().map(f).collect()))
+}
+
+// --------------------------------------------------
+// Commands print the error for each path or ID that fails and go on with
+// the rest, then fail with the first as the cause so that the exit code
+// reflects its kind
+#[cfg(feature = "cli")]
+pub(crate) fn incomplete(
+    action: &str,
+    errors: Vec<anyhow::Error>,
+) -> Result<()> {
+    let num = errors.len();
+    match errors.into_iter().next() {
+        Some(e) => Err(e.context(format!(
+            "{action} incomplete, {num} error{}",
+            if num == 1 { "" } else { "s" }
+        ))),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,133 @@
+use crate::dxenv::{get_dx_env, save_dx_env, DxEnvironment};
+use crate::json_parser::VALID_REGION;
+use crate::{api, parse_properties, NewProjectOptions};
+use anyhow::{bail, Result};
+use clap::Parser;
+use inquire::Text;
+
+#[derive(Clone, Parser, Debug)]
+pub struct NewProjectArgs {
+    /// Project name
+    #[arg()]
+    project_name: Option<String>,
+
+    /// Print only the project ID
+    #[arg(long)]
+    brief: bool,
+
+    /// Select the new project as current after creating
+    #[arg(short, long)]
+    select: bool,
+
+    /// Add PHI protection to project
+    #[arg(long)]
+    phi: bool,
+
+    /// Viewers on the project cannot access database data directly
+    #[arg(long("database-ui-view-only"))]
+    database_ui_view_only: bool,
+
+    /// Region affinity of the new project
+    #[arg(long)]
+    region: Option<String>,
+
+    /// Short summary of the project
+    #[arg(long)]
+    summary: Option<String>,
+
+    /// Longer description of the project
+    #[arg(long)]
+    description: Option<String>,
+
+    /// Tag to add to the project (may be repeated)
+    #[arg(long, value_name = "TAG")]
+    tag: Vec<String>,
+
+    /// Property to set on the project, as KEY=VALUE (may be repeated)
+    #[arg(long, value_name = "KEY=VALUE")]
+    property: Vec<String>,
+
+    /// Only administrators may delete data in the project
+    #[arg(long)]
+    protected: bool,
+
+    /// Data cannot be copied out of the project
+    #[arg(long)]
+    restricted: bool,
+
+    /// ID of the user or org to which the project will be
+    /// billed. The default value is the billTo of the
+    /// requesting user.
+    #[arg(long("bill-to"))]
+    bill_to: Option<String>,
+
+    /// Monthly project spending limit for compute
+    #[arg(long("monthly-compute-limit"))]
+    monthly_compute_limit: Option<u64>,
+
+    /// Monthly project spending limit for egress (in Bytes)
+    #[arg(long("monthly-egress-bytes-limit"))]
+    monthly_egress_bytes_limit: Option<u64>,
+}
+
+// --------------------------------------------------
+pub fn new_project(args: NewProjectArgs) -> Result<String> {
+    if let Some(region) = &args.region {
+        if !VALID_REGION.contains(&region.as_str()) || region == "*" {
+            bail!(
+                r#"Invalid region "{region}", choose from {}"#,
+                VALID_REGION
+                    .iter()
+                    .filter(|r| **r != "*")
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
+
+    let properties = parse_properties(&args.property)?;
+    let project_name = args
+        .project_name
+        .unwrap_or_else(|| Text::new("Project name:").prompt().unwrap());
+
+    let dx_env = get_dx_env()?;
+    let options = NewProjectOptions {
+        name: project_name.clone(),
+        summary: args.summary,
+        description: args.description,
+        protected: args.protected,
+        restricted: args.restricted,
+        download_restricted: false,
+        external_upload_restricted: false,
+        database_ui_view_only: args.database_ui_view_only,
+        contains_phi: args.phi,
+        tags: (!args.tag.is_empty()).then_some(args.tag),
+        properties: (!properties.is_empty()).then_some(properties),
+        bill_to: args.bill_to,
+        region: args.region,
+        monthly_compute_limit: args.monthly_compute_limit,
+        monthly_egress_bytes_limit: args.monthly_egress_bytes_limit,
+    };
+
+    match api::new_project(&dx_env, options) {
+        Ok(res) => {
+            if args.brief {
+                println!("{}", res.id);
+            } else {
+                println!(r#"Created project "{}""#, res.id);
+            }
+
+            if args.select {
+                let new_env = DxEnvironment {
+                    project_context_id: res.id.clone(),
+                    project_context_name: project_name.clone(),
+                    ..dx_env
+                };
+                save_dx_env(&new_env)?;
+            }
+        }
+        Err(e) => eprintln!("{e}"),
+    }
+    Ok("".to_string())
+}
@@ -0,0 +1,280 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::entity_id::EntityId;
+use crate::json_parser::{
+    value_matches_class, SystemRequirements, VALID_INSTANCE_TYPE,
+};
+use crate::{
+    api, entity_id, parse_run_inputs, AppInputSpec, AppletDescribeField,
+    AppletDescribeOptions, ArchivalState, FileDescribeField,
+    FileDescribeOptions, ProjectDescribeField, ProjectDescribeOptions,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+
+#[derive(Clone, Parser, Debug)]
+pub struct PreflightArgs {
+    /// Applet ID
+    #[arg()]
+    executable: String,
+
+    /// An input to the applet, e.g., "reads=file-xxxx"
+    #[arg(short, long, value_name = "NAME=VALUE")]
+    input: Vec<String>,
+
+    /// Instance type for all entry points or "ENTRYPOINT=TYPE"
+    #[arg(long, value_name = "[ENTRYPOINT=]TYPE")]
+    instance_type: Vec<String>,
+}
+
+// --------------------------------------------------
+pub fn preflight(args: PreflightArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let (project_id, applet_id) = match EntityId::parse(&args.executable) {
+        Some(EntityId::Applet {
+            project_id,
+            applet_id,
+        }) => (
+            project_id.unwrap_or(dx_env.project_context_id.clone()),
+            applet_id,
+        ),
+        _ => bail!(r#""{}" is not an applet ID"#, args.executable),
+    };
+
+    let applet_opts = AppletDescribeOptions {
+        project: Some(project_id.clone()),
+        fields: Some(
+            AppletDescribeField::iter()
+                .map(|e| (e, true))
+                .collect::<HashMap<_, _>>(),
+        ),
+    };
+    let applet = api::describe_applet(&dx_env, &applet_id, &applet_opts)?;
+
+    let project_opts = ProjectDescribeOptions {
+        fields: Some(HashMap::from([
+            (ProjectDescribeField::Region, true),
+            (ProjectDescribeField::Restricted, true),
+            (ProjectDescribeField::AtSpendingLimit, true),
+            (ProjectDescribeField::AllowedExecutables, true),
+        ])),
+    };
+    let project = api::describe_project(&dx_env, &project_id, &project_opts)?;
+
+    // Gather every problem rather than stopping at the first
+    let mut problems = vec![];
+    let inputs = parse_run_inputs(&args.input)?;
+    let spec = applet.input_spec.unwrap_or_default();
+    problems.extend(check_inputs(&spec, &inputs));
+
+    let file_ids: Vec<_> = inputs
+        .values()
+        .flat_map(link_ids)
+        .filter(|id| entity_id::FILE_ID.is_match(id))
+        .collect();
+    for file_id in file_ids {
+        if let Some(problem) = check_input_file(&dx_env, &file_id) {
+            problems.push(problem);
+        }
+    }
+
+    // Requested instance types override the applet's defaults
+    let mut instance_types: HashMap<String, String> = applet
+        .run_spec
+        .and_then(|spec| spec.system_requirements)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(entry, req)| req.instance_type.map(|t| (entry, t)))
+        .collect();
+    match parse_instance_types(&args.instance_type) {
+        Ok(requested) => instance_types.extend(
+            requested
+                .into_iter()
+                .map(|(entry, req)| (entry, req.instance_type)),
+        ),
+        Err(e) => problems.push(e.to_string()),
+    }
+
+    let region = project.region.unwrap_or_default();
+    let mut instance_types: Vec<_> = instance_types.into_values().collect();
+    instance_types.sort();
+    instance_types.dedup();
+    problems.extend(
+        instance_types
+            .iter()
+            .filter_map(|t| check_instance_type(&region, t)),
+    );
+
+    if project.at_spending_limit == Some(true) {
+        problems.push(format!("Project {project_id} is at its spending limit"));
+    }
+
+    if let Some(allowed) = project.allowed_executables {
+        if !allowed.contains(&applet_id) {
+            problems.push(format!(
+                "Project {project_id} does not allow running {applet_id}"
+            ));
+        }
+    }
+
+    let network = applet.access.map(|a| a.network).unwrap_or_default();
+    if project.restricted == Some(true) && !network.is_empty() {
+        problems.push(format!(
+            "Applet requests network access ({}) \
+            but project {project_id} is restricted",
+            network.join(", ")
+        ));
+    }
+
+    if problems.is_empty() {
+        println!("No problems found for {applet_id}");
+        Ok(())
+    } else {
+        for problem in &problems {
+            println!("  - {problem}");
+        }
+        bail!(
+            "Found {} problem{}",
+            problems.len(),
+            if problems.len() == 1 { "" } else { "s" }
+        )
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn check_inputs(
+    spec: &[AppInputSpec],
+    inputs: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<String> {
+    let mut problems = vec![];
+    for input in spec {
+        match inputs.get(&input.name) {
+            Some(val) => {
+                if !value_matches_class(&input.class, val) {
+                    problems.push(format!(
+                        r#"Input "{}" should be {}"#,
+                        input.name, input.class
+                    ));
+                }
+            }
+            _ => {
+                if !input.optional.unwrap_or(false) && input.default.is_none() {
+                    problems.push(format!(
+                        r#"Missing required input "{}" ({})"#,
+                        input.name, input.class
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut unknown: Vec<_> = inputs
+        .keys()
+        .filter(|name| !spec.iter().any(|input| &&input.name == name))
+        .collect();
+    unknown.sort();
+    for name in unknown {
+        problems.push(format!(r#"Unknown input "{name}""#));
+    }
+
+    problems
+}
+
+// --------------------------------------------------
+// IDs in "$dnanexus_link" values, which may be nested in arrays
+pub(crate) fn link_ids(val: &serde_json::Value) -> Vec<String> {
+    match val {
+        serde_json::Value::Array(vals) => {
+            vals.iter().flat_map(link_ids).collect()
+        }
+        serde_json::Value::Object(map) => match map.get("$dnanexus_link") {
+            Some(serde_json::Value::String(id)) => vec![id.clone()],
+            Some(link) => link["id"]
+                .as_str()
+                .map_or(vec![], |id| vec![id.to_string()]),
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+// --------------------------------------------------
+fn check_input_file(dx_env: &DxEnvironment, file_id: &str) -> Option<String> {
+    let options = FileDescribeOptions {
+        project: None,
+        fields: Some(
+            FileDescribeField::iter()
+                .map(|e| (e, true))
+                .collect::<HashMap<_, _>>(),
+        ),
+        details: false,
+        properties: false,
+    };
+
+    match api::describe_file(dx_env, file_id, &options) {
+        Err(e) => Some(format!("File {file_id}: {e}")),
+        Ok(file) => {
+            let state = file.state.unwrap_or_default();
+            if state != "closed" {
+                Some(format!("File {file_id} is {state}, not closed"))
+            } else {
+                match file.archival_state {
+                    Some(ArchivalState::Live) | None => None,
+                    Some(other) => {
+                        Some(format!("File {file_id} is {other}, not live"))
+                    }
+                }
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn check_instance_type(
+    region: &str,
+    instance_type: &str,
+) -> Option<String> {
+    if !VALID_INSTANCE_TYPE.contains(&instance_type) {
+        return Some(format!(r#"Invalid instance type "{instance_type}""#));
+    }
+
+    // Azure instance types carry an "azure:" prefix
+    let is_azure_region = region.starts_with("azure:");
+    let is_azure_type = instance_type.starts_with("azure:");
+    (is_azure_region != is_azure_type).then(|| {
+        format!(
+            r#"Instance type "{instance_type}" is not available in "{region}""#
+        )
+    })
+}
+
+// --------------------------------------------------
+pub(crate) fn parse_instance_types(
+    instance_types: &[String],
+) -> Result<HashMap<String, SystemRequirements>> {
+    let mut requirements = HashMap::new();
+
+    for val in instance_types {
+        let (entry_point, instance_type) =
+            val.split_once('=').unwrap_or(("*", val));
+
+        if entry_point.is_empty() {
+            bail!(r#"Missing entry point in "{val}""#);
+        }
+
+        if !VALID_INSTANCE_TYPE.contains(&instance_type) {
+            bail!(r#"Invalid instance type "{instance_type}""#);
+        }
+
+        requirements.insert(
+            entry_point.to_string(),
+            SystemRequirements {
+                instance_type: instance_type.to_string(),
+                cluster_spec: None,
+            },
+        );
+    }
+
+    Ok(requirements)
+}
@@ -0,0 +1,170 @@
+use crate::dxenv::DxEnvironment;
+use crate::exit::CliError;
+use crate::{
+    dxenv, entity_id, make_table, output_format, print_records, print_table,
+    tsv_value, OutputFormat,
+};
+use anyhow::{bail, Result};
+use clap::{builder::PossibleValue, Parser, ValueEnum};
+
+#[derive(Clone, Parser, Debug)]
+pub struct ProfileArgs {
+    /// Action
+    #[arg(value_name = "ACTION", default_value = "list")]
+    action: ProfileAction,
+
+    /// Profile name
+    #[arg()]
+    name: Option<String>,
+
+    /// API server host for a new profile
+    #[arg(long, default_value = "api.dnanexus.com")]
+    host: String,
+
+    /// API server port for a new profile
+    #[arg(long, default_value = "443")]
+    port: u32,
+
+    /// API server protocol for a new profile
+    #[arg(long, default_value = "https")]
+    protocol: String,
+
+    /// API token for a new profile, otherwise use "login"
+    #[arg(short, long)]
+    token: Option<String>,
+
+    /// Default project ID for a new profile
+    #[arg(short, long)]
+    project: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProfileAction {
+    List,
+    Add,
+    Switch,
+    Delete,
+}
+
+impl ValueEnum for ProfileAction {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            ProfileAction::List,
+            ProfileAction::Add,
+            ProfileAction::Switch,
+            ProfileAction::Delete,
+        ]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            ProfileAction::List => PossibleValue::new("list"),
+            ProfileAction::Add => PossibleValue::new("add"),
+            ProfileAction::Switch => PossibleValue::new("switch"),
+            ProfileAction::Delete => PossibleValue::new("delete"),
+        })
+    }
+}
+
+// --------------------------------------------------
+pub fn profile(args: ProfileArgs) -> Result<()> {
+    let mut profiles = dxenv::get_profiles()?;
+    let name = || match &args.name {
+        Some(name) => Ok(name.clone()),
+        _ => bail!("Please provide a profile name"),
+    };
+
+    match args.action {
+        ProfileAction::List => {
+            let current = dxenv::active_profile(&profiles);
+            let records: Vec<_> = profiles
+                .profiles
+                .iter()
+                .map(|(name, dx_env)| {
+                    serde_json::json!({
+                        "current": current.as_ref() == Some(name),
+                        "name": name,
+                        "host": dx_env.apiserver_host,
+                        "username": dx_env.username,
+                        "project": dx_env.project_context_name,
+                    })
+                })
+                .collect();
+
+            if output_format() != OutputFormat::Table {
+                let fields = ["current", "name", "host", "username", "project"];
+                return print_records(&records, &fields);
+            }
+
+            if records.is_empty() {
+                println!(r#"No profiles, use "profile add NAME""#);
+            } else {
+                let rows = records
+                    .iter()
+                    .map(|profile| {
+                        vec![
+                            if profile["current"] == true { "*" } else { "" }
+                                .to_string(),
+                            tsv_value(&profile["name"]),
+                            tsv_value(&profile["host"]),
+                            tsv_value(&profile["username"]),
+                            tsv_value(&profile["project"]),
+                        ]
+                    })
+                    .collect();
+                print_table(
+                    &make_table("{:<} {:<} {:<} {:<} {:<}", rows, 4, false),
+                    false,
+                );
+            }
+        }
+        ProfileAction::Add => {
+            let name = name()?;
+            if profiles.profiles.contains_key(&name) {
+                bail!(r#"Profile "{name}" already exists"#)
+            }
+
+            let project_id = args.project.clone().unwrap_or_default();
+            if !project_id.is_empty()
+                && !entity_id::PROJECT_ID.is_match(&project_id)
+            {
+                bail!(CliError::Usage(format!(
+                    r#""{project_id}" is not a project ID"#
+                )))
+            }
+
+            let dx_env = DxEnvironment {
+                apiserver_protocol: args.protocol.clone(),
+                apiserver_host: args.host.clone(),
+                project_context_id: project_id.clone(),
+                project_context_name: project_id,
+                apiserver_port: args.port,
+                auth_token: args.token.clone().unwrap_or_default(),
+                ..Default::default()
+            };
+            dxenv::add_profile(&name, dx_env)?;
+            println!(r#"Added profile "{name}""#);
+            if args.token.is_none() {
+                println!(r#"Run "dxrs --profile {name} login" to log in"#);
+            }
+        }
+        ProfileAction::Switch => {
+            let name = name()?;
+            if !profiles.profiles.contains_key(&name) {
+                bail!(CliError::NotFound(format!(
+                    r#"Unknown profile "{name}""#
+                )))
+            }
+            profiles.current = Some(name.clone());
+            dxenv::save_profiles(&profiles)?;
+            println!(r#"Switched to profile "{name}""#);
+        }
+        ProfileAction::Delete => {
+            let name = name()?;
+            dxenv::delete_profile(&name)?;
+            println!(r#"Deleted profile "{name}""#);
+        }
+    }
+
+    Ok(())
+}
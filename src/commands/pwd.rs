@@ -0,0 +1,16 @@
+use crate::dxenv::get_dx_env;
+use anyhow::Result;
+
+// --------------------------------------------------
+pub fn pwd() -> Result<()> {
+    let dx_env = get_dx_env()?;
+
+    println!(
+        "{} {}:{}",
+        &dx_env.project_context_name,
+        &dx_env.project_context_id,
+        &dx_env.cli_wd
+    );
+
+    Ok(())
+}
@@ -0,0 +1,295 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::entity_id::EntityId;
+use crate::exit::CliError;
+use crate::{
+    api, csv_quote, describe_object, make_table, new_spinner,
+    parse_instance_types, resolve_file_id, resolve_named_objects, shell_quote,
+    write_formatted, Description, DownloadOptions, JobDescribeField,
+    JobDescribeOptions, ObjectType, ProgressFormat, RunOptions,
+    TERMINAL_JOB_STATES,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::{builder::PossibleValue, Parser, ValueEnum};
+use inquire::Confirm;
+use std::{
+    collections::HashMap,
+    io::{self, Read},
+    thread,
+    time::Duration,
+};
+
+/// The platform has no SQL route for databases, so "query" runs Spark
+/// SQL in a job of this app
+const QUERY_APP: &str = "app-dxjupyterlab_spark_cluster";
+
+/// Where query jobs leave their results, one TSV per job
+const QUERY_RESULTS_FOLDER: &str = "/.query";
+
+/// Seconds between checks on a query job
+const QUERY_POLL_SECS: u64 = 10;
+
+#[derive(Clone, Parser, Debug)]
+pub struct QueryArgs {
+    /// Database path or ID
+    #[arg(required_unless_present = "results")]
+    database: Option<String>,
+
+    /// SQL to run, or "-" for STDIN
+    #[arg(required_unless_present = "results")]
+    sql: Option<String>,
+
+    /// Page through the results file of an earlier query
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["database", "sql"])]
+    results: Option<String>,
+
+    /// Rows per page
+    #[arg(short, long, value_name = "NUM", default_value = "100")]
+    limit: usize,
+
+    /// Page of results to show, starting at 1
+    #[arg(short, long, value_name = "NUM", default_value = "1")]
+    page: usize,
+
+    /// Output format
+    #[arg(short, long, value_name = "FORMAT", default_value = "table")]
+    format: QueryFormat,
+
+    /// Output file, "-" for STDOUT
+    #[arg(short, long, value_name = "FILE", default_value = "-")]
+    output: String,
+
+    /// Instance type for the Spark cluster job
+    #[arg(long, value_name = "TYPE")]
+    instance_type: Option<String>,
+
+    /// Start the job without asking
+    #[arg(short, long, default_value = "false")]
+    yes: bool,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QueryFormat {
+    Table,
+    Csv,
+}
+
+impl ValueEnum for QueryFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[QueryFormat::Table, QueryFormat::Csv]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            QueryFormat::Table => PossibleValue::new("table"),
+            QueryFormat::Csv => PossibleValue::new("csv"),
+        })
+    }
+}
+
+// --------------------------------------------------
+pub fn query(args: QueryArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let file_id = match &args.results {
+        Some(path) => resolve_file_id(&dx_env, path)?,
+        _ => run_query(&dx_env, &args)?,
+    };
+
+    let download =
+        api::download(&dx_env, &file_id, &DownloadOptions::default())?;
+    let mut bytes = vec![];
+    api::download_file(&download, &mut bytes, &file_id, ProgressFormat::None_)?;
+    let (header, rows) = parse_query_results(&String::from_utf8_lossy(&bytes));
+    if header.is_empty() {
+        bail!("Query returned no results");
+    }
+
+    let (start, end) = query_page(rows.len(), args.limit, args.page);
+    let page = &rows[start..end];
+    let text = match args.format {
+        QueryFormat::Csv => std::iter::once(&header)
+            .chain(page)
+            .map(|row| {
+                row.iter()
+                    .map(|val| csv_quote(val))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        QueryFormat::Table => {
+            let fmt = vec!["{:<}"; header.len()].join("  ");
+            let table_rows = std::iter::once(header.clone())
+                .chain(page.to_vec())
+                .collect();
+            make_table(&fmt, table_rows, header.len() - 1, args.wide)
+                .to_string()
+                .trim_end()
+                .to_string()
+        }
+    };
+    write_formatted(&text, &args.output)?;
+
+    note!(
+        "Rows {}-{end} of {} from {file_id}",
+        (start + 1).min(end),
+        rows.len()
+    );
+    if end < rows.len() {
+        note!(
+            "Use \"--results {file_id} --page {}\" for more",
+            args.page.max(1) + 1
+        );
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// Runs the SQL in a job that uploads the results to the database's
+// project and returns the ID of the results file
+fn run_query(dx_env: &DxEnvironment, args: &QueryArgs) -> Result<String> {
+    let database = args.database.clone().unwrap_or_default();
+    let sql = match args.sql.as_deref() {
+        Some("-") => {
+            let mut sql = String::new();
+            io::stdin().read_to_string(&mut sql)?;
+            sql
+        }
+        Some(sql) => sql.to_string(),
+        _ => bail!("Missing SQL"),
+    };
+
+    let database_id = match EntityId::parse(&database) {
+        Some(_) => database.clone(),
+        _ => resolve_named_objects(
+            dx_env,
+            &database,
+            &[ObjectType::Database],
+            false,
+            false,
+        )?
+        .remove(0),
+    };
+    let db = match describe_object(dx_env, &database_id, None)? {
+        Description::Database(db) => db,
+        _ => bail!(CliError::Usage(format!(
+            r#""{database}" is not a database"#
+        ))),
+    };
+    let db_name = db
+        .unique_database_name
+        .ok_or(anyhow!(r#"Database "{database}" has no name to query"#))?;
+    let project_id = db.project.unwrap_or(dx_env.project_context_id.clone());
+
+    if !args.yes {
+        let consent = Confirm::new(&format!(
+            "Start a {QUERY_APP} job in {project_id} to run the query?"
+        ))
+        .with_default(true)
+        .prompt()?;
+        if !consent {
+            bail!("Query cancelled");
+        }
+    }
+
+    let options = RunOptions {
+        input: serde_json::json!({
+            "cmd": query_command(&db_name, &sql, &project_id),
+        }),
+        project: Some(project_id.clone()),
+        folder: None,
+        name: Some(format!("Query of {database}")),
+        tags: vec![],
+        system_requirements: parse_instance_types(
+            args.instance_type.as_slice(),
+        )?,
+        allow_ssh: vec![],
+        stage_system_requirements: HashMap::new(),
+    };
+    let job = api::run(dx_env, QUERY_APP, &options)?;
+    note!("Started query job {}", job.id);
+
+    let spinner = new_spinner();
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    let desc_opts = JobDescribeOptions {
+        default_fields: None,
+        fields: Some(HashMap::from([
+            (JobDescribeField::State, true),
+            (JobDescribeField::FailureReason, true),
+            (JobDescribeField::FailureMessage, true),
+        ])),
+        try_number: None,
+    };
+    let desc = loop {
+        let desc = api::describe_job(dx_env, &job.id, &desc_opts)?;
+        let state = desc.state.clone().unwrap_or("unknown".to_string());
+        if TERMINAL_JOB_STATES.contains(&state.as_str()) {
+            break desc;
+        }
+        spinner.set_message(format!("Waiting for {} ({state})", job.id));
+        thread::sleep(Duration::from_secs(QUERY_POLL_SECS));
+    };
+    spinner.finish_and_clear();
+
+    if desc.state.as_deref() != Some("done") {
+        bail!(
+            "Query job {} {}: {}",
+            job.id,
+            desc.state.unwrap_or_default(),
+            desc.failure_message.unwrap_or_default()
+        );
+    }
+
+    resolve_file_id(
+        dx_env,
+        &format!("{project_id}:{QUERY_RESULTS_FOLDER}/{}.tsv", job.id),
+    )
+}
+
+// --------------------------------------------------
+// Runs in the job, where Spark knows the database by its unique name,
+// and names the results after the job
+pub(crate) fn query_command(
+    db_name: &str,
+    sql: &str,
+    project_id: &str,
+) -> String {
+    format!(
+        "spark-sql --silent --hiveconf hive.cli.print.header=true \
+        --database {} -e {} > query.tsv && \
+        dx upload query.tsv --parents --brief \
+        --path \"{project_id}:{QUERY_RESULTS_FOLDER}/$DX_JOB_ID.tsv\"",
+        shell_quote(db_name),
+        shell_quote(sql)
+    )
+}
+
+// --------------------------------------------------
+// Spark writes a header and then tab-separated rows
+pub(crate) fn parse_query_results(
+    text: &str,
+) -> (Vec<String>, Vec<Vec<String>>) {
+    let mut lines = text.lines().filter(|line| !line.is_empty());
+    let split = |line: &str| -> Vec<String> {
+        line.split('\t').map(str::to_string).collect()
+    };
+    let header = lines.next().map(split).unwrap_or_default();
+    let rows = lines.map(split).collect();
+    (header, rows)
+}
+
+// --------------------------------------------------
+// The start and end of a page of rows, where pages start at 1
+pub(crate) fn query_page(
+    num_rows: usize,
+    limit: usize,
+    page: usize,
+) -> (usize, usize) {
+    let start = (page.max(1) - 1).saturating_mul(limit).min(num_rows);
+    let end = start.saturating_add(limit).min(num_rows);
+    (start, end)
+}
@@ -0,0 +1,204 @@
+use crate::dxenv::get_dx_env;
+use crate::entity_id::EntityId;
+use crate::json_parser::SystemRequirements;
+use crate::{
+    api, entity_id, format_watch_message, normalize_remote_path,
+    parse_instance_types, parse_run_inputs, resolve_local_inputs,
+    AnalysisDescribeField, AnalysisDescribeOptions, JobDescribeField,
+    JobDescribeOptions, KitchenSink, RunOptions, WatchOptions,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use log::debug;
+use std::collections::HashMap;
+
+#[derive(Clone, Parser, Debug)]
+pub struct RerunArgs {
+    /// Job or analysis ID
+    #[arg()]
+    execution_id: String,
+
+    /// Override an input, e.g., "reads=file-xxxx"
+    #[arg(short, long, value_name = "NAME=VALUE")]
+    input: Vec<String>,
+
+    /// Instance type for all entry points or "ENTRYPOINT=TYPE"
+    #[arg(long, value_name = "[ENTRYPOINT=]TYPE")]
+    instance_type: Vec<String>,
+
+    /// Output folder, defaults to that of the original execution
+    #[arg(long)]
+    folder: Option<String>,
+
+    /// Name for the new execution
+    #[arg(long)]
+    name: Option<String>,
+
+    /// Watch the job after launching
+    #[arg(long, default_value = "false")]
+    watch: bool,
+
+    /// Print only the new execution ID
+    #[arg(long, default_value = "false")]
+    brief: bool,
+}
+
+// --------------------------------------------------
+pub fn rerun(args: RerunArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let (executable_id, run_input, mut options) =
+        match EntityId::parse(&args.execution_id) {
+            Some(EntityId::Job { job_id }) => {
+                let desc_opts = JobDescribeOptions {
+                    default_fields: None,
+                    fields: Some(HashMap::from([
+                        (JobDescribeField::Applet, true),
+                        (JobDescribeField::App, true),
+                        (JobDescribeField::Function, true),
+                        (JobDescribeField::RunInput, true),
+                        (JobDescribeField::Project, true),
+                        (JobDescribeField::Folder, true),
+                        (JobDescribeField::Name, true),
+                        (JobDescribeField::Tags, true),
+                        (JobDescribeField::SystemRequirements, true),
+                    ])),
+                    try_number: None,
+                };
+                let job = api::describe_job(&dx_env, &job_id, &desc_opts)?;
+
+                // Subjobs run an entry point that can't be started directly
+                if job.function.as_ref().is_some_and(|f| f != "main") {
+                    bail!("{job_id} is a subjob, rerun its parent instead")
+                }
+
+                let executable_id = job.applet.or(job.app).ok_or(anyhow!(
+                    "Cannot find the executable for {job_id}"
+                ))?;
+
+                // Keep any instance types the original job was run with
+                let system_requirements = job
+                    .system_requirements
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|(entry_point, req)| {
+                        req.instance_type.map(|instance_type| {
+                            (
+                                entry_point,
+                                SystemRequirements {
+                                    instance_type,
+                                    cluster_spec: None,
+                                },
+                            )
+                        })
+                    })
+                    .collect();
+
+                let options = RunOptions {
+                    input: serde_json::Value::Null,
+                    project: job.project,
+                    folder: job.folder,
+                    name: job.name,
+                    tags: job.tags.unwrap_or_default(),
+                    system_requirements,
+                    allow_ssh: vec![],
+                    stage_system_requirements: HashMap::new(),
+                };
+                (executable_id, job.run_input, options)
+            }
+            Some(EntityId::Analysis { analysis_id }) => {
+                let desc_opts = AnalysisDescribeOptions {
+                    fields: HashMap::from([
+                        (AnalysisDescribeField::Executable, true),
+                        (AnalysisDescribeField::RunInput, true),
+                        (AnalysisDescribeField::Project, true),
+                        (AnalysisDescribeField::Folder, true),
+                        (AnalysisDescribeField::Name, true),
+                        (AnalysisDescribeField::Tags, true),
+                    ]),
+                };
+                let analysis =
+                    api::describe_analysis(&dx_env, &analysis_id, &desc_opts)?;
+                let executable_id = analysis.executable.ok_or(anyhow!(
+                    "Cannot find the workflow for {analysis_id}"
+                ))?;
+
+                let options = RunOptions {
+                    input: serde_json::Value::Null,
+                    project: analysis.project,
+                    folder: analysis.folder,
+                    name: analysis.name,
+                    tags: analysis.tags.unwrap_or_default(),
+                    system_requirements: HashMap::new(),
+                    allow_ssh: vec![],
+                    stage_system_requirements: HashMap::new(),
+                };
+                (executable_id, analysis.run_input, options)
+            }
+            _ => {
+                bail!(r#""{}" is not a job or analysis ID"#, args.execution_id)
+            }
+        };
+
+    let project_id = options
+        .project
+        .clone()
+        .unwrap_or(dx_env.project_context_id.clone());
+    let overrides = resolve_local_inputs(
+        &dx_env,
+        &project_id,
+        &args.input,
+        &mut HashMap::new(),
+    )?;
+    options.input =
+        serde_json::Value::Object(rerun_input(run_input, &overrides)?);
+    options
+        .system_requirements
+        .extend(parse_instance_types(&args.instance_type)?);
+    if let Some(folder) = &args.folder {
+        options.folder = Some(normalize_remote_path(&dx_env.cli_wd, folder));
+    }
+    if args.name.is_some() {
+        options.name = args.name.clone();
+    }
+    debug!("{}", serde_json::to_string_pretty(&options)?);
+
+    let execution = api::run(&dx_env, &executable_id, &options)?;
+    if args.brief {
+        println!("{}", execution.id);
+    } else {
+        println!("Started {} from {}", execution.id, args.execution_id);
+    }
+
+    if args.watch {
+        if !entity_id::JOB_ID.is_match(&execution.id) {
+            bail!("Only jobs can be watched, not {}", execution.id)
+        }
+        let watch_opts = WatchOptions {
+            num_recent_messages: None,
+            recurse_jobs: Some(false),
+            tail: Some(true),
+            levels: vec![],
+        };
+        api::watch(&dx_env, &execution.id, &watch_opts, |msg| {
+            println!("{}", format_watch_message(&msg, true, false));
+            Ok(true)
+        })?;
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+pub(crate) fn rerun_input(
+    run_input: Option<HashMap<String, KitchenSink>>,
+    overrides: &[String],
+) -> Result<serde_json::Map<String, serde_json::Value>> {
+    let mut input = match serde_json::to_value(run_input.unwrap_or_default())? {
+        serde_json::Value::Object(map) => map,
+        _ => serde_json::Map::new(),
+    };
+
+    // Overrides replace the original value rather than appending to it
+    input.extend(parse_run_inputs(overrides)?);
+    Ok(input)
+}
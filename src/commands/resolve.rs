@@ -0,0 +1,910 @@
+use crate::dxenv::DxEnvironment;
+#[cfg(feature = "cli")]
+use crate::exit::CliError;
+use crate::{
+    api, entity_id, DxPath, FileOrPath, FindDataOptions, FindDataResult,
+    FindDataScope, FindDescribe, FindName, ObjectType,
+};
+#[cfg(feature = "cli")]
+use crate::{
+    make_table, AppDescribeField, AppDescribeOptions, AppInputSpec,
+    AppletDescribeField, AppletDescribeOptions, FindAppsOptions,
+};
+#[cfg(feature = "cli")]
+use anyhow::bail;
+use anyhow::{anyhow, Result};
+#[cfg(feature = "cli")]
+use inquire::Select;
+use regex::Regex;
+#[cfg(feature = "cli")]
+use size::Size;
+use std::path::Path;
+#[cfg(feature = "cli")]
+use std::{
+    collections::HashMap,
+    io::{self, BufRead},
+};
+#[cfg(feature = "cli")]
+use tabular::{Row, Table};
+
+// --------------------------------------------------
+// An executable ID, optionally with a project, or the name of an applet,
+// workflow or app
+#[cfg(feature = "cli")]
+pub(crate) fn resolve_executable(
+    dx_env: &DxEnvironment,
+    executable: &str,
+) -> Result<(String, String)> {
+    if let Some(caps) = entity_id::EXECUTABLE_ID.captures(executable) {
+        return Ok((
+            caps.get(1).map_or(dx_env.project_context_id.clone(), |v| {
+                v.as_str().to_string()
+            }),
+            caps.get(2).unwrap().as_str().to_string(),
+        ));
+    }
+
+    let ids = resolve_named_objects(
+        dx_env,
+        executable,
+        &[ObjectType::Applet, ObjectType::Workflow],
+        true,
+        false,
+    )?;
+    match ids.first() {
+        Some(id) => resolve_executable(dx_env, id),
+        _ => bail!(CliError::NotFound(format!(
+            r#"No executable named "{executable}""#
+        ))),
+    }
+}
+
+// --------------------------------------------------
+/// Finds the objects of the given classes named by a path like
+/// "/tools/bwa" or "project-xxxx:bwa" in its folder, along with any app
+/// of that name when given a bare name. Several matches go to a prompt
+/// unless "all" is set. Returns IDs with their projects.
+#[cfg(feature = "cli")]
+pub(crate) fn resolve_named_objects(
+    dx_env: &DxEnvironment,
+    name: &str,
+    classes: &[ObjectType],
+    include_apps: bool,
+    all: bool,
+) -> Result<Vec<String>> {
+    let dx_path = resolve_path(dx_env, name)?;
+    let path = Path::new(&dx_path.path);
+    let basename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or(anyhow!(r#""{name}" does not name an object"#))?;
+    let folder = path
+        .parent()
+        .map_or("/".to_string(), |dir| dir.display().to_string());
+
+    // Each match is the class, the path and the ID
+    let mut matches: Vec<[String; 3]> = vec![];
+    for class in classes {
+        let mut options = FindDataOptions {
+            class: Some(class.clone()),
+            state: None,
+            name: Some(FindName::Glob(basename.clone())),
+            visibility: None,
+            id: vec![],
+            object_type: None,
+            tags: vec![],
+            region: vec![],
+            properties: None,
+            link: None,
+            scope: Some(FindDataScope {
+                project: Some(dx_path.project_id.clone()),
+                folder: Some(folder.clone()),
+                recurse: Some(false),
+            }),
+            sort_by: None,
+            level: None,
+            modified: None,
+            created: None,
+            describe: Some(FindDescribe::Mapping(HashMap::from([
+                ("name".to_string(), true),
+                ("folder".to_string(), true),
+            ]))),
+            starting: None,
+            limit: None,
+            archival_state: None,
+        };
+
+        for found in api::find_data(dx_env, &mut options)? {
+            let path = found.describe.and_then(|desc| {
+                desc.name.map(|name| {
+                    Path::new(&desc.folder.unwrap_or(folder.clone()))
+                        .join(name)
+                        .display()
+                        .to_string()
+                })
+            });
+            matches.push([
+                object_class(&found.id),
+                path.unwrap_or(dx_path.path.clone()),
+                format!("{}:{}", found.project, found.id),
+            ]);
+        }
+    }
+
+    if include_apps && !name.contains(['/', ':']) {
+        let mut options = FindAppsOptions {
+            name: Some(FindName::Glob(name.to_string())),
+            category: None,
+            all_versions: None,
+            published: None,
+            bill_to: vec![],
+            created_by: None,
+            developer: None,
+            authorized_user: None,
+            installed: None,
+            starting: None,
+            limit: None,
+            describe: None,
+        };
+        for app in api::find_apps(dx_env, &mut options)? {
+            matches.push(["app".to_string(), name.to_string(), app.id]);
+        }
+    }
+
+    match matches.len() {
+        0 => bail!(CliError::NotFound(format!(
+            r#"Found nothing named "{name}""#
+        ))),
+        1 => Ok(vec![matches.remove(0)[2].clone()]),
+        _ if all => Ok(matches.into_iter().map(|[_, _, id]| id).collect()),
+        num_matches => {
+            let rows = matches.iter().map(|row| row.to_vec()).collect();
+            let table = make_table("{:<} {:<} {:<}", rows, 1, false);
+            let table = table.to_string();
+            let choices: Vec<_> = table.lines().collect();
+            let found =
+                format!(r#"Found {num_matches} objects named "{name}""#);
+            let prompt = format!("{found} (Ctrl-C to exit):");
+            match Select::new(&prompt, choices).raw_prompt() {
+                Ok(chosen) => Ok(vec![matches[chosen.index][2].clone()]),
+                _ => bail!(CliError::Usage(format!(
+                    "{found}, use an ID to choose one"
+                ))),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+// The class of an object is the prefix of its ID, e.g., "applet"
+#[cfg(feature = "cli")]
+pub(crate) fn object_class(id: &str) -> String {
+    id.split_once('-')
+        .map_or(id.to_string(), |(class, _)| class.to_string())
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn executable_input_spec(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    executable_id: &str,
+) -> Result<Vec<AppInputSpec>> {
+    let spec = if executable_id.starts_with("app-") {
+        let options = AppDescribeOptions {
+            fields: HashMap::from([(AppDescribeField::InputSpec, true)]),
+        };
+        api::describe_app(dx_env, executable_id, &options)?.input_spec
+    } else if executable_id.starts_with("applet-") {
+        let options = AppletDescribeOptions {
+            project: Some(project_id.to_string()),
+            fields: Some(HashMap::from([(
+                AppletDescribeField::InputSpec,
+                true,
+            )])),
+        };
+        api::describe_applet(dx_env, executable_id, &options)?.input_spec
+    } else {
+        bail!(r#""{executable_id}" is not an app or applet"#)
+    };
+
+    Ok(spec.unwrap_or_default())
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn resolve_file_id(
+    dx_env: &DxEnvironment,
+    path: &str,
+) -> Result<String> {
+    if entity_id::FILE_ID.is_match(path) {
+        return Ok(path.to_string());
+    }
+
+    let dx_path = resolve_path(dx_env, path)?;
+    let files = find_files_by_path(dx_env, &dx_path.path, &dx_path.project_id)?;
+    match select_file_from_list(&files, false) {
+        Some(file_id) => Ok(file_id),
+        _ => {
+            bail!(CliError::NotFound(format!(r#"No file matches "{path}""#)))
+        }
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn find_files_by_path(
+    dx_env: &DxEnvironment,
+    path: &str,
+    project_id: &str,
+) -> Result<Vec<FindDataResult>> {
+    let (folder, name, folder_glob) = if entity_id::FILE_ID.is_match(path) {
+        ("/".to_string(), path.to_string(), None)
+    } else {
+        split_glob_path(path)
+    };
+
+    let mut options = FindDataOptions {
+        class: Some(ObjectType::File),
+        state: None,
+        name: Some(FindName::Glob(name)),
+        visibility: None,
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(project_id.to_string()),
+            folder: Some(folder),
+            recurse: Some(folder_glob.is_some()),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Boolean(true)),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+
+    let files = api::find_data(dx_env, &mut options)?;
+
+    // The API only takes a literal folder, so directory globs are
+    // searched recursively from the literal prefix and then filtered
+    match folder_glob {
+        Some(glob) => {
+            let re = glob_to_regex(&glob)?;
+            Ok(files
+                .into_iter()
+                .filter(|file| {
+                    file.describe
+                        .as_ref()
+                        .and_then(|desc| desc.folder.as_ref())
+                        .is_some_and(|dir| re.is_match(dir))
+                })
+                .collect())
+        }
+        _ => Ok(files),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn is_glob(path: &str) -> bool {
+    path.contains(['*', '?', '['])
+}
+
+// --------------------------------------------------
+// Returns the literal folder to search, the basename pattern,
+// and the folder pattern when the parent directories are globbed
+pub(crate) fn split_glob_path(path: &str) -> (String, String, Option<String>) {
+    let p = Path::new(&path);
+    let parent = p.parent().map_or("/".to_string(), |dirname| {
+        dirname.to_string_lossy().to_string()
+    });
+    let basename = p
+        .file_name()
+        .map_or(path.to_string(), |name| name.to_string_lossy().to_string());
+
+    if is_glob(&parent) {
+        let literal: Vec<_> = parent
+            .split('/')
+            .take_while(|component| !is_glob(component))
+            .collect();
+        let folder = match literal.join("/").as_str() {
+            "" => "/".to_string(),
+            dir => dir.to_string(),
+        };
+        (folder, basename, Some(parent))
+    } else {
+        (parent, basename, None)
+    }
+}
+
+// --------------------------------------------------
+// Translates a shell-style glob into an anchored regex where
+// wildcards do not cross "/"
+pub(crate) fn glob_to_regex(glob: &str) -> Result<Regex> {
+    let mut pattern = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            '[' => {
+                pattern.push('[');
+                if chars.next_if_eq(&'!').is_some() {
+                    pattern.push('^');
+                }
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    if c == '\\' {
+                        pattern.push('\\');
+                    }
+                    pattern.push(c);
+                }
+                pattern.push(']');
+            }
+            _ => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+
+    Regex::new(&pattern).map_err(|e| anyhow!(r#"Bad glob "{glob}": {e}"#))
+}
+
+// --------------------------------------------------
+/// Replace any "-" argument (or append, with --stdin) with the
+/// newline-separated values on STDIN
+#[cfg(feature = "cli")]
+pub(crate) fn read_stdin_args(
+    args: &[String],
+    stdin: bool,
+) -> Result<Vec<String>> {
+    if stdin || args.iter().any(|arg| arg == "-") {
+        expand_stdin_args(args, stdin, io::stdin().lock())
+    } else {
+        Ok(args.to_vec())
+    }
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn expand_stdin_args(
+    args: &[String],
+    stdin: bool,
+    reader: impl BufRead,
+) -> Result<Vec<String>> {
+    let mut lines = vec![];
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+
+    let mut expanded = vec![];
+    for arg in args {
+        if arg == "-" {
+            expanded.append(&mut lines);
+        } else {
+            expanded.push(arg.clone());
+        }
+    }
+
+    if stdin {
+        expanded.append(&mut lines);
+    }
+    Ok(expanded)
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn select_file_from_list(
+    files: &[FindDataResult],
+    allow_all: bool,
+) -> Option<String> {
+    if files.len() > 1 {
+        let fmt = "{:<} {:<} {:>} {:<} {:<}";
+        let mut table = Table::new(fmt);
+        for desc in files.iter().filter_map(|f| f.describe.clone()) {
+            let modified = desc.modified.map_or("NA".to_string(), |ts| {
+                ts.format("%Y-%m-%d %H:%M:%S").to_string()
+            });
+
+            table.add_row(
+                Row::new()
+                    .with_cell(
+                        desc.archival_state
+                            .clone()
+                            .map_or("".to_string(), |s| s.to_string()),
+                    )
+                    .with_cell(modified)
+                    .with_cell(desc.size.map_or("NA".to_string(), |s| {
+                        Size::from_bytes(s).to_string()
+                    }))
+                    .with_cell(desc.name.clone().unwrap_or("".to_string()))
+                    .with_cell(desc.id.clone()),
+            );
+        }
+
+        if allow_all {
+            table.add_row(
+                Row::new()
+                    .with_cell("all".to_string())
+                    .with_cell("".to_string())
+                    .with_cell("".to_string())
+                    .with_cell("".to_string())
+                    .with_cell("".to_string()),
+            );
+        }
+
+        let message = "Please select a file (Ctrl-C to exit):";
+        let table = table.to_string();
+        let choices: Vec<_> = table.lines().collect();
+
+        Select::new(message, choices).prompt().ok().map(|chosen| {
+            if chosen.trim() == "all" {
+                "all".to_string()
+            } else {
+                entity_id::TRAILING_FILE_ID
+                    .captures(chosen)
+                    .map(|cap| cap.get(1).unwrap().as_str().to_string())
+                    .unwrap()
+            }
+        })
+    } else {
+        files.first().map(|file| file.id.clone())
+    }
+}
+
+// --------------------------------------------------
+#[test]
+fn test_normalize_remote_path() {
+    // Absolute paths ignore the working folder
+    assert_eq!(normalize_remote_path("/foo", "/"), "/");
+    assert_eq!(normalize_remote_path("/foo", "/bar"), "/bar");
+
+    // Relative paths are joined to the working folder
+    assert_eq!(normalize_remote_path("/", ""), "/");
+    assert_eq!(normalize_remote_path("/foo", ""), "/foo");
+    assert_eq!(normalize_remote_path("/foo", "bar"), "/foo/bar");
+    assert_eq!(normalize_remote_path("/foo/", "bar"), "/foo/bar");
+
+    // Trailing and repeated slashes are dropped
+    assert_eq!(normalize_remote_path("/", "foo/"), "/foo");
+    assert_eq!(normalize_remote_path("/", "//foo//bar///"), "/foo/bar");
+
+    // "." is the current folder
+    assert_eq!(normalize_remote_path("/foo", "."), "/foo");
+    assert_eq!(normalize_remote_path("/foo", "./bar"), "/foo/bar");
+    assert_eq!(normalize_remote_path("/foo", "bar/./baz/."), "/foo/bar/baz");
+
+    // ".." is the parent folder
+    assert_eq!(normalize_remote_path("/foo/bar", ".."), "/foo");
+    assert_eq!(normalize_remote_path("/foo/bar", "../.."), "/");
+    assert_eq!(normalize_remote_path("/foo/bar", "../baz/"), "/foo/baz");
+    assert_eq!(normalize_remote_path("/foo", "./sub/.."), "/foo");
+    assert_eq!(
+        normalize_remote_path("/", "/foo/../bar/x.txt"),
+        "/bar/x.txt"
+    );
+
+    // Nothing goes above the root
+    assert_eq!(normalize_remote_path("/", ".."), "/");
+    assert_eq!(normalize_remote_path("/foo", "../../../bar"), "/bar");
+
+    // Names merely starting with dots are kept
+    assert_eq!(normalize_remote_path("/", "..."), "/...");
+    assert_eq!(normalize_remote_path("/", ".hidden/..x"), "/.hidden/..x");
+}
+
+// --------------------------------------------------
+#[test]
+fn test_resolve_path() {
+    let project_id1 = "project-GbxZVz8071x9yvpXgxV4gVjK".to_string();
+    let project_id2 = "project-Gbxgky00Z4Y2kf4K204x8V26".to_string();
+
+    let dx_env1 = DxEnvironment {
+        apiserver_protocol: "".to_string(),
+        username: "".to_string(),
+        cli_wd: "/".to_string(),
+        apiserver_host: "".to_string(),
+        project_context_id: project_id1.clone(),
+        project_context_name: "test".to_string(),
+        apiserver_port: 20,
+        auth_token_type: "".to_string(),
+        auth_token: "".to_string(),
+        plaintext_store: false,
+        recent_projects: vec![],
+        previous_wd: None,
+        auth_token_expires: None,
+    };
+
+    // Use env project_id by default
+    let res = resolve_path(&dx_env1, "/");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/".to_string(),
+            project_id: project_id1.clone(),
+        }
+    );
+
+    // Use env project_id by default, folder is "/"
+    let res = resolve_path(&dx_env1, "");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/".to_string(),
+            project_id: project_id1.clone(),
+        }
+    );
+
+    // Use env project_id by default, folder is "/"
+    let res = resolve_path(&dx_env1, ":");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/".to_string(),
+            project_id: project_id1.clone(),
+        }
+    );
+
+    // Handle file ID, project_id from env
+    let res = resolve_path(&dx_env1, "file-Gbxj0k006jzv14J9J4Yp4vgG");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "file-Gbxj0k006jzv14J9J4Yp4vgG".to_string(),
+            project_id: project_id1.clone(),
+        }
+    );
+
+    // Handle project_id:file_id
+    let res = resolve_path(
+        &dx_env1,
+        &format!("{project_id2}:file-Gbxj0k006jzv14J9J4Yp4vgG"),
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "file-Gbxj0k006jzv14J9J4Yp4vgG".to_string(),
+            project_id: project_id2.clone(),
+        }
+    );
+
+    // Use env project_id by default, handle leading ":"
+    let res = resolve_path(&dx_env1, ":/foo");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/foo".to_string(),
+            project_id: project_id1.clone(),
+        }
+    );
+
+    // Use explicit project id, folder
+    let res = resolve_path(&dx_env1, &format!("{project_id2}:/foo"));
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/foo".to_string(),
+            project_id: project_id2.clone(),
+        }
+    );
+
+    // Use explicit project id, default folder is "/"
+    let res = resolve_path(&dx_env1, &project_id2);
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/".to_string(),
+            project_id: project_id2.clone(),
+        }
+    );
+
+    // Use explicit project id, default folder is "/"
+    let res = resolve_path(&dx_env1, &format!("{project_id2}:"));
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/".to_string(),
+            project_id: project_id2.clone(),
+        }
+    );
+
+    let dx_env2 = DxEnvironment {
+        apiserver_protocol: "".to_string(),
+        username: "".to_string(),
+        cli_wd: "/foo/bar".to_string(),
+        apiserver_host: "".to_string(),
+        project_context_id: project_id2.clone(),
+        project_context_name: "test".to_string(),
+        apiserver_port: 20,
+        auth_token_type: "".to_string(),
+        auth_token: "".to_string(),
+        plaintext_store: false,
+        recent_projects: vec![],
+        previous_wd: None,
+        auth_token_expires: None,
+    };
+
+    // Use env project_id/working_dir
+    let res = resolve_path(&dx_env2, "");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/foo/bar".to_string(),
+            project_id: project_id2.clone(),
+        }
+    );
+
+    // Use env working dir and append to path
+    let res = resolve_path(&dx_env2, "baz.txt");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/foo/bar/baz.txt".to_string(),
+            project_id: project_id2.clone(),
+        }
+    );
+
+    let res = resolve_path(&dx_env2, &format!("{project_id2}:baz.txt"));
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/foo/bar/baz.txt".to_string(),
+            project_id: project_id2.clone(),
+        }
+    );
+
+    let res = resolve_path(&dx_env2, &format!("{project_id1}:/baz.txt"));
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/baz.txt".to_string(),
+            project_id: project_id1.clone(),
+        }
+    );
+
+    // Relative to the working folder's parent
+    let res = resolve_path(&dx_env2, &format!("{project_id1}:../baz/"));
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/foo/baz".to_string(),
+            project_id: project_id1.clone(),
+        }
+    );
+
+    let res = resolve_path(&dx_env2, "./sub/..");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "/foo/bar".to_string(),
+            project_id: project_id2.clone(),
+        }
+    );
+
+    // Use env project id
+    let res = resolve_path(&dx_env2, "file-Gbxj0k006jzv14J9J4Yp4vgG");
+    assert!(res.is_ok());
+    assert_eq!(
+        res.unwrap(),
+        DxPath {
+            path: "file-Gbxj0k006jzv14J9J4Yp4vgG".to_string(),
+            project_id: project_id2.clone(),
+        }
+    );
+}
+
+// --------------------------------------------------
+pub fn resolve_path(dx_env: &DxEnvironment, path: &str) -> Result<DxPath> {
+    // Separate any project_id from path, use env if none
+
+    let mut path = path.to_string();
+
+    // Default project_id comes from env
+    let mut project_id = dx_env.project_context_id.to_string();
+
+    // The incoming path may start with project_id
+    // If so, use that and remove it from the path
+    if let Some(caps) = entity_id::PROJECT_PREFIX.captures(&path) {
+        project_id = caps.get(1).unwrap().as_str().to_string();
+        path = caps
+            .get(3)
+            .map_or("".to_string(), |m| m.as_str().to_string());
+    }
+
+    // Remove leading ":"
+    // This should match even the empty string, in which case
+    // default path comes from env
+    if let Some(caps) = entity_id::LEADING_COLON.captures(&path) {
+        path = caps
+            .get(1)
+            .map_or(dx_env.cli_wd.to_string(), |m| m.as_str().to_string());
+    }
+
+    // If remaining path does not look like a file ID, see if it's relative
+    if !entity_id::FILE_ID.is_match(&path) {
+        // Treat the identifier as a path like "/foo/bar.txt"
+        path = normalize_remote_path(&dx_env.cli_wd, &path);
+    }
+
+    Ok(DxPath {
+        path: path.to_string(),
+        project_id: project_id.to_string(),
+    })
+}
+
+// --------------------------------------------------
+/// Joins a relative path to the working folder and collapses any ".",
+/// "..", and repeated or trailing slashes like a shell would, stopping
+/// at "/"
+pub fn normalize_remote_path(cwd: &str, path: &str) -> String {
+    let mut parts: Vec<&str> = vec![];
+    let full_path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{cwd}/{path}")
+    };
+
+    for part in full_path.split('/') {
+        match part {
+            "" | "." => (),
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+
+    format!("/{}", parts.join("/"))
+}
+
+// --------------------------------------------------
+pub fn is_file_or_path(
+    dx_env: &DxEnvironment,
+    path: &str,
+) -> Result<FileOrPath> {
+    // Goal of this function is to guess whether a path
+    // is a file or something that might be a file or dir
+    // without making any API calls to resolve
+
+    let mut path = path.to_string();
+
+    // Default project_id comes from env
+    let mut project_id = dx_env.project_context_id.to_string();
+
+    // The incoming path may start with project_id
+    // If so, use that and remove it from the path
+    if let Some(caps) = entity_id::PROJECT_PREFIX.captures(&path) {
+        project_id = caps.get(1).unwrap().as_str().to_string();
+        path = caps
+            .get(3)
+            .map_or("".to_string(), |m| m.as_str().to_string());
+    }
+
+    // Remove leading ":"
+    // This should match even the empty string, in which case
+    // default path comes from env
+    if let Some(caps) = entity_id::LEADING_COLON.captures(&path) {
+        path = caps
+            .get(1)
+            .map_or(dx_env.cli_wd.to_string(), |m| m.as_str().to_string());
+    }
+
+    // If remaining path looks like a file ID, return that
+    if entity_id::FILE_ID.is_match(&path) {
+        return Ok(FileOrPath::File {
+            file_id: path.to_string(),
+            project_id: project_id.to_string(),
+        });
+    }
+
+    // Treat the identifier as a path like "/foo/bar.txt"
+    Ok(FileOrPath::Path {
+        path: normalize_remote_path(&dx_env.cli_wd, &path),
+        project_id: project_id.to_string(),
+    })
+
+    //if let Some(basename) = path.file_name() {
+    //    let dirname = path
+    //        .parent()
+    //        .unwrap_or(Path::new("/"))
+    //        .to_string_lossy()
+    //        .to_string();
+
+    //    // Look for a file "bar.txt" in a folder "/foo" ...
+    //    let mut opts = FindDataOptions {
+    //        class: Some(ObjectType::File),
+    //        state: None,
+    //        name: Some(FindName::Regexp(
+    //            basename.to_string_lossy().to_string(),
+    //        )),
+    //        visibility: None,
+    //        id: vec![],
+    //        object_type: None,
+    //        tags: vec![],
+    //        region: vec![],
+    //        properties: None,
+    //        link: None,
+    //        scope: Some(FindDataScope {
+    //            project: Some(project_id),
+    //            folder: Some(dirname),
+    //            recurse: Some(false),
+    //        }),
+    //        sort_by: None,
+    //        level: None,
+    //        modified: None,
+    //        created: None,
+    //        describe: Some(FindDescribe::Boolean(true)),
+    //        starting: None,
+    //        limit: None,
+    //        archival_state: None,
+    //    };
+
+    //    // If found, return the file ID
+    //    let data = api::find_data(dx_env, &mut opts)?;
+    //    if data.len() == 1 {
+    //        if let Some(val) = data.first() {
+    //            return Ok(FileOrDirectory::File {
+    //                name: val.id.clone(),
+    //                project_id: Some("".to_string()),
+    //            });
+    //        }
+    //    }
+    //}
+
+    //// Try looking for a folder with the given name
+    //let ls_opts = ListFolderOptions {
+    //    folder: "/",
+    //    only: Some(ListFolderOptionOnlyValue::Folders),
+    //    describe: true,
+    //    has_subfolder_flags: true,
+    //    include_hidden: true,
+    //};
+
+    //let list: ListFolderResult =
+    //    api::ls(dx_env, &dx_env.project_context_id, ls_opts)?;
+    //let wanted = path.display().to_string();
+
+    //if let Some(folders) = list.folders {
+    //    let matches: Vec<_> = folders
+    //        .iter()
+    //        .map(|t| t.0.clone())
+    //        .filter(|name| name == &wanted)
+    //        .collect();
+
+    //    if matches.len() == 1 {
+    //        if let Some(dir) = matches.first() {
+    //            return Ok(FileOrDirectory::Directory {
+    //                name: dir.to_string(),
+    //                project_id: Some(project_id.to_string()),
+    //            });
+    //        }
+    //    }
+    //}
+
+    //bail!(r#"Cannot find file or directory "{wanted}""#);
+}
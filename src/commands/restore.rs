@@ -0,0 +1,308 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::exit::CliError;
+use crate::{
+    api, subfolders, CloneOptions, ListFolderOptionOnlyValue,
+    ListFolderOptions, MakeFolderOptions, MoveOptions, ProjectSnapshot,
+    RmdirOptions, SnapshotObject, TRASH_FOLDER,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use std::{collections::BTreeMap, fs};
+
+#[derive(Clone, Parser, Debug)]
+pub struct RestoreArgs {
+    /// Trash folder to restore, defaults to the most recent
+    #[arg(value_name = "TIMESTAMP")]
+    timestamp: Option<String>,
+
+    /// Project ID, defaults to the current project
+    #[arg(short, long)]
+    project: Option<String>,
+
+    /// List the trash folders and exit
+    #[arg(short, long, default_value = "false")]
+    list: bool,
+
+    /// Recreate the folders of a "snapshot" manifest in the project and
+    /// clone its objects there
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["timestamp", "list"]
+    )]
+    snapshot: Option<String>,
+
+    /// Print the objects and folders that would be restored
+    #[arg(short('n'), long, default_value = "false")]
+    dry_run: bool,
+}
+
+// --------------------------------------------------
+pub fn restore(args: RestoreArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let project_id = args.project.unwrap_or(dx_env.project_context_id.clone());
+
+    if let Some(snapshot) = &args.snapshot {
+        return restore_snapshot(&dx_env, snapshot, &project_id, args.dry_run);
+    }
+    let trash_folders = trash_folders(&dx_env, &project_id)?;
+
+    if args.list {
+        for folder in &trash_folders {
+            let prefix = format!("{TRASH_FOLDER}/");
+            println!("{}", folder.strip_prefix(&prefix).unwrap_or(folder));
+        }
+        return Ok(());
+    }
+
+    let trash = match args.timestamp {
+        Some(timestamp) => {
+            let trash = format!("{TRASH_FOLDER}/{timestamp}");
+            if !trash_folders.contains(&trash) {
+                bail!(CliError::NotFound(format!(
+                    r#"No trash folder "{timestamp}" in {project_id}"#
+                )));
+            }
+            trash
+        }
+        _ => match trash_folders.last() {
+            Some(trash) => trash.clone(),
+            _ => bail!("Nothing to restore in {project_id}"),
+        },
+    };
+
+    // Walk the listing rather than searching so that objects not yet
+    // indexed and empty folders are restored too
+    let mut restored = Restored::default();
+    restore_folder(
+        &dx_env,
+        &project_id,
+        &trash,
+        &trash,
+        args.dry_run,
+        &mut restored,
+    )?;
+
+    if !args.dry_run {
+        // Without "recurse" anything left behind stays in the trash
+        for folder in &restored.emptied {
+            let options = RmdirOptions {
+                folder: folder.clone(),
+                recurse: None,
+                force: None,
+                partial: None,
+            };
+            api::rmdir(&dx_env, &project_id, &options).map_err(|e| {
+                anyhow!("Failed to remove {folder} from the trash: {e}")
+            })?;
+        }
+        println!(
+            "Restored {} object{} and {} folder{} from {trash}",
+            restored.objects,
+            if restored.objects == 1 { "" } else { "s" },
+            restored.folders,
+            if restored.folders == 1 { "" } else { "s" },
+        );
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+#[derive(Debug, Default)]
+struct Restored {
+    objects: usize,
+    folders: usize,
+
+    // The trash folders to remove afterwards, deepest first
+    emptied: Vec<String>,
+}
+
+// --------------------------------------------------
+// Move the contents of a trash folder back to where they came from. A
+// subfolder whose original is gone is moved whole, otherwise its
+// contents are merged into the original.
+fn restore_folder(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    trash: &str,
+    folder: &str,
+    dry_run: bool,
+    restored: &mut Restored,
+) -> Result<()> {
+    let options = ListFolderOptions {
+        folder,
+        only: Some(ListFolderOptionOnlyValue::All),
+        describe: false,
+        has_subfolder_flags: true,
+        include_hidden: true,
+    };
+    let listing = api::ls(dx_env, project_id, options)?;
+    let destination = restore_destination(trash, folder);
+    let existing = subfolders(dx_env, project_id, &destination)?;
+
+    let objects: Vec<String> = listing
+        .objects
+        .unwrap_or_default()
+        .into_iter()
+        .map(|obj| obj.id)
+        .collect();
+    let mut folders = vec![];
+    for (subfolder, _) in listing.folders.unwrap_or_default() {
+        if existing.contains(&restore_destination(trash, &subfolder)) {
+            restore_folder(
+                dx_env, project_id, trash, &subfolder, dry_run, restored,
+            )?;
+        } else {
+            folders.push(subfolder);
+        }
+    }
+
+    restored.objects += objects.len();
+    restored.folders += folders.len();
+    if dry_run {
+        for item in objects.iter().chain(&folders) {
+            println!("{item}\t{destination}");
+        }
+    } else if !(objects.is_empty() && folders.is_empty()) {
+        let options = MoveOptions {
+            objects,
+            folders,
+            destination,
+        };
+        api::mv(dx_env, project_id, &options)?;
+    }
+    restored.emptied.push(folder.to_string());
+
+    Ok(())
+}
+
+// Cloning keeps each object's ID, name, tags, and properties, so only
+// the folders need recreating
+fn restore_snapshot(
+    dx_env: &DxEnvironment,
+    filename: &str,
+    project_id: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let snapshot: ProjectSnapshot =
+        serde_json::from_str(&fs::read_to_string(filename)?)
+            .map_err(|e| anyhow!(r#"Invalid snapshot "{filename}": {e}"#))?;
+    if snapshot.project == project_id {
+        bail!("Choose another project to restore the snapshot of {project_id}");
+    }
+
+    let (clones, skipped) = snapshot_clones(&snapshot);
+    for obj in &skipped {
+        eprintln!(
+            "Skipping {} {}/{}",
+            obj.state.as_deref().unwrap_or("unknown"),
+            obj.folder.trim_end_matches('/'),
+            obj.name
+        );
+    }
+
+    if dry_run {
+        for folder in &snapshot.folders {
+            println!("{folder}");
+        }
+        for (folder, objects) in &clones {
+            for id in objects {
+                println!("{id}\t{folder}");
+            }
+        }
+        return Ok(());
+    }
+
+    for folder in snapshot.folders.iter().filter(|folder| *folder != "/") {
+        api::mkdir(
+            dx_env,
+            project_id,
+            MakeFolderOptions {
+                folder: folder.clone(),
+                parents: true,
+            },
+        )?;
+    }
+
+    let (mut num_cloned, mut num_existing) = (0, 0);
+    for (destination, objects) in clones {
+        num_cloned += objects.len();
+        let options = CloneOptions {
+            objects,
+            folders: vec![],
+            project: project_id.to_string(),
+            destination,
+            parents: true,
+        };
+        num_existing += api::clone(dx_env, &snapshot.project, &options)?
+            .exists
+            .len();
+    }
+
+    println!(
+        "Restored {} folder{} and {num_cloned} object{} from {} into \
+        {project_id}",
+        snapshot.folders.len(),
+        if snapshot.folders.len() == 1 { "" } else { "s" },
+        if num_cloned == 1 { "" } else { "s" },
+        snapshot.project,
+    );
+    if num_existing > 0 {
+        println!(
+            "{num_existing} object{} already in {project_id}",
+            if num_existing == 1 { " was" } else { "s were" }
+        );
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// The closed objects to clone by destination folder, and the objects
+// that cannot be cloned as they are still open
+pub(crate) fn snapshot_clones(
+    snapshot: &ProjectSnapshot,
+) -> (BTreeMap<String, Vec<String>>, Vec<&SnapshotObject>) {
+    let mut clones: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut skipped = vec![];
+    for obj in &snapshot.objects {
+        if obj.state.as_deref().is_none_or(|state| state == "closed") {
+            clones
+                .entry(obj.folder.clone())
+                .or_default()
+                .push(obj.id.clone());
+        } else {
+            skipped.push(obj);
+        }
+    }
+    (clones, skipped)
+}
+
+// --------------------------------------------------
+// The inverse of "trash_destination" for a folder inside the trash
+pub(crate) fn restore_destination(trash: &str, folder: &str) -> String {
+    match folder.strip_prefix(trash) {
+        Some(rest) if rest.starts_with('/') => rest.to_string(),
+        _ => "/".to_string(),
+    }
+}
+
+// --------------------------------------------------
+// The trash folders of a project, oldest first
+fn trash_folders(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+) -> Result<Vec<String>> {
+    // Listing a missing folder is an error
+    if !subfolders(dx_env, project_id, "/")?
+        .iter()
+        .any(|folder| folder == TRASH_FOLDER)
+    {
+        return Ok(vec![]);
+    }
+
+    let mut found = subfolders(dx_env, project_id, TRASH_FOLDER)?;
+    found.sort();
+    Ok(found)
+}
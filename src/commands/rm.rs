@@ -0,0 +1,506 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{
+    api, entity_id, find_files_by_path, incomplete, link_ids, read_stdin_args,
+    resolve_path, select_file_from_list, split_glob_path, AppletDescribeField,
+    AppletDescribeOptions, FindDataOptions, FindDataResult, FindDataScope,
+    FindDescribe, FindName, ListFolderOptionOnlyValue, ListFolderOptions,
+    ListFolderResult, MakeFolderOptions, MoveOptions, ObjectType, RmOptions,
+    RmdirOptions, Visibility,
+};
+use anyhow::{bail, Result};
+use chrono::Local;
+use clap::Parser;
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::Path,
+};
+
+/// Where "rm --trash" moves objects, one timestamped folder per command
+pub(crate) const TRASH_FOLDER: &str = "/.trash";
+
+#[derive(Clone, Parser, Debug)]
+pub struct RmArgs {
+    /// Object IDs or paths
+    #[arg()]
+    paths: Vec<String>,
+
+    /// Recurse into a directory
+    #[arg(short, long, default_value = "false")]
+    recursive: bool,
+
+    /// Force removal of files
+    #[arg(short, long, default_value = "false")]
+    force: bool,
+
+    /// Apply to all results with the same name without prompting
+    #[arg(short, long, default_value = "false")]
+    all: bool,
+
+    /// Read newline-separated IDs or paths from STDIN, same as "-"
+    #[arg(long, default_value = "false")]
+    stdin: bool,
+
+    /// Print the IDs of the objects that would be removed
+    #[arg(short('n'), long, default_value = "false")]
+    dry_run: bool,
+
+    /// Move to a timestamped folder under "/.trash" to allow "restore"
+    #[arg(short, long, default_value = "false")]
+    trash: bool,
+
+    /// Also remove the resource bundles of removed applets that no other
+    /// applet in the project uses
+    #[arg(long, default_value = "false", conflicts_with = "trash")]
+    resources: bool,
+}
+
+// --------------------------------------------------
+pub fn rm(args: RmArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+
+    // Everything removed by one command shares a trash folder
+    let trash = args.trash.then(|| {
+        format!("{TRASH_FOLDER}/{}", Local::now().format("%Y%m%d-%H%M%S"))
+    });
+
+    let mut errors = vec![];
+    for path in &read_stdin_args(&args.paths, args.stdin)? {
+        // Applet and workflow IDs have no path to resolve
+        if let Some((project_id, executable_id)) =
+            applet_or_workflow_id(&dx_env, path)
+        {
+            let res = rm_objects(
+                &dx_env,
+                &project_id,
+                path,
+                vec![executable_id],
+                &args,
+                &trash,
+            );
+            if let Err(e) = res {
+                eprintln!("{e}");
+                errors.push(e);
+            }
+            continue;
+        }
+
+        match resolve_path(&dx_env, path) {
+            Err(e) => {
+                eprintln!("{e}");
+                errors.push(e);
+            }
+            Ok(dx_path) if trash.is_some() && in_trash(&dx_path.path) => {
+                eprintln!(r#""{path}" is already in the trash"#)
+            }
+            Ok(dx_path) => {
+                let options = ListFolderOptions {
+                    folder: &Path::new(&dx_path.path)
+                        .parent()
+                        .expect("parent")
+                        .display()
+                        .to_string(),
+                    only: Some(ListFolderOptionOnlyValue::Folders),
+                    describe: true,
+                    has_subfolder_flags: true,
+                    include_hidden: args.all,
+                };
+
+                let list: ListFolderResult =
+                    api::ls(&dx_env, &dx_path.project_id, options)?;
+
+                let mut found_folder = false;
+                if let Some(folders) = list.folders {
+                    let matches: Vec<_> = folders
+                        .iter()
+                        .map(|t| t.0.clone())
+                        .filter(|name| name == &dx_path.path)
+                        .collect();
+
+                    // There can only be one folder by a name
+                    if let Some(folder) = matches.first() {
+                        found_folder = true;
+                        if !args.recursive {
+                            bail!(
+                                r#"Use recursive to remove folder "{folder}""#
+                            );
+                        }
+
+                        if args.dry_run {
+                            for (id, path) in objects_in_folder(
+                                &dx_env,
+                                &dx_path.project_id,
+                                folder,
+                            )? {
+                                println!("{id}\t{path}");
+                            }
+                        } else if let Some(trash) = &trash {
+                            move_to_trash(
+                                &dx_env,
+                                &dx_path.project_id,
+                                trash,
+                                folder,
+                                vec![],
+                                vec![folder.clone()],
+                            )?;
+                        } else {
+                            let rm_opts = RmdirOptions {
+                                folder: folder.clone(),
+                                recurse: Some(true),
+                                force: Some(true),
+                                partial: None,
+                            };
+
+                            let res = api::rmdir(
+                                &dx_env,
+                                &dx_path.project_id,
+                                &rm_opts,
+                            )?;
+
+                            if !res.completed.unwrap_or(true) {
+                                println!(r#"Unable to remove "{folder}"!"#);
+                            }
+                        }
+                    }
+                }
+
+                // Files, else applets and workflows by the same name
+                let mut files = find_files_by_path(
+                    &dx_env,
+                    &dx_path.path,
+                    &dx_path.project_id,
+                )?;
+                if files.is_empty() && !found_folder {
+                    files = find_executables_by_path(
+                        &dx_env,
+                        &dx_path.path,
+                        &dx_path.project_id,
+                    )?;
+                }
+
+                if files.is_empty() && !found_folder {
+                    println!(r#"No files or folders named "{path}""#);
+                } else {
+                    let selected = if args.all {
+                        Some("all".to_string())
+                    } else {
+                        select_file_from_list(&files, true)
+                    };
+
+                    let objects = selected.map_or(vec![], |file| {
+                        if file == "all" {
+                            files
+                                .iter()
+                                .filter_map(|f| f.describe.clone())
+                                .map(|desc| desc.id)
+                                .collect()
+                        } else {
+                            vec![file]
+                        }
+                    });
+
+                    if objects.is_empty() {
+                        continue;
+                    }
+
+                    let res = rm_objects(
+                        &dx_env,
+                        &dx_path.project_id,
+                        &dx_path.path,
+                        objects,
+                        &args,
+                        &trash,
+                    );
+                    if let Err(e) = res {
+                        eprintln!("{e}");
+                        errors.push(e);
+                    }
+                }
+            }
+        }
+    }
+
+    incomplete("Removal", errors)
+}
+
+// --------------------------------------------------
+// Removes or trashes the objects found for one path along with, given
+// "--resources," the bundles that only the removed applets use
+fn rm_objects(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    path: &str,
+    objects: Vec<String>,
+    args: &RmArgs,
+    trash: &Option<String>,
+) -> Result<()> {
+    // Applets must be described before they are gone
+    let resources = if args.resources {
+        unused_resources(dx_env, project_id, &objects)?
+    } else {
+        vec![]
+    };
+
+    if args.dry_run {
+        for id in &objects {
+            println!("{id}\t{path}");
+        }
+        for id in &resources {
+            println!("{id}\t(resources)");
+        }
+        return Ok(());
+    }
+
+    match trash {
+        Some(trash) => {
+            move_to_trash(dx_env, project_id, trash, path, objects, vec![])
+        }
+        _ => {
+            let options = RmOptions {
+                objects: objects.into_iter().chain(resources).collect(),
+                force: Some(args.force),
+            };
+            api::rm(dx_env, project_id, &options).map(|_| ())
+        }
+    }
+}
+
+// --------------------------------------------------
+// "project-xxxx:applet-xxxx" or "workflow-xxxx" in the current project
+pub(crate) fn applet_or_workflow_id(
+    dx_env: &DxEnvironment,
+    path: &str,
+) -> Option<(String, String)> {
+    let caps = entity_id::EXECUTABLE_ID.captures(path)?;
+    let id = caps.get(2)?.as_str();
+    (!id.starts_with("app-")).then(|| {
+        (
+            caps.get(1).map_or(dx_env.project_context_id.clone(), |m| {
+                m.as_str().to_string()
+            }),
+            id.to_string(),
+        )
+    })
+}
+
+// --------------------------------------------------
+fn find_executables_by_path(
+    dx_env: &DxEnvironment,
+    path: &str,
+    project_id: &str,
+) -> Result<Vec<FindDataResult>> {
+    let (folder, name, _) = split_glob_path(path);
+    let mut found = vec![];
+    for class in [ObjectType::Applet, ObjectType::Workflow] {
+        let mut options = FindDataOptions {
+            class: Some(class),
+            state: None,
+            name: Some(FindName::Glob(name.clone())),
+            visibility: None,
+            id: vec![],
+            object_type: None,
+            tags: vec![],
+            region: vec![],
+            properties: None,
+            link: None,
+            scope: Some(FindDataScope {
+                project: Some(project_id.to_string()),
+                folder: Some(folder.clone()),
+                recurse: Some(false),
+            }),
+            sort_by: None,
+            level: None,
+            modified: None,
+            created: None,
+            describe: Some(FindDescribe::Boolean(true)),
+            starting: None,
+            limit: None,
+            archival_state: None,
+        };
+        found.extend(api::find_data(dx_env, &mut options)?);
+    }
+    Ok(found)
+}
+
+// --------------------------------------------------
+// Files bundled into the applets that are in the same project and are
+// not bundled into any applet that will remain, e.g., the "resources"
+// tarball that "build" uploaded
+fn unused_resources(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    objects: &[String],
+) -> Result<Vec<String>> {
+    let applet_opts = AppletDescribeOptions {
+        project: Some(project_id.to_string()),
+        fields: Some(HashMap::from([(AppletDescribeField::RunSpec, true)])),
+    };
+
+    let mut bundled = BTreeSet::new();
+    for applet_id in objects.iter().filter(|id| id.starts_with("applet-")) {
+        let applet = api::describe_applet(dx_env, applet_id, &applet_opts)?;
+        let deps = applet
+            .run_spec
+            .and_then(|spec| spec.bundled_depends)
+            .unwrap_or_default();
+        for dep in deps {
+            bundled.extend(link_ids(&serde_json::to_value(&dep.id)?));
+        }
+    }
+
+    let mut unused = vec![];
+    for file_id in bundled {
+        let mut options = FindDataOptions {
+            class: Some(ObjectType::Applet),
+            state: None,
+            name: None,
+            visibility: Some(Visibility::Either),
+            id: vec![],
+            object_type: None,
+            tags: vec![],
+            region: vec![],
+            properties: None,
+            link: Some(file_id.clone()),
+            scope: Some(FindDataScope {
+                project: Some(project_id.to_string()),
+                folder: None,
+                recurse: Some(true),
+            }),
+            sort_by: None,
+            level: None,
+            modified: None,
+            created: None,
+            describe: None,
+            starting: None,
+            limit: None,
+            archival_state: None,
+        };
+        let users = api::find_data(dx_env, &mut options)?;
+        if !users.iter().all(|user| objects.contains(&user.id)) {
+            continue;
+        }
+
+        // Bundles from other projects, e.g., assets, stay where they are
+        options.class = Some(ObjectType::File);
+        options.link = None;
+        options.id = vec![file_id.clone()];
+        if !api::find_data(dx_env, &mut options)?.is_empty() {
+            unused.push(file_id);
+        }
+    }
+
+    Ok(unused)
+}
+
+// --------------------------------------------------
+pub(crate) fn in_trash(path: &str) -> bool {
+    Path::new(path).starts_with(TRASH_FOLDER)
+}
+
+// --------------------------------------------------
+// Mirrors the original folders inside the trash folder so that
+// "/data/a.txt" goes to "/.trash/<timestamp>/data"
+pub(crate) fn trash_destination(trash: &str, path: &str) -> String {
+    match Path::new(path).parent() {
+        Some(parent) if parent != Path::new("/") => {
+            format!("{trash}{}", parent.display())
+        }
+        _ => trash.to_string(),
+    }
+}
+
+// --------------------------------------------------
+fn move_to_trash(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    trash: &str,
+    path: &str,
+    objects: Vec<String>,
+    folders: Vec<String>,
+) -> Result<()> {
+    let destination = trash_destination(trash, path);
+    api::mkdir(
+        dx_env,
+        project_id,
+        MakeFolderOptions {
+            folder: destination.clone(),
+            parents: true,
+        },
+    )?;
+
+    let options = MoveOptions {
+        objects,
+        folders,
+        destination,
+    };
+    api::mv(dx_env, project_id, &options)?;
+    println!("Moved {path} to {}", options.destination);
+    Ok(())
+}
+
+// --------------------------------------------------
+pub(crate) fn subfolders(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    folder: &str,
+) -> Result<Vec<String>> {
+    let options = ListFolderOptions {
+        folder,
+        only: Some(ListFolderOptionOnlyValue::Folders),
+        describe: false,
+        has_subfolder_flags: true,
+        include_hidden: true,
+    };
+
+    Ok(api::ls(dx_env, project_id, options)?
+        .folders
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect())
+}
+
+// --------------------------------------------------
+// The IDs and paths of all the objects in and below a folder
+pub(crate) fn objects_in_folder(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    folder: &str,
+) -> Result<Vec<(String, String)>> {
+    let mut options = FindDataOptions {
+        class: None,
+        state: None,
+        name: None,
+        visibility: Some(Visibility::Either),
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(project_id.to_string()),
+            folder: Some(folder.to_string()),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Boolean(true)),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+
+    Ok(api::find_data(dx_env, &mut options)?
+        .into_iter()
+        .filter_map(|obj| obj.describe)
+        .map(|desc| {
+            let path = Path::new(&desc.folder.unwrap_or_default())
+                .join(desc.name.unwrap_or_default())
+                .display()
+                .to_string();
+            (desc.id, path)
+        })
+        .collect())
+}
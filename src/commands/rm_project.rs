@@ -0,0 +1,105 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{
+    api, entity_id, FindName, FindProjectsDescribe, FindProjectsOptions,
+    FindProjectsResult, ProjectDescribeField, RmProjectOptions,
+};
+use anyhow::Result;
+use clap::Parser;
+use inquire::Confirm;
+use std::collections::HashMap;
+
+#[derive(Clone, Parser, Debug)]
+pub struct RmProjectArgs {
+    /// Projects to remove
+    #[arg(required(true))]
+    projects: Vec<String>,
+
+    /// Do not ask for confirmation
+    #[arg(short('y'), long("yes"))]
+    force: bool,
+
+    /// Do not print purely informational messages
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+// --------------------------------------------------
+fn find_project(
+    dx_env: &DxEnvironment,
+    project: &str,
+) -> Result<Vec<FindProjectsResult>> {
+    let fields = HashMap::from([(ProjectDescribeField::Name, true)]);
+    let mut options = FindProjectsOptions {
+        name: None,
+        id: vec![],
+        level: None,
+        bill_to: None,
+        starting: None,
+        describe: Some(FindProjectsDescribe { fields }),
+    };
+
+    if entity_id::PROJECT_ID.is_match(project) {
+        options.id = vec![project.to_string()];
+    } else {
+        options.name = Some(FindName::Regexp(project.to_string()));
+    }
+
+    api::find_projects(dx_env, options)
+}
+
+// --------------------------------------------------
+pub fn rm_project(args: RmProjectArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+
+    for project in args.projects {
+        let found = find_project(&dx_env, &project)?;
+
+        let num_found = found.len();
+        match num_found {
+            0 => println!(r#"Project "{project}" cannot be found"#),
+            1 => {
+                let options = RmProjectOptions {
+                    terminate_jobs: None,
+                };
+                let project = found.first().unwrap();
+                let project_id = &project.id;
+                let confirm = if args.force {
+                    Ok(true)
+                } else {
+                    let name = project
+                        .describe
+                        .get("name")
+                        .map_or("NA".to_string(), String::from);
+
+                    Confirm::new(&format!(
+                        r#"Will delete project "{name}" ({project_id})"#
+                    ))
+                    .with_default(false)
+                    .prompt()
+                };
+
+                match confirm {
+                    Ok(true) => {
+                        let removed =
+                            api::rm_project(&dx_env, project_id, &options)?;
+                        println!(r#"Removed project "{}""#, removed.id);
+                    }
+                    Ok(false) => println!("Will not delete"),
+                    _ => println!("Try again"),
+                }
+            }
+            _ => {
+                println!(r#"Found {num_found} projects matching "{project}""#);
+                for project in found {
+                    let name = project
+                        .describe
+                        .get("name")
+                        .map_or("NA".to_string(), String::from);
+                    println!("- {} {}", project.id, name);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
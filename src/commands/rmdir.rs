@@ -0,0 +1,88 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{
+    api, new_spinner, resolve_path, DxPath, ListFolderOptionOnlyValue,
+    ListFolderOptions, RmdirOptions,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use indicatif::ProgressStyle;
+
+#[derive(Clone, Parser, Debug)]
+pub struct RmdirArgs {
+    /// Object IDs or paths
+    #[arg()]
+    paths: Vec<String>,
+
+    /// Remove the folder's contents, too
+    #[arg(short, long, default_value = "false")]
+    recursive: bool,
+
+    /// Allow removing the root folder of a project
+    #[arg(short, long, default_value = "false")]
+    force: bool,
+}
+
+// --------------------------------------------------
+pub fn rmdir(args: RmdirArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+
+    for path in &args.paths {
+        let dest = resolve_path(&dx_env, path)?;
+        let folder = dest.path.clone();
+
+        if folder == "/" && !args.force {
+            bail!(
+                r#"Will not remove the root of project "{}" without --force"#,
+                dest.project_id
+            );
+        }
+
+        if !args.recursive && !folder_is_empty(&dx_env, &dest)? {
+            bail!(r#"Folder "{folder}" is not empty, use --recursive"#);
+        }
+
+        let options = RmdirOptions {
+            folder: folder.clone(),
+            recurse: Some(args.recursive),
+            force: None,
+            partial: Some(true),
+        };
+
+        let progress = new_spinner();
+        progress.set_style(
+            ProgressStyle::default_spinner()
+                .template("{spinner:.green} {msg} ({pos} batches)")?,
+        );
+        progress.set_message(format!("Removing {dest}"));
+
+        loop {
+            let res = api::rmdir(&dx_env, &dest.project_id, &options)?;
+            progress.inc(1);
+
+            // Limit of 10K items to delete, so may need to repeat
+            if res.completed.unwrap_or(true) {
+                break;
+            }
+        }
+
+        progress.finish_and_clear();
+        println!("Removed {dest}");
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+fn folder_is_empty(dx_env: &DxEnvironment, dest: &DxPath) -> Result<bool> {
+    let options = ListFolderOptions {
+        folder: &dest.path,
+        only: Some(ListFolderOptionOnlyValue::All),
+        describe: false,
+        has_subfolder_flags: true,
+        include_hidden: true,
+    };
+
+    let list = api::ls(dx_env, &dest.project_id, options)?;
+    Ok(list.objects.unwrap_or_default().is_empty()
+        && list.folders.unwrap_or_default().is_empty())
+}
@@ -0,0 +1,829 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::exit::CliError;
+use crate::json_parser::{
+    value_matches_class, InputOutputClass, SystemRequirements,
+};
+use crate::{
+    api, check_inputs, entity_id, executable_input_spec, format_watch_message,
+    glob_to_regex, is_glob, local_md5s, normalize_remote_path,
+    parse_instance_types, remote_md5, report_job_failure, resolve_executable,
+    resolve_file_id, upload_local_file, AppInputSpec, FileDescribeField,
+    FileDescribeOptions, FindDataOptions, FindDataScope, FindDescribe,
+    FindName, KitchenSink, ObjectState, ObjectType, ProgressFormat,
+    ProjectPath, RunOptions, UploadProgress, WatchOptions, WhoAmIOptions,
+    WhoAmIOptionsFields,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use inquire::{
+    Confirm,
+    //validator::{StringValidator, Validation},
+    MultiSelect,
+    Select,
+    Text,
+};
+use log::debug;
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+/// Most files offered when prompting for a file input
+const INPUT_PICKER_LIMIT: u64 = 1000;
+
+#[derive(Clone, Parser, Debug)]
+pub struct RunArgs {
+    /// Applet, app or workflow ID or name
+    #[arg()]
+    executable: String,
+
+    /// An input to the executable, e.g., "reads=file-xxxx" or
+    /// "stage-xxxx.reads=file-xxxx" for a workflow, where local files are
+    /// uploaded unless the project has a copy
+    #[arg(short, long, value_name = "NAME=VALUE")]
+    input: Vec<String>,
+
+    /// JSON or YAML file of inputs, e.g., from "inputs --template",
+    /// which "--input" values override
+    #[arg(short('f'), long, value_name = "FILE")]
+    input_json: Option<PathBuf>,
+
+    /// Prompt for required inputs not given, then confirm the input JSON
+    /// before launching
+    #[arg(long, default_value = "false", conflicts_with = "batch_tsv")]
+    interactive: bool,
+
+    /// Instance type for all entry points or "ENTRYPOINT=TYPE"
+    #[arg(long, value_name = "[ENTRYPOINT=]TYPE")]
+    instance_type: Vec<String>,
+
+    /// Instance type for a workflow stage, e.g., "stage-xxxx=mem1_ssd1_v2_x4"
+    #[arg(long, value_name = "STAGE=[ENTRYPOINT=]TYPE")]
+    stage_instance_type: Vec<String>,
+
+    /// Output folder, "{batch_id}" is replaced in batch runs
+    #[arg(long)]
+    folder: Option<String>,
+
+    /// Name for the job, "{batch_id}" is replaced in batch runs
+    #[arg(long)]
+    name: Option<String>,
+
+    /// Tag for the job
+    #[arg(long)]
+    tag: Vec<String>,
+
+    /// TSV of inputs with one job per row and a header of input names
+    /// plus an optional "batch ID" column
+    #[arg(long, value_name = "FILE")]
+    batch_tsv: Option<PathBuf>,
+
+    /// Watch the job after launching
+    #[arg(long, default_value = "false", conflicts_with = "batch_tsv")]
+    watch: bool,
+
+    /// Print only the job ID
+    #[arg(long, default_value = "false")]
+    brief: bool,
+
+    /// Allow SSH from an address, defaults to this computer's address
+    #[arg(long, value_name = "ADDRESS")]
+    allow_ssh: Option<Option<String>>,
+}
+
+// --------------------------------------------------
+pub fn run(args: RunArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let (project_id, executable_id) =
+        resolve_executable(&dx_env, &args.executable)?;
+
+    let is_workflow = executable_id.starts_with("workflow-");
+    if is_workflow && args.watch {
+        bail!("Cannot watch an analysis, watch its stage jobs instead")
+    }
+    if !is_workflow && !args.stage_instance_type.is_empty() {
+        bail!("Stage instance types are only for workflows")
+    }
+    if is_workflow && args.interactive {
+        bail!(CliError::Usage(
+            "Cannot prompt for workflow inputs, use --input".to_string()
+        ))
+    }
+
+    let folder = args
+        .folder
+        .clone()
+        .map(|folder| normalize_remote_path(&dx_env.cli_wd, &folder));
+
+    let allow_ssh = match &args.allow_ssh {
+        Some(Some(address)) => vec![address.clone()],
+        Some(None) => {
+            let options = WhoAmIOptions {
+                fields: Some(HashMap::from([(
+                    WhoAmIOptionsFields::ClientIp,
+                    true,
+                )])),
+            };
+            match api::whoami(&dx_env, &options)?.client_ip {
+                Some(ip) => vec![ip],
+                _ => bail!("Cannot find this computer's address for SSH"),
+            }
+        }
+        _ => vec![],
+    };
+
+    // Resolve every batch row before launching so that a bad row
+    // does not leave a half-started batch
+    let mut uploaded = HashMap::new();
+    let shared_inputs =
+        resolve_local_inputs(&dx_env, &project_id, &args.input, &mut uploaded)?;
+    let launches = match &args.batch_tsv {
+        Some(path) => {
+            let text = fs::read_to_string(path)
+                .map_err(|e| anyhow!("{}: {e}", path.display()))?;
+            let mut launches = vec![];
+            for row in parse_batch_tsv(&text)? {
+                let mut inputs = vec![];
+                for (name, val) in row.inputs {
+                    let val = if Path::new(&val).is_file() {
+                        val
+                    } else {
+                        resolve_batch_value(&dx_env, &val)?
+                    };
+                    inputs.push(format!("{name}={val}"));
+                }
+                let mut inputs = resolve_local_inputs(
+                    &dx_env,
+                    &project_id,
+                    &inputs,
+                    &mut uploaded,
+                )?;
+                inputs.splice(0..0, shared_inputs.iter().cloned());
+                launches.push((Some(row.batch_id), parse_run_inputs(&inputs)?));
+            }
+            launches
+        }
+        _ => vec![(None, parse_run_inputs(&shared_inputs)?)],
+    };
+
+    let mut launches = if let Some(path) = &args.input_json {
+        let file_inputs = read_input_file(path)?;
+        launches
+            .into_iter()
+            .map(|(batch_id, input)| {
+                let mut merged = file_inputs.clone();
+                merged.extend(input);
+                (batch_id, merged)
+            })
+            .collect::<Vec<_>>()
+    } else {
+        launches
+    };
+
+    if (args.input_json.is_some() || args.interactive) && !is_workflow {
+        let spec = executable_input_spec(&dx_env, &project_id, &executable_id)?;
+        if args.interactive {
+            for (_, input) in &mut launches {
+                prompt_inputs(&dx_env, &project_id, &spec, input)?;
+            }
+        }
+
+        // Report every problem before anything is launched
+        let mut problems = vec![];
+        for (batch_id, input) in &launches {
+            problems.extend(check_inputs(&spec, input).into_iter().map(
+                |problem| match batch_id {
+                    Some(id) => format!("{id}: {problem}"),
+                    _ => problem,
+                },
+            ));
+        }
+
+        if !problems.is_empty() {
+            for problem in &problems {
+                eprintln!("{problem}");
+            }
+            bail!(
+                "Found {} problem{} with the inputs",
+                problems.len(),
+                if problems.len() == 1 { "" } else { "s" }
+            );
+        }
+    }
+
+    if args.interactive {
+        for (_, input) in &launches {
+            println!("{}", serde_json::to_string_pretty(input)?);
+        }
+        let consent = Confirm::new(&format!("Run {executable_id}?"))
+            .with_default(true)
+            .prompt()?;
+        if !consent {
+            bail!("Run cancelled");
+        }
+    }
+
+    if args.batch_tsv.is_some() {
+        if is_workflow {
+            println!("batch ID\tanalysis ID");
+        } else {
+            println!("batch ID\tjob ID");
+        }
+    }
+
+    let mut job_id = String::new();
+    for (batch_id, input) in launches {
+        let fill = |val: &String| match &batch_id {
+            Some(id) => val.replace("{batch_id}", id),
+            _ => val.clone(),
+        };
+        let options = RunOptions {
+            input: serde_json::Value::Object(input),
+            project: Some(project_id.clone()),
+            folder: folder.as_ref().map(fill),
+            name: args.name.as_ref().map(fill),
+            tags: args.tag.clone(),
+            system_requirements: parse_instance_types(&args.instance_type)?,
+            allow_ssh: allow_ssh.clone(),
+            stage_system_requirements: parse_stage_instance_types(
+                &args.stage_instance_type,
+            )?,
+        };
+        debug!("{}", serde_json::to_string_pretty(&options)?);
+
+        let res = api::run(&dx_env, &executable_id, &options)?;
+        job_id = res.id;
+        match batch_id {
+            Some(id) => println!("{id}\t{job_id}"),
+            _ if args.brief => println!("{job_id}"),
+            _ if is_workflow => {
+                println!("Started analysis {job_id}");
+                for (stage_num, stage_job_id) in res.stages.iter().enumerate() {
+                    println!("  Stage {stage_num}: {stage_job_id}");
+                }
+            }
+            _ => println!("Started job {job_id}"),
+        }
+    }
+
+    if args.watch {
+        let watch_opts = WatchOptions {
+            num_recent_messages: None,
+            recurse_jobs: Some(false),
+            tail: Some(true),
+            levels: vec![],
+        };
+        api::watch(&dx_env, &job_id, &watch_opts, |msg| {
+            println!("{}", format_watch_message(&msg, true, false));
+            Ok(true)
+        })?;
+        report_job_failure(&dx_env, &job_id)?;
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+#[derive(Debug, PartialEq)]
+pub(crate) struct BatchRow {
+    pub(crate) batch_id: String,
+    pub(crate) inputs: Vec<(String, String)>,
+}
+
+// --------------------------------------------------
+// The header names the inputs, and repeating a name makes an array.
+// Rows without a "batch ID" column are numbered from 1.
+pub(crate) fn parse_batch_tsv(text: &str) -> Result<Vec<BatchRow>> {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+    let header: Vec<&str> = match lines.next() {
+        Some(line) => line.split('\t').map(|col| col.trim()).collect(),
+        _ => bail!("Batch TSV is empty"),
+    };
+    if header.iter().any(|col| col.is_empty()) {
+        bail!("Batch TSV header has an empty column name")
+    }
+    let id_col = header.iter().position(|col| *col == "batch ID");
+
+    let mut rows = vec![];
+    for (i, line) in lines.enumerate() {
+        let cells: Vec<&str> = line.split('\t').collect();
+        if cells.len() != header.len() {
+            bail!(
+                "Batch TSV row {} has {} columns, expected {}",
+                i + 1,
+                cells.len(),
+                header.len()
+            )
+        }
+
+        let batch_id = id_col
+            .map_or((i + 1).to_string(), |col| cells[col].trim().to_string());
+        let inputs = header
+            .iter()
+            .zip(&cells)
+            .enumerate()
+            .filter(|(col, (_, val))| Some(*col) != id_col && !val.is_empty())
+            .map(|(_, (name, val))| (name.to_string(), val.to_string()))
+            .collect();
+        rows.push(BatchRow { batch_id, inputs });
+    }
+
+    if rows.is_empty() {
+        bail!("Batch TSV has no rows")
+    }
+
+    Ok(rows)
+}
+
+// --------------------------------------------------
+// Remote paths ("/dir/file" or "project-xxxx:/dir/file") become file IDs
+fn resolve_batch_value(dx_env: &DxEnvironment, val: &str) -> Result<String> {
+    if val.starts_with('/') || entity_id::PROJECT_PREFIX.is_match(val) {
+        resolve_file_id(dx_env, val)
+    } else {
+        Ok(val.to_string())
+    }
+}
+
+// --------------------------------------------------
+// Inputs naming a local file are replaced with the ID of a project file
+// with the same MD5 or else of a new upload. Uploads are remembered by
+// path so that a file used in several inputs or batch rows is sent once.
+pub(crate) fn resolve_local_inputs(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    inputs: &[String],
+    uploaded: &mut HashMap<PathBuf, String>,
+) -> Result<Vec<String>> {
+    let mut resolved = vec![];
+    for input in inputs {
+        match input.split_once('=') {
+            Some((name, val)) if Path::new(val).is_file() => {
+                let key = Path::new(val).canonicalize()?;
+                let file_id = match uploaded.get(&key) {
+                    Some(file_id) => file_id.clone(),
+                    _ => {
+                        let file_id =
+                            local_input_file(dx_env, project_id, val)?;
+                        uploaded.insert(key, file_id.clone());
+                        file_id
+                    }
+                };
+                resolved.push(format!("{name}={file_id}"));
+            }
+            _ => resolved.push(input.clone()),
+        }
+    }
+    Ok(resolved)
+}
+
+// --------------------------------------------------
+fn local_input_file(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    filename: &str,
+) -> Result<String> {
+    let (md5, _) = local_md5s(File::open(filename)?, &[])?;
+    if let Some(file_id) = find_file_by_md5(dx_env, project_id, filename, &md5)?
+    {
+        note!(r#"Using {file_id} for "{filename}""#);
+        return Ok(file_id);
+    }
+
+    // Uploads land in the working directory of the current project
+    let folder = if project_id == dx_env.project_context_id {
+        dx_env.cli_wd.clone()
+    } else {
+        "/".to_string()
+    };
+    let destination = ProjectPath {
+        project_id: project_id.to_string(),
+        path: folder.into(),
+    };
+    let file_id = upload_local_file(
+        dx_env,
+        filename,
+        &destination,
+        Some(HashMap::from([("md5".to_string(), md5)])),
+        &[],
+        &UploadProgress::new(ProgressFormat::Bar, 0, 1),
+        true,
+    )?;
+    note!(r#"Uploaded "{filename}" as {file_id}"#);
+    Ok(file_id)
+}
+
+// --------------------------------------------------
+// Look first for an "md5" property, as set by uploads of run inputs,
+// and then for a file of the same name and size whose checksum matches
+fn find_file_by_md5(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    filename: &str,
+    md5: &str,
+) -> Result<Option<String>> {
+    let find = |name: Option<FindName>, properties: Option<KitchenSink>| {
+        FindDataOptions {
+            class: Some(ObjectType::File),
+            state: Some(ObjectState::Closed),
+            name,
+            visibility: None,
+            id: vec![],
+            object_type: None,
+            tags: vec![],
+            region: vec![],
+            properties,
+            link: None,
+            scope: Some(FindDataScope {
+                project: Some(project_id.to_string()),
+                folder: Some("/".to_string()),
+                recurse: Some(true),
+            }),
+            sort_by: None,
+            level: None,
+            modified: None,
+            created: None,
+            describe: Some(FindDescribe::Mapping(HashMap::from([(
+                "size".to_string(),
+                true,
+            )]))),
+            starting: None,
+            limit: None,
+            archival_state: None,
+        }
+    };
+
+    let mut options = find(
+        None,
+        Some(KitchenSink::Mapping(HashMap::from([(
+            "md5".to_string(),
+            KitchenSink::StringValue(md5.to_string()),
+        )]))),
+    );
+    if let Some(file) = api::find_data(dx_env, &mut options)?.first() {
+        return Ok(Some(file.id.clone()));
+    }
+
+    let basename = Path::new(filename)
+        .file_name()
+        .map_or(String::new(), |name| name.to_string_lossy().to_string());
+    if basename.is_empty() || is_glob(&basename) {
+        return Ok(None);
+    }
+
+    let size = fs::metadata(filename)?.len();
+    let mut options = find(Some(FindName::Glob(basename)), None);
+    let describe_opts = FileDescribeOptions {
+        project: Some(project_id.to_string()),
+        fields: Some(HashMap::from([
+            (FileDescribeField::Properties, true),
+            (FileDescribeField::Parts, true),
+        ])),
+        properties: true,
+        details: false,
+    };
+    for file in api::find_data(dx_env, &mut options)? {
+        if file.describe.as_ref().and_then(|desc| desc.size) != Some(size) {
+            continue;
+        }
+        let desc = api::describe_file(dx_env, &file.id, &describe_opts)?;
+        if remote_md5(&desc).as_deref() == Some(md5) {
+            return Ok(Some(file.id));
+        }
+    }
+
+    Ok(None)
+}
+
+// --------------------------------------------------
+// Values go through "KitchenSink" so that anything that is not an input
+// value (e.g., null) is an error naming the file
+pub(crate) fn read_input_file(
+    path: &Path,
+) -> Result<serde_json::Map<String, serde_json::Value>> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| anyhow!("{}: {e}", path.display()))?;
+    let is_yaml = path
+        .extension()
+        .is_some_and(|ext| ext == "yaml" || ext == "yml");
+    let inputs: HashMap<String, KitchenSink> = if is_yaml {
+        serde_yaml::from_str(&text)
+            .map_err(|e| anyhow!("{}: {e}", path.display()))?
+    } else {
+        serde_json::from_str(&text)
+            .map_err(|e| anyhow!("{}: {e}", path.display()))?
+    };
+
+    let mut map = serde_json::Map::new();
+    for (name, val) in inputs {
+        map.insert(name, serde_json::to_value(val)?);
+    }
+    Ok(map)
+}
+
+// --------------------------------------------------
+pub(crate) fn parse_run_inputs(
+    inputs: &[String],
+) -> Result<serde_json::Map<String, serde_json::Value>> {
+    let mut map = serde_json::Map::new();
+
+    for input in inputs {
+        let (name, val) = input
+            .split_once('=')
+            .ok_or(anyhow!(r#"Input "{input}" must be NAME=VALUE"#))?;
+        let value = parse_input_value(val);
+
+        // Repeating a name creates an array input
+        match map.get_mut(name) {
+            Some(serde_json::Value::Array(vals)) => vals.push(value),
+            Some(prev) => *prev = serde_json::json!([prev.clone(), value]),
+            _ => {
+                map.insert(name.to_string(), value);
+            }
+        }
+    }
+
+    Ok(map)
+}
+
+// --------------------------------------------------
+fn parse_input_value(val: &str) -> serde_json::Value {
+    if entity_id::PROJECT_OBJECT_ID.is_match(val) {
+        match val.split_once(':') {
+            Some((project, id)) => serde_json::json!({
+                "$dnanexus_link": { "project": project, "id": id }
+            }),
+            _ => serde_json::json!({ "$dnanexus_link": val }),
+        }
+    } else {
+        // Numbers, booleans, and JSON literals pass through as-is
+        serde_json::from_str(val)
+            .unwrap_or(serde_json::Value::String(val.to_string()))
+    }
+}
+
+// --------------------------------------------------
+// Asks for each required input that was not given and has no default
+fn prompt_inputs(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    spec: &[AppInputSpec],
+    inputs: &mut serde_json::Map<String, serde_json::Value>,
+) -> Result<()> {
+    for input in spec {
+        if inputs.contains_key(&input.name)
+            || input.optional.unwrap_or(false)
+            || input.default.is_some()
+        {
+            continue;
+        }
+
+        let value = prompt_input_value(dx_env, project_id, input)?;
+        inputs.insert(input.name.clone(), value);
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+fn prompt_input_value(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    input: &AppInputSpec,
+) -> Result<serde_json::Value> {
+    let message = format!(
+        "{} ({}):",
+        input.label.as_ref().unwrap_or(&input.name),
+        input.class
+    );
+    let help = input.help.as_deref().unwrap_or_default();
+    let is_array = input.class != input.class.item_class();
+
+    if let Some(choices) = input.choices.as_ref().filter(|c| !c.is_empty()) {
+        let labels: Vec<String> = choices
+            .iter()
+            .map(|choice| match choice {
+                serde_json::Value::String(val) => val.clone(),
+                _ => choice.to_string(),
+            })
+            .collect();
+        return if is_array {
+            let chosen = MultiSelect::new(&message, labels.clone())
+                .with_help_message(help)
+                .prompt()?;
+            Ok(serde_json::Value::Array(
+                choices
+                    .iter()
+                    .zip(&labels)
+                    .filter(|(_, label)| chosen.contains(label))
+                    .map(|(choice, _)| choice.clone())
+                    .collect(),
+            ))
+        } else {
+            let chosen = Select::new(&message, labels.clone())
+                .with_help_message(help)
+                .prompt()?;
+            let pos = labels.iter().position(|label| label == &chosen);
+            Ok(pos.map_or(serde_json::Value::Null, |i| choices[i].clone()))
+        };
+    }
+
+    match input.class.item_class() {
+        InputOutputClass::File => {
+            prompt_input_files(dx_env, project_id, input, &message, is_array)
+        }
+        InputOutputClass::Boolean if !is_array => Ok(Confirm::new(&message)
+            .with_help_message(help)
+            .prompt()?
+            .into()),
+        _ => loop {
+            let text = Text::new(&message)
+                .with_help_message(help)
+                .with_placeholder(if is_array { "comma-separated" } else { "" })
+                .prompt()?;
+            match parse_prompted_value(&input.class, &text) {
+                Some(value) => break Ok(value),
+                _ => println!(r#""{text}" is not {}"#, input.class),
+            }
+        },
+    }
+}
+
+// --------------------------------------------------
+// What was typed for an input, taking a JSON array or else
+// comma-separated values for an array class
+pub(crate) fn parse_prompted_value(
+    class: &InputOutputClass,
+    text: &str,
+) -> Option<serde_json::Value> {
+    let text = text.trim();
+    let item_class = class.item_class();
+    let parse_item = |text: &str| match item_class {
+        InputOutputClass::String => serde_json::Value::String(text.to_string()),
+        _ => parse_input_value(text),
+    };
+
+    let value = if *class == item_class {
+        parse_item(text)
+    } else {
+        match serde_json::from_str(text) {
+            Ok(serde_json::Value::Array(vals)) => {
+                serde_json::Value::Array(vals)
+            }
+            _ => serde_json::Value::Array(
+                text.split(',')
+                    .map(|val| val.trim())
+                    .filter(|val| !val.is_empty())
+                    .map(parse_item)
+                    .collect(),
+            ),
+        }
+    };
+
+    (!text.is_empty() && value_matches_class(class, &value)).then_some(value)
+}
+
+// --------------------------------------------------
+// Closed files in the project that match the input's patterns, filtered
+// by typing, or else a path or ID entered for a file elsewhere
+fn prompt_input_files(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    input: &AppInputSpec,
+    message: &str,
+    is_array: bool,
+) -> Result<serde_json::Value> {
+    let mut options = FindDataOptions {
+        class: Some(ObjectType::File),
+        state: Some(ObjectState::Closed),
+        name: None,
+        visibility: None,
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(project_id.to_string()),
+            folder: Some("/".to_string()),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Mapping(HashMap::from([
+            ("name".to_string(), true),
+            ("folder".to_string(), true),
+        ]))),
+        starting: None,
+        limit: Some(INPUT_PICKER_LIMIT),
+        archival_state: None,
+    };
+    let patterns = input
+        .patterns
+        .iter()
+        .flatten()
+        .map(|pattern| glob_to_regex(pattern))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut files: Vec<(String, String)> =
+        api::find_data(dx_env, &mut options)?
+            .into_iter()
+            .filter_map(|file| {
+                let desc = file.describe?;
+                let name = desc.name.unwrap_or_default();
+                let matches = patterns.is_empty()
+                    || patterns.iter().any(|re| re.is_match(&name));
+                matches.then(|| {
+                    let folder = desc.folder.unwrap_or("/".to_string());
+                    let path =
+                        format!("{}/{name}", folder.trim_end_matches('/'));
+                    (format!("{path} ({})", file.id), file.id)
+                })
+            })
+            .collect();
+    files.sort();
+    let labels: Vec<String> =
+        files.iter().map(|(label, _)| label.clone()).collect();
+    let help = "Type to filter";
+
+    let mut file_ids = vec![];
+    if is_array && !labels.is_empty() {
+        let chosen = MultiSelect::new(message, labels)
+            .with_help_message(help)
+            .prompt()?;
+        file_ids.extend(
+            files
+                .iter()
+                .filter(|(label, _)| chosen.contains(label))
+                .map(|(_, id)| id.clone()),
+        );
+    } else if !labels.is_empty() {
+        let other = "Enter a path or ID".to_string();
+        let choices: Vec<String> =
+            [other.clone()].into_iter().chain(labels).collect();
+        let chosen = Select::new(message, choices)
+            .with_help_message(help)
+            .prompt()?;
+        if let Some((_, id)) = files.iter().find(|(label, _)| label == &chosen)
+        {
+            file_ids.push(id.clone());
+        }
+    }
+
+    // Nothing chosen from the list, so ask for paths or IDs instead
+    while file_ids.is_empty() {
+        let text = Text::new(message)
+            .with_placeholder(if is_array {
+                "paths or IDs, comma-separated"
+            } else {
+                "path or ID"
+            })
+            .prompt()?;
+        for path in text.split(',').map(|v| v.trim()).filter(|v| !v.is_empty())
+        {
+            match resolve_file_id(dx_env, path) {
+                Ok(id) => file_ids.push(id),
+                Err(e) => {
+                    println!("{e}");
+                    file_ids.clear();
+                    break;
+                }
+            }
+        }
+    }
+
+    let links: Vec<_> = file_ids
+        .iter()
+        .map(|id| serde_json::json!({ "$dnanexus_link": id }))
+        .collect();
+    Ok(if is_array {
+        serde_json::Value::Array(links)
+    } else {
+        links[0].clone()
+    })
+}
+
+// --------------------------------------------------
+// "STAGE=[ENTRYPOINT=]TYPE" values for the stages of a workflow
+pub(crate) fn parse_stage_instance_types(
+    instance_types: &[String],
+) -> Result<HashMap<String, HashMap<String, SystemRequirements>>> {
+    let mut requirements: HashMap<String, HashMap<String, _>> = HashMap::new();
+
+    for val in instance_types {
+        match val.split_once('=') {
+            Some((stage, rest)) if !stage.is_empty() => {
+                requirements
+                    .entry(stage.to_string())
+                    .or_default()
+                    .extend(parse_instance_types(&[rest.to_string()])?);
+            }
+            _ => bail!(r#"Missing stage in "{val}""#),
+        }
+    }
+
+    Ok(requirements)
+}
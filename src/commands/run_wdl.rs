@@ -0,0 +1,150 @@
+use crate::dxenv::get_dx_env;
+use crate::entity_id::EntityId;
+use crate::{
+    api, entity_id, normalize_remote_path, parse_run_inputs, RunOptions,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use log::debug;
+use std::{collections::HashMap, env, path::PathBuf, process};
+
+#[derive(Clone, Parser, Debug)]
+pub struct RunWdlArgs {
+    /// WDL file
+    #[arg(default_value = "main.wdl")]
+    wdl: PathBuf,
+
+    /// dxCompiler JAR or executable, defaults to $DXCOMPILER_JAR or
+    /// "dxCompiler" in $PATH
+    #[arg(long, value_name = "PATH")]
+    compiler: Option<PathBuf>,
+
+    /// Destination folder for the compiled workflow
+    #[arg(long)]
+    folder: Option<String>,
+
+    /// Overwrite an existing compiled workflow
+    #[arg(short, long)]
+    force: bool,
+
+    /// Run the workflow after compiling
+    #[arg(long)]
+    run: bool,
+
+    /// An input to the workflow, e.g., "stage-common.reads=file-xxxx"
+    #[arg(short, long, value_name = "NAME=VALUE", requires = "run")]
+    input: Vec<String>,
+}
+
+// --------------------------------------------------
+pub fn run_wdl(args: RunWdlArgs) -> Result<()> {
+    if !args.wdl.is_file() {
+        bail!(r#""{}" is not a file"#, args.wdl.display());
+    }
+
+    let dx_env = get_dx_env()?;
+    let folder = match &args.folder {
+        Some(folder) => normalize_remote_path(&dx_env.cli_wd, folder),
+        _ => dx_env.cli_wd.clone(),
+    };
+
+    let mut cmd = dxcompiler_command(&args.compiler)?;
+    cmd.arg("compile")
+        .arg(&args.wdl)
+        .args(["-project", &dx_env.project_context_id])
+        .args(["-folder", &folder]);
+    if args.force {
+        cmd.arg("-f");
+    }
+
+    // dxCompiler reads the same variables as the dx-toolkit
+    let security = serde_json::json!({
+        "auth_token_type": dx_env.auth_token_type,
+        "auth_token": dx_env.auth_token,
+    });
+    cmd.env("DX_SECURITY_CONTEXT", security.to_string())
+        .env("DX_APISERVER_HOST", &dx_env.apiserver_host)
+        .env("DX_APISERVER_PORT", dx_env.apiserver_port.to_string())
+        .env("DX_APISERVER_PROTOCOL", &dx_env.apiserver_protocol)
+        .env("DX_PROJECT_CONTEXT_ID", &dx_env.project_context_id)
+        .stderr(process::Stdio::inherit());
+
+    debug!("{:?}", &cmd);
+    let output = cmd.output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        bail!("dxCompiler failed: {}", stdout.trim())
+    }
+
+    let executable_id = compiled_executable_id(&stdout).ok_or(anyhow!(
+        "Cannot find a workflow ID in dxCompiler output: {}",
+        stdout.trim()
+    ))?;
+    println!("Compiled {} to {executable_id}", args.wdl.display());
+
+    if args.run {
+        let options = RunOptions {
+            input: serde_json::Value::Object(parse_run_inputs(&args.input)?),
+            project: Some(dx_env.project_context_id.clone()),
+            folder: None,
+            name: None,
+            tags: vec![],
+            system_requirements: HashMap::new(),
+            allow_ssh: vec![],
+            stage_system_requirements: HashMap::new(),
+        };
+        let res = api::run(&dx_env, &executable_id, &options)?;
+        println!("Started {}", res.id);
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+fn dxcompiler_command(compiler: &Option<PathBuf>) -> Result<process::Command> {
+    let compiler = compiler
+        .clone()
+        .or(env::var("DXCOMPILER_JAR").ok().map(PathBuf::from))
+        .or(find_in_path("dxCompiler"));
+
+    match compiler {
+        Some(path) if path.extension().is_some_and(|ext| ext == "jar") => {
+            let mut cmd = process::Command::new("java");
+            cmd.arg("-jar").arg(path);
+            Ok(cmd)
+        }
+        Some(path) => Ok(process::Command::new(path)),
+        _ => bail!(
+            "Cannot find dxCompiler, use --compiler or set DXCOMPILER_JAR"
+        ),
+    }
+}
+
+// --------------------------------------------------
+fn find_in_path(name: &str) -> Option<PathBuf> {
+    env::var_os("PATH").and_then(|paths| {
+        env::split_paths(&paths)
+            .map(|dir| dir.join(name))
+            .find(|path| path.is_file())
+    })
+}
+
+// --------------------------------------------------
+// dxCompiler prints the ID of the compiled executable last
+pub(crate) fn compiled_executable_id(output: &str) -> Option<String> {
+    output
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| {
+            entity_id::WORKFLOW_ID.is_match(line)
+                || matches!(
+                    EntityId::parse(line),
+                    Some(EntityId::Applet {
+                        project_id: None,
+                        ..
+                    })
+                )
+        })
+        .map(str::to_string)
+}
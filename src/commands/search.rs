@@ -0,0 +1,201 @@
+use crate::dxenv::get_dx_env;
+use crate::{
+    api, make_table, normalize_remote_path, output_format, print_records,
+    print_table, tsv_value, FindDataDescribe, FindDataOptions, FindDataScope,
+    FindDescribe, KitchenSink, ObjectType, OutputFormat,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use log::debug;
+use std::{collections::HashMap, path::Path, str::FromStr};
+
+#[derive(Parser, Clone, Debug)]
+pub struct SearchArgs {
+    /// Text to find in property values or record details
+    #[arg()]
+    text: Option<String>,
+
+    /// Property that must be present
+    #[arg(short, long)]
+    key: Option<String>,
+
+    /// Value the property must have
+    #[arg(short, long, requires = "key")]
+    value: Option<String>,
+
+    /// Class
+    #[arg(short, long)]
+    class: Option<String>,
+
+    /// Search path
+    #[arg(short, long)]
+    path: Option<String>,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+// --------------------------------------------------
+pub fn search(args: SearchArgs) -> Result<()> {
+    if args.text.is_none() && args.key.is_none() {
+        bail!("Please provide text to find or --key")
+    }
+
+    let dx_env = get_dx_env()?;
+    let folder = normalize_remote_path(
+        &dx_env.cli_wd,
+        &args.path.clone().unwrap_or("".to_string()),
+    );
+
+    // The server can filter on properties, but details must be
+    // fetched and searched here
+    let properties = args.key.as_ref().map(|key| {
+        KitchenSink::Mapping(HashMap::from([(
+            key.clone(),
+            args.value
+                .as_ref()
+                .map_or(KitchenSink::BooleanValue(true), |v| {
+                    KitchenSink::StringValue(v.clone())
+                }),
+        )]))
+    });
+
+    let mut options = FindDataOptions {
+        class: None,
+        state: None,
+        name: None,
+        visibility: None,
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(dx_env.project_context_id.clone()),
+            folder: Some(folder),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Mapping(HashMap::from([
+            ("properties".to_string(), true),
+            ("details".to_string(), args.text.is_some()),
+        ]))),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+
+    if let Some(val) = &args.class {
+        options.class = Some(ObjectType::from_str(val)?);
+    }
+
+    debug!("{:#?}", &options);
+    let data = api::find_data(&dx_env, &mut options)?;
+
+    let mut records = vec![];
+    for desc in data.into_iter().filter_map(|row| row.describe) {
+        let found = match &args.text {
+            Some(text) => match search_object(&desc, text) {
+                Some(found) => found,
+                _ => continue,
+            },
+            _ => {
+                format!("properties.{}", args.key.clone().unwrap_or_default())
+            }
+        };
+
+        let folder = desc.folder.unwrap_or("".to_string());
+        let name = Path::new(&folder)
+            .join(desc.name.unwrap_or("".to_string()))
+            .display()
+            .to_string();
+        records.push(serde_json::json!({
+            "name": name,
+            "id": desc.id,
+            "match": found,
+        }));
+    }
+
+    if output_format() != OutputFormat::Table {
+        return print_records(&records, &["name", "id", "match"]);
+    }
+
+    if records.is_empty() {
+        println!("No matches");
+    } else {
+        let rows = records
+            .iter()
+            .map(|rec| {
+                vec![
+                    tsv_value(&rec["name"]),
+                    tsv_value(&rec["id"]),
+                    tsv_value(&rec["match"]),
+                ]
+            })
+            .collect();
+        print_table(
+            &make_table("{:<} {:<} {:<}", rows, 0, args.wide),
+            args.wide,
+        );
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// Where the text first appears in an object's properties or details
+fn search_object(desc: &FindDataDescribe, text: &str) -> Option<String> {
+    let text = text.to_lowercase();
+    if let Some(props) = &desc.properties {
+        let mut keys: Vec<_> = props.keys().collect();
+        keys.sort();
+        for key in keys {
+            if key.to_lowercase().contains(&text)
+                || props[key].to_lowercase().contains(&text)
+            {
+                return Some(format!("properties.{key}"));
+            }
+        }
+    }
+
+    desc.details
+        .as_ref()
+        .and_then(|details| search_json(details, &text, "details"))
+}
+
+// --------------------------------------------------
+// Path to the first key or value containing the lowercased text
+pub(crate) fn search_json(
+    val: &serde_json::Value,
+    text: &str,
+    path: &str,
+) -> Option<String> {
+    match val {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<_> = map.keys().collect();
+            keys.sort();
+            keys.into_iter().find_map(|key| {
+                let path = format!("{path}.{key}");
+                if key.to_lowercase().contains(text) {
+                    Some(path)
+                } else {
+                    search_json(&map[key], text, &path)
+                }
+            })
+        }
+        serde_json::Value::Array(vals) => {
+            vals.iter().enumerate().find_map(|(i, val)| {
+                search_json(val, text, &format!("{path}[{i}]"))
+            })
+        }
+        serde_json::Value::String(s) => {
+            s.to_lowercase().contains(text).then(|| path.to_string())
+        }
+        serde_json::Value::Null => None,
+        other => other.to_string().contains(text).then(|| path.to_string()),
+    }
+}
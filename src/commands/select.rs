@@ -0,0 +1,165 @@
+use crate::dxenv::{get_dx_env, save_dx_env, DxEnvironment};
+use crate::exit::CliError;
+use crate::{
+    api, entity_id, AccessLevel, FindName, FindProjectsDescribe,
+    FindProjectsOptions, FindProjectsResult, ProjectDescribeField,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use inquire::Select;
+use std::collections::HashMap;
+
+/// Number of recently selected projects shown first by "select"
+pub(crate) const MAX_RECENT_PROJECTS: usize = 10;
+
+const SELECT_PAGE_SIZE: usize = 15;
+
+#[derive(Parser, Clone, Debug)]
+pub struct SelectArgs {
+    /// Project ID or name
+    #[arg()]
+    pub(crate) project: Option<String>,
+
+    /// Access level
+    #[arg(long, value_enum, default_value = "CONTRIBUTE")]
+    pub(crate) level: Option<AccessLevel>,
+
+    /// Print the current project and exit
+    #[arg(long, default_value = "false", conflicts_with = "project")]
+    pub(crate) current: bool,
+}
+
+// --------------------------------------------------
+pub fn select_project(args: SelectArgs) -> Result<()> {
+    let level = &args.level.clone().or(Some(AccessLevel::Contribute));
+    let level_display = format!("{}", &level.as_ref().unwrap());
+    let dx_env = get_dx_env()?;
+
+    if args.current {
+        if dx_env.project_context_id.is_empty() {
+            bail!(CliError::Usage("No project selected".to_string()))
+        }
+        println!(
+            "{} ({})",
+            dx_env.project_context_name, dx_env.project_context_id
+        );
+        return Ok(());
+    }
+    let fields = HashMap::from([(ProjectDescribeField::Name, true)]);
+
+    let mut options = FindProjectsOptions {
+        name: None,
+        id: vec![],
+        level: level.clone(),
+        bill_to: None,
+        starting: None,
+        describe: Some(FindProjectsDescribe { fields }),
+    };
+
+    if let Some(project) = &args.project {
+        if entity_id::PROJECT_ID.is_match(project) {
+            options.id = vec![project.clone()];
+        } else {
+            options.name = Some(FindName::Regexp(project.clone()));
+        }
+    } else {
+        options.name = Some(FindName::Glob("*".to_string()))
+    }
+
+    let projects = api::find_projects(&dx_env, options)?;
+    let num_projects = projects.len();
+
+    let mut lookup: HashMap<String, FindProjectsResult> = HashMap::new();
+    for project in projects {
+        let display = format!(
+            "{} ({}) [{}]",
+            project.describe.get("name").unwrap_or(&"NA".to_string()),
+            project.id,
+            project.level
+        );
+        lookup.insert(display, project);
+    }
+
+    let mut choices: Vec<&String> = lookup.keys().collect();
+    choices.sort();
+
+    // Recently selected projects go on top, newest first
+    let recent: Vec<&String> = dx_env
+        .recent_projects
+        .iter()
+        .filter_map(|id| {
+            lookup
+                .iter()
+                .find(|(_, project)| &project.id == id)
+                .map(|(key, _)| key)
+        })
+        .collect();
+    choices.retain(|key| !recent.contains(key));
+    let mut choices = [recent, choices].concat();
+
+    let selected = if num_projects == 1 {
+        Ok(choices.pop().unwrap())
+    } else {
+        let prompt = format!(
+            "{} available project{} ({} or higher)",
+            num_projects,
+            if num_projects == 1 { "" } else { "s" },
+            level_display,
+        );
+
+        Select::new(&prompt, choices)
+            .with_filter(&|input, _, choice, _| fuzzy_match(input, choice))
+            .with_page_size(SELECT_PAGE_SIZE)
+            .prompt()
+    };
+
+    match selected {
+        Ok(key) => {
+            let project = lookup.get(key).unwrap();
+            let name = project.describe.get("name").unwrap().clone();
+            let dx_env = get_dx_env()?;
+            let new_env = DxEnvironment {
+                project_context_id: project.id.clone(),
+                project_context_name: name.clone(),
+                recent_projects: remember_project(
+                    &dx_env.recent_projects,
+                    &project.id,
+                ),
+                ..dx_env
+            };
+            save_dx_env(&new_env)?;
+            println!("Selected project \"{}\"", project.id);
+            Ok(())
+        }
+        _ => bail!("Failed to select project"),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn remember_project(
+    recent: &[String],
+    project_id: &str,
+) -> Vec<String> {
+    std::iter::once(project_id)
+        .chain(
+            recent
+                .iter()
+                .map(String::as_str)
+                .filter(|id| *id != project_id),
+        )
+        .take(MAX_RECENT_PROJECTS)
+        .map(String::from)
+        .collect()
+}
+
+// --------------------------------------------------
+// Case-insensitive match of the pattern's characters in order, not
+// necessarily adjacent, e.g., "rnsq" matches "RNA-seq"
+pub(crate) fn fuzzy_match(pattern: &str, text: &str) -> bool {
+    let mut chars = text.chars().flat_map(char::to_lowercase);
+    pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .all(|p| chars.any(|c| c == p))
+}
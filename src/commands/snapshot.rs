@@ -0,0 +1,168 @@
+use crate::dxenv::get_dx_env;
+use crate::{
+    api, object_class, write_formatted, FindDataDescribe, FindDataOptions,
+    FindDataScope, FindDescribe, ProjectDescribeField, ProjectDescribeOptions,
+    Visibility,
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Clone, Parser, Debug)]
+pub struct SnapshotArgs {
+    /// Project ID, defaults to the current project
+    #[arg()]
+    project: Option<String>,
+
+    /// Output file, "-" for STDOUT
+    #[arg(short, long, value_name = "FILE", default_value = "-")]
+    output: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ProjectSnapshot {
+    pub(crate) project: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    created: DateTime<Utc>,
+
+    pub(crate) folders: Vec<String>,
+
+    pub(crate) objects: Vec<SnapshotObject>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SnapshotObject {
+    pub(crate) id: String,
+
+    pub(crate) name: String,
+
+    pub(crate) folder: String,
+
+    pub(crate) class: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<String>,
+
+    #[serde(default)]
+    pub(crate) hidden: bool,
+
+    #[serde(default)]
+    pub(crate) tags: Vec<String>,
+
+    #[serde(default)]
+    pub(crate) properties: BTreeMap<String, String>,
+}
+
+// --------------------------------------------------
+pub fn snapshot(args: SnapshotArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let project_id = args.project.unwrap_or(dx_env.project_context_id.clone());
+
+    let desc_opts = ProjectDescribeOptions {
+        fields: Some(HashMap::from([
+            (ProjectDescribeField::Name, true),
+            (ProjectDescribeField::Folders, true),
+        ])),
+    };
+    let project = api::describe_project(&dx_env, &project_id, &desc_opts)?;
+
+    let mut options = FindDataOptions {
+        class: None,
+        state: None,
+        name: None,
+        visibility: Some(Visibility::Either),
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(project_id.clone()),
+            folder: Some("/".to_string()),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Mapping(HashMap::from([(
+            "properties".to_string(),
+            true,
+        )]))),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+    let found = api::find_data(&dx_env, &mut options)?;
+
+    let snapshot = project_snapshot(
+        &project_id,
+        project.name,
+        project.folders.unwrap_or_default(),
+        found.into_iter().filter_map(|obj| obj.describe).collect(),
+        Utc::now(),
+    );
+    write_formatted(&serde_json::to_string_pretty(&snapshot)?, &args.output)?;
+
+    if args.output != "-" {
+        note!(
+            r#"Saved {} folder{} and {} object{} to "{}""#,
+            snapshot.folders.len(),
+            if snapshot.folders.len() == 1 { "" } else { "s" },
+            snapshot.objects.len(),
+            if snapshot.objects.len() == 1 { "" } else { "s" },
+            args.output
+        );
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// Folders and objects are sorted so that snapshots of the same project
+// can be compared with "diff"
+pub(crate) fn project_snapshot(
+    project_id: &str,
+    name: Option<String>,
+    mut folders: Vec<String>,
+    objects: Vec<FindDataDescribe>,
+    created: DateTime<Utc>,
+) -> ProjectSnapshot {
+    folders.sort();
+    folders.dedup();
+
+    let mut objects: Vec<SnapshotObject> = objects
+        .into_iter()
+        .map(|desc| SnapshotObject {
+            class: object_class(&desc.id),
+            name: desc.name.unwrap_or_default(),
+            folder: desc.folder.unwrap_or("/".to_string()),
+            state: desc.state.map(|state| state.to_string()),
+            hidden: desc.hidden.unwrap_or_default(),
+            tags: desc.tags,
+            properties: desc
+                .properties
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            id: desc.id,
+        })
+        .collect();
+    objects.sort_by(|a, b| {
+        (&a.folder, &a.name, &a.id).cmp(&(&b.folder, &b.name, &b.id))
+    });
+
+    ProjectSnapshot {
+        project: project_id.to_string(),
+        name,
+        created,
+        folders,
+        objects,
+    }
+}
@@ -0,0 +1,99 @@
+use crate::dxenv::get_dx_env;
+use crate::exit::CliError;
+use crate::{api, entity_id, JobDescribeField, JobDescribeOptions};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use log::debug;
+use std::{collections::HashMap, fs, path::PathBuf, process};
+
+#[derive(Clone, Parser, Debug)]
+pub struct SshArgs {
+    /// Job ID
+    #[arg()]
+    job_id: String,
+
+    /// Private key file passed to ssh
+    #[arg(short, long, value_name = "FILE")]
+    identity: Option<PathBuf>,
+
+    /// Extra arguments for ssh, e.g., "-- -L 8080:localhost:8080"
+    #[arg(last = true)]
+    ssh_args: Vec<String>,
+}
+
+// --------------------------------------------------
+pub fn ssh(args: SshArgs) -> Result<()> {
+    if !entity_id::JOB_ID.is_match(&args.job_id) {
+        bail!(CliError::Usage(format!(
+            r#""{}" is not a job ID"#,
+            args.job_id
+        )))
+    }
+
+    let dx_env = get_dx_env()?;
+    let options = JobDescribeOptions {
+        default_fields: None,
+        fields: Some(HashMap::from([
+            (JobDescribeField::State, true),
+            (JobDescribeField::Host, true),
+            (JobDescribeField::SshPort, true),
+            (JobDescribeField::SshHostKey, true),
+        ])),
+        try_number: None,
+    };
+    let job = api::describe_job(&dx_env, &args.job_id, &options)?;
+
+    let state = job.state.unwrap_or_default();
+    if state != "running" {
+        bail!("Job {} is {state}, not running", args.job_id)
+    }
+
+    let (host, port, host_key) =
+        match (job.host, job.ssh_port, job.ssh_host_key) {
+            (Some(host), Some(port), Some(key)) => (host, port, key),
+            _ => bail!(
+                "Job {} does not allow SSH, use \"run --allow-ssh\"",
+                args.job_id
+            ),
+        };
+
+    // Only accept the host key the platform reports for this job
+    let known_hosts = tempfile::NamedTempFile::new()?;
+    fs::write(
+        known_hosts.path(),
+        known_hosts_line(&host, &port, &host_key),
+    )?;
+
+    let mut cmd = process::Command::new("ssh");
+    cmd.args(["-p", &port])
+        .arg("-o")
+        .arg(format!(
+            "UserKnownHostsFile={}",
+            known_hosts.path().display()
+        ))
+        .args(["-o", "StrictHostKeyChecking=yes"]);
+    if let Some(identity) = &args.identity {
+        cmd.arg("-i").arg(identity);
+    }
+    cmd.args(&args.ssh_args).arg(format!("dnanexus@{host}"));
+
+    debug!("{:?}", &cmd);
+    let status = cmd.status().map_err(|e| anyhow!("Cannot run ssh: {e}"))?;
+    if !status.success() {
+        bail!("ssh exited with {status}")
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+pub(crate) fn known_hosts_line(
+    host: &str,
+    port: &str,
+    host_key: &str,
+) -> String {
+    if port == "22" {
+        format!("{host} {}\n", host_key.trim())
+    } else {
+        format!("[{host}]:{port} {}\n", host_key.trim())
+    }
+}
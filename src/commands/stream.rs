@@ -0,0 +1,124 @@
+use crate::FileDescribeResult;
+use anyhow::Result;
+use std::{
+    io::{self, Read},
+    sync::mpsc,
+};
+
+pub(crate) const MD5_READ_CHUNK_SIZE: usize = 1024 * 1024 * 4;
+
+/// Archives are streamed in chunks of this size, with at most this many
+/// waiting to be sent
+pub(crate) const ARCHIVE_CHUNK_SIZE: usize = 1024 * 1024;
+
+pub(crate) const ARCHIVE_CHUNKS: usize = 8;
+
+// Sends what is written to a "ChunkReader" on another thread
+pub(crate) struct ChunkWriter(pub(crate) mpsc::SyncSender<io::Result<Vec<u8>>>);
+
+impl io::Write for ChunkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .send(Ok(buf.to_vec()))
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Reads chunks until the writer hangs up, failing rather than ending
+// early if the writer sent an error, so a partial archive is not closed
+pub(crate) struct ChunkReader {
+    pub(crate) chunks: mpsc::Receiver<io::Result<Vec<u8>>>,
+    pub(crate) chunk: io::Cursor<Vec<u8>>,
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let bytes_read = self.chunk.read(buf)?;
+            if bytes_read > 0 || buf.is_empty() {
+                return Ok(bytes_read);
+            }
+            match self.chunks.recv() {
+                Ok(chunk) => self.chunk = io::Cursor::new(chunk?),
+                _ => return Ok(0),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+// Fills the buffer unless the stream ends first, as pipes and STDIN
+// return short reads
+pub(crate) fn read_part(
+    reader: &mut impl Read,
+    buffer: &mut [u8],
+) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(filled)
+}
+
+// --------------------------------------------------
+// The platform keeps no whole-file checksum, but some uploaders set
+// an "md5" property, and a single part covers the whole file
+pub(crate) fn remote_md5(desc: &FileDescribeResult) -> Option<String> {
+    desc.properties
+        .as_ref()
+        .and_then(|props| props.get("md5").cloned())
+        .or_else(|| match &desc.parts {
+            Some(parts) if parts.len() == 1 => {
+                parts.values().next().and_then(|part| part.md5.clone())
+            }
+            _ => None,
+        })
+}
+
+// --------------------------------------------------
+// The MD5 of the whole stream and of each consecutive part of the given
+// sizes, reading MD5_READ_CHUNK_SIZE bytes at a time
+pub(crate) fn local_md5s(
+    mut reader: impl Read,
+    part_sizes: &[u64],
+) -> Result<(String, Vec<String>)> {
+    let mut buffer = vec![0; MD5_READ_CHUNK_SIZE];
+    let mut whole = md5::Context::new();
+    let mut part_md5s = vec![];
+
+    for part_size in part_sizes {
+        let mut part = md5::Context::new();
+        let mut remaining = *part_size;
+        while remaining > 0 {
+            let want = remaining.min(buffer.len() as u64) as usize;
+            let bytes_read = read_part(&mut reader, &mut buffer[..want])?;
+            if bytes_read == 0 {
+                break;
+            }
+            part.consume(&buffer[..bytes_read]);
+            whole.consume(&buffer[..bytes_read]);
+            remaining -= bytes_read as u64;
+        }
+        part_md5s.push(format!("{:x}", part.compute()));
+    }
+
+    loop {
+        let bytes_read = read_part(&mut reader, &mut buffer)?;
+        if bytes_read == 0 {
+            break;
+        }
+        whole.consume(&buffer[..bytes_read]);
+    }
+
+    Ok((format!("{:x}", whole.compute()), part_md5s))
+}
@@ -0,0 +1,496 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::exit::CliError;
+use crate::{
+    api, local_md5s, remote_md5, resolve_path, upload_local_file,
+    DownloadOptions, FileDescribeField, FileDescribeOptions, FilePart,
+    FindDataOptions, FindDataScope, FindDescribe, ObjectState, ObjectType,
+    ProgressFormat, ProjectPath, RmOptions, UploadProgress, Visibility,
+    FOLDER_WALK_THREADS,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::{builder::PossibleValue, Parser, ValueEnum};
+use inquire::Confirm;
+use rayon::prelude::*;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+// Downloads in progress, which the local scan ignores
+const SYNC_TMP_SUFFIX: &str = ".dxrs-sync";
+
+#[derive(Clone, Parser, Debug)]
+pub struct SyncArgs {
+    /// Local directory
+    #[arg(value_name = "LOCAL")]
+    local: PathBuf,
+
+    /// Remote folder, e.g., "project-xxxx:/data"
+    #[arg(value_name = "REMOTE")]
+    remote: String,
+
+    /// Copy local changes up to the remote or remote changes down
+    #[arg(long, value_name = "DIRECTION", default_value = "up")]
+    direction: SyncDirection,
+
+    /// Show the changes without making them
+    #[arg(short = 'n', long, default_value = "false")]
+    dry_run: bool,
+
+    /// Remove files that are missing from the source
+    #[arg(long, default_value = "false")]
+    delete: bool,
+
+    /// Compare the MD5 of files with the same size
+    #[arg(short, long, default_value = "false")]
+    checksum: bool,
+
+    /// Remove files without confirmation
+    #[arg(short, long, default_value = "false")]
+    yes: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyncDirection {
+    Up,
+    Down,
+}
+
+impl ValueEnum for SyncDirection {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[SyncDirection::Up, SyncDirection::Down]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            SyncDirection::Up => PossibleValue::new("up"),
+            SyncDirection::Down => PossibleValue::new("down"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SyncOp {
+    Add,
+    Update,
+    Delete,
+}
+
+impl fmt::Display for SyncOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self {
+            SyncOp::Add => "add",
+            SyncOp::Update => "update",
+            SyncOp::Delete => "delete",
+        };
+        write!(f, "{op}")
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct SyncAction {
+    pub(crate) op: SyncOp,
+
+    // Relative to the local directory and the remote folder
+    pub(crate) path: String,
+}
+
+#[derive(Debug, Clone)]
+struct SyncRemoteFile {
+    id: String,
+
+    size: u64,
+}
+
+// --------------------------------------------------
+pub fn sync(args: SyncArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let dx_path = resolve_path(&dx_env, &args.remote)?;
+    let project_id = dx_path.project_id.clone();
+    let remote_root = PathBuf::from(&dx_path.path);
+
+    if args.local.exists() && !args.local.is_dir() {
+        bail!(r#""{}" is not a directory"#, args.local.display())
+    } else if !args.local.exists() && args.direction == SyncDirection::Up {
+        bail!(CliError::NotFound(format!(
+            r#""{}" does not exist"#,
+            args.local.display()
+        )))
+    }
+
+    let local_files = sync_local_files(&args.local);
+    let remote_files = sync_remote_files(&dx_env, &project_id, &dx_path.path)?;
+    let remote_sizes: BTreeMap<String, u64> = remote_files
+        .iter()
+        .map(|(path, file)| (path.clone(), file.size))
+        .collect();
+
+    // Only files that match by size need their content compared
+    let mut changed = BTreeSet::new();
+    if args.checksum {
+        let same_size: Vec<(&String, &SyncRemoteFile)> = remote_files
+            .iter()
+            .filter(|(path, file)| local_files.get(*path) == Some(&file.size))
+            .collect();
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(FOLDER_WALK_THREADS)
+            .build()?;
+        let results: Vec<(String, Result<bool>)> = pool.install(|| {
+            same_size
+                .par_iter()
+                .map(|(path, file)| {
+                    (
+                        path.to_string(),
+                        sync_same_content(
+                            &dx_env,
+                            &project_id,
+                            &file.id,
+                            &args.local.join(path),
+                        ),
+                    )
+                })
+                .collect()
+        });
+        for (path, result) in results {
+            match result {
+                Ok(true) => (),
+                Ok(false) => {
+                    changed.insert(path);
+                }
+                Err(e) => eprintln!("{path}: {e}"),
+            }
+        }
+    }
+
+    let (source, dest) = match args.direction {
+        SyncDirection::Up => (&local_files, &remote_sizes),
+        SyncDirection::Down => (&remote_sizes, &local_files),
+    };
+    let actions = sync_plan(source, dest, &changed, args.delete);
+
+    if actions.is_empty() {
+        note!("Already in sync");
+        return Ok(());
+    }
+
+    if args.dry_run {
+        for action in &actions {
+            println!("{} {}", action.op, action.path);
+        }
+        note!("Dry run, no changes made");
+        return Ok(());
+    }
+
+    let num_delete = actions.iter().filter(|a| a.op == SyncOp::Delete).count();
+    let delete_consent = num_delete == 0
+        || args.yes
+        || Confirm::new(&format!(
+            "Delete {num_delete} file{}?",
+            if num_delete == 1 { "" } else { "s" }
+        ))
+        .with_default(false)
+        .prompt()
+        .unwrap_or(false);
+    if !delete_consent {
+        note!(
+            "Skipped deleting {num_delete} file{}",
+            if num_delete == 1 { "" } else { "s" }
+        );
+    }
+
+    let progress = UploadProgress::new(ProgressFormat::Bar, 0, 1);
+    let (mut num_ok, mut num_failed) = (0, 0);
+    let (mut remote_removals, mut num_remote_deletes) = (vec![], 0);
+    for action in &actions {
+        if action.op == SyncOp::Delete && !delete_consent {
+            continue;
+        }
+
+        let local_path = args.local.join(&action.path);
+        let remote = remote_files.get(&action.path);
+        let result = match (args.direction, action.op) {
+            // Counted only once the removal succeeds
+            (SyncDirection::Up, SyncOp::Delete) => {
+                if let Some(file) = remote {
+                    remote_removals.push(file.id.clone());
+                    num_remote_deletes += 1;
+                }
+                continue;
+            }
+            (SyncDirection::Up, _) => {
+                let rel_dir =
+                    Path::new(&action.path).parent().unwrap_or(Path::new(""));
+                let destination = ProjectPath {
+                    project_id: project_id.clone(),
+                    path: remote_root.join(rel_dir),
+                };
+                upload_local_file(
+                    &dx_env,
+                    &local_path.display().to_string(),
+                    &destination,
+                    None,
+                    &[],
+                    &progress,
+                    false,
+                )
+                .map(|_| {
+                    // The old copy goes only once its replacement exists
+                    remote_removals.extend(remote.map(|file| file.id.clone()))
+                })
+            }
+            (SyncDirection::Down, SyncOp::Delete) => {
+                fs::remove_file(&local_path).map_err(|e| anyhow!(e))
+            }
+            (SyncDirection::Down, _) => match remote {
+                Some(file) => sync_download(&dx_env, &file.id, &local_path),
+                _ => Err(anyhow!("No remote file")),
+            },
+        };
+
+        match result {
+            Ok(_) => num_ok += 1,
+            Err(e) => {
+                eprintln!("{}: {e}", action.path);
+                num_failed += 1;
+            }
+        }
+    }
+
+    if !remote_removals.is_empty() {
+        let options = RmOptions {
+            objects: remote_removals,
+            force: Some(true),
+        };
+        match api::rm(&dx_env, &project_id, &options) {
+            Ok(_) => num_ok += num_remote_deletes,
+            Err(e) => {
+                eprintln!("Failed to remove remote files: {e}");
+                num_failed += num_remote_deletes;
+            }
+        }
+    }
+
+    note!(
+        "Synced {num_ok} file{}{}",
+        if num_ok == 1 { "" } else { "s" },
+        if num_failed > 0 {
+            format!(", {num_failed} failed")
+        } else {
+            "".to_string()
+        }
+    );
+
+    if num_failed > 0 {
+        bail!("Sync incomplete")
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// Paths are relative to the root and use "/" to match remote folders
+pub(crate) fn sync_local_files(root: &Path) -> BTreeMap<String, u64> {
+    walkdir::WalkDir::new(root)
+        .into_iter()
+        .flatten()
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && !entry
+                    .file_name()
+                    .to_string_lossy()
+                    .ends_with(SYNC_TMP_SUFFIX)
+        })
+        .filter_map(|entry| {
+            let rel = entry.path().strip_prefix(root).ok()?;
+            let path = rel
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+                .join("/");
+            let size = entry.metadata().ok()?.len();
+            Some((path, size))
+        })
+        .collect()
+}
+
+// --------------------------------------------------
+fn sync_remote_files(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    folder: &str,
+) -> Result<BTreeMap<String, SyncRemoteFile>> {
+    let mut options = FindDataOptions {
+        class: Some(ObjectType::File),
+        state: Some(ObjectState::Closed),
+        name: None,
+        visibility: Some(Visibility::Visible),
+        id: vec![],
+        object_type: None,
+        tags: vec![],
+        region: vec![],
+        properties: None,
+        link: None,
+        scope: Some(FindDataScope {
+            project: Some(project_id.to_string()),
+            folder: Some(folder.to_string()),
+            recurse: Some(true),
+        }),
+        sort_by: None,
+        level: None,
+        modified: None,
+        created: None,
+        describe: Some(FindDescribe::Boolean(true)),
+        starting: None,
+        limit: None,
+        archival_state: None,
+    };
+
+    let mut files = BTreeMap::new();
+    for desc in api::find_data(dx_env, &mut options)?
+        .into_iter()
+        .filter_map(|file| file.describe)
+    {
+        let file_folder = desc.folder.unwrap_or("/".to_string());
+        let rel_folder = Path::new(&file_folder)
+            .strip_prefix(folder)
+            .unwrap_or(Path::new(""))
+            .display()
+            .to_string();
+        let name = desc.name.unwrap_or_default();
+        let path = if rel_folder.is_empty() {
+            name
+        } else {
+            format!("{rel_folder}/{name}")
+        };
+
+        // Names are not unique remotely, so only the first one syncs
+        if files.contains_key(&path) {
+            eprintln!(
+                r#"Skipping duplicate remote file "{path}" ({})"#,
+                desc.id
+            );
+            continue;
+        }
+        files.insert(
+            path,
+            SyncRemoteFile {
+                id: desc.id,
+                size: desc.size.unwrap_or_default(),
+            },
+        );
+    }
+
+    Ok(files)
+}
+
+// --------------------------------------------------
+// Compares the whole-file MD5 when the platform has one, else the MD5
+// of each part
+fn sync_same_content(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    file_id: &str,
+    local_path: &Path,
+) -> Result<bool> {
+    let options = FileDescribeOptions {
+        project: Some(project_id.to_string()),
+        fields: Some(HashMap::from([
+            (FileDescribeField::Properties, true),
+            (FileDescribeField::Parts, true),
+        ])),
+        properties: true,
+        details: false,
+    };
+    let desc = api::describe_file(dx_env, file_id, &options)?;
+
+    if let Some(md5) = remote_md5(&desc) {
+        let (local, _) = local_md5s(File::open(local_path)?, &[])?;
+        return Ok(local == md5);
+    }
+
+    let mut parts: Vec<(u64, FilePart)> = desc
+        .parts
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(index, part)| index.parse().ok().map(|i| (i, part)))
+        .collect();
+    parts.sort_by_key(|(index, _)| *index);
+    let part_sizes: Vec<u64> = parts
+        .iter()
+        .map(|(_, part)| part.size.unwrap_or(0))
+        .collect();
+    let remote_md5s: Vec<Option<String>> =
+        parts.into_iter().map(|(_, part)| part.md5).collect();
+    if remote_md5s.is_empty() || remote_md5s.iter().any(|m| m.is_none()) {
+        bail!("No checksum to compare")
+    }
+
+    let (_, local) = local_md5s(File::open(local_path)?, &part_sizes)?;
+    Ok(local.into_iter().map(Some).collect::<Vec<_>>() == remote_md5s)
+}
+
+// --------------------------------------------------
+// Writes to a temporary file beside the target so an interrupted
+// download never leaves a partial file that would look current on the
+// next sync
+fn sync_download(
+    dx_env: &DxEnvironment,
+    file_id: &str,
+    local_path: &Path,
+) -> Result<()> {
+    let dir = local_path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(dir)?;
+    let download = api::download(dx_env, file_id, &DownloadOptions::default())?;
+    let tmp = tempfile::Builder::new()
+        .prefix(".")
+        .suffix(SYNC_TMP_SUFFIX)
+        .tempfile_in(dir)?;
+    api::download_file(
+        &download,
+        tmp.as_file(),
+        &local_path.display().to_string(),
+        ProgressFormat::Bar,
+    )?;
+    tmp.persist(local_path)?;
+    Ok(())
+}
+
+// --------------------------------------------------
+// Files missing from the destination are added, those with a different
+// size or checksum are updated, and extras are deleted only on request
+pub(crate) fn sync_plan(
+    source: &BTreeMap<String, u64>,
+    dest: &BTreeMap<String, u64>,
+    changed: &BTreeSet<String>,
+    delete: bool,
+) -> Vec<SyncAction> {
+    let mut actions: Vec<SyncAction> = source
+        .iter()
+        .filter_map(|(path, size)| {
+            let op = match dest.get(path) {
+                None => Some(SyncOp::Add),
+                Some(dest_size) if dest_size != size => Some(SyncOp::Update),
+                _ if changed.contains(path) => Some(SyncOp::Update),
+                _ => None,
+            };
+            op.map(|op| SyncAction {
+                op,
+                path: path.clone(),
+            })
+        })
+        .collect();
+
+    if delete {
+        actions.extend(
+            dest.keys().filter(|path| !source.contains_key(*path)).map(
+                |path| SyncAction {
+                    op: SyncOp::Delete,
+                    path: path.clone(),
+                },
+            ),
+        );
+    }
+
+    actions
+}
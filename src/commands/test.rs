@@ -0,0 +1,389 @@
+use crate::exit::CliError;
+use crate::json_parser::{DxApp, InputOutputClass, InputSpec, Interpreter};
+use crate::{json_parser, shell_quote};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use log::debug;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+const LOCAL_JOB_HOME: &str = "/home/dnanexus";
+
+const LOCAL_SHIM_DIR: &str = "/usr/local/dxrs/bin";
+
+// Stand-ins for the dx commands used by wizard-generated bash applets
+const LOCAL_DX_SHIM: &str = r#"#!/usr/bin/env bash
+set -e
+cmd=$1
+shift
+case "$cmd" in
+    download)
+        src=$1
+        shift
+        dest=.
+        while [ $# -gt 0 ]; do
+            case "$1" in
+                -o|--output) dest=$2; shift 2 ;;
+                *) shift ;;
+            esac
+        done
+        cp -r "$src" "$dest"
+        ;;
+    upload)
+        mkdir -p "$HOME/out"
+        cp -r "$1" "$HOME/out/"
+        echo "$HOME/out/$(basename "$1")"
+        ;;
+    *)
+        echo "dx $cmd is not available when testing locally" >&2
+        exit 1
+        ;;
+esac
+"#;
+
+const LOCAL_ADD_OUTPUT_SHIM: &str = r#"#!/usr/bin/env bash
+set -e
+name=$1
+shift
+class=string
+values=()
+while [ $# -gt 0 ]; do
+    case "$1" in
+        --class=*) class=${1#--class=}; shift ;;
+        --class) class=$2; shift 2 ;;
+        *) values+=("$1"); shift ;;
+    esac
+done
+for value in "${values[@]}"; do
+    printf '%s\t%s\t%s\n' "$name" "$class" "$value" \
+        >> "$HOME/.job_outputs.tsv"
+done
+"#;
+
+#[derive(Clone, Parser, Debug)]
+pub struct TestArgs {
+    /// Applet source directory
+    #[arg(default_value = ".")]
+    src_dir: PathBuf,
+
+    /// Input as NAME=VALUE, file paths are from the source directory
+    #[arg(short, long, value_name = "NAME=VALUE")]
+    input: Vec<String>,
+
+    /// JSON file of inputs, using local file paths
+    #[arg(short('j'), long, value_name = "FILE")]
+    input_json: Option<PathBuf>,
+
+    /// Docker image, defaults to Ubuntu matching the run spec
+    #[arg(long)]
+    image: Option<String>,
+
+    /// Write the job outputs to this file
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Keep the job's home directory here instead of a temp directory
+    #[arg(long, value_name = "DIR")]
+    workdir: Option<PathBuf>,
+}
+
+// --------------------------------------------------
+pub fn test(args: TestArgs) -> Result<()> {
+    let dxapp = args.src_dir.join("dxapp.json");
+    let app: DxApp = json_parser::parse(&dxapp.display().to_string())?;
+    let run_file = app
+        .run_spec
+        .file
+        .clone()
+        .ok_or(anyhow!(r#"No runSpec file in "{}""#, dxapp.display()))?;
+
+    let mut input = match &args.input_json {
+        Some(filename) => {
+            match serde_json::from_str(&fs::read_to_string(filename)?)? {
+                serde_json::Value::Object(map) => map,
+                _ => {
+                    bail!(r#""{}" is not a JSON object"#, filename.display())
+                }
+            }
+        }
+        _ => serde_json::Map::new(),
+    };
+    for arg in &args.input {
+        let (name, val) = arg
+            .split_once('=')
+            .ok_or(anyhow!(r#"Input "{arg}" must be NAME=VALUE"#))?;
+        input.insert(
+            name.to_string(),
+            serde_json::from_str(val)
+                .unwrap_or(serde_json::Value::String(val.to_string())),
+        );
+    }
+
+    let inputs = stage_local_inputs(&app.input_spec, &input, &args.src_dir)?;
+
+    let tmp_dir = tempfile::tempdir()?;
+    let home = match &args.workdir {
+        Some(dir) => dir.clone(),
+        _ => tmp_dir.path().join("home"),
+    };
+    let shims = tmp_dir.path().join("bin");
+    fs::create_dir_all(&home)?;
+    fs::create_dir_all(&shims)?;
+    fs::write(shims.join("dx"), LOCAL_DX_SHIM)?;
+    fs::write(shims.join("dx-jobutil-add-output"), LOCAL_ADD_OUTPUT_SHIM)?;
+
+    for (src, dest) in &inputs.files {
+        if !src.is_file() {
+            bail!(CliError::NotFound(format!(
+                r#"Input file "{}" does not exist"#,
+                src.display()
+            )));
+        }
+        let dest = home.join(dest);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(src, dest)?;
+    }
+    fs::write(
+        home.join("job_input.json"),
+        serde_json::to_string_pretty(&inputs.job_input)?,
+    )?;
+    fs::write(home.join(".inputs.sh"), &inputs.script)?;
+
+    let release = app
+        .run_spec
+        .release
+        .as_ref()
+        .map_or("20.04".to_string(), |r| r.to_string());
+    let image = args.image.clone().unwrap_or(format!("ubuntu:{release}"));
+    let entry = match app.run_spec.interpreter {
+        Some(Interpreter::Bash) => format!(
+            "source {LOCAL_JOB_HOME}/.inputs.sh; source /app/{run_file}; main"
+        ),
+        _ => format!("python3 /app/{run_file}"),
+    };
+    let script = format!(
+        "set -e; chmod +x {LOCAL_SHIM_DIR}/*; \
+        if [ -d /app/resources ]; then cp -a /app/resources/. /; fi; \
+        cd {LOCAL_JOB_HOME}; {entry}"
+    );
+
+    let mut cmd = process::Command::new("docker");
+    cmd.args(["run", "--rm"])
+        .arg("-v")
+        .arg(format!(
+            "{}:/app:ro",
+            args.src_dir.canonicalize()?.display()
+        ))
+        .arg("-v")
+        .arg(format!(
+            "{}:{LOCAL_JOB_HOME}",
+            home.canonicalize()?.display()
+        ))
+        .arg("-v")
+        .arg(format!("{}:{LOCAL_SHIM_DIR}", shims.display()))
+        .args(["-e", &format!("HOME={LOCAL_JOB_HOME}")])
+        .args([
+            "-e",
+            &format!(
+                "PATH={LOCAL_SHIM_DIR}:/usr/local/sbin:/usr/local/bin:\
+                /usr/sbin:/usr/bin:/sbin:/bin"
+            ),
+        ])
+        .args(["-w", LOCAL_JOB_HOME])
+        .arg(&image)
+        .args(["bash", "-o", "pipefail", "-c", &script]);
+
+    println!("Running {} in {image}", app.name.unwrap_or_default());
+    debug!("{:?}", &cmd);
+    let status = cmd
+        .status()
+        .map_err(|e| anyhow!("Failed to run docker: {e}"))?;
+    if !status.success() {
+        bail!("Applet exited with {status}");
+    }
+
+    // Python applets write their own outputs, bash ones use the shim
+    let outputs = match fs::read_to_string(home.join(".job_outputs.tsv")) {
+        Ok(tsv) => serde_json::Value::Object(local_job_outputs(&tsv)?),
+        _ => match fs::read_to_string(home.join("job_output.json")) {
+            Ok(json) => serde_json::from_str(&json)?,
+            _ => serde_json::json!({}),
+        },
+    };
+    let outputs = serde_json::to_string_pretty(&outputs)?;
+    match &args.output {
+        Some(filename) => fs::write(filename, format!("{outputs}\n"))?,
+        _ => println!("{outputs}"),
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct LocalInputs {
+    pub(crate) files: Vec<(PathBuf, String)>,
+
+    pub(crate) job_input: serde_json::Map<String, serde_json::Value>,
+
+    pub(crate) script: String,
+}
+
+// --------------------------------------------------
+// Lay out inputs the way a job sees them: files under "in/NAME",
+// a job_input.json, and bash variables like "NAME_path"
+pub(crate) fn stage_local_inputs(
+    spec: &[InputSpec],
+    input: &serde_json::Map<String, serde_json::Value>,
+    src_dir: &Path,
+) -> Result<LocalInputs> {
+    if let Some(name) = input
+        .keys()
+        .find(|name| !spec.iter().any(|s| &s.name == *name))
+    {
+        bail!(CliError::Usage(format!(r#"Unknown input "{name}""#)));
+    }
+
+    let mut inputs = LocalInputs {
+        files: vec![],
+        job_input: serde_json::Map::new(),
+        script: String::new(),
+    };
+
+    for param in spec {
+        let value = match input.get(&param.name).or(param.default.as_ref()) {
+            Some(value) => value,
+            _ if param.optional.unwrap_or(false) => continue,
+            _ => bail!(r#"Missing required input "{}""#, param.name),
+        };
+
+        let name = &param.name;
+        match param.class {
+            InputOutputClass::File | InputOutputClass::ArrayFile => {
+                let paths = match value {
+                    serde_json::Value::String(path) => vec![path.as_str()],
+                    serde_json::Value::Array(vals) => vals
+                        .iter()
+                        .map(|v| {
+                            v.as_str().ok_or(anyhow!(
+                                r#"Input "{name}" must be local file paths"#
+                            ))
+                        })
+                        .collect::<Result<_>>()?,
+                    _ => bail!(r#"Input "{name}" must be a local file path"#),
+                };
+                let single = param.class == InputOutputClass::File;
+                if single && paths.len() != 1 {
+                    bail!(r#"Input "{name}" takes one file"#);
+                }
+
+                let mut vars: [Vec<String>; 4] = Default::default();
+                let mut links = vec![];
+                for (i, path) in paths.iter().enumerate() {
+                    let src = src_dir.join(path);
+                    let basename =
+                        src.file_name().map_or(path.to_string(), |n| {
+                            n.to_string_lossy().to_string()
+                        });
+                    let dest = if single {
+                        format!("in/{name}/{basename}")
+                    } else {
+                        format!("in/{name}/{i}/{basename}")
+                    };
+                    let local = format!("{LOCAL_JOB_HOME}/{dest}");
+                    let prefix = basename
+                        .split_once('.')
+                        .map_or(basename.clone(), |(pre, _)| pre.to_string());
+                    links.push(serde_json::json!({ "$dnanexus_link": local }));
+                    vars[0].push(local.clone());
+                    vars[1].push(local);
+                    vars[2].push(basename);
+                    vars[3].push(prefix);
+                    inputs.files.push((src, dest));
+                }
+
+                inputs.job_input.insert(
+                    name.clone(),
+                    if single {
+                        links.remove(0)
+                    } else {
+                        serde_json::Value::Array(links)
+                    },
+                );
+                for (suffix, vals) in
+                    ["", "_path", "_name", "_prefix"].iter().zip(vars.iter())
+                {
+                    let quoted: Vec<_> =
+                        vals.iter().map(|v| shell_quote(v)).collect();
+                    inputs.script += &if single {
+                        format!("{name}{suffix}={}\n", quoted.join(" "))
+                    } else {
+                        format!("{name}{suffix}=({})\n", quoted.join(" "))
+                    };
+                }
+            }
+            _ => {
+                let quote = |val: &serde_json::Value| match val {
+                    serde_json::Value::String(s) => shell_quote(s),
+                    other => shell_quote(&other.to_string()),
+                };
+                inputs.script += &match (&param.class, value) {
+                    (InputOutputClass::Hash, _) => {
+                        format!("{name}={}\n", quote(value))
+                    }
+                    (_, serde_json::Value::Array(vals)) => format!(
+                        "{name}=({})\n",
+                        vals.iter().map(quote).collect::<Vec<_>>().join(" ")
+                    ),
+                    _ => format!("{name}={}\n", quote(value)),
+                };
+                inputs.job_input.insert(name.clone(), value.clone());
+            }
+        }
+    }
+
+    Ok(inputs)
+}
+
+// --------------------------------------------------
+// Collect the "NAME\tCLASS\tVALUE" lines from dx-jobutil-add-output
+pub(crate) fn local_job_outputs(
+    tsv: &str,
+) -> Result<serde_json::Map<String, serde_json::Value>> {
+    let mut outputs = serde_json::Map::new();
+    for line in tsv.lines().filter(|line| !line.is_empty()) {
+        let mut fields = line.splitn(3, '\t');
+        let (name, class, value) =
+            match (fields.next(), fields.next(), fields.next()) {
+                (Some(name), Some(class), Some(value)) => (name, class, value),
+                _ => bail!(r#"Invalid output line "{line}""#),
+            };
+
+        let (is_array, class) = match class.strip_prefix("array:") {
+            Some(class) => (true, class),
+            _ => (false, class),
+        };
+        let value = match class {
+            "file" => serde_json::json!({ "$dnanexus_link": value }),
+            "string" => serde_json::Value::String(value.to_string()),
+            _ => serde_json::from_str(value)
+                .unwrap_or(serde_json::Value::String(value.to_string())),
+        };
+
+        if is_array {
+            let entry = outputs
+                .entry(name.to_string())
+                .or_insert(serde_json::Value::Array(vec![]));
+            if let serde_json::Value::Array(vals) = entry {
+                vals.push(value);
+            }
+        } else {
+            outputs.insert(name.to_string(), value);
+        }
+    }
+    Ok(outputs)
+}
@@ -0,0 +1,143 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{api, dxenv, print_record, print_table};
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use clap::Parser;
+use std::env;
+use tabular::{Row, Table};
+
+/// Commands warn when the token expires within this many days
+const TOKEN_EXPIRY_WARNING_DAYS: i64 = 3;
+
+#[derive(Clone, Parser, Debug)]
+pub struct TokenInfoArgs {
+    /// Output JSON representation
+    #[arg(long, default_value = "false")]
+    json: bool,
+}
+
+// --------------------------------------------------
+pub fn token_info(args: TokenInfoArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let info = api::describe_token(&dx_env)?;
+    let origin = token_origin(&dx_env);
+
+    if args.json {
+        let mut record = serde_json::to_value(&info)?;
+        record["origin"] = serde_json::json!(origin);
+        return print_record(&record);
+    }
+
+    let date = |d: Option<DateTime<Utc>>| {
+        d.map_or("-".to_string(), |d| {
+            d.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+    };
+    let expires = match info.expires {
+        Some(expires) => match token_expiry_warning(expires, Utc::now()) {
+            Some(warning) => format!("{} ({warning})", date(Some(expires))),
+            _ => date(Some(expires)),
+        },
+        _ => "never".to_string(),
+    };
+
+    let mut table = Table::new("{:<}    {:<}");
+    table.add_row(
+        Row::new().with_cell("User").with_cell(
+            info.user.unwrap_or(format!("user-{}", dx_env.username)),
+        ),
+    );
+    table.add_row(
+        Row::new()
+            .with_cell("Label")
+            .with_cell(info.label.unwrap_or("-".to_string())),
+    );
+    table.add_row(
+        Row::new().with_cell("Scope").with_cell(
+            info.scope.as_ref().map_or("-".to_string(), token_scope),
+        ),
+    );
+    table.add_row(
+        Row::new()
+            .with_cell("Created")
+            .with_cell(date(info.created)),
+    );
+    table.add_row(Row::new().with_cell("Expires").with_cell(expires));
+    table.add_row(
+        Row::new()
+            .with_cell("Last Used")
+            .with_cell(date(info.last_used)),
+    );
+    table.add_row(Row::new().with_cell("Origin").with_cell(origin));
+    print_table(&table, true);
+    Ok(())
+}
+
+// --------------------------------------------------
+// Where this process found the token
+fn token_origin(dx_env: &DxEnvironment) -> &'static str {
+    if env::var("DX_API_TOKEN").is_ok_and(|token| !token.is_empty()) {
+        "$DX_API_TOKEN"
+    } else if dxenv::env_auth_token().is_some() {
+        "$DX_SECURITY_CONTEXT"
+    } else if dx_env.plaintext_store {
+        "config file"
+    } else {
+        "keychain"
+    }
+}
+
+// --------------------------------------------------
+// e.g., "full (VIEW)" or "project-xxxx (CONTRIBUTE), project-yyyy (VIEW)"
+pub(crate) fn token_scope(scope: &serde_json::Value) -> String {
+    if let Some(level) = scope["full"]["level"].as_str() {
+        return format!("full ({level})");
+    }
+
+    match scope["projects"].as_object() {
+        Some(projects) => projects
+            .iter()
+            .map(|(id, level)| {
+                format!("{id} ({})", level.as_str().unwrap_or("?"))
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => scope.to_string(),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn token_expiry_warning(
+    expires: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Option<String> {
+    let left = expires - now;
+    if left > chrono::Duration::days(TOKEN_EXPIRY_WARNING_DAYS) {
+        None
+    } else if left <= chrono::Duration::zero() {
+        Some("expired".to_string())
+    } else if left < chrono::Duration::days(1) {
+        Some(format!("expires in {} hours", left.num_hours().max(1)))
+    } else {
+        Some(format!("expires in {} days", left.num_days()))
+    }
+}
+
+// --------------------------------------------------
+/// Warns on STDERR when the saved token is about to expire
+pub fn warn_token_expiry() {
+    // A token from the environment is not the one whose expiry was saved
+    if dxenv::env_auth_token().is_some() {
+        return;
+    }
+
+    let expires = dxenv::saved_token_expiry()
+        .and_then(DateTime::<Utc>::from_timestamp_millis);
+    if let Some(warning) =
+        expires.and_then(|t| token_expiry_warning(t, Utc::now()))
+    {
+        note!(r#"Your token {warning}, use "login --refresh" for a new one"#);
+    }
+}
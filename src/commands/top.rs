@@ -0,0 +1,277 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::watch;
+use crate::{
+    api, format_price, make_table, ExecutionCost, FindExecutionsOptions,
+    WatchArgs, ACTIVE_JOB_STATES,
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue, terminal,
+};
+use std::{
+    collections::BTreeMap,
+    io::{self, Write as _},
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Parser, Debug)]
+pub struct TopArgs {
+    /// Project ID, defaults to current project
+    #[arg()]
+    project: Option<String>,
+
+    /// Seconds between refreshes
+    #[arg(short = 'n', long, value_name = "SECONDS", default_value = "5")]
+    interval: u64,
+
+    /// Print the jobs once rather than refreshing
+    #[arg(long, default_value = "false")]
+    once: bool,
+}
+
+// --------------------------------------------------
+pub fn top(args: TopArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let project_id = args
+        .project
+        .clone()
+        .unwrap_or(dx_env.project_context_id.clone());
+
+    // Without a terminal there is nothing to refresh or select
+    if args.once || !console::Term::stdout().is_term() {
+        let jobs = active_jobs(&dx_env, &project_id)?;
+        for line in top_lines(&jobs, None, Utc::now(), false) {
+            println!("{line}");
+        }
+        return Ok(());
+    }
+
+    let interval = Duration::from_secs(args.interval.max(1));
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let res = top_dashboard(&dx_env, &project_id, interval);
+    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+
+    // Watching a job replaces the dashboard
+    match res? {
+        Some(job_id) => watch(WatchArgs::try_parse_from(["watch", &job_id])?),
+        _ => Ok(()),
+    }
+}
+
+// --------------------------------------------------
+// Redraws the jobs until "q" is pressed, returning any job to watch
+fn top_dashboard(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+    interval: Duration,
+) -> Result<Option<String>> {
+    let mut jobs: Vec<ExecutionCost> = vec![];
+    let mut selected = 0;
+    let mut status = String::new();
+    let mut to_terminate: Option<String> = None;
+    let mut next_refresh = Instant::now();
+
+    loop {
+        if Instant::now() >= next_refresh {
+            match active_jobs(dx_env, project_id) {
+                Ok(found) => jobs = found,
+                Err(e) => status = e.to_string(),
+            }
+            next_refresh = Instant::now() + interval;
+        }
+        selected = selected.min(jobs.len().saturating_sub(1));
+
+        let mut lines = top_lines(&jobs, Some(selected), Utc::now(), false);
+        lines.push(match &to_terminate {
+            Some(job_id) => format!("Terminate {job_id}? [y/N]"),
+            _ if status.is_empty() => {
+                "[up/down] select  [t] terminate  [w] watch  \
+                [r] refresh  [q] quit"
+                    .to_string()
+            }
+            _ => status.clone(),
+        });
+
+        let mut stdout = io::stdout();
+        queue!(
+            stdout,
+            terminal::Clear(terminal::ClearType::All),
+            cursor::MoveTo(0, 0)
+        )?;
+        write!(stdout, "{}", lines.join("\r\n"))?;
+        stdout.flush()?;
+
+        // Wait for a key until the next refresh
+        let timeout = next_refresh.saturating_duration_since(Instant::now());
+        if !event::poll(timeout)? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        if let Some(job_id) = to_terminate.take() {
+            if key.code == KeyCode::Char('y') {
+                status = match api::terminate_job(dx_env, &job_id) {
+                    Ok(_) => format!("Terminating {job_id}"),
+                    Err(e) => e.to_string(),
+                };
+                next_refresh = Instant::now();
+            }
+            continue;
+        }
+
+        let job_id = jobs.get(selected).map(|job| job.id.clone());
+        match key.code {
+            KeyCode::Char('c')
+                if key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                return Ok(None)
+            }
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
+            KeyCode::Up | KeyCode::Char('k') => {
+                selected = selected.saturating_sub(1)
+            }
+            KeyCode::Down | KeyCode::Char('j') => selected += 1,
+            KeyCode::Char('t') => to_terminate = job_id,
+            KeyCode::Char('w') if job_id.is_some() => return Ok(job_id),
+            KeyCode::Char('r') => {
+                status.clear();
+                next_refresh = Instant::now();
+            }
+            _ => (),
+        }
+    }
+}
+
+// --------------------------------------------------
+// Jobs in the project that have not yet finished, including subjobs
+fn active_jobs(
+    dx_env: &DxEnvironment,
+    project_id: &str,
+) -> Result<Vec<ExecutionCost>> {
+    let mut options = FindExecutionsOptions {
+        project: Some(project_id.to_string()),
+        root_execution: None,
+        include_subjobs: true,
+        created: None,
+        class: Some("job".to_string()),
+        state: Some(ACTIVE_JOB_STATES.iter().map(|s| s.to_string()).collect()),
+        describe: serde_json::json!({
+            "fields": {
+                "id": true,
+                "name": true,
+                "state": true,
+                "instanceType": true,
+                "startedRunning": true,
+                "totalPrice": true,
+                "currency": true,
+            }
+        }),
+        starting: None,
+    };
+
+    Ok(api::find_executions(dx_env, &mut options)?
+        .into_iter()
+        .filter_map(|res| res.describe)
+        .collect())
+}
+
+// --------------------------------------------------
+// Counts of job states and instance types above a table of the jobs,
+// marking the selected job with ">"
+pub(crate) fn top_lines(
+    jobs: &[ExecutionCost],
+    selected: Option<usize>,
+    now: DateTime<Utc>,
+    wide: bool,
+) -> Vec<String> {
+    let mut states: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut instance_types: BTreeMap<&str, usize> = BTreeMap::new();
+    for job in jobs {
+        *states
+            .entry(job.state.as_deref().unwrap_or("unknown"))
+            .or_default() += 1;
+        if let Some(instance_type) = job.instance_type.as_deref() {
+            *instance_types.entry(instance_type).or_default() += 1;
+        }
+    }
+    let counts = |counts: &BTreeMap<&str, usize>| {
+        counts
+            .iter()
+            .map(|(name, count)| format!("{count} {name}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let currency = jobs.iter().find_map(|job| job.currency.clone());
+    let cost: f64 = jobs.iter().filter_map(|job| job.total_price).sum();
+    let mut lines = vec![
+        if jobs.is_empty() {
+            "Jobs: 0".to_string()
+        } else {
+            format!("Jobs: {} ({})", jobs.len(), counts(&states))
+        },
+        format!("Instance types: {}", counts(&instance_types))
+            .trim_end()
+            .to_string(),
+        format!("Cost so far: {}", format_price(Some(cost), &currency)),
+        "".to_string(),
+    ];
+
+    if jobs.is_empty() {
+        lines.push("No active jobs".to_string());
+        return lines;
+    }
+
+    let mut rows = vec![[
+        "",
+        "JOB",
+        "NAME",
+        "STATE",
+        "INSTANCE TYPE",
+        "RUNTIME",
+        "COST",
+    ]
+    .map(|header| header.to_string())
+    .to_vec()];
+    for (i, job) in jobs.iter().enumerate() {
+        rows.push(vec![
+            if selected == Some(i) { ">" } else { "" }.to_string(),
+            job.id.clone(),
+            job.name.clone().unwrap_or_default(),
+            job.state.clone().unwrap_or_default(),
+            job.instance_type.clone().unwrap_or_default(),
+            job.started_running.map_or("".to_string(), |started| {
+                format_runtime((now - started).num_seconds())
+            }),
+            format_price(job.total_price, &job.currency),
+        ]);
+    }
+
+    let table = make_table("{:<} {:<} {:<} {:<} {:<} {:>} {:>}", rows, 2, wide);
+    lines.extend(table.to_string().lines().map(|line| line.to_string()));
+    lines
+}
+
+// --------------------------------------------------
+// Seconds as "H:MM:SS"
+pub(crate) fn format_runtime(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
@@ -0,0 +1,203 @@
+use crate::dxenv::get_dx_env;
+use crate::{
+    api, output_format, print_listing, resolve_path, theme, walk_folders,
+    FolderWalk, ListColumn, ListFolderObjectDescribe, ListFolderResult,
+    ListingRow, ObjectType, OutputFormat, ProjectDescribeField,
+    ProjectDescribeOptions,
+};
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use size::Size;
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    str::FromStr,
+};
+use tabular::{Row, Table};
+use termtree::Tree;
+
+#[derive(Clone, Parser, Debug)]
+pub struct TreeArgs {
+    /// Directoy path
+    #[arg()]
+    path: Option<String>,
+
+    /// Long listing format
+    #[arg(short, long, default_value = "false")]
+    long: bool,
+
+    /// Human-readable file sizes
+    #[arg(short('H'), long, default_value = "false")]
+    human: bool,
+
+    /// Show hidden files
+    #[arg(short, long, default_value = "false")]
+    all: bool,
+
+    /// Maximum number of folder levels to descend
+    #[arg(short, long, value_name = "N")]
+    depth: Option<usize>,
+
+    /// Show only folders
+    #[arg(long)]
+    dirs_only: bool,
+
+    /// Only show objects of this class (file, applet, record)
+    #[arg(short, long)]
+    class: Option<String>,
+}
+
+// --------------------------------------------------
+pub fn tree(args: TreeArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let path = args.path.clone().unwrap_or(dx_env.cli_wd.clone());
+    let dx_path = resolve_path(&dx_env, &path)?;
+    let class = args
+        .class
+        .as_deref()
+        .map(ObjectType::from_str)
+        .transpose()
+        .map_err(|_| anyhow!("Invalid class {:?}", args.class))?;
+    let walk = FolderWalk {
+        depth: args.depth,
+        dirs_only: args.dirs_only,
+        include_hidden: true,
+    };
+    let listings =
+        walk_folders(&dx_env, &dx_path.project_id, &dx_path.path, &walk)?;
+
+    if output_format() != OutputFormat::Table {
+        let mut rows = vec![];
+        tree_rows(&listings, &dx_path.path, &class, &mut rows);
+        let columns = [
+            ListColumn::State,
+            ListColumn::Modified,
+            ListColumn::Size,
+            ListColumn::Name,
+            ListColumn::Id,
+        ];
+        return print_listing(&rows, &columns, args.human, true, false);
+    }
+
+    let mut root = Tree::new(dx_path.path.clone());
+    let tree = mk_tree(&mut root, &listings, &dx_path.path, &class, &args);
+
+    let desc_opts = ProjectDescribeOptions {
+        fields: Some(HashMap::from([(ProjectDescribeField::Name, true)])),
+    };
+
+    let project =
+        api::describe_project(&dx_env, &dx_path.project_id, &desc_opts)?;
+
+    println!(
+        "{} {}:{}",
+        project.name.unwrap_or("".to_string()),
+        dx_path.project_id,
+        dx_path.path
+    );
+    println!("{}", tree);
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// Flattens the tree into rows with full paths for scripting
+fn tree_rows(
+    listings: &BTreeMap<String, ListFolderResult>,
+    folder: &str,
+    class: &Option<ObjectType>,
+    rows: &mut Vec<ListingRow>,
+) {
+    let Some(ls) = listings.get(folder) else {
+        return;
+    };
+
+    for (subdir, _) in ls.folders.iter().flatten() {
+        rows.push(ListingRow {
+            name: subdir.clone(),
+            id: "".to_string(),
+            state: "folder".to_string(),
+            size: None,
+            modified: None,
+        });
+        tree_rows(listings, subdir, class, rows);
+    }
+
+    for desc in tree_objects(ls, class) {
+        rows.push(ListingRow {
+            name: Path::new(folder).join(&desc.name).display().to_string(),
+            id: desc.id.clone(),
+            state: desc.state.to_string(),
+            size: desc.size,
+            modified: desc.modified,
+        });
+    }
+}
+
+// --------------------------------------------------
+fn tree_objects<'a>(
+    ls: &'a ListFolderResult,
+    class: &'a Option<ObjectType>,
+) -> impl Iterator<Item = &'a ListFolderObjectDescribe> {
+    ls.objects
+        .iter()
+        .flatten()
+        .flat_map(|o| &o.describe)
+        .filter(move |desc| class.as_ref().is_none_or(|c| *c == desc.class))
+}
+
+// --------------------------------------------------
+fn mk_tree(
+    root: &mut Tree<String>,
+    listings: &BTreeMap<String, ListFolderResult>,
+    folder: &str,
+    class: &Option<ObjectType>,
+    args: &TreeArgs,
+) -> Tree<String> {
+    let Some(ls) = listings.get(folder) else {
+        return root.clone();
+    };
+
+    if let Some(folders) = &ls.folders {
+        for (subdir, _) in folders {
+            let path = Path::new(subdir);
+            let dirname = path
+                .file_name()
+                .expect("filename")
+                .to_string_lossy()
+                .to_string();
+            let mut new_root = Tree::new(theme::folder(&dirname));
+            root.push(mk_tree(&mut new_root, listings, subdir, class, args));
+        }
+    }
+
+    for desc in tree_objects(ls, class) {
+        if args.long {
+            let fmt = "{:<} {:<} {:>} {:<} ({:<})";
+            let mut table = Table::new(fmt);
+            let modified = desc.modified.map_or("NA".to_string(), |ts| {
+                ts.format("%Y-%m-%d %H:%M:%S").to_string()
+            });
+
+            table.add_row(
+                Row::new()
+                    .with_cell(theme::state(desc.state.to_string()))
+                    .with_cell(modified)
+                    .with_cell(desc.size.map_or("NA".to_string(), |s| {
+                        if args.human {
+                            Size::from_bytes(s).to_string()
+                        } else {
+                            s.to_string()
+                        }
+                    }))
+                    .with_cell(desc.name.clone())
+                    .with_cell(theme::id(&desc.id)),
+            );
+            root.push(format!("{}", table));
+        } else {
+            root.push(desc.name.clone());
+        }
+    }
+
+    root.clone()
+}
@@ -0,0 +1,902 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::exit::CliError;
+use crate::{
+    api, close_remote_file, entity_id, exit, incomplete, parse_properties,
+    parse_size, read_part, subfolders, write_formatted, FileNewOptions,
+    FileUploadOptions, ProgressEvent, ProgressFormat, ProjectPath, RmOptions,
+    UploadProgress, MD5_READ_CHUNK_SIZE,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use inquire::Confirm;
+use log::debug;
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use signal_hook::{
+    consts::{SIGINT, SIGTERM},
+    iterator::Signals,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{self, BufReader, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+};
+use tar::Builder;
+use textnonce::TextNonce;
+
+const BATCH_MANIFEST_PROPERTY: &str = "batch_manifest";
+
+const BATCH_COUNT_PROPERTY: &str = "batch_file_count";
+
+// (project ID, file ID) of uploads created but not yet closed
+pub(crate) static OPEN_UPLOADS: Lazy<Mutex<Vec<(String, String)>>> =
+    Lazy::new(|| Mutex::new(vec![]));
+
+#[derive(Clone, Parser, Debug)]
+pub struct UploadArgs {
+    /// Local files or directories, or "-" for STDIN (requires --path
+    /// with the remote file name)
+    #[arg()]
+    files: Vec<String>,
+
+    /// Upload directories recursively
+    #[arg(short, long, default_value = "false")]
+    recursive: bool,
+
+    /// Create any necessary parent folders
+    #[arg(short, long, default_value = "false")]
+    parents: bool,
+
+    /// Destination folder, or the remote file name for a single file
+    /// (end with "/" to upload into a new folder)
+    #[arg(long)]
+    path: Option<String>,
+
+    /// Do not show a progress bar
+    #[arg(short, long, default_value = "false")]
+    quiet: bool,
+
+    /// Bundle files smaller than SIZE (e.g., 64K, 1M) into one
+    /// tarball per folder
+    #[arg(long, value_name = "SIZE")]
+    batch_small_files: Option<String>,
+
+    /// Progress display, "json" writes events to STDERR
+    #[arg(long, value_name = "FORMAT", default_value = "bar")]
+    progress: ProgressFormat,
+
+    /// Wait until each file is closed
+    #[arg(long)]
+    wait: bool,
+
+    /// TSV of uploads with a "path" column and optional "folder",
+    /// "tags" and "properties" columns
+    #[arg(long, value_name = "FILE", conflicts_with = "files")]
+    manifest: Option<String>,
+
+    /// Where to write the manifest with file IDs [default:
+    /// MANIFEST.results.tsv]
+    #[arg(long, value_name = "FILE", requires = "manifest")]
+    manifest_out: Option<String>,
+
+    /// Number of concurrent uploads for a manifest
+    #[arg(short('j'), long, value_name = "NUM", default_value = "1")]
+    threads: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct UploadBundle {
+    pub(crate) filename: String,
+
+    /// Remote name, after the folder so that reruns are recognizable
+    pub(crate) name: String,
+
+    pub(crate) destination: ProjectPath,
+
+    pub(crate) properties: HashMap<String, String>,
+}
+
+// One row of an upload manifest
+#[derive(Debug, PartialEq)]
+pub(crate) struct ManifestRow {
+    pub(crate) path: String,
+
+    // Remote folder, else "--path"
+    pub(crate) folder: Option<String>,
+
+    pub(crate) tags: Vec<String>,
+
+    pub(crate) properties: HashMap<String, String>,
+}
+
+// A stream to upload as a new file, e.g., a local file or STDIN
+pub(crate) struct UploadSource<R: Read> {
+    // How the source is shown in progress and errors
+    pub(crate) label: String,
+
+    // Name of the remote file
+    pub(crate) name: String,
+
+    // Not known for pipes
+    pub(crate) size: Option<u64>,
+
+    pub(crate) reader: R,
+}
+
+// --------------------------------------------------
+pub(crate) fn parse_project_path(
+    dx_env: &DxEnvironment,
+    destination: &Option<String>,
+) -> ProjectPath {
+    let destination = destination.clone().unwrap_or(dx_env.cli_wd.clone());
+    let current_project_id = dx_env.project_context_id.clone();
+
+    //let (project_id, mut path) = match re.captures(&destination) {
+    //    Some(caps) => (
+    //        caps.get(1).map_or(current_project_id, |v| {
+    //            v.as_str().to_string().clone()
+    //        }),
+    //        caps.get(2).unwrap().as_str().to_string(),
+    //    ),
+    //    _ => (current_project_id, destination.clone()),
+    //};
+
+    let (project_id, mut path) = if let Some(caps) =
+        entity_id::OPTIONAL_PROJECT_PATH.captures(&destination)
+    {
+        let project_id = match caps.get(1) {
+            Some(val) => val.as_str(),
+            _ => &current_project_id,
+        };
+        let dirname = caps.get(2).unwrap().as_str();
+        (project_id, dirname.to_string())
+    } else {
+        (current_project_id.as_str(), destination.clone())
+    };
+
+    if !path.starts_with('/') {
+        path = format!("/{path}")
+    }
+
+    ProjectPath {
+        project_id: project_id.to_string(),
+        path: PathBuf::from(path),
+    }
+}
+
+// --------------------------------------------------
+pub fn upload(args: UploadArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    debug!("{:?}", &args);
+
+    if args.files.iter().any(|file| file == "-") {
+        if args.files.len() > 1 {
+            bail!(CliError::Usage(
+                "Cannot upload STDIN along with other files".to_string()
+            ));
+        }
+        return upload_stdin(&dx_env, &args);
+    }
+
+    if let Some(manifest) = &args.manifest {
+        return upload_manifest(&dx_env, &args, manifest);
+    }
+
+    let destination = parse_project_path(&dx_env, &args.path);
+
+    // Like STDIN, a single file is named by --path unless it is a folder
+    let (destination, name) = match args.files.as_slice() {
+        [file] if !Path::new(file).is_dir() => {
+            named_destination(&dx_env, destination, args.path.as_deref())
+        }
+        _ => (destination, None),
+    };
+
+    // Pair each local file with its remote folder
+    let mut uploads: Vec<(String, ProjectPath)> = vec![];
+    for file in &args.files {
+        let path = Path::new(file);
+        if path.is_dir() {
+            if !args.recursive {
+                bail!(r#"Use recursive to upload directory "{file}""#);
+            }
+
+            let parent = path.parent().unwrap_or(Path::new(""));
+            for entry in walkdir::WalkDir::new(path)
+                .into_iter()
+                .flatten()
+                .filter(|e| e.file_type().is_file())
+            {
+                let local = entry.path();
+                let rel_dir = local
+                    .parent()
+                    .and_then(|dir| dir.strip_prefix(parent).ok())
+                    .unwrap_or(Path::new(""));
+                uploads.push((
+                    local.display().to_string(),
+                    ProjectPath {
+                        project_id: destination.project_id.clone(),
+                        path: destination.path.join(rel_dir),
+                    },
+                ));
+            }
+        } else {
+            uploads.push((
+                file.clone(),
+                ProjectPath {
+                    project_id: destination.project_id.clone(),
+                    path: destination.path.clone(),
+                },
+            ));
+        }
+    }
+
+    // Bundles live in the temp dir until the uploads finish
+    let tmp_dir = tempfile::tempdir()?;
+    let mut bundles: Vec<UploadBundle> = vec![];
+    if let Some(size) =
+        args.batch_small_files.as_ref().filter(|_| name.is_none())
+    {
+        let threshold = parse_size(size)?;
+        let (small, large): (Vec<_>, Vec<_>) =
+            uploads.into_iter().partition(|(file, _)| {
+                fs::metadata(file)
+                    .is_ok_and(|meta| meta.len() < threshold && !is_fifo(&meta))
+            });
+        uploads = large;
+        bundles = batch_small_files(small, tmp_dir.path())?;
+    }
+
+    let total_bytes = uploads
+        .iter()
+        .map(|(file, _)| file)
+        .chain(bundles.iter().map(|bundle| &bundle.filename))
+        .filter_map(|file| fs::metadata(file).ok())
+        .map(|meta| meta.len())
+        .sum();
+    let num_uploads = uploads.len() + bundles.len();
+    let format = if args.quiet {
+        ProgressFormat::None_
+    } else {
+        args.progress
+    };
+    let progress = UploadProgress::new(format, total_bytes, num_uploads);
+
+    let uploads: Vec<_> = uploads
+        .into_iter()
+        .map(|(file, dest)| (file, (dest, name.clone(), None)))
+        .chain(bundles.into_iter().map(|bundle| {
+            (
+                bundle.filename,
+                (
+                    bundle.destination,
+                    Some(bundle.name),
+                    Some(bundle.properties),
+                ),
+            )
+        }))
+        .collect();
+
+    remove_open_uploads_on_interrupt()?;
+
+    upload_each(uploads, &progress, |file, (dest, name, props)| {
+        local_source(file, name.as_deref()).and_then(|source| {
+            upload_source(
+                &dx_env,
+                source,
+                &dest,
+                props,
+                &[],
+                &progress,
+                args.wait,
+            )
+        })
+    })
+}
+
+// --------------------------------------------------
+// Uploads the files one at a time, reporting each as it finishes, and
+// fails after the rest are done if any upload failed
+pub(crate) fn upload_each<T>(
+    uploads: Vec<(String, T)>,
+    progress: &UploadProgress,
+    mut upload: impl FnMut(&str, T) -> Result<String>,
+) -> Result<()> {
+    let num_uploads = uploads.len();
+    let (mut num_ok, mut errors) = (0, vec![]);
+    for (file, target) in uploads {
+        match upload(&file, target) {
+            Ok(file_id) => {
+                num_ok += 1;
+                progress.event(ProgressEvent::Finished {
+                    file: &file,
+                    id: Some(&file_id),
+                });
+                progress.println(format!("{file} => {file_id}"));
+            }
+            Err(e) => {
+                progress.event(ProgressEvent::Failed {
+                    file: &file,
+                    error: e.to_string(),
+                });
+                progress.println(format!("{file}: {e}"));
+                errors.push(e);
+            }
+        }
+    }
+    progress.finish();
+
+    if num_uploads > 1 {
+        println!(
+            "Uploaded {num_ok} file{}{}",
+            if num_ok == 1 { "" } else { "s" },
+            if errors.is_empty() {
+                "".to_string()
+            } else {
+                format!(", {} failed", errors.len())
+            }
+        );
+    }
+
+    incomplete("Upload", errors)
+}
+
+// --------------------------------------------------
+// Splits the file name from a --path that does not end in "/" and is not
+// an existing folder
+fn named_destination(
+    dx_env: &DxEnvironment,
+    destination: ProjectPath,
+    path: Option<&str>,
+) -> (ProjectPath, Option<String>) {
+    let (Some(parent), Some(name)) =
+        (destination.path.parent(), destination.path.file_name())
+    else {
+        return (destination, None);
+    };
+    if path.is_none_or(|path| path.ends_with('/')) {
+        return (destination, None);
+    }
+
+    // Listing a missing parent is an error, so then it is not a folder
+    let folder = destination.path.display().to_string();
+    let is_folder = subfolders(
+        dx_env,
+        &destination.project_id,
+        &parent.display().to_string(),
+    )
+    .is_ok_and(|folders| folders.contains(&folder));
+    if is_folder {
+        return (destination, None);
+    }
+
+    let name = name.to_string_lossy().to_string();
+    let folder = ProjectPath {
+        project_id: destination.project_id,
+        path: parent.to_path_buf(),
+    };
+    (folder, Some(name))
+}
+
+// --------------------------------------------------
+fn upload_manifest(
+    dx_env: &DxEnvironment,
+    args: &UploadArgs,
+    manifest: &str,
+) -> Result<()> {
+    let rows = parse_upload_manifest(&fs::read_to_string(manifest)?)
+        .map_err(|e| anyhow!(r#"Manifest "{manifest}": {e}"#))?;
+    let out = args.manifest_out.clone().unwrap_or_else(|| {
+        Path::new(manifest)
+            .with_extension("results.tsv")
+            .display()
+            .to_string()
+    });
+
+    let destination = parse_project_path(dx_env, &args.path);
+    let total_bytes = rows
+        .iter()
+        .filter_map(|row| fs::metadata(&row.path).ok())
+        .map(|meta| meta.len())
+        .sum();
+    let format = if args.quiet {
+        ProgressFormat::None_
+    } else {
+        args.progress
+    };
+    let progress = UploadProgress::new(format, total_bytes, rows.len());
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.threads.max(1))
+        .build()?;
+
+    remove_open_uploads_on_interrupt()?;
+
+    let results: Vec<Result<String>> = pool.install(|| {
+        rows.par_iter()
+            .map(|row| {
+                let dest = match &row.folder {
+                    Some(folder) => {
+                        parse_project_path(dx_env, &Some(folder.clone()))
+                    }
+                    _ => ProjectPath {
+                        project_id: destination.project_id.clone(),
+                        path: destination.path.clone(),
+                    },
+                };
+                let props = (!row.properties.is_empty())
+                    .then(|| row.properties.clone());
+                match upload_local_file(
+                    dx_env, &row.path, &dest, props, &row.tags, &progress,
+                    args.wait,
+                ) {
+                    Ok(file_id) => {
+                        progress.event(ProgressEvent::Finished {
+                            file: &row.path,
+                            id: Some(&file_id),
+                        });
+                        progress.println(format!("{} => {file_id}", row.path));
+                        Ok(file_id)
+                    }
+                    Err(e) => {
+                        progress.event(ProgressEvent::Failed {
+                            file: &row.path,
+                            error: e.to_string(),
+                        });
+                        progress.println(format!("{}: {e}", row.path));
+                        Err(e)
+                    }
+                }
+            })
+            .collect()
+    });
+    progress.finish();
+
+    let summary: Vec<_> = results
+        .iter()
+        .map(|res| match res {
+            Ok(id) => Ok(id.clone()),
+            Err(e) => Err(e.to_string()),
+        })
+        .collect();
+    write_formatted(&upload_manifest_results(&rows, &summary), &out)?;
+
+    let errors: Vec<_> = results.into_iter().filter_map(Result::err).collect();
+    let num_ok = rows.len() - errors.len();
+    let num_failed = errors.len();
+    println!(
+        "Uploaded {num_ok} file{}{}, results in \"{out}\"",
+        if num_ok == 1 { "" } else { "s" },
+        if num_failed > 0 {
+            format!(", {num_failed} failed")
+        } else {
+            "".to_string()
+        }
+    );
+
+    incomplete("Upload", errors)
+}
+
+// --------------------------------------------------
+// A TSV with a header naming the columns, where tags are separated by
+// commas and properties are comma-separated KEY=VALUE pairs
+pub(crate) fn parse_upload_manifest(
+    contents: &str,
+) -> Result<Vec<ManifestRow>> {
+    let mut lines = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'));
+
+    let header: Vec<_> = match lines.next() {
+        Some((_, line)) => line.split('\t').map(|col| col.trim()).collect(),
+        _ => bail!("Missing header"),
+    };
+    let column = |name: &str| header.iter().position(|col| *col == name);
+    let path_col = column("path").ok_or(anyhow!(r#"Missing "path" column"#))?;
+    let (folder_col, tags_col, props_col) =
+        (column("folder"), column("tags"), column("properties"));
+
+    let mut rows = vec![];
+    for (line_num, line) in lines {
+        let fields: Vec<_> = line.split('\t').map(|f| f.trim()).collect();
+        let field = |col: Option<usize>| {
+            col.and_then(|i| fields.get(i))
+                .filter(|val| !val.is_empty())
+                .map(|val| val.to_string())
+        };
+        let path = field(Some(path_col))
+            .ok_or(anyhow!("Line {}: missing path", line_num + 1))?;
+        let split = |val: Option<String>| -> Vec<String> {
+            val.map(|val| {
+                val.split(',')
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+        };
+        let properties = parse_properties(&split(field(props_col)))
+            .map_err(|e| anyhow!("Line {}: {e}", line_num + 1))?;
+
+        rows.push(ManifestRow {
+            path,
+            folder: field(folder_col),
+            tags: split(field(tags_col)),
+            properties,
+        });
+    }
+
+    Ok(rows)
+}
+
+// --------------------------------------------------
+// The manifest with each file ID or error appended
+pub(crate) fn upload_manifest_results(
+    rows: &[ManifestRow],
+    results: &[Result<String, String>],
+) -> String {
+    let mut out = vec!["path\tfolder\ttags\tproperties\tid\terror".to_string()];
+    for (row, result) in rows.iter().zip(results) {
+        let mut properties: Vec<_> = row
+            .properties
+            .iter()
+            .map(|(key, val)| format!("{key}={val}"))
+            .collect();
+        properties.sort();
+        let (id, error) = match result {
+            Ok(id) => (id.as_str(), "".to_string()),
+            Err(e) => ("", e.replace(['\t', '\n'], " ")),
+        };
+        out.push(
+            [
+                row.path.as_str(),
+                row.folder.as_deref().unwrap_or(""),
+                &row.tags.join(","),
+                &properties.join(","),
+                id,
+                &error,
+            ]
+            .join("\t"),
+        );
+    }
+    out.join("\n")
+}
+
+// --------------------------------------------------
+pub(crate) fn track_open_upload(project_id: &str, file_id: &str) {
+    if let Ok(mut open) = OPEN_UPLOADS.lock() {
+        open.push((project_id.to_string(), file_id.to_string()));
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn untrack_open_upload(file_id: &str) {
+    if let Ok(mut open) = OPEN_UPLOADS.lock() {
+        open.retain(|(_, id)| id != file_id);
+    }
+}
+
+// --------------------------------------------------
+// An interrupted upload leaves its file open, where it still counts
+// against storage, so offer to remove whatever this session created
+fn remove_open_uploads_on_interrupt() -> Result<()> {
+    let mut signals = Signals::new([SIGINT, SIGTERM])?;
+    thread::spawn(move || {
+        if signals.forever().next().is_none() {
+            return;
+        }
+
+        let open = OPEN_UPLOADS
+            .lock()
+            .map(|open| open.clone())
+            .unwrap_or_default();
+
+        if !open.is_empty() {
+            eprintln!();
+            let consent = Confirm::new(&format!(
+                "Remove {} incomplete upload{}?",
+                open.len(),
+                if open.len() == 1 { "" } else { "s" }
+            ))
+            .with_default(true)
+            .prompt()
+            .unwrap_or(false);
+
+            if consent {
+                if let Err(e) = remove_open_uploads(open) {
+                    eprintln!("{e}");
+                }
+            } else {
+                eprintln!(
+                    "Use \"dxrs clean --open-uploads\" to remove them later"
+                );
+            }
+        }
+
+        std::process::exit(exit::INTERRUPTED);
+    });
+
+    Ok(())
+}
+
+// --------------------------------------------------
+fn remove_open_uploads(open: Vec<(String, String)>) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let mut by_project: HashMap<String, Vec<String>> = HashMap::new();
+    for (project_id, file_id) in open {
+        by_project.entry(project_id).or_default().push(file_id);
+    }
+
+    for (project_id, objects) in by_project {
+        let options = RmOptions {
+            objects,
+            force: Some(true),
+        };
+        api::rm(&dx_env, &project_id, &options)?;
+    }
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// Groups small files by remote folder into one tarball each and
+// returns the tarball with its destination and manifest properties
+pub(crate) fn batch_small_files(
+    files: Vec<(String, ProjectPath)>,
+    tmp_dir: &Path,
+) -> Result<Vec<UploadBundle>> {
+    let mut by_folder: BTreeMap<(String, PathBuf), Vec<String>> =
+        BTreeMap::new();
+    for (file, dest) in files {
+        by_folder
+            .entry((dest.project_id, dest.path))
+            .or_default()
+            .push(file);
+    }
+
+    let mut bundles = vec![];
+    for (num, ((project_id, path), mut files)) in
+        by_folder.into_iter().enumerate()
+    {
+        files.sort();
+        let outpath = tmp_dir.join(format!("small_files_{}.tar.gz", num + 1));
+        let outfile = File::create(&outpath)?;
+        let enc = GzEncoder::new(outfile, Compression::default());
+        let mut tarball = Builder::new(enc);
+        let mut manifest = vec![];
+        for file in &files {
+            let name = Path::new(file)
+                .file_name()
+                .map_or(file.to_string(), |n| n.to_string_lossy().to_string());
+            // One would overwrite the other when the bundle is unpacked
+            if manifest.contains(&name) {
+                bail!(CliError::Usage(format!(
+                    r#"Cannot bundle two files named "{name}" into "{}""#,
+                    path.display()
+                )));
+            }
+            tarball.append_path_with_name(file, &name)?;
+            manifest.push(name);
+        }
+        tarball.into_inner()?.finish()?;
+
+        let properties = HashMap::from([
+            (
+                BATCH_MANIFEST_PROPERTY.to_string(),
+                serde_json::to_string(&manifest)?,
+            ),
+            (BATCH_COUNT_PROPERTY.to_string(), manifest.len().to_string()),
+        ]);
+
+        let name = match path.file_name() {
+            Some(folder) => {
+                format!("{}_small_files.tar.gz", folder.to_string_lossy())
+            }
+            _ => "small_files.tar.gz".to_string(),
+        };
+        bundles.push(UploadBundle {
+            filename: outpath.display().to_string(),
+            name,
+            destination: ProjectPath { project_id, path },
+            properties,
+        });
+    }
+
+    Ok(bundles)
+}
+
+// --------------------------------------------------
+fn upload_stdin(dx_env: &DxEnvironment, args: &UploadArgs) -> Result<()> {
+    let destination = match &args.path {
+        Some(path) if !path.ends_with('/') => {
+            parse_project_path(dx_env, &args.path)
+        }
+        _ => bail!("Use --path to name the file uploaded from STDIN"),
+    };
+    let name = destination
+        .path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or(anyhow!("Use --path to name the file uploaded from STDIN"))?;
+    let folder = ProjectPath {
+        project_id: destination.project_id.clone(),
+        path: destination
+            .path
+            .parent()
+            .map_or(PathBuf::from("/"), |dir| dir.to_path_buf()),
+    };
+
+    let format = if args.quiet {
+        ProgressFormat::None_
+    } else {
+        args.progress
+    };
+    let progress = UploadProgress::new(format, 0, 1);
+    let source = UploadSource {
+        label: "STDIN".to_string(),
+        name,
+        size: None,
+        reader: io::stdin().lock(),
+    };
+
+    remove_open_uploads_on_interrupt()?;
+    let file_id = upload_source(
+        dx_env,
+        source,
+        &folder,
+        None,
+        &[],
+        &progress,
+        args.wait,
+    )?;
+    progress.event(ProgressEvent::Finished {
+        file: "STDIN",
+        id: Some(&file_id),
+    });
+    progress.println(format!("STDIN => {file_id}"));
+
+    Ok(())
+}
+
+// --------------------------------------------------
+pub fn upload_local_file(
+    dx_env: &DxEnvironment,
+    filename: &str,
+    destination: &ProjectPath,
+    properties: Option<HashMap<String, String>>,
+    tags: &[String],
+    progress: &UploadProgress,
+    wait: bool,
+) -> Result<String> {
+    upload_source(
+        dx_env,
+        local_source(filename, None)?,
+        destination,
+        properties,
+        tags,
+        progress,
+        wait,
+    )
+}
+
+// --------------------------------------------------
+// The destination is a folder, so the remote file keeps the local name
+// unless another is given
+pub(crate) fn local_source(
+    filename: &str,
+    name: Option<&str>,
+) -> Result<UploadSource<BufReader<File>>> {
+    // Named pipes have no length until they are read
+    let metadata = fs::metadata(filename)?;
+    let size = (!is_fifo(&metadata)).then_some(metadata.len());
+    if size == Some(0) {
+        bail!(r#"File "{filename}" is empty"#);
+    }
+
+    let name = match name {
+        Some(name) => name.to_string(),
+        _ => Path::new(filename)
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string(),
+    };
+
+    Ok(UploadSource {
+        label: filename.to_string(),
+        name,
+        size,
+        reader: BufReader::new(File::open(filename)?),
+    })
+}
+
+// --------------------------------------------------
+// Streams the source in parts of MD5_READ_CHUNK_SIZE, so the total size
+// need not be known before the upload starts
+pub(crate) fn upload_source(
+    dx_env: &DxEnvironment,
+    mut source: UploadSource<impl Read>,
+    destination: &ProjectPath,
+    properties: Option<HashMap<String, String>>,
+    tags: &[String],
+    progress: &UploadProgress,
+    wait: bool,
+) -> Result<String> {
+    // Read ahead so that an empty stream never creates a remote file
+    let mut buffer = vec![0; MD5_READ_CHUNK_SIZE];
+    let mut bytes_read = read_part(&mut source.reader, &mut buffer)?;
+    if bytes_read == 0 {
+        bail!(r#""{}" is empty"#, source.label);
+    }
+
+    let new_opts = FileNewOptions {
+        project: destination.project_id.clone(),
+        name: Some(source.name.clone()),
+        tags: tags.to_vec(),
+        types: vec![],
+        hidden: Some(false),
+        details: None,
+        properties,
+        folder: Some(destination.path.display().to_string()),
+        parents: Some(true),
+        media: None,
+        nonce: Some(TextNonce::new().into_string()),
+    };
+
+    let new_file = api::file_new(dx_env, &new_opts)?;
+    track_open_upload(&destination.project_id, &new_file.id);
+    let bar = progress.file_bar(&source.label, source.size);
+    let mut sent = 0;
+
+    for index in 1.. {
+        if bytes_read == 0 {
+            break;
+        }
+
+        let bytes = &buffer[..bytes_read];
+        let upload_opts = FileUploadOptions {
+            size: bytes_read,
+            md5: format!("{:x}", md5::compute(bytes)),
+            index,
+        };
+
+        let upload = api::file_upload(dx_env, &new_file.id, &upload_opts)?;
+        api::file_upload_part(upload, bytes.to_vec())?;
+        progress.inc(&bar, bytes_read as u64);
+        sent += bytes_read as u64;
+        progress.event(ProgressEvent::Bytes {
+            file: &source.label,
+            bytes: sent,
+            total: source.size.unwrap_or_default(),
+        });
+
+        bytes_read = read_part(&mut source.reader, &mut buffer)?;
+    }
+
+    if let Some(pb) = bar {
+        pb.finish_and_clear();
+    }
+
+    close_remote_file(dx_env, &new_file.id, wait)?;
+    untrack_open_upload(&new_file.id);
+
+    Ok(new_file.id.to_string())
+}
+
+// --------------------------------------------------
+#[cfg(unix)]
+fn is_fifo(metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    metadata.file_type().is_fifo()
+}
+
+// --------------------------------------------------
+#[cfg(not(unix))]
+fn is_fifo(_metadata: &fs::Metadata) -> bool {
+    false
+}
@@ -0,0 +1,137 @@
+use crate::dxenv::get_dx_env;
+use crate::{
+    api, local_md5s, make_table, output_format, print_records, print_table,
+    remote_md5, resolve_file_id, FileDescribeField, FileDescribeOptions,
+    FilePart, OutputFormat,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+};
+
+#[derive(Clone, Parser, Debug)]
+pub struct VerifyArgs {
+    /// Local file
+    #[arg()]
+    local: String,
+
+    /// Remote file path or ID
+    #[arg()]
+    remote: String,
+
+    /// Also compare the checksum of each uploaded part
+    #[arg(long)]
+    parts: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct VerifyRow {
+    check: String,
+    status: String,
+    local: String,
+    remote: String,
+}
+
+// --------------------------------------------------
+pub fn verify(args: VerifyArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let file_id = resolve_file_id(&dx_env, &args.remote)?;
+    let mut fields = HashMap::from([
+        (FileDescribeField::Size, true),
+        (FileDescribeField::Properties, true),
+    ]);
+    if args.parts {
+        fields.insert(FileDescribeField::Parts, true);
+    }
+    let options = FileDescribeOptions {
+        project: None,
+        fields: Some(fields),
+        properties: true,
+        details: false,
+    };
+    let desc = api::describe_file(&dx_env, &file_id, &options)?;
+    let remote_md5 = remote_md5(&desc);
+
+    // Parts are numbered from 1 and must be read in order
+    let mut parts: Vec<(u64, FilePart)> = desc
+        .parts
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(index, part)| index.parse().ok().map(|i| (i, part)))
+        .collect();
+    parts.sort_by_key(|(index, _)| *index);
+
+    let part_sizes: Vec<u64> = parts
+        .iter()
+        .map(|(_, part)| part.size.unwrap_or(0))
+        .collect();
+    let local_size = fs::metadata(&args.local)?.len();
+    let (local_md5, local_part_md5s) =
+        local_md5s(File::open(&args.local)?, &part_sizes)?;
+
+    let row = |check: &str, local: String, remote: Option<String>| {
+        let status = match &remote {
+            Some(val) if *val == local => "match",
+            Some(_) => "mismatch",
+            _ => "unknown",
+        };
+        VerifyRow {
+            check: check.to_string(),
+            status: status.to_string(),
+            local,
+            remote: remote.unwrap_or("NA".to_string()),
+        }
+    };
+
+    let mut rows = vec![
+        row(
+            "size",
+            local_size.to_string(),
+            desc.size.map(|s| s.to_string()),
+        ),
+        row("md5", local_md5, remote_md5),
+    ];
+    if args.parts {
+        rows.push(row(
+            "parts",
+            local_part_md5s.len().to_string(),
+            Some(parts.len().to_string()),
+        ));
+        for ((index, part), local) in parts.iter().zip(local_part_md5s) {
+            let part_row =
+                row(&format!("part {index}"), local, part.md5.clone());
+            if part_row.status != "match" {
+                rows.push(part_row);
+            }
+        }
+    }
+
+    if output_format() != OutputFormat::Table {
+        print_records(&rows, &["check", "status", "local", "remote"])?;
+    } else {
+        let table_rows = rows
+            .iter()
+            .map(|row| {
+                vec![
+                    row.check.clone(),
+                    row.status.clone(),
+                    row.local.clone(),
+                    row.remote.clone(),
+                ]
+            })
+            .collect();
+        print_table(
+            &make_table("{:<} {:<} {:<} {:<}", table_rows, 2, false),
+            false,
+        );
+    }
+
+    if rows.iter().any(|row| row.status == "mismatch") {
+        bail!("{} differs from {file_id}", args.local);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use crate::dxenv::get_dx_env;
+use crate::exit::CliError;
+use crate::{
+    api, make_table, output_format, print_records, print_table, tsv_value,
+    AppDescribeField, FindAppsDescribe, FindAppsOptions, FindAppsResult,
+    FindName, OutputFormat,
+};
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use std::collections::HashMap;
+
+#[derive(Clone, Parser, Debug)]
+pub struct VersionsArgs {
+    /// App name or ID
+    #[arg()]
+    app: String,
+
+    /// Output JSON representation
+    #[arg(long, default_value = "false")]
+    json: bool,
+
+    /// Do not truncate output to the terminal width
+    #[arg(short, long, default_value = "false")]
+    wide: bool,
+}
+
+// --------------------------------------------------
+pub fn versions(args: VersionsArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+
+    // Accept "app-NAME" and "NAME/VERSION" as well as the bare name
+    let name = args.app.strip_prefix("app-").unwrap_or(&args.app);
+    let name = name.split_once('/').map_or(name, |(name, _)| name);
+    let mut options = FindAppsOptions {
+        name: Some(FindName::Glob(name.to_string())),
+        category: None,
+        all_versions: Some(true),
+        published: None,
+        bill_to: vec![],
+        created_by: None,
+        developer: None,
+        authorized_user: None,
+        installed: None,
+        starting: None,
+        limit: None,
+        describe: Some(FindAppsDescribe {
+            fields: HashMap::from([
+                (AppDescribeField::Version, true),
+                (AppDescribeField::Aliases, true),
+                (AppDescribeField::Created, true),
+                (AppDescribeField::Published, true),
+            ]),
+        }),
+    };
+
+    let mut apps = api::find_apps(&dx_env, &mut options)?;
+    if apps.is_empty() {
+        bail!(CliError::NotFound(format!(
+            r#"Found no app named "{name}""#
+        )))
+    }
+    sort_app_versions(&mut apps);
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&apps)?);
+        return Ok(());
+    }
+
+    let records: Vec<_> = apps.into_iter().map(app_version_record).collect();
+    let fields = ["version", "default", "published", "created", "id"];
+    if output_format() == OutputFormat::Table {
+        let rows = records
+            .iter()
+            .map(|app| {
+                fields
+                    .iter()
+                    .map(|&f| match (f, &app[f]) {
+                        ("default", val) => {
+                            if val == &serde_json::json!(true) {
+                                "default".to_string()
+                            } else {
+                                "".to_string()
+                            }
+                        }
+                        (_, val) => tsv_value(val),
+                    })
+                    .collect()
+            })
+            .collect();
+        print_table(
+            &make_table("{:<} {:<} {:<} {:<} {:<}", rows, 0, args.wide),
+            args.wide,
+        );
+        Ok(())
+    } else {
+        print_records(&records, &fields)
+    }
+}
+
+// --------------------------------------------------
+// Newest version first, comparing the numeric parts of each version and
+// putting a release such as "1.0.0" after its prereleases, e.g. "1.0.0-rc1"
+pub(crate) fn sort_app_versions(apps: &mut [FindAppsResult]) {
+    let version_key = |app: &FindAppsResult| {
+        let version = app
+            .describe
+            .as_ref()
+            .and_then(|desc| desc.version.clone())
+            .unwrap_or_default();
+        let version = version.split('+').next().unwrap_or_default();
+        let (core, pre) = version
+            .split_once('-')
+            .map_or((version, None), |(core, pre)| (core, Some(pre)));
+        let parts: Vec<u64> = core
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect();
+        (parts, pre.is_none(), pre.map(|pre| pre.to_string()))
+    };
+    apps.sort_by_key(|app| std::cmp::Reverse(version_key(app)));
+}
+
+// --------------------------------------------------
+pub(crate) fn app_version_record(app: FindAppsResult) -> serde_json::Value {
+    let desc = app.describe.unwrap_or_default();
+    let format_date =
+        |d: DateTime<Utc>| d.format("%Y-%m-%d %H:%M:%S").to_string();
+    serde_json::json!({
+        "version": desc.version,
+        "default": desc.aliases.is_some_and(|aliases| {
+            aliases.iter().any(|alias| alias == "default")
+        }),
+        "published": desc.published.map(format_date),
+        "created": desc.created.map(format_date),
+        "id": app.id,
+    })
+}
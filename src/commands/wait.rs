@@ -0,0 +1,186 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::exit::CliError;
+use crate::{
+    api, entity_id, fan_out, file_is_closed, file_state, new_spinner,
+    report_job_failure, resolve_file_id, theme, AnalysisDescribeField,
+    AnalysisDescribeOptions, JobDescribeField, JobDescribeOptions,
+    TERMINAL_JOB_STATES,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use std::{
+    collections::HashMap,
+    thread,
+    time::{Duration, Instant},
+};
+
+// The longest pause between polls when waiting on executions and files
+const WAIT_POLL_MAX_SECS: u64 = 30;
+
+#[derive(Clone, Parser, Debug)]
+pub struct WaitArgs {
+    /// Job or analysis IDs, or file paths or IDs
+    #[arg(required = true)]
+    ids: Vec<String>,
+
+    /// Give up after this many seconds
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<u64>,
+}
+
+// --------------------------------------------------
+pub fn wait(args: WaitArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let mut pending = args
+        .ids
+        .iter()
+        .map(|id| wait_target(&dx_env, id))
+        .collect::<Result<Vec<_>>>()?;
+    let timeout = args.timeout.map(Duration::from_secs);
+    let start = Instant::now();
+    let spinner = new_spinner();
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    let mut failed = vec![];
+
+    for attempt in 0.. {
+        let states =
+            fan_out(&pending, |target| wait_target_state(&dx_env, target))?
+                .into_iter()
+                .collect::<Result<Vec<_>>>()?;
+
+        let mut still_pending = vec![];
+        for (target, state) in pending.into_iter().zip(states) {
+            if target.is_settled(&state)? {
+                spinner.suspend(|| {
+                    println!("{}\t{}", target.id(), theme::state(&state))
+                });
+                if target.is_failed(&state) {
+                    if let WaitTarget::Job(job_id) = &target {
+                        spinner
+                            .suspend(|| report_job_failure(&dx_env, job_id))?;
+                    }
+                    failed.push(target);
+                }
+            } else {
+                still_pending.push(target);
+            }
+        }
+        pending = still_pending;
+
+        if pending.is_empty() {
+            break;
+        }
+
+        let mut delay = wait_poll_delay(attempt);
+        if let Some(timeout) = timeout {
+            let remaining = timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                spinner.finish_and_clear();
+                bail!(CliError::Timeout(format!(
+                    "Timed out waiting for {}",
+                    pending
+                        .iter()
+                        .map(|target| target.id())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )));
+            }
+            delay = delay.min(remaining);
+        }
+
+        spinner.set_message(format!(
+            "Waiting for {} of {}",
+            pending.len(),
+            args.ids.len()
+        ));
+        thread::sleep(delay);
+    }
+    spinner.finish_and_clear();
+
+    match failed.len() {
+        0 => Ok(()),
+        1 => bail!("{} did not succeed", failed[0].id()),
+        n => bail!("{n} of {} did not succeed", args.ids.len()),
+    }
+}
+
+// --------------------------------------------------
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum WaitTarget {
+    Job(String),
+    Analysis(String),
+    File(String),
+}
+
+impl WaitTarget {
+    fn id(&self) -> &str {
+        match self {
+            WaitTarget::Job(id)
+            | WaitTarget::Analysis(id)
+            | WaitTarget::File(id) => id,
+        }
+    }
+
+    // Executions settle in a terminal state and files once closed
+    pub(crate) fn is_settled(&self, state: &str) -> Result<bool> {
+        match self {
+            WaitTarget::Job(_) | WaitTarget::Analysis(_) => {
+                Ok(TERMINAL_JOB_STATES.contains(&state))
+            }
+            WaitTarget::File(_) => file_is_closed(Some(state)),
+        }
+    }
+
+    pub(crate) fn is_failed(&self, state: &str) -> bool {
+        matches!(
+            (self, state),
+            (
+                WaitTarget::Job(_) | WaitTarget::Analysis(_),
+                "failed" | "terminated"
+            )
+        )
+    }
+}
+
+// --------------------------------------------------
+// Execution IDs are taken as given while anything else names a file
+fn wait_target(dx_env: &DxEnvironment, id: &str) -> Result<WaitTarget> {
+    if entity_id::JOB_ID.is_match(id) {
+        Ok(WaitTarget::Job(id.to_string()))
+    } else if entity_id::ANALYSIS_ID.is_match(id) {
+        Ok(WaitTarget::Analysis(id.to_string()))
+    } else {
+        Ok(WaitTarget::File(resolve_file_id(dx_env, id)?))
+    }
+}
+
+// --------------------------------------------------
+fn wait_target_state(
+    dx_env: &DxEnvironment,
+    target: &WaitTarget,
+) -> Result<String> {
+    let state = match target {
+        WaitTarget::Job(id) => {
+            let options = JobDescribeOptions {
+                default_fields: None,
+                fields: Some(HashMap::from([(JobDescribeField::State, true)])),
+                try_number: None,
+            };
+            api::describe_job(dx_env, id, &options)?.state
+        }
+        WaitTarget::Analysis(id) => {
+            let options = AnalysisDescribeOptions {
+                fields: HashMap::from([(AnalysisDescribeField::State, true)]),
+            };
+            api::describe_analysis(dx_env, id, &options)?.state
+        }
+        WaitTarget::File(id) => file_state(dx_env, id)?,
+    };
+    state.ok_or_else(|| anyhow!("Missing state for {}", target.id()))
+}
+
+// --------------------------------------------------
+// Poll quickly at first for short jobs, then back off for long ones
+pub(crate) fn wait_poll_delay(attempt: u32) -> Duration {
+    Duration::from_secs(2u64.saturating_pow(attempt).min(WAIT_POLL_MAX_SECS))
+}
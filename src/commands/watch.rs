@@ -0,0 +1,772 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{
+    api, theme, JobDescribeField, JobDescribeOptions, JobDescribeResult,
+    WatchLevel, WatchMessage, WatchOptions, TERMINAL_JOB_STATES,
+};
+use anyhow::{anyhow, bail, Result};
+use chrono::DateTime;
+use clap::{builder::PossibleValue, ArgAction, Parser, ValueEnum};
+use log::debug;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Write as _},
+    path::{Path, PathBuf},
+    process,
+};
+use tabular::{Row, Table};
+
+/// Lines of stderr shown from the job where a failure started
+const FAILURE_STDERR_LINES: u32 = 10;
+
+/// Fields of a job's metrics messages, see "parse_metrics"
+static METRICS_CPU: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"CPU: ([\d.]+)%(?: \((\d+) cores?\))?").unwrap());
+
+static METRICS_MEMORY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Memory: ([\d.]+)/([\d.]+)MB").unwrap());
+
+static METRICS_STORAGE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Storage: ([\d.]+)/([\d.]+)GB").unwrap());
+
+static METRICS_NET: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Net: ([\d.]+)↓/([\d.]+)↑MBps").unwrap());
+
+static METRICS_DISK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Disk: ([\d.]+)r/([\d.]+)wMBps").unwrap());
+
+#[derive(Clone, Parser, Debug)]
+pub struct WatchArgs {
+    /// Show user ID instead of username
+    job_id: String,
+
+    /// Number of recent messages to get
+    #[arg(short, long)]
+    num_recent_messages: Option<u32>,
+
+    /// Include the entire job tree
+    #[arg(long, action(ArgAction::SetTrue))]
+    tree: Option<bool>,
+
+    /// Level
+    #[arg(short, long)]
+    level: Vec<WatchLevel>,
+
+    /// Watch particular try. T=0 is first try. Default is latest try.
+    #[arg(long("try"))]
+    try_number: Option<u32>,
+
+    /// Extract STDOUT only from this job
+    #[arg(long, action(ArgAction::SetTrue))]
+    get_stdout: bool,
+
+    /// Extract STDERR only from this job
+    #[arg(long, action(ArgAction::SetTrue))]
+    get_stderr: bool,
+
+    /// Extract only STDOUT/STDERR from this job
+    #[arg(long, action(ArgAction::SetTrue))]
+    get_streams: bool,
+
+    /// Omit timestamps from messages
+    #[arg(long, action(ArgAction::SetTrue))]
+    no_timestamps: bool,
+
+    /// Print job ID in each message
+    #[arg(long, action(ArgAction::SetTrue))]
+    job_ids: bool,
+
+    /// Omit job info and status updates
+    #[arg(long, action(ArgAction::SetTrue))]
+    no_job_ids: bool,
+
+    /// Do not print extra info messages
+    #[arg(long, action(ArgAction::SetTrue))]
+    quiet: bool,
+
+    /// Message format, "jsonl" writes each message as received
+    #[arg(short, long)]
+    format: Option<WatchFormat>,
+
+    /// Write every message of every level to a file to archive the log
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Exit after the first new message is received
+    #[arg(long, action(ArgAction::SetTrue))]
+    no_wait: bool,
+
+    /// Select display mode for detailed job metrics, if they were collected
+    #[arg(long)]
+    metrics: Option<WatchMetricsFormat>,
+
+    /// File for "--metrics csv", defaults to JOB_ID.metrics.csv
+    #[arg(long, value_name = "FILE")]
+    metrics_file: Option<PathBuf>,
+
+    /// Shell command to run when the job finishes, given a JSON summary
+    /// on STDIN
+    #[arg(long, value_name = "CMD")]
+    notify_cmd: Option<String>,
+
+    /// URL to POST a JSON summary to when the job finishes
+    #[arg(long, value_name = "URL")]
+    notify_webhook: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub enum WatchMetricsFormat {
+    Interspersed,
+    Top,
+    Csv,
+    None_,
+}
+
+impl ValueEnum for WatchMetricsFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            WatchMetricsFormat::Interspersed,
+            WatchMetricsFormat::Top,
+            WatchMetricsFormat::Csv,
+            WatchMetricsFormat::None_,
+        ]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            WatchMetricsFormat::Interspersed => {
+                PossibleValue::new("interspersed")
+            }
+            WatchMetricsFormat::Top => PossibleValue::new("top"),
+            WatchMetricsFormat::Csv => PossibleValue::new("csv"),
+            WatchMetricsFormat::None_ => PossibleValue::new("none"),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum WatchFormat {
+    Job,
+    Try,
+    Level,
+    Msg,
+    Date,
+    Jsonl,
+}
+
+impl ValueEnum for WatchFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            WatchFormat::Job,
+            WatchFormat::Try,
+            WatchFormat::Level,
+            WatchFormat::Msg,
+            WatchFormat::Date,
+            WatchFormat::Jsonl,
+        ]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            WatchFormat::Job => PossibleValue::new("job"),
+            WatchFormat::Try => PossibleValue::new("try"),
+            WatchFormat::Level => PossibleValue::new("level"),
+            WatchFormat::Msg => PossibleValue::new("msg"),
+            WatchFormat::Date => PossibleValue::new("date"),
+            WatchFormat::Jsonl => PossibleValue::new("jsonl"),
+        })
+    }
+}
+
+// --------------------------------------------------
+pub fn watch(args: WatchArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let desc_opts = JobDescribeOptions {
+        default_fields: None,
+        fields: Some(HashMap::from([(
+            JobDescribeField::OutputReusedFrom,
+            true,
+        )])),
+        try_number: args.try_number.map(u64::from),
+    };
+
+    let job = api::describe_job(&dx_env, &args.job_id, &desc_opts)?;
+    let job_id = job.output_reused_from.unwrap_or(args.job_id);
+
+    let streams_only = args.get_stdout || args.get_stderr || args.get_streams;
+    let mut levels = args.level;
+    if args.get_stdout || args.get_streams {
+        levels.push(WatchLevel::Stdout);
+    }
+    if args.get_stderr || args.get_streams {
+        levels.push(WatchLevel::Stderr);
+    }
+
+    let jsonl = matches!(args.format, Some(WatchFormat::Jsonl));
+    if let Some(path) = &args.output {
+        if levels.is_empty() {
+            levels = WatchLevel::value_variants().to_vec();
+        }
+        let watch_opts = WatchOptions {
+            num_recent_messages: args.num_recent_messages,
+            recurse_jobs: args.tree,
+            tail: Some(true),
+            levels,
+        };
+        let file = File::create(path)
+            .map_err(|e| anyhow!("{}: {e}", path.display()))?;
+        let num_messages =
+            write_watch_log(&dx_env, &job_id, &watch_opts, jsonl, file)?;
+        note!("Wrote {num_messages} messages to \"{}\"", path.display());
+        report_job_failure(&dx_env, &job_id)?;
+        return notify_job_done(
+            &dx_env,
+            &job_id,
+            args.notify_cmd.as_deref(),
+            args.notify_webhook.as_deref(),
+        );
+    }
+
+    let metrics = args.metrics.unwrap_or(WatchMetricsFormat::Interspersed);
+    if !levels.is_empty()
+        && !matches!(metrics, WatchMetricsFormat::None_)
+        && !levels.iter().any(|l| matches!(l, WatchLevel::Metrics))
+    {
+        levels.push(WatchLevel::Metrics);
+    }
+
+    let metrics_file = args
+        .metrics_file
+        .unwrap_or_else(|| PathBuf::from(format!("{job_id}.metrics.csv")));
+    let mut renderer = MetricsRenderer::new(metrics, &metrics_file)?;
+
+    let watch_opts = WatchOptions {
+        num_recent_messages: args.num_recent_messages,
+        recurse_jobs: args.tree,
+        tail: Some(true),
+        levels,
+    };
+
+    api::watch(&dx_env, &job_id, &watch_opts, |msg| {
+        let line = if jsonl {
+            serde_json::to_string(&msg)?
+        } else if streams_only {
+            msg.msg.clone()
+        } else {
+            format_watch_message(&msg, !args.no_timestamps, args.job_ids)
+        };
+
+        match (!jsonl && msg.level == "METRICS").then(|| parse_metrics(&msg)) {
+            Some(Some(sample)) => renderer.sample(&line, sample)?,
+            _ => renderer.println(&line)?,
+        }
+
+        Ok(!args.no_wait)
+    })?;
+
+    renderer.finish()?;
+    if matches!(renderer.format, WatchMetricsFormat::Csv) {
+        note!("Wrote metrics to \"{}\"", metrics_file.display());
+    }
+    report_job_failure(&dx_env, &job_id)?;
+
+    notify_job_done(
+        &dx_env,
+        &job_id,
+        args.notify_cmd.as_deref(),
+        args.notify_webhook.as_deref(),
+    )
+}
+
+// --------------------------------------------------
+// Why a failed job failed, so that nobody has to walk the execution
+// tree to find the job where the failure started
+#[derive(Debug, PartialEq)]
+pub(crate) struct JobFailure {
+    job_id: String,
+
+    // Where the failure started, which may be the job itself
+    pub(crate) origin_id: String,
+
+    // The origin's executable, entry point, and try, if not the job
+    origin: Option<String>,
+
+    reason: String,
+
+    message: String,
+
+    reports: Vec<String>,
+
+    // The end of the origin's stderr
+    pub(crate) stderr: Vec<String>,
+}
+
+impl fmt::Display for JobFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} {}: {}: {}",
+            self.job_id,
+            theme::state("failed"),
+            self.reason,
+            self.message
+        )?;
+        if let Some(origin) = &self.origin {
+            writeln!(f, "  Failure started in {origin}")?;
+        }
+        for report in &self.reports {
+            writeln!(f, "  {report}")?;
+        }
+        if !self.stderr.is_empty() {
+            writeln!(f, "  Last lines of stderr from {}:", self.origin_id)?;
+            for line in &self.stderr {
+                writeln!(f, "    {line}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// --------------------------------------------------
+// "failureFrom" names the job where the failure started, and
+// "failureReports" the jobs it was passed along to
+pub(crate) fn job_failure(job: &JobDescribeResult) -> Option<JobFailure> {
+    if job.state.as_deref() != Some("failed") {
+        return None;
+    }
+
+    let (origin_id, origin, reason, message) = match &job.failure_from {
+        Some(from) if from.id != job.id => (
+            from.id.clone(),
+            Some(format!(
+                "{} ({}:{}, try {})",
+                from.id, from.executable_name, from.function, from.try_number
+            )),
+            from.failure_reason.clone(),
+            from.failure_message.clone(),
+        ),
+        _ => (
+            job.id.clone(),
+            None,
+            job.failure_reason.clone().unwrap_or("Unknown".to_string()),
+            job.failure_message.clone().unwrap_or_default(),
+        ),
+    };
+
+    let reports = job
+        .failure_reports
+        .iter()
+        .flatten()
+        .map(|report| {
+            let at = report.at.map_or(String::new(), |at| {
+                format!(" at {}", at.format("%Y-%m-%d %H:%M:%S"))
+            });
+            format!("Reported to {} by {}{at}", report.to, report.by)
+        })
+        .collect();
+
+    Some(JobFailure {
+        job_id: job.id.clone(),
+        origin_id,
+        origin,
+        reason,
+        message,
+        reports,
+        stderr: vec![],
+    })
+}
+
+// --------------------------------------------------
+// The failure of a described job along with the end of the origin's
+// stderr, which is left out if the log cannot be read
+pub(crate) fn job_failure_with_stderr(
+    dx_env: &DxEnvironment,
+    job: &JobDescribeResult,
+) -> Option<JobFailure> {
+    let mut failure = job_failure(job)?;
+    let watch_opts = WatchOptions {
+        num_recent_messages: Some(FAILURE_STDERR_LINES),
+        recurse_jobs: Some(false),
+        tail: Some(false),
+        levels: vec![WatchLevel::Stderr],
+    };
+    let mut lines = vec![];
+    let res = api::watch(dx_env, &failure.origin_id, &watch_opts, |msg| {
+        lines.push(msg.msg);
+        Ok(true)
+    });
+    match res {
+        Ok(_) => {
+            let skip =
+                lines.len().saturating_sub(FAILURE_STDERR_LINES as usize);
+            failure.stderr = lines.split_off(skip);
+        }
+        Err(e) => debug!("Cannot read stderr of {}: {e}", failure.origin_id),
+    }
+    Some(failure)
+}
+
+// --------------------------------------------------
+// Explains on STDERR why a watched job failed, if it did
+pub(crate) fn report_job_failure(
+    dx_env: &DxEnvironment,
+    job_id: &str,
+) -> Result<()> {
+    let desc_opts = JobDescribeOptions {
+        default_fields: None,
+        fields: Some(HashMap::from([
+            (JobDescribeField::State, true),
+            (JobDescribeField::FailureReason, true),
+            (JobDescribeField::FailureMessage, true),
+            (JobDescribeField::FailureFrom, true),
+            (JobDescribeField::FailureReports, true),
+        ])),
+        try_number: None,
+    };
+    let job = api::describe_job(dx_env, job_id, &desc_opts)?;
+    if let Some(failure) = job_failure_with_stderr(dx_env, &job) {
+        eprint!("{failure}");
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// Tells a command and/or a webhook how a finished job ended, e.g., to
+// post to a chat channel or let a CI pipeline continue
+fn notify_job_done(
+    dx_env: &DxEnvironment,
+    job_id: &str,
+    cmd: Option<&str>,
+    webhook: Option<&str>,
+) -> Result<()> {
+    if cmd.is_none() && webhook.is_none() {
+        return Ok(());
+    }
+
+    let desc_opts = JobDescribeOptions {
+        default_fields: None,
+        fields: Some(HashMap::from([
+            (JobDescribeField::Name, true),
+            (JobDescribeField::State, true),
+            (JobDescribeField::TotalPrice, true),
+            (JobDescribeField::Currency, true),
+            (JobDescribeField::FailureReason, true),
+            (JobDescribeField::FailureMessage, true),
+        ])),
+        try_number: None,
+    };
+    let job = api::describe_job(dx_env, job_id, &desc_opts)?;
+    let notification = job_notification(&job);
+
+    // E.g., "--no-wait" can stop watching before the job is done
+    if !TERMINAL_JOB_STATES.contains(&notification.state.as_str()) {
+        note!("Not notifying as {job_id} is still {}", notification.state);
+        return Ok(());
+    }
+
+    if let Some(cmd) = cmd {
+        let mut child = process::Command::new("sh")
+            .arg("-c")
+            .arg(cmd)
+            .env("DX_JOB_ID", job_id)
+            .env("DX_JOB_STATE", &notification.state)
+            .stdin(process::Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(serde_json::to_string(&notification)?.as_bytes())?;
+        }
+        let status = child.wait()?;
+        if !status.success() {
+            bail!("Notify command failed: {status}")
+        }
+    }
+
+    if let Some(url) = webhook {
+        api::post_webhook(url, &notification)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct JobNotification {
+    job: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    state: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cost: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    currency: Option<String>,
+
+    #[serde(rename = "failureReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure_reason: Option<String>,
+
+    #[serde(rename = "failureMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure_message: Option<String>,
+}
+
+// --------------------------------------------------
+pub(crate) fn job_notification(job: &JobDescribeResult) -> JobNotification {
+    JobNotification {
+        job: job.id.clone(),
+        name: job.name.clone(),
+        state: job.state.clone().unwrap_or("unknown".to_string()),
+        cost: job.total_price,
+        currency: job.currency.as_ref().map(|c| c.code.clone()),
+        failure_reason: job.failure_reason.clone(),
+        failure_message: job.failure_message.clone(),
+    }
+}
+
+// --------------------------------------------------
+// Saves the log with the timestamps and job IDs of every message and
+// returns the number of messages
+fn write_watch_log(
+    dx_env: &DxEnvironment,
+    job_id: &str,
+    options: &WatchOptions,
+    jsonl: bool,
+    file: File,
+) -> Result<usize> {
+    let mut out = BufWriter::new(file);
+    let mut num_messages = 0;
+    api::watch(dx_env, job_id, options, |msg| {
+        let line = if jsonl {
+            serde_json::to_string(&msg)?
+        } else {
+            format_watch_message(&msg, true, true)
+        };
+        writeln!(out, "{line}")?;
+        num_messages += 1;
+        Ok(true)
+    })?;
+    out.flush()?;
+    Ok(num_messages)
+}
+
+// --------------------------------------------------
+pub(crate) fn format_watch_message(
+    msg: &WatchMessage,
+    timestamps: bool,
+    job_ids: bool,
+) -> String {
+    let mut parts = vec![];
+    if timestamps {
+        if let Some(dt) =
+            msg.timestamp.and_then(DateTime::from_timestamp_millis)
+        {
+            parts.push(dt.format("%Y-%m-%d %H:%M:%S").to_string());
+        }
+    }
+    if job_ids && !msg.job.is_empty() {
+        parts.push(msg.job.clone());
+    }
+    parts.push(msg.level.clone());
+    parts.push(msg.msg.clone());
+    parts.join(" ")
+}
+
+// --------------------------------------------------
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct MetricsSample {
+    timestamp: Option<i64>,
+    job: String,
+    pub(crate) cpu: f64,
+    pub(crate) cores: Option<u32>,
+    pub(crate) mem_used_mb: Option<f64>,
+    mem_total_mb: Option<f64>,
+    storage_used_gb: Option<f64>,
+    pub(crate) storage_total_gb: Option<f64>,
+    net_down_mbps: Option<f64>,
+    pub(crate) net_up_mbps: Option<f64>,
+    disk_read_mbps: Option<f64>,
+    pub(crate) disk_write_mbps: Option<f64>,
+}
+
+pub(crate) const METRICS_CSV_HEADER: &str = "timestamp,job,cpu_pct,cores,\
+    mem_used_mb,mem_total_mb,storage_used_gb,storage_total_gb,\
+    net_down_mbps,net_up_mbps,disk_read_mbps,disk_write_mbps";
+
+impl MetricsSample {
+    pub(crate) fn csv_row(&self) -> String {
+        let num =
+            |val: Option<f64>| val.map_or(String::new(), |v| v.to_string());
+        [
+            self.timestamp.map_or(String::new(), |t| t.to_string()),
+            self.job.clone(),
+            self.cpu.to_string(),
+            self.cores.map_or(String::new(), |c| c.to_string()),
+            num(self.mem_used_mb),
+            num(self.mem_total_mb),
+            num(self.storage_used_gb),
+            num(self.storage_total_gb),
+            num(self.net_down_mbps),
+            num(self.net_up_mbps),
+            num(self.disk_read_mbps),
+            num(self.disk_write_mbps),
+        ]
+        .join(",")
+    }
+
+    fn summary_row(&self) -> Row {
+        let pair = |a: Option<f64>, b: Option<f64>, sep: &str| match (a, b) {
+            (Some(a), Some(b)) => format!("{a}{sep}{b}"),
+            _ => "NA".to_string(),
+        };
+        let cpu = match self.cores {
+            Some(cores) => format!("{}% ({cores})", self.cpu),
+            _ => format!("{}%", self.cpu),
+        };
+        Row::new()
+            .with_cell(&self.job)
+            .with_cell(cpu)
+            .with_cell(pair(self.mem_used_mb, self.mem_total_mb, "/"))
+            .with_cell(pair(self.storage_used_gb, self.storage_total_gb, "/"))
+            .with_cell(pair(self.net_down_mbps, self.net_up_mbps, "↓/") + "↑")
+            .with_cell(
+                pair(self.disk_read_mbps, self.disk_write_mbps, "r/") + "w",
+            )
+    }
+}
+
+// --------------------------------------------------
+// Metrics messages look like:
+// CPU: 12% (4 cores) * Memory: 1024/15006MB * Storage: 5/78GB *
+// Net: 0↓/0↑MBps * Disk: 0r/1wMBps
+pub(crate) fn parse_metrics(msg: &WatchMessage) -> Option<MetricsSample> {
+    let pair = |re: &Regex| -> (Option<f64>, Option<f64>) {
+        re.captures(&msg.msg).map_or((None, None), |caps| {
+            (caps[1].parse().ok(), caps[2].parse().ok())
+        })
+    };
+
+    let cpu_caps = METRICS_CPU.captures(&msg.msg)?;
+    let (mem_used_mb, mem_total_mb) = pair(&METRICS_MEMORY);
+    let (storage_used_gb, storage_total_gb) = pair(&METRICS_STORAGE);
+    let (net_down_mbps, net_up_mbps) = pair(&METRICS_NET);
+    let (disk_read_mbps, disk_write_mbps) = pair(&METRICS_DISK);
+
+    Some(MetricsSample {
+        timestamp: msg.timestamp,
+        job: msg.job.clone(),
+        cpu: cpu_caps[1].parse().ok()?,
+        cores: cpu_caps.get(2).and_then(|m| m.as_str().parse().ok()),
+        mem_used_mb,
+        mem_total_mb,
+        storage_used_gb,
+        storage_total_gb,
+        net_down_mbps,
+        net_up_mbps,
+        disk_read_mbps,
+        disk_write_mbps,
+    })
+}
+
+// --------------------------------------------------
+struct MetricsRenderer {
+    format: WatchMetricsFormat,
+    latest: BTreeMap<String, MetricsSample>,
+    drawn: usize,
+    csv: Option<Box<dyn io::Write>>,
+}
+
+impl MetricsRenderer {
+    fn new(format: WatchMetricsFormat, csv_path: &Path) -> Result<Self> {
+        let csv: Option<Box<dyn io::Write>> = match format {
+            WatchMetricsFormat::Csv => {
+                let mut file = BufWriter::new(
+                    File::create(csv_path)
+                        .map_err(|e| anyhow!("{}: {e}", csv_path.display()))?,
+                );
+                writeln!(file, "{METRICS_CSV_HEADER}")?;
+                Some(Box::new(file))
+            }
+            _ => None,
+        };
+
+        Ok(MetricsRenderer {
+            format,
+            latest: BTreeMap::new(),
+            drawn: 0,
+            csv,
+        })
+    }
+
+    // Print a log line above the "top" summary
+    fn println(&mut self, line: &str) -> Result<()> {
+        self.clear()?;
+        println!("{line}");
+        self.draw()
+    }
+
+    fn sample(&mut self, line: &str, sample: MetricsSample) -> Result<()> {
+        match self.format {
+            WatchMetricsFormat::Interspersed => self.println(line)?,
+            WatchMetricsFormat::Top => {
+                self.clear()?;
+                self.latest.insert(sample.job.clone(), sample);
+                self.draw()?;
+            }
+            WatchMetricsFormat::Csv => {
+                if let Some(csv) = self.csv.as_mut() {
+                    writeln!(csv, "{}", sample.csv_row())?;
+                }
+            }
+            WatchMetricsFormat::None_ => (),
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        if let Some(csv) = self.csv.as_mut() {
+            csv.flush()?;
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<()> {
+        if self.drawn > 0 {
+            console::Term::stderr().clear_last_lines(self.drawn)?;
+            self.drawn = 0;
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self) -> Result<()> {
+        let term = console::Term::stderr();
+        if self.latest.is_empty() || !term.is_term() {
+            return Ok(());
+        }
+
+        let mut table = Table::new("{:<}  {:>}  {:>}  {:>}  {:>}  {:>}");
+        table.add_row(
+            Row::new()
+                .with_cell("JOB")
+                .with_cell("CPU")
+                .with_cell("MEM MB")
+                .with_cell("STORAGE GB")
+                .with_cell("NET MBps")
+                .with_cell("DISK MBps"),
+        );
+        for sample in self.latest.values() {
+            table.add_row(sample.summary_row());
+        }
+
+        let text = table.to_string();
+        term.write_str(&text)?;
+        self.drawn = text.lines().count();
+        Ok(())
+    }
+}
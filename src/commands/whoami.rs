@@ -0,0 +1,116 @@
+use crate::dxenv::{get_dx_env, DxEnvironment};
+use crate::{
+    api, print_record, print_table, FindOrgsOptions, UserDescribeOptions,
+    UserInfo, WhoAmIOptions, WhoAmIOptionsFields,
+};
+use anyhow::Result;
+use clap::Parser;
+use std::collections::HashMap;
+use tabular::{Row, Table};
+
+#[derive(Clone, Parser, Debug)]
+pub struct WhoamiArgs {
+    /// Show user ID instead of username
+    #[arg(long, default_value = "false")]
+    id: bool,
+
+    /// Output JSON representation
+    #[arg(long, default_value = "false")]
+    json: bool,
+}
+
+// --------------------------------------------------
+pub fn whoami(args: WhoamiArgs) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    if args.id {
+        let options = WhoAmIOptions { fields: None };
+        println!("{}", api::whoami(&dx_env, &options)?.id);
+        return Ok(());
+    }
+
+    let user = user_info(&dx_env)?;
+    if args.json {
+        return print_record(&user);
+    }
+
+    let name = [&user.user.first, &user.user.middle, &user.user.last]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut orgs = user
+        .orgs
+        .iter()
+        .map(|org| {
+            let name = org.describe.as_ref().and_then(|d| d.name.clone());
+            format!(
+                "{} ({}) [{}]",
+                name.unwrap_or("NA".to_string()),
+                org.id,
+                org.level
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let fmt = "{:<}    {:<}";
+    let mut table = Table::new(fmt);
+    for (label, value) in [
+        ("ID", Some(user.user.id)),
+        ("Username", user.user.handle),
+        ("Name", (!name.is_empty()).then_some(name)),
+        ("Email", user.user.email),
+        ("Bill To", user.user.bill_to),
+        ("Client IP", user.client_ip),
+    ] {
+        table.add_row(
+            Row::new()
+                .with_cell(label)
+                .with_cell(value.unwrap_or("NA".to_string())),
+        );
+    }
+
+    // One org per row with the label on the first
+    if orgs.is_empty() {
+        orgs.push("-".to_string());
+    }
+    for (i, org) in orgs.into_iter().enumerate() {
+        table.add_row(
+            Row::new()
+                .with_cell(if i == 0 { "Orgs" } else { "" })
+                .with_cell(org),
+        );
+    }
+
+    print_table(&table, false);
+    Ok(())
+}
+
+// --------------------------------------------------
+pub fn user_info(dx_env: &DxEnvironment) -> Result<UserInfo> {
+    let options = WhoAmIOptions {
+        fields: Some(HashMap::from([(WhoAmIOptionsFields::ClientIp, true)])),
+    };
+    let whoami = api::whoami(dx_env, &options)?;
+    let user = api::describe_user(
+        dx_env,
+        &whoami.id,
+        &UserDescribeOptions::default(),
+    )?;
+    let orgs = api::find_orgs(
+        dx_env,
+        FindOrgsOptions {
+            level: "MEMBER".to_string(),
+            describe: true,
+            starting: None,
+        },
+    )?;
+
+    Ok(UserInfo {
+        user,
+        orgs,
+        client_ip: whoami.client_ip,
+    })
+}
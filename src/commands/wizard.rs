@@ -0,0 +1,1474 @@
+use crate::dxenv::get_dx_env;
+use crate::exit::CliError;
+use crate::json_parser::{
+    lint_app_https_app, AccessSpec, ClusterSpec, DxApp, DxAsset, ExecDepends,
+    HttpsApp, HttpsAppDns, InputOutputClass, InputSpec, Interpreter,
+    LinuxDistribution, LinuxRelease, LinuxVersion, OutputSpec, PackageManager,
+    RegionalOptions, RunSpec, SystemRequirements, TimeoutUnit,
+    VALID_CLUSTER_SPEC_VERSION, VALID_HTTPS_APP_PORTS,
+    VALID_HTTPS_APP_SHARED_ACCESS, VALID_INSTANCE_TYPE, VALID_REGION,
+};
+use crate::{
+    api, check_instance_type, dxenv, ProjectDescribeField,
+    ProjectDescribeOptions,
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use inquire::{MultiSelect, Select, Text};
+use ordinal::Ordinal;
+use regex::Regex;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    fs::{self, File},
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use strum::IntoEnumIterator;
+
+pub(crate) const DXAPI_VERSION: &str = "1.0.0";
+
+type TemplateFiles = &'static [(&'static str, &'static str)];
+
+// Built-in skeletons for "wizard --template"
+pub(crate) const EMBEDDED_TEMPLATES: &[(&str, TemplateFiles)] = &[
+    (
+        "bash",
+        &[
+            ("dxapp.json", include_str!("../templates/bash/dxapp.json")),
+            ("src/run.sh", include_str!("../templates/bash/src/run.sh")),
+        ],
+    ),
+    (
+        "fastqc-like",
+        &[
+            (
+                "dxapp.json",
+                include_str!("../templates/fastqc-like/dxapp.json"),
+            ),
+            (
+                "src/run.sh",
+                include_str!("../templates/fastqc-like/src/run.sh"),
+            ),
+        ],
+    ),
+    (
+        "python",
+        &[
+            ("dxapp.json", include_str!("../templates/python/dxapp.json")),
+            ("src/run.py", include_str!("../templates/python/src/run.py")),
+        ],
+    ),
+    (
+        "wdl",
+        &[("main.wdl", include_str!("../templates/wdl/main.wdl"))],
+    ),
+];
+
+#[derive(Clone, Parser, Debug)]
+pub struct WizardArgs {
+    /// Applet name
+    #[arg()]
+    name: Option<String>,
+
+    /// Template
+    #[arg(short, long, value_name = "dxapp.json")]
+    json_template: Option<String>,
+
+    /// Named template, e.g., "bash", "python", "fastqc-like", "wdl"
+    #[arg(short, long, value_name = "NAME", conflicts_with = "json_template")]
+    template: Option<String>,
+
+    /// Directory of user templates, one subdirectory per template
+    #[arg(long, value_name = "DIR")]
+    template_dir: Option<PathBuf>,
+
+    /// List the available templates
+    #[arg(long, default_value = "false")]
+    list_templates: bool,
+}
+
+// --------------------------------------------------
+pub fn wizard(args: WizardArgs) -> Result<()> {
+    let template_dir = match &args.template_dir {
+        Some(dir) => dir.clone(),
+        _ => dxenv::templates_dir()?,
+    };
+
+    if args.list_templates {
+        for (name, source) in list_templates(&template_dir)? {
+            println!("{name:<20} {source}");
+        }
+        return Ok(());
+    }
+
+    if let Some(template) = &args.template {
+        return wizard_template(args.name, template, &template_dir);
+    }
+
+    let choices = vec!["DxApp", "DxAsset", "WDL"];
+
+    match Select::new("Output:", choices).prompt() {
+        Ok(output) => match output {
+            "DxApp" => wizard_applet(args.name, args.json_template),
+            "DxAsset" => wizard_asset(args.name, args.json_template),
+            _ => wizard_wdl(args.name, args.json_template),
+        },
+        _ => Ok(()),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn list_templates(
+    template_dir: &Path,
+) -> Result<Vec<(String, String)>> {
+    let mut templates: BTreeMap<String, String> = EMBEDDED_TEMPLATES
+        .iter()
+        .map(|(name, _)| (name.to_string(), "built-in".to_string()))
+        .collect();
+
+    if template_dir.is_dir() {
+        for entry in fs::read_dir(template_dir)?.flatten() {
+            if entry.path().is_dir() {
+                templates.insert(
+                    entry.file_name().to_string_lossy().to_string(),
+                    entry.path().display().to_string(),
+                );
+            }
+        }
+    }
+
+    Ok(templates.into_iter().collect())
+}
+
+// --------------------------------------------------
+// A user template directory overrides a built-in one of the same name
+pub(crate) fn template_files(
+    template: &str,
+    template_dir: &Path,
+) -> Result<Vec<(PathBuf, String)>> {
+    let user_dir = template_dir.join(template);
+    if user_dir.is_dir() {
+        let mut files = vec![];
+        for entry in walkdir::WalkDir::new(&user_dir)
+            .into_iter()
+            .flatten()
+            .filter(|e| e.file_type().is_file())
+        {
+            let rel_path = entry.path().strip_prefix(&user_dir)?.to_path_buf();
+            files.push((rel_path, fs::read_to_string(entry.path())?));
+        }
+        return Ok(files);
+    }
+
+    match EMBEDDED_TEMPLATES
+        .iter()
+        .find(|(name, _)| name == &template)
+    {
+        Some((_, files)) => Ok(files
+            .iter()
+            .map(|(path, contents)| (PathBuf::from(path), contents.to_string()))
+            .collect()),
+        _ => {
+            bail!(CliError::NotFound(format!(
+                r#"Unknown template "{template}", try "--list-templates""#
+            )))
+        }
+    }
+}
+
+// --------------------------------------------------
+// Replace "{{key}}" placeholders
+pub(crate) fn render_template(
+    text: &str,
+    vars: &BTreeMap<&str, String>,
+) -> String {
+    vars.iter().fold(text.to_string(), |text, (key, val)| {
+        text.replace(&format!("{{{{{key}}}}}"), val)
+    })
+}
+
+// --------------------------------------------------
+pub fn wizard_template(
+    name: Option<String>,
+    template: &str,
+    template_dir: &Path,
+) -> Result<()> {
+    let files = template_files(template, template_dir)?;
+
+    let applet_name = normalize(
+        Text::new("Applet Name*:")
+            .with_initial_value(&name.unwrap_or("".to_string()))
+            .prompt()
+            .unwrap(),
+    )?;
+
+    let dir = Path::new(&applet_name);
+    if dir.exists() {
+        bail!("\"{applet_name}\" exists, cannot create directory");
+    }
+
+    let title = Text::new("Title:")
+        .with_default(&applet_name.clone())
+        .prompt()
+        .unwrap();
+    let summary = Text::new("Summary:").prompt().unwrap();
+    let version = Text::new("Version:")
+        .with_default("0.1.0")
+        .prompt()
+        .unwrap();
+
+    let vars = BTreeMap::from([
+        ("ident", applet_name.replace(['-', '.'], "_")),
+        ("name", applet_name.clone()),
+        ("summary", summary),
+        ("title", title),
+        ("version", version),
+    ]);
+
+    let mut rendered = vec![];
+    for (path, contents) in files {
+        let contents = render_template(&contents, &vars);
+        if path == Path::new("dxapp.json") {
+            serde_json::from_str::<DxApp>(&contents).map_err(|e| {
+                anyhow!(
+                    r#"Template "{template}" has an invalid dxapp.json: {e}"#
+                )
+            })?;
+        }
+        rendered.push((dir.join(path), contents));
+    }
+
+    for (path, contents) in rendered {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+
+    println!("See output in \"{}\"", dir.display());
+    Ok(())
+}
+
+// --------------------------------------------------
+pub fn wizard_applet(
+    name: Option<String>,
+    json_template: Option<String>,
+) -> Result<()> {
+    let dx_env = get_dx_env()?;
+    let options = ProjectDescribeOptions {
+        fields: Some(HashMap::from([(ProjectDescribeField::Region, true)])),
+    };
+    let project =
+        api::describe_project(&dx_env, &dx_env.project_context_id, &options)?;
+
+    let template: Result<Option<DxApp>> = match json_template {
+        Some(filename) => {
+            let json = fs::read_to_string(filename)?;
+            let app: DxApp = serde_json::from_str(&json)?;
+            Ok(Some(app))
+        }
+        _ => Ok(None),
+    };
+    let template = template.unwrap();
+
+    let preamble = ">>> Basic Metadata <<<
+
+Please enter basic metadata fields to describes your applet. 
+Starred (*) values are required.
+At the end of this wizard, the files necessary for building 
+your applet will be generated from the answers you provide.
+
+The name of your applet must be unique on the DNAnexus platform.
+After creation, you will be able to publish new versions using 
+the same applet name.
+
+Applet names are restricted to alphanumeric characters
+(a-z, A-Z, 0-9), and the characters \".\", \"_\", and \"-\".
+    ";
+
+    println!("{preamble}");
+
+    //let default_name =
+    //    &name
+    //        .unwrap_or(template.map_or("".to_string(), |t| {
+    //            t.name.unwrap_or("".to_string())
+    //        }));
+
+    let applet_name = normalize(
+        Text::new("Applet Name*:")
+            .with_initial_value(&name.unwrap_or("".to_string()))
+            .prompt()
+            .unwrap(),
+    )?;
+
+    // TODO: Why are applet name/source dir linked?
+    let dir = &applet_name.clone();
+    let dir = Path::new(dir);
+    if dir.exists() {
+        bail!("\"{applet_name}\" exists, cannot create directory");
+    }
+    fs::create_dir(dir)?;
+
+    let title = Text::new("Title:")
+        .with_default(&applet_name.clone())
+        .prompt()
+        .unwrap();
+
+    let summary = Text::new("Summary:").prompt().unwrap();
+
+    let version = Text::new("Version:")
+        .with_default("0.1.0")
+        .prompt()
+        .unwrap();
+
+    let timeout = get_timeout()?;
+
+    let language =
+        Select::new("Programming Language:", ["bash", "python3"].to_vec())
+            .prompt()
+            .unwrap();
+
+    let allow_internet_access =
+        Select::new("Allow Internet Access:", ["No", "Yes"].to_vec())
+            .prompt()
+            .unwrap()
+            == "Yes";
+
+    let allow_parent_project_access =
+        Select::new("Allow Access to Parent Project:", ["No", "Yes"].to_vec())
+            .prompt()
+            .unwrap()
+            == "Yes";
+
+    let https_app = get_https_app()?;
+
+    let region_names = VALID_REGION.to_vec();
+    let project_region = project.region.unwrap_or("*".to_string());
+    let starting_cursor = &region_names
+        .iter()
+        .position(|v| v == &project_region)
+        .unwrap_or(0);
+    let region_name = Select::new("Region:", region_names)
+        .with_starting_cursor(*starting_cursor)
+        .prompt()
+        .unwrap();
+
+    // Only offer the instance types available in the region
+    let types: Vec<_> = VALID_INSTANCE_TYPE
+        .iter()
+        .copied()
+        .filter(|t| {
+            region_name == "*" || check_instance_type(region_name, t).is_none()
+        })
+        .collect();
+    let starting_cursor = &types
+        .iter()
+        .position(|v| v == &"mem1_ssd1_v2_x4" || v == &"azure:mem1_ssd1_x4")
+        .unwrap_or(0);
+
+    let instance_type = Select::new("Default Instance Type:", types)
+        .with_starting_cursor(*starting_cursor)
+        .prompt()
+        .unwrap();
+
+    let sys_req = SystemRequirements {
+        instance_type: instance_type.to_string(),
+        cluster_spec: get_cluster_spec(dir)?,
+    };
+
+    let regional_options = HashMap::from([(
+        region_name.to_string(),
+        RegionalOptions {
+            resources: None,
+            system_requirements: HashMap::from([("*".to_string(), sys_req)]),
+        },
+    )]);
+
+    let input_spec: Vec<InputSpec> = match &template {
+        Some(app_template) => app_template.input_spec.clone(),
+        _ => get_inputs()?,
+    };
+
+    let output_spec: Vec<OutputSpec> = match &template {
+        Some(app_template) => app_template.output_spec.clone(),
+        _ => get_outputs(&input_spec)?,
+    };
+
+    let interpreter = Interpreter::from_str(language)?;
+    let run_file = match interpreter {
+        Interpreter::Bash => "run.sh",
+        _ => "run.py",
+    };
+
+    let run_spec = RunSpec {
+        interpreter: Some(interpreter.clone()),
+        file: Some(format!("src/{run_file}")),
+        distribution: LinuxDistribution::Ubuntu,
+        release: Some(LinuxRelease::V20_04),
+        version: Some(LinuxVersion::V0),
+        code: None,
+        head_job_on_demand: None,
+        restartable_entry_points: None,
+        asset_depends: None,
+        exec_depends: vec![],
+        system_requirements: HashMap::new(),
+        timeout_policy: Some(HashMap::from([(
+            "*".to_string(),
+            HashMap::from([timeout]),
+        )])),
+    };
+
+    let access = AccessSpec {
+        network: if allow_internet_access {
+            vec!["*".to_string()]
+        } else {
+            vec![]
+        },
+        project: if allow_parent_project_access {
+            Some("CONTRIBUTE".to_string())
+        } else {
+            None
+        },
+        all_projects: None,
+        developer: None,
+        project_creation: None,
+    };
+
+    let app = DxApp {
+        name: Some(applet_name.clone()),
+        title,
+        dxapi: Some(DXAPI_VERSION.to_string()),
+        summary: if summary.is_empty() {
+            None
+        } else {
+            Some(summary)
+        },
+        description: None,
+        version: Some(version),
+        developer_notes: None,
+        types: vec![],
+        bill_to: None,
+        open_source: None,
+        categories: vec![],
+        developers: vec![],
+        authorized_users: vec![],
+        input_spec: input_spec.clone(),
+        output_spec: output_spec.clone(),
+        run_spec,
+        https_app,
+        access: Some(access),
+        regional_options: Some(regional_options),
+        details: None,
+        ignore_reuse: None,
+    };
+
+    fs::write(dir.join("dxapp.json"), serde_json::to_string_pretty(&app)?)?;
+    fs::write(dir.join("Readme.md"), readme_template(&applet_name))?;
+    fs::write(
+        dir.join("Readme.developer.md"),
+        readme_dev_template(&applet_name),
+    )?;
+
+    let src_dir = &dir.join("src");
+    fs::create_dir(src_dir)?;
+
+    let resources_dir = &dir.join("resources");
+    fs::create_dir(resources_dir)?;
+
+    // TODO: Python2.7?
+    let template = match interpreter {
+        Interpreter::Bash => {
+            bash_template(&applet_name, &input_spec, &output_spec)
+        }
+        _ => python_template(&applet_name, &input_spec, &output_spec),
+    };
+
+    fs::write(src_dir.join(run_file), template)?;
+
+    let harness =
+        Select::new("Test Harness:", ["None", "pytest", "bats"].to_vec())
+            .with_starting_cursor(match interpreter {
+                Interpreter::Bash => 2,
+                _ => 1,
+            })
+            .prompt()
+            .unwrap();
+    if harness != "None" {
+        let test_dir = &dir.join("test");
+        fs::create_dir_all(test_dir.join("data"))?;
+        fs::write(
+            test_dir.join("inputs.json"),
+            serde_json::to_string_pretty(&test_inputs_template(&input_spec))?,
+        )?;
+        if harness == "pytest" {
+            fs::write(
+                test_dir.join(format!(
+                    "test_{}.py",
+                    applet_name.replace(['-', '.'], "_")
+                )),
+                pytest_template(&applet_name, &output_spec),
+            )?;
+        } else {
+            fs::write(
+                test_dir.join(format!("{applet_name}.bats")),
+                bats_template(&applet_name, &output_spec),
+            )?;
+        }
+    }
+
+    println!("See output in \"{}\"", dir.display());
+
+    Ok(())
+}
+
+// --------------------------------------------------
+// Sample inputs for "dxrs test", files point into "test/data"
+pub(crate) fn test_inputs_template(
+    input_spec: &[InputSpec],
+) -> serde_json::Value {
+    let mut inputs = serde_json::Map::new();
+    for input in input_spec {
+        if input.default.is_some() || input.optional.unwrap_or(false) {
+            continue;
+        }
+
+        let data_file = format!("test/data/{}.txt", input.name);
+        let value = match input.class {
+            InputOutputClass::Boolean => serde_json::json!(false),
+            InputOutputClass::File => serde_json::json!(data_file),
+            InputOutputClass::Float => serde_json::json!(0.0),
+            InputOutputClass::Hash => serde_json::json!({}),
+            InputOutputClass::Int => serde_json::json!(0),
+            InputOutputClass::ArrayFile => serde_json::json!([data_file]),
+            InputOutputClass::ArrayApplet
+            | InputOutputClass::ArrayBoolean
+            | InputOutputClass::ArrayFloat
+            | InputOutputClass::ArrayInt
+            | InputOutputClass::ArrayRecord
+            | InputOutputClass::ArrayString => serde_json::json!([]),
+            _ => serde_json::json!(""),
+        };
+        inputs.insert(input.name.clone(), value);
+    }
+    serde_json::Value::Object(inputs)
+}
+
+// --------------------------------------------------
+fn pytest_template(applet_name: &str, outputs: &[OutputSpec]) -> String {
+    let mut lines: Vec<String> = vec![
+        r#"""""#.to_string(),
+        format!("Local tests for {applet_name}, run with \"pytest test\""),
+        r#"""""#.to_string(),
+        "".to_string(),
+        "import json".to_string(),
+        "import os".to_string(),
+        "import subprocess".to_string(),
+        "".to_string(),
+        "APP_DIR = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))"
+            .to_string(),
+        "".to_string(),
+        "".to_string(),
+        "def test_run(tmp_path):".to_string(),
+        r#"    """ Runs with test/inputs.json """"#.to_string(),
+        "    out_file = tmp_path / 'job_output.json'".to_string(),
+        "    inputs = os.path.join(APP_DIR, 'test', 'inputs.json')".to_string(),
+        "    proc = subprocess.run(".to_string(),
+        "        ['dxrs', 'test', APP_DIR, '--input-json', inputs,".to_string(),
+        "         '--output', str(out_file)])".to_string(),
+        "    assert proc.returncode == 0".to_string(),
+        "".to_string(),
+        "    outputs = json.loads(out_file.read_text())".to_string(),
+    ];
+
+    for output in outputs.iter().filter(|o| !o.optional.unwrap_or(false)) {
+        lines.push(format!("    assert '{}' in outputs", output.name));
+    }
+
+    lines.push("".to_string());
+    lines.join("\n")
+}
+
+// --------------------------------------------------
+fn bats_template(applet_name: &str, outputs: &[OutputSpec]) -> String {
+    let mut lines: Vec<String> = vec![
+        "#!/usr/bin/env bats".to_string(),
+        format!("# Local tests for {applet_name}, run with \"bats test\""),
+        "".to_string(),
+        "setup() {".to_string(),
+        r#"    APP_DIR="$(cd "$(dirname "$BATS_TEST_FILENAME")/.." && pwd)""#
+            .to_string(),
+        r#"    OUT_FILE="$BATS_TEST_TMPDIR/job_output.json""#.to_string(),
+        "}".to_string(),
+        "".to_string(),
+        r#"@test "runs with test/inputs.json" {"#.to_string(),
+        r#"    run dxrs test "$APP_DIR" \"#.to_string(),
+        r#"        --input-json "$APP_DIR/test/inputs.json" \"#.to_string(),
+        r#"        --output "$OUT_FILE""#.to_string(),
+        r#"    [ "$status" -eq 0 ]"#.to_string(),
+    ];
+
+    for output in outputs.iter().filter(|o| !o.optional.unwrap_or(false)) {
+        lines.push(format!(r#"    grep -q '"{}"' "$OUT_FILE""#, output.name));
+    }
+
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    lines.join("\n")
+}
+
+// --------------------------------------------------
+fn get_inputs() -> Result<Vec<InputSpec>> {
+    println!(">>> Input Specification <<<");
+
+    let classes: Vec<String> =
+        InputOutputClass::iter().map(|v| v.to_string()).collect();
+    let mut input_spec: Vec<InputSpec> = vec![];
+
+    for num in 1.. {
+        let name_prompt =
+            &format!("{} input name* <ENTER to finish>:", Ordinal(num));
+
+        let taken: Vec<_> =
+            input_spec.iter().map(|v| v.name.as_str()).collect();
+
+        let name = get_identifier(name_prompt, &taken, None)?;
+
+        if name.is_empty() {
+            break;
+        }
+
+        let label = Text::new("Label:").prompt().ok();
+
+        let class = Select::new("Input Class", classes.clone())
+            .prompt()
+            .unwrap();
+        let class = InputOutputClass::from_str(&class)?;
+
+        let optional =
+            Select::new("Is this value optional?", ["No", "Yes"].to_vec())
+                .prompt()
+                .unwrap()
+                == "Yes";
+
+        let default: Option<serde_json::Value> = if optional {
+            Some(get_default(&class)?)
+        } else {
+            None
+        };
+
+        input_spec.push(InputSpec {
+            name,
+            label,
+            class,
+            optional: Some(optional),
+            default,
+            patterns: vec![],
+            help: None,
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        });
+    }
+
+    Ok(input_spec)
+}
+
+// --------------------------------------------------
+pub(crate) fn get_outputs(input_spec: &[InputSpec]) -> Result<Vec<OutputSpec>> {
+    println!(">>> Output Specification <<<");
+
+    let classes: Vec<String> =
+        InputOutputClass::iter().map(|v| v.to_string()).collect();
+    let mut output_spec: Vec<OutputSpec> = vec![];
+
+    for num in 1.. {
+        let name_prompt =
+            &format!("{} output name* <ENTER to finish>:", Ordinal(num));
+
+        // Make name unique across inputs and outputs for WDL
+        let mut taken: Vec<_> =
+            input_spec.iter().map(|v| v.name.as_str()).collect();
+        let mut out_names: Vec<_> =
+            output_spec.iter().map(|v| v.name.as_str()).collect();
+        taken.append(&mut out_names);
+
+        let name = get_identifier(name_prompt, &taken, None)?;
+        if name.is_empty() {
+            break;
+        }
+
+        let label = Text::new("Label:").prompt().ok();
+
+        let class = Select::new("Output Class", classes.clone())
+            .prompt()
+            .unwrap();
+        let class = InputOutputClass::from_str(&class)?;
+
+        let optional =
+            Select::new("Is this value optional?", ["No", "Yes"].to_vec())
+                .prompt()
+                .unwrap()
+                == "Yes";
+
+        output_spec.push(OutputSpec {
+            name,
+            class,
+            label,
+            help: None,
+            optional: Some(optional),
+            patterns: vec![],
+        });
+    }
+    Ok(output_spec)
+}
+
+// --------------------------------------------------
+pub fn wizard_asset(
+    name: Option<String>,
+    json_template: Option<String>,
+) -> Result<()> {
+    // TODO: Also parse a dxasset.json, ADD TESTS
+    let template: Result<Option<Vec<ExecDepends>>> = match json_template {
+        Some(filename) => {
+            let json = fs::read_to_string(&filename)?;
+            let app: DxApp = serde_json::from_str(&json)?;
+            if !app.run_spec.exec_depends.is_empty() {
+                Ok(Some(app.run_spec.exec_depends.clone()))
+            } else {
+                Ok(None)
+            }
+        }
+        _ => Ok(None),
+    };
+    let template = template.unwrap();
+
+    let asset_name = get_identifier("Asset Name:", &[], name)?;
+
+    let out_dir =
+        get_identifier("Output Directory:", &[], Some(asset_name.clone()))?;
+    let out_dir = Path::new(&out_dir);
+
+    if out_dir.is_dir() {
+        bail!(r#""{}" already exists."#, out_dir.display())
+    } else {
+        println!("Creating {}", out_dir.display());
+        fs::create_dir(out_dir)?;
+    }
+
+    let title = Text::new("Title:").prompt().unwrap();
+    let description = Text::new("Description:").prompt().unwrap();
+    let version = Text::new("Version:").prompt().unwrap();
+
+    let package_managers: Vec<String> =
+        PackageManager::iter().map(|v| v.to_string()).collect();
+
+    let mut exec_depends: Vec<ExecDepends> = vec![];
+    //let template_depends = template.run_spec.map(|r| r.exec_depends);
+
+    if let Some(deps) = template {
+        exec_depends = deps;
+    } else {
+        println!(">>> ExecDepends <<<");
+        for num in 1.. {
+            let name_prompt =
+                &format!("{} dependency* <ENTER to finish>:", Ordinal(num));
+
+            // Make name unique across inputs and outputs for WDL
+            let taken: Vec<_> =
+                exec_depends.iter().map(|v| v.name.as_str()).collect();
+
+            let dependency_name = get_identifier(name_prompt, &taken, None)?;
+            if dependency_name.is_empty() {
+                break;
+            }
+
+            let package_manager =
+                Select::new("Package Manager:", package_managers.to_vec())
+                    .prompt()
+                    .unwrap();
+
+            let asset_version = Text::new("Version:").prompt().unwrap();
+            let asset_version = if asset_version.is_empty() {
+                None
+            } else {
+                Some(asset_version)
+            };
+
+            // TODO: Add stages?
+            // let stages = Text::new("Stages:").prompt().unwrap();
+            exec_depends.push(ExecDepends {
+                name: dependency_name,
+                package_manager: Some(PackageManager::from_str(
+                    &package_manager,
+                )?),
+                version: asset_version,
+                stages: vec![],
+            });
+        }
+    }
+
+    let asset = DxAsset {
+        name: Some(asset_name),
+        title,
+        description: Some(description),
+        version: Some(version),
+        distribution: LinuxDistribution::Ubuntu,
+        release: Some(LinuxRelease::V20_04),
+        exec_depends,
+    };
+
+    fs::write(
+        out_dir.join("dxasset.json"),
+        serde_json::to_string_pretty(&asset)?,
+    )?;
+
+    println!(r#"See output in "{}""#, out_dir.display());
+
+    Ok(())
+}
+
+// --------------------------------------------------
+pub fn wizard_wdl(
+    name: Option<String>,
+    json_template: Option<String>,
+) -> Result<()> {
+    let res: Result<Option<(DxApp, Option<PathBuf>)>> = match json_template {
+        Some(filename) => {
+            let json = fs::read_to_string(&filename)?;
+            let app: DxApp = serde_json::from_str(&json)?;
+            let app_dir = Path::new(&filename).parent().expect("app_dir");
+            let run_file = match app.run_spec.file {
+                Some(ref f) => {
+                    let buf = &app_dir.join(f).to_path_buf();
+                    Some(buf.clone())
+                }
+                _ => None,
+            };
+
+            Ok(Some((app, run_file)))
+        }
+        _ => Ok(None),
+    };
+    let res = res.unwrap();
+
+    let (template, run_file) = match res {
+        Some((t, d)) => (Some(t), d),
+        None => (None, None),
+    };
+
+    let task_name = get_identifier("Task Name:", &[], name)?;
+    let out_dir = get_identifier("Output Directory:", &[], None)?;
+    let out_dir = Path::new(&out_dir);
+
+    if out_dir.is_dir() {
+        bail!(r#""{}" already exists."#, out_dir.display())
+    } else {
+        println!("Creating {}", out_dir.display());
+        fs::create_dir(out_dir)?;
+    }
+
+    //let run_file = app
+    //    .run_spec
+    //    .file
+    //    .map(|f| &applet_dir.join(f).to_path_buf().clone());
+
+    let input_spec: Vec<InputSpec> = match &template {
+        Some(app) => app.input_spec.clone(),
+        _ => get_inputs()?,
+    };
+
+    let output_spec: Vec<OutputSpec> = match &template {
+        Some(app) => app.output_spec.clone(),
+        _ => get_outputs(&input_spec)?,
+    };
+
+    let wdl =
+        wdl_template(&task_name, &input_spec, &output_spec, run_file.as_ref())?;
+
+    fs::write(out_dir.join("main.wdl"), wdl)?;
+
+    Ok(())
+}
+
+// --------------------------------------------------
+fn readme_template(applet_name: &str) -> String {
+    let lines = vec![
+        "<!-- dx-header -->".to_string(),
+        format!("# {applet_name} (DNAnexus Platform App)"),
+        "".to_string(),
+        "This is the source code for an app that runs on the DNAnexus Platform.".to_string(),
+        "For more information about how to run or modify it, see".to_string(),
+        "https://documentation.dnanexus.com/.".to_string(),
+        "<!-- /dx-header -->".to_string(),
+        "".to_string(),
+        "<!-- Insert a description of your app here -->".to_string(),
+        "".to_string(),
+        "<!--".to_string(),
+        "TODO: This app directory was automatically generated by a wizard.".to_string(),
+        "Please edit this Readme.md file to include essential documentation about".to_string(),
+        "your app that would be helpful to users. (Also see the".to_string(),
+        "Readme.developer.md.) Once you're done, you can remove these TODO".to_string(),
+        "comments.".to_string(),
+        "".to_string(),
+        "For more info, see https://documentation.dnanexus.com/developer.".to_string(),
+        "-->".to_string(),
+    ];
+
+    lines.join("\n")
+}
+
+// --------------------------------------------------
+fn readme_dev_template(applet_name: &str) -> String {
+    let lines = vec![
+        format!("# {applet_name}"),
+        "".to_string(),
+        "<!--".to_string(),
+        "TODO: Please edit this Readme.developer.md file to include information".to_string(),
+        "for developers or advanced users, for example:".to_string(),
+        "".to_string(),
+        "* Information about app internals and implementation details".to_string(),
+        "* How to report bugs or contribute to development".to_string(),
+"-->".to_string(),
+    ];
+
+    lines.join("\n")
+}
+
+// --------------------------------------------------
+// TODO: Add tests
+pub(crate) fn python_template(
+    applet_name: &str,
+    inputs: &[InputSpec],
+    outputs: &[OutputSpec],
+) -> String {
+    let mut lines: Vec<String> = vec![
+        "#!/usr/bin/env python".to_string(),
+        r#"""""#.to_string(),
+        format!("{applet_name}"),
+        r#"""""#.to_string(),
+        "".to_string(),
+        "import os".to_string(),
+        "import dxpy".to_string(),
+        "".to_string(),
+        "@dxpy.entry_point('main')".to_string(),
+        format!(
+            "def main({}):",
+            inputs
+                .iter()
+                .map(|v| v.name.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        r#"    """ Entry Point """"#.to_string(),
+        "".to_string(),
+    ];
+
+    if !inputs.is_empty() {
+        lines.push("    # Inputs".to_string());
+        for input in inputs.iter() {
+            match input.class {
+                InputOutputClass::Applet => lines.push(format!(
+                    "    {} = dxpy.DXApplet({})",
+                    input.name, input.name
+                )),
+                InputOutputClass::ArrayApplet => lines.push(format!(
+                    "    {} = [dxpy.DXApplet(item) for item in {}]",
+                    input.name, input.name
+                )),
+                InputOutputClass::ArrayFile => {
+                    lines.push(format!(
+                        "    {} = [dxpy.DXFile(item) for item in {}]",
+                        input.name, input.name
+                    ));
+                    lines.push(format!(
+                        "    for i, file in enumerate({}):",
+                        input.name,
+                    ));
+                    lines.push(format!(
+                        "{}dxpy.download_dxfile(file.get_id(), f'{}-{{i}}')",
+                        " ".repeat(8),
+                        input.name
+                    ));
+                }
+                InputOutputClass::ArrayRecord => lines.push(format!(
+                    "    {} = [dxpy.DXRecord(item) for item in {}]",
+                    input.name, input.name
+                )),
+                InputOutputClass::File => {
+                    lines.push(format!(
+                        "    {} = dxpy.DXFile({})",
+                        input.name, input.name
+                    ));
+                    lines.push(format!(
+                        "    dxpy.download_dxfile({}.get_id(), '{}')",
+                        input.name, input.name
+                    ));
+                }
+                InputOutputClass::Record => lines.push(format!(
+                    "    {} = dxpy.DXRecord({})",
+                    input.name, input.name
+                )),
+                _ => (),
+            }
+        }
+        lines.push("".to_string());
+    }
+
+    lines.push("    # Add your code here".to_string());
+    lines.push("".to_string());
+    lines.push("    # Outputs".to_string());
+    lines.push("    output = {}".to_string());
+
+    for output in outputs.iter() {
+        match output.class {
+            InputOutputClass::Applet => lines.push(format!(
+                "    output['{}'] = dxpy.dxlink({}_id)",
+                output.name, output.name
+            )),
+            InputOutputClass::ArrayApplet => {
+                lines.push(format!("    output['{}'] = [", output.name));
+                lines.push(format!(
+                    "{}dxpy.dxlink(item) for item in {}_ids",
+                    " ".repeat(8),
+                    output.name
+                ));
+                lines.push("    ]".to_string());
+            }
+            InputOutputClass::ArrayFile => {
+                lines.push(format!("    {}_ids = [", output.name));
+                lines.push(format!(
+                    "{}dxpy.upload_local_file(file) for file in {}",
+                    " ".repeat(8),
+                    output.name
+                ));
+                lines.push("    ]".to_string());
+                lines.push(format!("    output['{}'] = [", output.name));
+                lines.push(format!(
+                    "{}dxpy.dxlink(item) for item in {}_ids",
+                    " ".repeat(8),
+                    output.name
+                ));
+                lines.push("    ]".to_string());
+            }
+            InputOutputClass::ArrayRecord => {
+                lines.push(format!("    output['{}'] = [", output.name));
+                lines.push(format!(
+                    "{}dxpy.dxlink(item) for item in {}",
+                    " ".repeat(8),
+                    output.name
+                ));
+                lines.push("    ]".to_string());
+            }
+            InputOutputClass::File => {
+                lines.push(format!(
+                    "    {}_id = dxpy.upload_local_file('{}')",
+                    output.name, output.name
+                ));
+                lines.push(format!(
+                    "    output['{}'] = dxpy.dxlink({}_id)",
+                    output.name, output.name
+                ));
+            }
+            InputOutputClass::Record => lines.push(format!(
+                "    output['{}'] = dxpy.dxlink({})",
+                output.name, output.name
+            )),
+            _ => lines.push(format!(
+                "    output['{}'] = {}",
+                output.name, output.name
+            )),
+        }
+    }
+
+    lines.push("    return output".to_string());
+    lines.push("".to_string());
+    lines.push("dxpy.run()".to_string());
+    lines.push("".to_string());
+    lines.join("\n")
+}
+
+// --------------------------------------------------
+pub(crate) fn wdl_template(
+    applet_name: &str,
+    inputs: &[InputSpec],
+    outputs: &[OutputSpec],
+    run_file: Option<&PathBuf>,
+) -> Result<String> {
+    let mut lines: Vec<String> = vec![
+        "version 1.0".to_string(),
+        "".to_string(),
+        format!("task {applet_name} {{"),
+        "    input {".to_string(),
+    ];
+
+    // Inputs
+    for input in inputs {
+        lines.push(format!(
+            "{}{}{} {}",
+            " ".repeat(8),
+            &input.class.wdl_class(),
+            input.optional.map_or("".to_string(), |val| if val {
+                "?".to_string()
+            } else {
+                "".to_string()
+            }),
+            input.name
+        ));
+    }
+    lines.push("    }".to_string());
+
+    lines.push("".to_string());
+    lines.push("    command <<<".to_string());
+    let commands: String = match run_file {
+        Some(f) => {
+            let fh = BufReader::new(File::open(f)?);
+            let spaces = " ".repeat(8);
+            fh.lines().map_while(Result::ok).fold(
+                String::new(),
+                |mut output, line| {
+                    let _ = writeln!(output, "{spaces}{line}");
+                    output
+                },
+            )
+        }
+        _ => "".to_string(),
+    };
+    lines.push(commands);
+    lines.push("    >>>".to_string());
+    lines.push("".to_string());
+
+    // Runtime
+    lines.push("    runtime {".to_string());
+    lines.push(format!(r#"{}docker: """#, " ".repeat(8)));
+    lines.push(format!(r#"{}dx_instance_type: """#, " ".repeat(8)));
+    lines.push("    }".to_string());
+    lines.push("".to_string());
+
+    // Outputs
+    lines.push("    output {".to_string());
+    for output in outputs {
+        lines.push(format!(
+            r#"{}{}{} {} = """#,
+            " ".repeat(8),
+            &output.class.wdl_class(),
+            output.optional.map_or("".to_string(), |val| if val {
+                "?".to_string()
+            } else {
+                "".to_string()
+            }),
+            output.name
+        ));
+    }
+    lines.push("    }".to_string());
+    lines.push("}".to_string());
+    lines.push("".to_string());
+
+    Ok(lines.join("\n"))
+}
+
+// --------------------------------------------------
+// TODO: Expand tests
+pub(crate) fn bash_template(
+    applet_name: &str,
+    inputs: &[InputSpec],
+    outputs: &[OutputSpec],
+) -> String {
+    let mut lines: Vec<String> = vec![
+        "#!/usr/bin/env bash".to_string(),
+        format!("# {applet_name}"),
+        "".to_string(),
+        "set -exo pipefail".to_string(),
+        "".to_string(),
+        "main() {".to_string(),
+    ];
+
+    if !inputs.is_empty() {
+        lines.push("    # Inputs".to_string());
+        for input in inputs.iter() {
+            lines.push(format!(
+                r#"    echo "Value of {}: \"${{{}}}\"""#,
+                input.name, input.name
+            ));
+        }
+
+        lines.push("".to_string());
+
+        for input in inputs.iter() {
+            match input.class {
+                InputOutputClass::File => lines.push(format!(
+                    r#"    dx download "${}" -o "{}_name""#,
+                    input.name, input.name
+                )),
+                InputOutputClass::ArrayFile => {
+                    lines.push(format!(
+                        "    for i in ${{{}[@]}}; do",
+                        input.name
+                    ));
+                    lines.push(format!(
+                        r#"        dx download "${{{}[${{i}}]}}" -o "{}-${{i}}""#,
+                        input.name, input.name,
+                    ));
+                    lines.push("    done".to_string());
+                }
+                _ => (),
+            }
+        }
+
+        lines.push("".to_string());
+    }
+
+    lines.push("    # Add your code here".to_string());
+
+    if !outputs.is_empty() {
+        lines.push("".to_string());
+        lines.push("    # Outputs".to_string());
+        for output in outputs {
+            match output.class {
+                InputOutputClass::File => {
+                    lines.push(format!(
+                        r#"    {}_id=$(dx upload "{}_name" --brief)"#,
+                        output.name, output.name
+                    ));
+                    lines.push(format!(
+                    r#"    dx-jobutil-add-output {} "${{{}_id}}" --class=file"#,
+                    output.name, output.name
+                ));
+                }
+                InputOutputClass::ArrayFile => {
+                    lines.push(format!(
+                        "    for i in ${{{}[@]}}; do",
+                        output.name
+                    ));
+                    lines.push(format!(
+                        r#"{}${{{}[$i]}}=$(dx upload "{}-${{i}}" --brief)"#,
+                        " ".repeat(8),
+                        output.name,
+                        output.name
+                    ));
+                    lines.push("    done".to_string());
+                    lines.push(format!(
+                    r#"    dx-jobutil-add-output {} "${{{}[@]}}" --class "array:file""#,
+                    output.name, output.name,
+                ));
+                }
+                _ => (),
+            }
+        }
+    }
+
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    lines.join("\n")
+}
+
+// --------------------------------------------------
+fn get_identifier(
+    message: &str,
+    taken: &[&str],
+    initial_value: Option<String>,
+) -> Result<String> {
+    loop {
+        let prompt = match initial_value {
+            Some(ref val) => Text::new(message).with_initial_value(val),
+            _ => Text::new(message),
+        };
+        let name = prompt.prompt().unwrap();
+        if name.is_empty() {
+            return Ok(name);
+        }
+
+        match normalize(name) {
+            Err(e) => println!("{e}"),
+            Ok(name) => {
+                if taken.iter().any(|v| v == &name) {
+                    println!("\"{name}\" is already taken");
+                } else {
+                    return Ok(name);
+                }
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+fn get_timeout() -> Result<(TimeoutUnit, u32)> {
+    let re = Regex::new(r"^(\d+)([hmd])$").unwrap();
+
+    loop {
+        let val = Text::new("Timeout <ENTER> to exit:")
+            .with_default("48h")
+            .prompt()
+            .unwrap();
+
+        if val.is_empty() {
+            bail!("Could not get timeout")
+        }
+
+        match re.captures(&val) {
+            Some(caps) => {
+                let num: u32 = caps[1].parse()?;
+                let unit = TimeoutUnit::from_str(&caps[2])?;
+                return Ok((unit, num));
+            }
+            _ => println!("\"{val}\" is not a valid timeout"),
+        }
+    }
+}
+
+// --------------------------------------------------
+fn get_cluster_spec(dir: &Path) -> Result<Option<ClusterSpec>> {
+    let cluster_type = Select::new(
+        "Cluster Type:",
+        ["None", "generic", "dxspark", "apachespark"].to_vec(),
+    )
+    .prompt()
+    .unwrap();
+
+    if cluster_type == "None" {
+        return Ok(None);
+    }
+
+    let version = if cluster_type == "generic" {
+        "".to_string()
+    } else {
+        let versions: Vec<_> = VALID_CLUSTER_SPEC_VERSION
+            .iter()
+            .filter(|v| !v.is_empty())
+            .rev()
+            .copied()
+            .collect();
+        Select::new("Spark Version:", versions)
+            .prompt()
+            .unwrap()
+            .to_string()
+    };
+
+    let initial_instance_count = loop {
+        let val = Text::new("Initial Instance Count:")
+            .with_default("1")
+            .prompt()
+            .unwrap();
+        match val.parse::<u32>() {
+            Ok(num) if num > 0 => break num,
+            _ => println!("\"{val}\" is not a positive integer"),
+        }
+    };
+
+    let bootstrap_script = Text::new("Bootstrap Script <ENTER> for none:")
+        .with_placeholder("src/bootstrap.sh")
+        .prompt()
+        .unwrap();
+    if !bootstrap_script.is_empty() {
+        let path = dir.join(&bootstrap_script);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(
+            path,
+            "#!/usr/bin/env bash\n# Runs on every cluster node at startup\n",
+        )?;
+    }
+
+    Ok(Some(ClusterSpec {
+        cluster_type: cluster_type.to_string(),
+        version,
+        initial_instance_count,
+        ports: "".to_string(),
+        bootstrap_script,
+    }))
+}
+
+// --------------------------------------------------
+fn get_https_app() -> Result<Option<HttpsApp>> {
+    let serve =
+        Select::new("Serve a Web App (httpsApp):", ["No", "Yes"].to_vec())
+            .prompt()
+            .unwrap()
+            == "Yes";
+
+    if !serve {
+        return Ok(None);
+    }
+
+    let ports = loop {
+        let ports = MultiSelect::new("Ports:", VALID_HTTPS_APP_PORTS.to_vec())
+            .with_default(&[0])
+            .prompt()
+            .unwrap();
+        if !ports.is_empty() {
+            break ports;
+        }
+        println!("Select at least one port");
+    };
+
+    let shared_access =
+        Select::new("Shared Access:", VALID_HTTPS_APP_SHARED_ACCESS.to_vec())
+            .prompt()
+            .unwrap()
+            .to_string();
+
+    loop {
+        let hostname = Text::new("DNS Hostname <ENTER> for none:")
+            .with_placeholder("my-app")
+            .prompt()
+            .unwrap();
+        let https_app = HttpsApp {
+            ports: ports.clone(),
+            shared_access: shared_access.clone(),
+            dns: (!hostname.is_empty()).then_some(HttpsAppDns { hostname }),
+        };
+        match lint_app_https_app(&https_app).first() {
+            Some(problem) => println!("{problem}"),
+            _ => return Ok(Some(https_app)),
+        }
+    }
+}
+
+// --------------------------------------------------
+fn get_default(class: &InputOutputClass) -> Result<serde_json::Value> {
+    loop {
+        let val = Text::new("Default Value <ENTER> to quit:")
+            .prompt()
+            .unwrap();
+
+        if val.is_empty() {
+            break;
+        }
+
+        let parsed: Result<serde_json::Value> = match class {
+            InputOutputClass::Float => {
+                val.parse::<f64>().map_err(|e| anyhow!(e)).map(|v| v.into())
+            }
+            InputOutputClass::Int => {
+                val.parse::<u64>().map_err(|e| anyhow!(e)).map(|v| v.into())
+            }
+            _ => Ok(val.into()),
+        };
+
+        match parsed {
+            Ok(v) => return Ok(v),
+            Err(e) => println!("{e}"),
+        }
+    }
+
+    bail!("Unable to get default value")
+}
+
+// --------------------------------------------------
+pub(crate) fn normalize(val: String) -> Result<String> {
+    let char_start = Regex::new(r"^[A-Za-z]").unwrap();
+
+    match char_start.find(&val) {
+        Some(_) => {
+            let spaces = Regex::new(r"\s").unwrap();
+            let bad_chars = Regex::new(r"[^a-zA-Z0-9_.-]").unwrap();
+            Ok(bad_chars
+                .replace_all(&spaces.replace(&val, "_"), "")
+                .to_string())
+        }
+        _ => bail!("Value \"{val}\" must begin with a character"),
+    }
+}
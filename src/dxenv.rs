@@ -1,13 +1,66 @@
-use anyhow::{bail, Result};
+use crate::exit::CliError;
+use crate::theme::Theme;
+use anyhow::{anyhow, bail, Result};
 use dirs::home_dir;
-use serde::{Deserialize, Serialize};
+use inquire::Confirm;
+use keyring::Entry;
+use once_cell::sync::OnceCell;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
     env,
-    fs::{self, File},
-    path::PathBuf,
+    fs::{self, File, OpenOptions},
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
 };
 
-#[derive(Debug, Serialize, Deserialize)]
+const DX_TOOLKIT_VARS: &[&str] = &[
+    "DX_APISERVER_HOST",
+    "DX_APISERVER_PORT",
+    "DX_APISERVER_PROTOCOL",
+    "DX_CLI_WD",
+    "DX_PROJECT_CONTEXT_ID",
+    "DX_PROJECT_CONTEXT_NAME",
+    "DX_SECURITY_CONTEXT",
+    "DX_USERNAME",
+];
+
+/// Profile chosen with the global "--profile" option
+static PROFILE: OnceCell<String> = OnceCell::new();
+
+const KEYCHAIN_SERVICE: &str = "dxrs";
+
+/// Number of earlier copies kept of each config file
+const BACKUP_COUNT: usize = 3;
+
+const LOCK_FILE: &str = ".dxrs.lock";
+
+thread_local! {
+    /// Nesting depth and open lock file of the config lock
+    static CONFIG_LOCK: RefCell<(usize, Option<File>)> =
+        const { RefCell::new((0, None)) };
+}
+
+/// Held while the config is read and rewritten so that concurrent
+/// invocations do not clobber each other's changes
+struct ConfigLock;
+
+impl Drop for ConfigLock {
+    fn drop(&mut self) {
+        CONFIG_LOCK.with(|lock| {
+            let mut lock = lock.borrow_mut();
+            lock.0 -= 1;
+            if lock.0 == 0 {
+                // Closing the file releases the lock
+                lock.1 = None;
+            }
+        });
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DxEnvironment {
     pub apiserver_protocol: String,
 
@@ -26,6 +79,123 @@ pub struct DxEnvironment {
     pub auth_token_type: String,
 
     pub auth_token: String,
+
+    /// Keep the token in the config file rather than the keychain
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub plaintext_store: bool,
+
+    /// IDs of the projects last chosen with "select", newest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_projects: Vec<String>,
+
+    /// Working directory before the last "cd" for "cd -"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_wd: Option<String>,
+
+    /// When the token expires in milliseconds since the epoch, if known,
+    /// so that commands can warn before it does
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_token_expires: Option<i64>,
+}
+
+impl Default for DxEnvironment {
+    fn default() -> Self {
+        DxEnvironment {
+            apiserver_protocol: "https".to_string(),
+            username: "".to_string(),
+            cli_wd: "/".to_string(),
+            apiserver_host: "api.dnanexus.com".to_string(),
+            project_context_id: "".to_string(),
+            project_context_name: "".to_string(),
+            apiserver_port: 443,
+            auth_token_type: "Bearer".to_string(),
+            auth_token: "".to_string(),
+            plaintext_store: false,
+            recent_projects: vec![],
+            previous_wd: None,
+            auth_token_expires: None,
+        }
+    }
+}
+
+impl DxEnvironment {
+    /// Base URL of the API server, e.g., "https://api.dnanexus.com"
+    pub fn api_url(&self) -> String {
+        let default_port = match self.apiserver_protocol.as_str() {
+            "http" => 80,
+            _ => 443,
+        };
+        if self.apiserver_port == default_port {
+            format!("{}://{}", self.apiserver_protocol, self.apiserver_host)
+        } else {
+            format!(
+                "{}://{}:{}",
+                self.apiserver_protocol,
+                self.apiserver_host,
+                self.apiserver_port
+            )
+        }
+    }
+
+    /// Variables in the Python dx-toolkit's "environment.json" scheme
+    pub fn dx_toolkit_vars(&self) -> BTreeMap<String, String> {
+        let security = serde_json::json!({
+            "auth_token_type": self.auth_token_type,
+            "auth_token": self.auth_token,
+        });
+
+        BTreeMap::from([
+            ("DX_APISERVER_HOST", self.apiserver_host.clone()),
+            ("DX_APISERVER_PORT", self.apiserver_port.to_string()),
+            ("DX_APISERVER_PROTOCOL", self.apiserver_protocol.clone()),
+            ("DX_CLI_WD", self.cli_wd.clone()),
+            ("DX_PROJECT_CONTEXT_ID", self.project_context_id.clone()),
+            ("DX_PROJECT_CONTEXT_NAME", self.project_context_name.clone()),
+            ("DX_SECURITY_CONTEXT", security.to_string()),
+            ("DX_USERNAME", self.username.clone()),
+        ])
+        .into_iter()
+        .map(|(key, val)| (key.to_string(), val))
+        .collect()
+    }
+
+    /// Base URL of the matching auth server, which shares the API
+    /// server's domain, e.g., "stagingapi" becomes "stagingauth"
+    pub fn auth_url(&self) -> String {
+        format!(
+            "{}://{}",
+            self.apiserver_protocol,
+            self.apiserver_host.replacen("api", "auth", 1)
+        )
+    }
+}
+
+/// Named environments, e.g., for production and staging servers
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DxProfiles {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<String>,
+
+    #[serde(default)]
+    pub profiles: BTreeMap<String, DxEnvironment>,
+}
+
+/// Settings shared by every profile
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DxConfig {
+    #[serde(default)]
+    pub colors: Theme,
+
+    /// Do not offer again to import the dx-toolkit login
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub declined_import: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DxSecurityContext {
+    auth_token_type: String,
+
+    auth_token: String,
 }
 
 // --------------------------------------------------
@@ -44,6 +214,330 @@ fn dx_env_json() -> Result<PathBuf> {
     dx_env_dir().map(|dir| dir.join("dx_env.json"))
 }
 
+// --------------------------------------------------
+pub fn templates_dir() -> Result<PathBuf> {
+    dx_env_dir().map(|dir| dir.join("templates"))
+}
+
+// --------------------------------------------------
+pub fn cache_dir() -> Result<PathBuf> {
+    dx_env_dir().map(|dir| dir.join("cache"))
+}
+
+// --------------------------------------------------
+pub fn builds_dir() -> Result<PathBuf> {
+    dx_env_dir().map(|dir| dir.join("builds"))
+}
+
+// --------------------------------------------------
+fn profiles_json() -> Result<PathBuf> {
+    dx_env_dir().map(|dir| dir.join("profiles.json"))
+}
+
+// --------------------------------------------------
+fn config_json() -> Result<PathBuf> {
+    dx_env_dir().map(|dir| dir.join("config.json"))
+}
+
+// --------------------------------------------------
+/// The settings in "config.json," which is edited by hand but for
+/// remembering answers to prompts
+pub fn get_config() -> Result<DxConfig> {
+    let file = config_json()?;
+    if file.is_file() {
+        let contents = fs::read_to_string(&file)?;
+        serde_json::from_str(&contents)
+            .map_err(|e| anyhow!(r#""{}" is invalid: {e}"#, file.display()))
+    } else {
+        Ok(DxConfig::default())
+    }
+}
+
+// --------------------------------------------------
+fn save_config(config: &DxConfig) -> Result<()> {
+    let conf_dir = dx_env_dir()?;
+    if !conf_dir.is_dir() {
+        fs::create_dir(&conf_dir)?;
+    }
+
+    let _lock = lock_config()?;
+    write_json_atomic(&config_json()?, config, false)
+}
+
+// --------------------------------------------------
+// The lock is reentrant so that, e.g., "add_profile" can hold it around
+// "save_profiles". There is nothing to lock before the directory exists.
+fn lock_config() -> Result<ConfigLock> {
+    CONFIG_LOCK.with(|lock| {
+        let mut lock = lock.borrow_mut();
+        if lock.1.is_none() {
+            let conf_dir = dx_env_dir()?;
+            if conf_dir.is_dir() {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .truncate(false)
+                    .write(true)
+                    .open(conf_dir.join(LOCK_FILE))?;
+                file.lock()?;
+                lock.1 = Some(file);
+            }
+        }
+        lock.0 += 1;
+        Ok(ConfigLock)
+    })
+}
+
+// --------------------------------------------------
+fn backup_path(path: &Path, num: usize) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".bak.{num}"));
+    path.with_file_name(name)
+}
+
+// --------------------------------------------------
+// Writes a temp file beside the target and renames it into place so that
+// a crash or a concurrent reader never sees a partial file
+fn write_json_atomic<T: Serialize>(
+    path: &Path,
+    value: &T,
+    backup: bool,
+) -> Result<()> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
+    serde_json::to_writer_pretty(&mut tmp, value)?;
+    tmp.flush()?;
+    tmp.as_file().sync_all()?;
+
+    // A file that does not parse is not worth keeping as a backup
+    let previous = fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<Value>(&contents).ok());
+    if let Some(mut previous) = previous.filter(|_| backup) {
+        for num in (1..BACKUP_COUNT).rev() {
+            let older = backup_path(path, num);
+            if older.is_file() {
+                fs::rename(older, backup_path(path, num + 1))?;
+            }
+        }
+        blank_tokens(&mut previous);
+        fs::write(
+            backup_path(path, 1),
+            serde_json::to_string_pretty(&previous)?,
+        )?;
+    }
+
+    tmp.persist(path)?;
+    Ok(())
+}
+
+// --------------------------------------------------
+// Backups outlive the token they were taken with, so only a token that
+// is meant to be kept in plaintext is copied into one
+fn blank_tokens(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let plaintext = map
+                .get("plaintext_store")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            if !plaintext {
+                if let Some(token) = map.get_mut("auth_token") {
+                    *token = Value::String("".to_string());
+                }
+            }
+            map.values_mut().for_each(blank_tokens);
+        }
+        Value::Array(values) => values.iter_mut().for_each(blank_tokens),
+        _ => (),
+    }
+}
+
+// --------------------------------------------------
+/// Deletes the backups of the config files, e.g., after logging out
+pub fn remove_backups() -> Result<()> {
+    let _lock = lock_config()?;
+    for path in [dx_env_json()?, profiles_json()?] {
+        for num in 1..=BACKUP_COUNT {
+            let backup = backup_path(&path, num);
+            if backup.is_file() {
+                fs::remove_file(backup)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// A truncated or corrupt file is replaced by the newest readable backup
+fn read_json<T: DeserializeOwned + Serialize>(path: &Path) -> Result<T> {
+    let contents = fs::read_to_string(path)?;
+    let err = match serde_json::from_str(&contents) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+
+    for num in 1..=BACKUP_COUNT {
+        let backup = backup_path(path, num);
+        let Some(value) = fs::read_to_string(&backup)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<T>(&contents).ok())
+        else {
+            continue;
+        };
+
+        eprintln!(
+            r#""{}" is corrupt ({err}), restored it from "{}""#,
+            path.display(),
+            backup.display()
+        );
+        write_json_atomic(path, &value, false)?;
+        return Ok(value);
+    }
+
+    bail!(
+        "\"{}\" is corrupt and has no readable backup: {err}\n\
+        Remove it and run \"dxrs login\"",
+        path.display()
+    )
+}
+
+// --------------------------------------------------
+pub fn set_profile(profile: Option<String>) {
+    if let Some(profile) = profile {
+        let _ = PROFILE.set(profile);
+    }
+}
+
+// --------------------------------------------------
+pub fn get_profiles() -> Result<DxProfiles> {
+    let file = profiles_json()?;
+    if file.is_file() {
+        let _lock = lock_config()?;
+        read_json(&file)
+    } else {
+        Ok(DxProfiles::default())
+    }
+}
+
+// --------------------------------------------------
+pub fn save_profiles(profiles: &DxProfiles) -> Result<()> {
+    let conf_dir = dx_env_dir()?;
+    if !conf_dir.is_dir() {
+        fs::create_dir(&conf_dir)?;
+    }
+
+    let _lock = lock_config()?;
+    write_json_atomic(&profiles_json()?, profiles, true)
+}
+
+// --------------------------------------------------
+pub fn add_profile(name: &str, dx_env: DxEnvironment) -> Result<()> {
+    let _lock = lock_config()?;
+    let mut profiles = get_profiles()?;
+
+    // Keep the existing login as "default" so adding a second
+    // server does not lose the first one
+    if profiles.profiles.is_empty() {
+        let file = dx_env_json()?;
+        if file.is_file() && name != "default" {
+            let current = read_json::<DxEnvironment>(&file)?;
+            profiles.profiles.insert("default".to_string(), current);
+            profiles.current = Some("default".to_string());
+        }
+    }
+
+    if profiles.current.is_none() {
+        profiles.current = Some(name.to_string());
+    }
+
+    let mut dx_env = dx_env;
+    stash_token(name, &mut dx_env);
+    profiles.profiles.insert(name.to_string(), dx_env);
+    save_profiles(&profiles)
+}
+
+// --------------------------------------------------
+pub fn delete_profile(name: &str) -> Result<()> {
+    let _lock = lock_config()?;
+    let mut profiles = get_profiles()?;
+    match profiles.profiles.remove(name) {
+        Some(mut dx_env) => {
+            dx_env.auth_token = "".to_string();
+            stash_token(name, &mut dx_env);
+        }
+        _ => bail!(r#"Unknown profile "{name}""#),
+    }
+
+    if profiles.current.as_deref() == Some(name) {
+        profiles.current = None;
+    }
+    save_profiles(&profiles)
+}
+
+// --------------------------------------------------
+fn keychain_entry(
+    profile: &str,
+    dx_env: &DxEnvironment,
+) -> keyring::Result<Entry> {
+    Entry::new(
+        KEYCHAIN_SERVICE,
+        &format!("{profile}@{}", dx_env.apiserver_host),
+    )
+}
+
+// --------------------------------------------------
+// Moves the token into the keychain and blanks it in the config, or
+// removes the keychain entry when the token is empty (e.g., logout)
+fn stash_token(profile: &str, dx_env: &mut DxEnvironment) {
+    if dx_env.plaintext_store {
+        return;
+    }
+
+    let entry = match keychain_entry(profile, dx_env) {
+        Ok(entry) => entry,
+        Err(e) => {
+            eprintln!("Keychain unavailable, storing token in plaintext: {e}");
+            dx_env.plaintext_store = true;
+            return;
+        }
+    };
+
+    if dx_env.auth_token.is_empty() {
+        let _ = entry.delete_credential();
+    } else {
+        match entry.set_password(&dx_env.auth_token) {
+            Ok(_) => dx_env.auth_token = "".to_string(),
+            Err(e) => {
+                eprintln!(
+                    "Keychain unavailable, storing token in plaintext: {e}"
+                );
+                dx_env.plaintext_store = true;
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+// Fills in a token kept in the keychain
+fn unstash_token(profile: &str, dx_env: &mut DxEnvironment) -> Result<()> {
+    if dx_env.plaintext_store || !dx_env.auth_token.is_empty() {
+        return Ok(());
+    }
+
+    match keychain_entry(profile, dx_env).and_then(|e| e.get_password()) {
+        Ok(token) => dx_env.auth_token = token,
+        Err(keyring::Error::NoEntry) => (),
+        Err(e) => bail!("Cannot read token from keychain: {e}"),
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+/// The "--profile" option overrides the profile set by "profile switch"
+pub fn active_profile(profiles: &DxProfiles) -> Option<String> {
+    PROFILE.get().cloned().or(profiles.current.clone())
+}
+
 // --------------------------------------------------
 pub fn get_dx_username() -> Option<String> {
     if let Ok(conf_dir) = dx_env_dir() {
@@ -56,26 +550,667 @@ pub fn get_dx_username() -> Option<String> {
 
 // --------------------------------------------------
 pub fn get_dx_env() -> Result<DxEnvironment> {
-    let file = dx_env_json()?;
-    if file.is_file() {
-        let contents = fs::read_to_string(file)?;
-        Ok(serde_json::from_str::<DxEnvironment>(&contents)?)
+    // A token in the environment needs no saved login, e.g., in CI
+    let mut dx_env = match env_auth_token() {
+        Some(token) => DxEnvironment {
+            auth_token: token,
+            auth_token_type: "Bearer".to_string(),
+            ..saved_dx_env(false)?.unwrap_or_default()
+        },
+        _ => match saved_dx_env(true)? {
+            Some(dx_env) => dx_env,
+            _ => bail!(CliError::Permission("Please login".to_string())),
+        },
+    };
+    apply_env_vars(&mut dx_env, |name| env::var(name).ok());
+    Ok(dx_env)
+}
+
+// --------------------------------------------------
+// The API server can be set in the environment, and a running job
+// works in its temporary workspace rather than a project
+fn apply_env_vars(
+    dx_env: &mut DxEnvironment,
+    var: impl Fn(&str) -> Option<String>,
+) {
+    if let Some(host) = var("DX_APISERVER_HOST") {
+        dx_env.apiserver_host = host;
+    }
+    if let Some(port) = var("DX_APISERVER_PORT").and_then(|p| p.parse().ok()) {
+        dx_env.apiserver_port = port;
+    }
+    if let Some(protocol) = var("DX_APISERVER_PROTOCOL") {
+        dx_env.apiserver_protocol = protocol;
+    }
+
+    if var("DX_JOB_ID").is_some() {
+        if let Some(workspace_id) = var("DX_WORKSPACE_ID") {
+            dx_env.project_context_name = workspace_id.clone();
+            dx_env.project_context_id = workspace_id;
+        }
+    }
+}
+
+// --------------------------------------------------
+/// ID of the job when running inside a job worker
+pub fn job_id() -> Option<String> {
+    env::var("DX_JOB_ID").ok().filter(|id| !id.is_empty())
+}
+
+// --------------------------------------------------
+/// Token from $DX_API_TOKEN or a dx-toolkit $DX_SECURITY_CONTEXT
+pub fn env_auth_token() -> Option<String> {
+    if let Ok(token) = env::var("DX_API_TOKEN") {
+        if !token.is_empty() {
+            return Some(token);
+        }
+    }
+
+    let context = env::var("DX_SECURITY_CONTEXT").ok()?;
+    serde_json::from_str::<DxSecurityContext>(&context)
+        .ok()
+        .map(|context| context.auth_token)
+}
+
+// --------------------------------------------------
+/// The saved expiry of the token, read without touching the keychain or
+/// the dx-toolkit config so that it is cheap to check before a command
+pub fn saved_token_expiry() -> Option<i64> {
+    fn read<T: DeserializeOwned>(path: PathBuf) -> Option<T> {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+    }
+
+    let profiles: DxProfiles = read(profiles_json().ok()?).unwrap_or_default();
+    match active_profile(&profiles) {
+        Some(name) => profiles.profiles.get(&name)?.auth_token_expires,
+        _ => read::<DxEnvironment>(dx_env_json().ok()?)?.auth_token_expires,
+    }
+}
+
+// --------------------------------------------------
+fn saved_dx_env(import: bool) -> Result<Option<DxEnvironment>> {
+    let _lock = lock_config()?;
+    let profiles = get_profiles()?;
+    let profile = active_profile(&profiles);
+    let dx_env = if let Some(name) = &profile {
+        match profiles.profiles.get(name) {
+            Some(dx_env) => dx_env.clone(),
+            _ => bail!(r#"Unknown profile "{name}""#),
+        }
+    } else {
+        let file = dx_env_json()?;
+        if file.is_file() {
+            read_json::<DxEnvironment>(&file)?
+        } else if !import {
+            return Ok(None);
+        } else if let Some(dx_env) = import_dx_toolkit_env()? {
+            save_dx_env(&dx_env)?;
+            return Ok(Some(dx_env));
+        } else {
+            return Ok(None);
+        }
+    };
+
+    let mut dx_env = dx_env;
+    if !dx_env.plaintext_store && !dx_env.auth_token.is_empty() {
+        // Move a token saved by an older version into the keychain
+        write_dx_env(&dx_env)?;
     } else {
-        bail!("Please login")
+        unstash_token(profile.as_deref().unwrap_or("default"), &mut dx_env)?;
+    }
+
+    // Pick up a newer "dx login" or "dx select" from the dx-toolkit
+    if profile.is_none() {
+        if let Some(updated) = newer_dx_toolkit_env(&dx_env)? {
+            write_dx_env(&updated)?;
+            return Ok(Some(updated));
+        }
+    }
+    Ok(Some(dx_env))
+}
+
+// --------------------------------------------------
+fn dx_toolkit_env_json() -> Result<PathBuf> {
+    dx_env_dir().map(|dir| dir.join("environment.json"))
+}
+
+// --------------------------------------------------
+fn newer_dx_toolkit_env(
+    dx_env: &DxEnvironment,
+) -> Result<Option<DxEnvironment>> {
+    let toolkit_file = dx_toolkit_env_json()?;
+    if !toolkit_file.is_file() {
+        return Ok(None);
+    }
+
+    let modified = |path: &Path| fs::metadata(path).and_then(|m| m.modified());
+    if modified(&toolkit_file)? <= modified(&dx_env_json()?)? {
+        return Ok(None);
+    }
+
+    let vars = read_dx_toolkit_vars(&dx_env_dir()?)?;
+    Ok(dx_toolkit_update(dx_env, &vars))
+}
+
+// --------------------------------------------------
+// The dx-toolkit's environment when it differs from ours
+fn dx_toolkit_update(
+    dx_env: &DxEnvironment,
+    vars: &HashMap<String, String>,
+) -> Option<DxEnvironment> {
+    let mut toolkit_env = dx_toolkit_env(vars)?;
+
+    // Our export leaves out a token kept in the keychain
+    if toolkit_env.auth_token.is_empty() {
+        toolkit_env.auth_token = dx_env.auth_token.clone();
+    }
+    let ours: HashMap<_, _> = dx_env.dx_toolkit_vars().into_iter().collect();
+    let theirs: HashMap<_, _> =
+        toolkit_env.dx_toolkit_vars().into_iter().collect();
+
+    // Only the fields the dx-toolkit shares change, and the expiry is
+    // unknown for a token from a new login
+    let same_token = toolkit_env.auth_token == dx_env.auth_token;
+    (ours != theirs).then(|| DxEnvironment {
+        apiserver_protocol: toolkit_env.apiserver_protocol,
+        username: toolkit_env.username,
+        cli_wd: toolkit_env.cli_wd,
+        apiserver_host: toolkit_env.apiserver_host,
+        project_context_id: toolkit_env.project_context_id,
+        project_context_name: toolkit_env.project_context_name,
+        apiserver_port: toolkit_env.apiserver_port,
+        auth_token_type: toolkit_env.auth_token_type,
+        auth_token: toolkit_env.auth_token,
+        auth_token_expires: dx_env.auth_token_expires.filter(|_| same_token),
+        ..dx_env.clone()
+    })
+}
+
+// --------------------------------------------------
+// Keeps the dx-toolkit in step, but only for users who already have
+// its environment file
+fn export_dx_toolkit_env(dx_env: &DxEnvironment) -> Result<()> {
+    let toolkit_file = dx_toolkit_env_json()?;
+    if toolkit_file.is_file() {
+        if !dx_env.plaintext_store {
+            note!(
+                "The token is kept in the keychain, so it is not shared \
+                with the dx-toolkit"
+            );
+        }
+        // Other settings in the file are the dx-toolkit's own
+        let mut vars: serde_json::Map<String, Value> =
+            fs::read_to_string(&toolkit_file)
+                .ok()
+                .and_then(|contents| serde_json::from_str(&contents).ok())
+                .unwrap_or_default();
+        vars.extend(
+            dx_toolkit_export(dx_env)
+                .into_iter()
+                .map(|(key, val)| (key, Value::String(val))),
+        );
+        write_json_atomic(&toolkit_file, &vars, false)?;
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// A token kept in the keychain stays out of the dx-toolkit's plaintext
+// file
+fn dx_toolkit_export(dx_env: &DxEnvironment) -> BTreeMap<String, String> {
+    let mut vars = dx_env.dx_toolkit_vars();
+    if !dx_env.plaintext_store {
+        let security = serde_json::json!({
+            "auth_token_type": dx_env.auth_token_type,
+            "auth_token": "",
+        });
+        vars.insert("DX_SECURITY_CONTEXT".to_string(), security.to_string());
+    }
+    vars
+}
+
+// --------------------------------------------------
+fn import_dx_toolkit_env() -> Result<Option<DxEnvironment>> {
+    // The Python dx-toolkit shares the config directory, so offer
+    // to reuse its session rather than forcing a new login
+    let conf_dir = dx_env_dir()?;
+    let vars = read_dx_toolkit_vars(&conf_dir)?;
+    if vars.is_empty() {
+        return Ok(None);
+    }
+
+    let dx_env = match dx_toolkit_env(&vars) {
+        Some(dx_env) if !dx_env.auth_token.is_empty() => dx_env,
+        _ => return Ok(None),
+    };
+
+    // Nobody can answer in a script, and a "no" is asked only once
+    if !io::stdin().is_terminal()
+        || get_config().is_ok_and(|config| config.declined_import)
+    {
+        return Ok(None);
+    }
+
+    let consent = Confirm::new(&format!(
+        r#"Import existing dx-toolkit login for "{}"?"#,
+        dx_env.username
+    ))
+    .with_default(true)
+    .prompt();
+
+    match consent {
+        Ok(true) => Ok(Some(dx_env)),
+        Ok(false) => {
+            let config = DxConfig {
+                declined_import: true,
+                ..get_config()?
+            };
+            save_config(&config)?;
+            Ok(None)
+        }
+        // E.g., escaped, so ask again next time
+        _ => Ok(None),
+    }
+}
+
+// --------------------------------------------------
+fn read_dx_toolkit_vars(conf_dir: &Path) -> Result<HashMap<String, String>> {
+    // Newer dx-toolkit versions write "environment.json",
+    // older versions write one file per variable
+    let env_json = conf_dir.join("environment.json");
+    if env_json.is_file() {
+        let contents = fs::read_to_string(env_json)?;
+        let vars: HashMap<String, serde_json::Value> =
+            serde_json::from_str(&contents)?;
+        return Ok(vars
+            .into_iter()
+            .map(|(key, val)| match val {
+                serde_json::Value::String(s) => (key, s),
+                other => (key, other.to_string()),
+            })
+            .collect());
+    }
+
+    let mut vars = HashMap::new();
+    for name in DX_TOOLKIT_VARS {
+        if let Ok(val) = fs::read_to_string(conf_dir.join(name)) {
+            vars.insert(name.to_string(), val.trim().to_string());
+        }
     }
+    Ok(vars)
+}
+
+// --------------------------------------------------
+fn dx_toolkit_env(vars: &HashMap<String, String>) -> Option<DxEnvironment> {
+    let security: DxSecurityContext =
+        serde_json::from_str(vars.get("DX_SECURITY_CONTEXT")?).ok()?;
+    let get = |key: &str, default: &str| {
+        vars.get(key).cloned().unwrap_or(default.to_string())
+    };
+
+    Some(DxEnvironment {
+        apiserver_protocol: get("DX_APISERVER_PROTOCOL", "https"),
+        username: get("DX_USERNAME", ""),
+        cli_wd: get("DX_CLI_WD", "/"),
+        apiserver_host: get("DX_APISERVER_HOST", "api.dnanexus.com"),
+        project_context_id: get("DX_PROJECT_CONTEXT_ID", ""),
+        project_context_name: get("DX_PROJECT_CONTEXT_NAME", ""),
+        apiserver_port: get("DX_APISERVER_PORT", "443").parse().ok()?,
+        auth_token_type: security.auth_token_type,
+        auth_token: security.auth_token,
+        plaintext_store: false,
+        recent_projects: vec![],
+        previous_wd: None,
+        auth_token_expires: None,
+    })
 }
 
 // --------------------------------------------------
 pub fn save_dx_env(dx_env: &DxEnvironment) -> Result<()> {
+    let _lock = lock_config()?;
+
+    // Never write a token that came from the environment to disk
+    let mut dx_env = dx_env.clone();
+    if env_auth_token().as_ref() == Some(&dx_env.auth_token) {
+        dx_env.auth_token = saved_dx_env(false)?
+            .map(|saved| saved.auth_token)
+            .unwrap_or_default();
+    }
+
+    write_dx_env(&dx_env)
+}
+
+// --------------------------------------------------
+/// Saves the environment of a new login along with its token, even one
+/// that also came from the environment, since logging in asks for that
+pub fn save_login(dx_env: &DxEnvironment) -> Result<()> {
+    let _lock = lock_config()?;
+    write_dx_env(dx_env)
+}
+
+// --------------------------------------------------
+fn write_dx_env(dx_env: &DxEnvironment) -> Result<()> {
+    let toolkit_env = dx_env;
+    let mut dx_env = dx_env.clone();
+    let _lock = lock_config()?;
+    let mut profiles = get_profiles()?;
+    let profile = active_profile(&profiles);
+    stash_token(profile.as_deref().unwrap_or("default"), &mut dx_env);
+
+    if let Some(name) = profile {
+        profiles.profiles.insert(name, dx_env);
+        return save_profiles(&profiles);
+    }
+
     let conf_dir = dx_env_dir()?;
-    //dbg!(&conf_dir);
 
     if !conf_dir.is_dir() {
         fs::create_dir(&conf_dir)?;
     }
 
-    let dx_env_file = dx_env_json()?;
-    let fh = File::create(dx_env_file)?;
-    serde_json::to_writer_pretty(&fh, &dx_env)?;
-    Ok(())
+    // The directory may have just been created, so take the file lock
+    let _lock = lock_config()?;
+    write_json_atomic(&dx_env_json()?, &dx_env, true)?;
+    export_dx_toolkit_env(toolkit_env)
+}
+
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_env_vars, backup_path, dx_toolkit_env, dx_toolkit_export,
+        dx_toolkit_update, read_dx_toolkit_vars, read_json, write_json_atomic,
+        DxEnvironment, BACKUP_COUNT,
+    };
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+    use std::{collections::HashMap, fs};
+
+    #[test]
+    fn test_read_dx_toolkit_environment_json() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(
+            dir.path().join("environment.json"),
+            r#"{
+                "DX_APISERVER_HOST": "api.dnanexus.com",
+                "DX_APISERVER_PORT": "443",
+                "DX_APISERVER_PROTOCOL": "https",
+                "DX_CLI_WD": "/data",
+                "DX_PROJECT_CONTEXT_ID": "project-GbxZVz8071x9yvpXgxV4gVjK",
+                "DX_PROJECT_CONTEXT_NAME": "test",
+                "DX_SECURITY_CONTEXT": "{\"auth_token_type\": \"Bearer\", \"auth_token\": \"abc\"}",
+                "DX_USERNAME": "kyclark"
+            }"#,
+        )?;
+
+        let vars = read_dx_toolkit_vars(dir.path())?;
+        let dx_env = dx_toolkit_env(&vars).expect("environment");
+        assert_eq!(dx_env.username, "kyclark");
+        assert_eq!(dx_env.cli_wd, "/data");
+        assert_eq!(dx_env.apiserver_port, 443);
+        assert_eq!(dx_env.auth_token_type, "Bearer");
+        assert_eq!(dx_env.auth_token, "abc");
+        assert_eq!(
+            dx_env.project_context_id,
+            "project-GbxZVz8071x9yvpXgxV4gVjK"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_dx_toolkit_var_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("DX_USERNAME"), "kyclark\n")?;
+        fs::write(dir.path().join("DX_CLI_WD"), "/\n")?;
+
+        // No security context means nothing to import
+        let vars = read_dx_toolkit_vars(dir.path())?;
+        assert_eq!(vars.get("DX_USERNAME").unwrap(), "kyclark");
+        assert!(dx_toolkit_env(&vars).is_none());
+
+        fs::write(
+            dir.path().join("DX_SECURITY_CONTEXT"),
+            r#"{"auth_token_type": "Bearer", "auth_token": "abc"}"#,
+        )?;
+        let vars = read_dx_toolkit_vars(dir.path())?;
+        let dx_env = dx_toolkit_env(&vars).expect("environment");
+        assert_eq!(dx_env.apiserver_host, "api.dnanexus.com");
+        assert_eq!(dx_env.auth_token, "abc");
+        Ok(())
+    }
+
+    #[test]
+    fn test_server_urls() {
+        let mut dx_env = DxEnvironment {
+            apiserver_protocol: "https".to_string(),
+            username: "kyclark".to_string(),
+            cli_wd: "/".to_string(),
+            apiserver_host: "stagingapi.dnanexus.com".to_string(),
+            project_context_id: "".to_string(),
+            project_context_name: "".to_string(),
+            apiserver_port: 443,
+            auth_token_type: "Bearer".to_string(),
+            auth_token: "abc".to_string(),
+            plaintext_store: false,
+            recent_projects: vec![],
+            previous_wd: None,
+            auth_token_expires: None,
+        };
+        assert_eq!(dx_env.api_url(), "https://stagingapi.dnanexus.com");
+        assert_eq!(dx_env.auth_url(), "https://stagingauth.dnanexus.com");
+
+        dx_env.apiserver_port = 8124;
+        assert_eq!(dx_env.api_url(), "https://stagingapi.dnanexus.com:8124");
+    }
+
+    #[test]
+    fn test_apply_env_vars() {
+        let vars = HashMap::from([
+            ("DX_APISERVER_HOST", "10.0.3.1"),
+            ("DX_APISERVER_PORT", "8124"),
+            ("DX_APISERVER_PROTOCOL", "http"),
+            ("DX_WORKSPACE_ID", "container-GJzjbP008QGyXPpkFv7bxf1G"),
+        ]);
+        let var = |name: &str| vars.get(name).map(|v| v.to_string());
+
+        // The workspace is only used inside a job
+        let mut dx_env = DxEnvironment::default();
+        apply_env_vars(&mut dx_env, var);
+        assert_eq!(dx_env.api_url(), "http://10.0.3.1:8124");
+        assert_eq!(dx_env.project_context_id, "");
+
+        let mut vars = vars.clone();
+        vars.insert("DX_JOB_ID", "job-GFfkqz0054JJG8p1GBpv7qGb");
+        let mut dx_env = DxEnvironment::default();
+        apply_env_vars(&mut dx_env, |name| {
+            vars.get(name).map(|v| v.to_string())
+        });
+        assert_eq!(
+            dx_env.project_context_id,
+            "container-GJzjbP008QGyXPpkFv7bxf1G"
+        );
+    }
+
+    #[test]
+    fn test_dx_toolkit_vars() {
+        let dx_env = DxEnvironment {
+            username: "kyclark".to_string(),
+            cli_wd: "/data".to_string(),
+            project_context_id: "project-GbxZVz8071x9yvpXgxV4gVjK".to_string(),
+            auth_token: "abc".to_string(),
+            ..Default::default()
+        };
+
+        let vars: HashMap<_, _> =
+            dx_env.dx_toolkit_vars().into_iter().collect();
+        assert_eq!(vars["DX_APISERVER_PORT"], "443");
+        let round_trip = dx_toolkit_env(&vars).expect("environment");
+        assert_eq!(round_trip.username, "kyclark");
+        assert_eq!(round_trip.cli_wd, "/data");
+        assert_eq!(round_trip.auth_token, "abc");
+        assert_eq!(
+            round_trip.project_context_id,
+            "project-GbxZVz8071x9yvpXgxV4gVjK"
+        );
+    }
+
+    #[test]
+    fn test_dx_toolkit_update() {
+        let dx_env = DxEnvironment {
+            username: "kyclark".to_string(),
+            auth_token: "abc".to_string(),
+            plaintext_store: true,
+            ..Default::default()
+        };
+
+        let vars: HashMap<_, _> =
+            dx_env.dx_toolkit_vars().into_iter().collect();
+        assert!(dx_toolkit_update(&dx_env, &vars).is_none());
+
+        // A new "dx login" changes the token
+        let mut vars = vars;
+        vars.insert(
+            "DX_SECURITY_CONTEXT".to_string(),
+            r#"{"auth_token_type": "Bearer", "auth_token": "xyz"}"#.to_string(),
+        );
+        let updated = dx_toolkit_update(&dx_env, &vars).expect("update");
+        assert_eq!(updated.auth_token, "xyz");
+        assert!(updated.plaintext_store);
+    }
+
+    #[test]
+    fn test_dx_toolkit_update_keeps_fields() {
+        let dx_env = DxEnvironment {
+            username: "kyclark".to_string(),
+            auth_token: "abc".to_string(),
+            plaintext_store: true,
+            previous_wd: Some("/data".to_string()),
+            auth_token_expires: Some(1700000000000),
+            ..Default::default()
+        };
+
+        // A "dx select" changes the project but not the token
+        let mut vars: HashMap<_, _> =
+            dx_env.dx_toolkit_vars().into_iter().collect();
+        vars.insert(
+            "DX_PROJECT_CONTEXT_ID".to_string(),
+            "project-GbxZVz8071x9yvpXgxV4gVjK".to_string(),
+        );
+        let updated = dx_toolkit_update(&dx_env, &vars).expect("update");
+        assert_eq!(
+            updated.project_context_id,
+            "project-GbxZVz8071x9yvpXgxV4gVjK"
+        );
+        assert_eq!(updated.previous_wd, Some("/data".to_string()));
+        assert_eq!(updated.auth_token_expires, Some(1700000000000));
+
+        // A new login's token has no known expiry
+        vars.insert(
+            "DX_SECURITY_CONTEXT".to_string(),
+            r#"{"auth_token_type": "Bearer", "auth_token": "xyz"}"#.to_string(),
+        );
+        let updated = dx_toolkit_update(&dx_env, &vars).expect("update");
+        assert_eq!(updated.previous_wd, Some("/data".to_string()));
+        assert_eq!(updated.auth_token_expires, None);
+    }
+
+    #[test]
+    fn test_dx_toolkit_export() {
+        let dx_env = DxEnvironment {
+            username: "kyclark".to_string(),
+            auth_token: "abc".to_string(),
+            ..Default::default()
+        };
+
+        // A token in the keychain is not exported
+        let vars: HashMap<_, _> =
+            dx_toolkit_export(&dx_env).into_iter().collect();
+        assert_eq!(dx_toolkit_env(&vars).expect("environment").auth_token, "");
+        assert_eq!(vars["DX_USERNAME"], "kyclark");
+
+        // Nor is it lost when read back
+        assert!(dx_toolkit_update(&dx_env, &vars).is_none());
+
+        let dx_env = DxEnvironment {
+            plaintext_store: true,
+            ..dx_env
+        };
+        let vars: HashMap<_, _> =
+            dx_toolkit_export(&dx_env).into_iter().collect();
+        assert_eq!(
+            dx_toolkit_env(&vars).expect("environment").auth_token,
+            "abc"
+        );
+    }
+
+    #[test]
+    fn test_write_json_atomic() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("dx_env.json");
+        for num in 0..BACKUP_COUNT + 2 {
+            write_json_atomic(&path, &num, true)?;
+        }
+
+        // Backups rotate, newest first, and no temp files remain
+        assert_eq!(fs::read_to_string(&path)?, "4");
+        assert_eq!(fs::read_to_string(backup_path(&path, 1))?, "3");
+        assert_eq!(fs::read_to_string(backup_path(&path, 3))?, "1");
+        assert!(!backup_path(&path, BACKUP_COUNT + 1).exists());
+        assert_eq!(fs::read_dir(dir.path())?.count(), BACKUP_COUNT + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_backup_blanks_token() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("dx_env.json");
+        let dx_env = DxEnvironment {
+            auth_token: "abc123".to_string(),
+            ..Default::default()
+        };
+        write_json_atomic(&path, &dx_env, true)?;
+        write_json_atomic(&path, &dx_env, true)?;
+        let backup: DxEnvironment = read_json(&backup_path(&path, 1))?;
+        assert_eq!(backup.auth_token, "");
+
+        // A token kept in plaintext on purpose is backed up as is
+        let dx_env = DxEnvironment {
+            plaintext_store: true,
+            ..dx_env
+        };
+        write_json_atomic(&path, &dx_env, true)?;
+        write_json_atomic(&path, &dx_env, true)?;
+        let backup: DxEnvironment = read_json(&backup_path(&path, 1))?;
+        assert_eq!(backup.auth_token, "abc123");
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_json() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("dx_env.json");
+        let dx_env = DxEnvironment {
+            username: "kyclark".to_string(),
+            ..Default::default()
+        };
+        write_json_atomic(&path, &dx_env, true)?;
+        write_json_atomic(&path, &dx_env, true)?;
+
+        // A truncated file is restored from the backup
+        let contents = fs::read_to_string(&path)?;
+        fs::write(&path, &contents[..contents.len() / 2])?;
+        let restored: DxEnvironment = read_json(&path)?;
+        assert_eq!(restored.username, "kyclark");
+        assert_eq!(fs::read_to_string(&path)?, contents);
+
+        // With no usable backup, the error explains what to do
+        fs::write(&path, "{")?;
+        fs::write(backup_path(&path, 1), "")?;
+        let res = read_json::<DxEnvironment>(&path);
+        assert!(res.is_err());
+        assert!(res.unwrap_err().to_string().contains("dxrs login"));
+        Ok(())
+    }
 }
@@ -0,0 +1,233 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// Compiled once and shared so that bulk operations are not
+// rebuilding the same patterns for every path and ID
+pub static ANALYSIS_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^analysis-[A-Za-z0-9]{24}$").unwrap());
+
+pub static APP_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^app-[A-Za-z0-9]{24}$").unwrap());
+
+pub static CONTAINER_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^container-[A-Za-z0-9]{24}$").unwrap());
+
+pub static FILE_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^file-[A-Za-z0-9]{24}$").unwrap());
+
+pub static JOB_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^job-[A-Za-z0-9]{24}$").unwrap());
+
+pub static WORKFLOW_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^workflow-[A-Za-z0-9]{24}$").unwrap());
+
+pub static PROJECT_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^project-[A-Za-z0-9]{24}$").unwrap());
+
+/// A file ID at the end of a string, e.g., a row in a selection table
+pub static TRAILING_FILE_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new("(file-[A-Za-z0-9]{24})$").unwrap());
+
+/// "project-xxxx:object-xxxx" or just "object-xxxx"
+pub static PROJECT_OBJECT_ID: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        "^(?:(project-[A-Za-z0-9]{24}):)?\
+        ((file|record|database|applet)-[A-Za-z0-9]{24})$",
+    )
+    .unwrap()
+});
+
+/// Applet or workflow ID (optionally project-qualified) or app ID/name
+pub static EXECUTABLE_ID: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        "^(?:(project-[A-Za-z0-9]{24}):)?\
+        ((?:applet|workflow)-[A-Za-z0-9]{24}|app-.+)$",
+    )
+    .unwrap()
+});
+
+/// A leading project ID and an optional ":path"
+pub static PROJECT_PREFIX: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^(project-[A-Za-z0-9]{24})(:(.*))?").unwrap());
+
+/// An optional "project-xxxx:" followed by a required path
+pub static PROJECT_PATH: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^(?:(project-[A-Za-z0-9]{24}):)?(.+)$").unwrap());
+
+/// An optional "project-xxxx" and ":" followed by an optional path
+pub static OPTIONAL_PROJECT_PATH: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^(project-[A-Za-z0-9]{24})?:?(.*)$").unwrap());
+
+/// Strips a leading ":" left over after removing a project ID
+pub static LEADING_COLON: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^:?(.+)?").unwrap());
+
+#[derive(Debug, PartialEq)]
+pub enum EntityId {
+    Analysis {
+        analysis_id: String,
+    },
+    App {
+        app_id: String,
+    },
+    Applet {
+        project_id: Option<String>,
+        applet_id: String,
+    },
+    Container {
+        container_id: String,
+    },
+    Database {
+        project_id: Option<String>,
+        database_id: String,
+    },
+    File {
+        project_id: Option<String>,
+        file_id: String,
+    },
+    Job {
+        job_id: String,
+    },
+    Project {
+        project_id: String,
+    },
+    Record {
+        project_id: Option<String>,
+        record_id: String,
+    },
+}
+
+impl EntityId {
+    pub fn parse(id: &str) -> Option<EntityId> {
+        if ANALYSIS_ID.is_match(id) {
+            Some(EntityId::Analysis {
+                analysis_id: id.to_string(),
+            })
+        } else if PROJECT_ID.is_match(id) {
+            Some(EntityId::Project {
+                project_id: id.to_string(),
+            })
+        } else if JOB_ID.is_match(id) {
+            Some(EntityId::Job {
+                job_id: id.to_string(),
+            })
+        } else if APP_ID.is_match(id) {
+            Some(EntityId::App {
+                app_id: id.to_string(),
+            })
+        } else if CONTAINER_ID.is_match(id) {
+            Some(EntityId::Container {
+                container_id: id.to_string(),
+            })
+        } else if let Some(caps) = PROJECT_OBJECT_ID.captures(id) {
+            let project_id = caps.get(1).map(|v| v.as_str().to_string());
+            let object_id = caps.get(2).unwrap().as_str().to_string();
+            match caps.get(3).unwrap().as_str() {
+                "file" => Some(EntityId::File {
+                    project_id,
+                    file_id: object_id,
+                }),
+                "record" => Some(EntityId::Record {
+                    project_id,
+                    record_id: object_id,
+                }),
+                "database" => Some(EntityId::Database {
+                    project_id,
+                    database_id: object_id,
+                }),
+                _ => Some(EntityId::Applet {
+                    project_id,
+                    applet_id: object_id,
+                }),
+            }
+        } else {
+            None
+        }
+    }
+}
+
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::EntityId;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(EntityId::parse(""), None);
+        assert_eq!(EntityId::parse("foo"), None);
+        assert_eq!(EntityId::parse("file-123"), None);
+
+        assert_eq!(
+            EntityId::parse("analysis-GFfkqz0054JJG8p1GBpv7qGX"),
+            Some(EntityId::Analysis {
+                analysis_id: "analysis-GFfkqz0054JJG8p1GBpv7qGX".to_string()
+            })
+        );
+
+        assert_eq!(
+            EntityId::parse("project-GYgj4800jZ5YqgZ24ZzJpZvq"),
+            Some(EntityId::Project {
+                project_id: "project-GYgj4800jZ5YqgZ24ZzJpZvq".to_string()
+            })
+        );
+
+        assert_eq!(
+            EntityId::parse("job-GFfkqz0054JJG8p1GBpv7qGb"),
+            Some(EntityId::Job {
+                job_id: "job-GFfkqz0054JJG8p1GBpv7qGb".to_string()
+            })
+        );
+
+        assert_eq!(
+            EntityId::parse("file-GFfbj0Q054J4ypqJ8vQjF4V7"),
+            Some(EntityId::File {
+                project_id: None,
+                file_id: "file-GFfbj0Q054J4ypqJ8vQjF4V7".to_string()
+            })
+        );
+
+        assert_eq!(
+            EntityId::parse(
+                "project-GYgj4800jZ5YqgZ24ZzJpZvq:\
+                record-GZ6vQPj0b5pJfbQ3XffQB1BJ"
+            ),
+            Some(EntityId::Record {
+                project_id: Some(
+                    "project-GYgj4800jZ5YqgZ24ZzJpZvq".to_string()
+                ),
+                record_id: "record-GZ6vQPj0b5pJfbQ3XffQB1BJ".to_string()
+            })
+        );
+
+        assert_eq!(
+            EntityId::parse("database-GZ6vP1801xf4fXjB3YVX011f"),
+            Some(EntityId::Database {
+                project_id: None,
+                database_id: "database-GZ6vP1801xf4fXjB3YVX011f".to_string()
+            })
+        );
+
+        assert_eq!(
+            EntityId::parse("applet-GZ2BF8Q0jZ5qj3bQBX5BFjjZ"),
+            Some(EntityId::Applet {
+                project_id: None,
+                applet_id: "applet-GZ2BF8Q0jZ5qj3bQBX5BFjjZ".to_string()
+            })
+        );
+
+        assert_eq!(
+            EntityId::parse("app-GJzjbP00vyjyXPpkFv7bxf1F"),
+            Some(EntityId::App {
+                app_id: "app-GJzjbP00vyjyXPpkFv7bxf1F".to_string()
+            })
+        );
+
+        assert_eq!(
+            EntityId::parse("container-GJzjbP008QGyXPpkFv7bxf1G"),
+            Some(EntityId::Container {
+                container_id: "container-GJzjbP008QGyXPpkFv7bxf1G".to_string()
+            })
+        );
+    }
+}
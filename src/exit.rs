@@ -0,0 +1,120 @@
+//! Exit codes so that scripts can branch on the kind of failure
+//!
+//! | Code | Meaning                                            |
+//! |------|----------------------------------------------------|
+//! | 0    | Success                                            |
+//! | 1    | Any other failure                                  |
+//! | 2    | Invalid usage, e.g., a bad argument or input       |
+//! | 3    | The object, file, or name was not found            |
+//! | 4    | Permission denied or not logged in                 |
+//! | 5    | Network failure or throttled by the platform       |
+//! | 6    | Timed out waiting on the platform                  |
+//! | 130  | Interrupted with Ctrl-C                            |
+use crate::DxErrorResponse;
+use std::{fmt, io};
+
+pub const SUCCESS: i32 = 0;
+pub const FAILURE: i32 = 1;
+pub const USAGE: i32 = 2;
+pub const NOT_FOUND: i32 = 3;
+pub const PERMISSION: i32 = 4;
+pub const NETWORK: i32 = 5;
+pub const TIMEOUT: i32 = 6;
+pub const INTERRUPTED: i32 = 130;
+
+/// A failure found before or without asking the API that should exit
+/// with a specific code
+#[derive(Debug)]
+pub enum CliError {
+    Usage(String),
+    NotFound(String),
+    Permission(String),
+    Timeout(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(msg)
+            | CliError::NotFound(msg)
+            | CliError::Permission(msg)
+            | CliError::Timeout(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+// --------------------------------------------------
+/// The exit code for an error, taken from the first cause that has one
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    err.chain()
+        .find_map(|cause| {
+            if let Some(e) = cause.downcast_ref::<CliError>() {
+                Some(match e {
+                    CliError::Usage(_) => USAGE,
+                    CliError::NotFound(_) => NOT_FOUND,
+                    CliError::Permission(_) => PERMISSION,
+                    CliError::Timeout(_) => TIMEOUT,
+                })
+            } else if let Some(e) = cause.downcast_ref::<DxErrorResponse>() {
+                api_error_code(e.error_type())
+            } else if cause.downcast_ref::<reqwest::Error>().is_some() {
+                Some(NETWORK)
+            } else if let Some(e) = cause.downcast_ref::<io::Error>() {
+                match e.kind() {
+                    io::ErrorKind::NotFound => Some(NOT_FOUND),
+                    io::ErrorKind::PermissionDenied => Some(PERMISSION),
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        })
+        .unwrap_or(FAILURE)
+}
+
+// --------------------------------------------------
+// Error types are listed in the platform's API documentation
+fn api_error_code(error_type: &str) -> Option<i32> {
+    match error_type {
+        "InvalidInput" | "InvalidType" | "MalformedJSON" => Some(USAGE),
+        "ResourceNotFound" => Some(NOT_FOUND),
+        "PermissionDenied" | "InvalidAuthentication" | "Unauthorized" => {
+            Some(PERMISSION)
+        }
+        "RateLimitConditional" | "ServiceUnavailable" => Some(NETWORK),
+        _ => None,
+    }
+}
+
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::{exit_code, CliError, FAILURE, NOT_FOUND, PERMISSION, USAGE};
+    use anyhow::{anyhow, Context};
+    use std::io;
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(exit_code(&anyhow!("anything")), FAILURE);
+        assert_eq!(
+            exit_code(&anyhow!(CliError::Usage("bad".to_string()))),
+            USAGE
+        );
+
+        let err = anyhow!(CliError::NotFound("gone".to_string()))
+            .context("Resolving path");
+        assert_eq!(exit_code(&err), NOT_FOUND);
+
+        let err: Result<(), io::Error> =
+            Err(io::Error::from(io::ErrorKind::PermissionDenied));
+        assert_eq!(exit_code(&err.context("Reading").unwrap_err()), PERMISSION);
+
+        let api_err: crate::DxErrorResponse = serde_json::from_str(
+            r#"{"error": {"type": "ResourceNotFound", "message": "No"}}"#,
+        )
+        .unwrap();
+        assert_eq!(exit_code(&anyhow!(api_err)), NOT_FOUND);
+    }
+}
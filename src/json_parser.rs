@@ -2,11 +2,12 @@ use anyhow::{bail, Result};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap},
     fmt,
     fs::File,
     io,
     io::{Read, Write},
+    str::FromStr,
 };
 use strum_macros::{EnumIter, EnumString};
 
@@ -156,6 +157,102 @@ pub const VALID_CLUSTER_SPEC_TYPE: &[&str] =
 
 pub const VALID_CLUSTER_SPEC_VERSION: &[&str] = &["", "2.4.4", "3.2.0"];
 
+pub const VALID_TIMEOUT_UNIT: &[&str] = &["minutes", "hours", "days"];
+
+pub const VALID_HTTPS_APP_PORTS: &[u32] = &[443, 8080, 8081];
+
+pub const VALID_HTTPS_APP_SHARED_ACCESS: &[&str] =
+    &["VIEW", "CONTRIBUTE", "ADMINISTER", "NONE"];
+
+const APP_KEYS: &[&str] = &[
+    "name",
+    "title",
+    "dxapi",
+    "summary",
+    "description",
+    "version",
+    "developerNotes",
+    "types",
+    "categories",
+    "billTo",
+    "openSource",
+    "developers",
+    "authorizedUsers",
+    "inputSpec",
+    "outputSpec",
+    "runSpec",
+    "httpsApp",
+    "access",
+    "regionalOptions",
+    "details",
+    "ignoreReuse",
+];
+
+const RUN_SPEC_KEYS: &[&str] = &[
+    "interpreter",
+    "file",
+    "distribution",
+    "release",
+    "version",
+    "code",
+    "headJobOnDemand",
+    "restartableEntryPoints",
+    "assetDepends",
+    "execDepends",
+    "systemRequirements",
+    "timeoutPolicy",
+];
+
+const INPUT_SPEC_KEYS: &[&str] = &[
+    "name",
+    "label",
+    "class",
+    "optional",
+    "default",
+    "patterns",
+    "help",
+    "choices",
+    "type",
+    "group",
+    "suggestions",
+];
+
+const OUTPUT_SPEC_KEYS: &[&str] =
+    &["name", "class", "label", "help", "optional", "patterns"];
+
+const ACCESS_KEYS: &[&str] = &[
+    "network",
+    "project",
+    "allProjects",
+    "developer",
+    "projectCreation",
+];
+
+const HTTPS_APP_KEYS: &[&str] =
+    &["ports", "sharedAccess", "shared_access", "dns"];
+
+const HTTPS_APP_DNS_KEYS: &[&str] = &["hostname"];
+
+const REGIONAL_OPTIONS_KEYS: &[&str] = &["resources", "systemRequirements"];
+
+const SYSTEM_REQUIREMENTS_KEYS: &[&str] = &["instanceType", "clusterSpec"];
+
+const CLUSTER_SPEC_KEYS: &[&str] = &[
+    "type",
+    "version",
+    "initialInstanceCount",
+    "ports",
+    "bootstrapScript",
+];
+
+const EXEC_DEPENDS_KEYS: &[&str] = &[
+    "name",
+    "package_manager",
+    "packageManager",
+    "version",
+    "stages",
+];
+
 // --------------------------------------------------
 #[derive(Debug, Serialize, Deserialize, EnumString, Clone)]
 pub enum Interpreter {
@@ -352,6 +449,20 @@ pub enum InputOutputClass {
 }
 
 impl InputOutputClass {
+    // The class of the elements of an array class, or the class itself
+    pub fn item_class(&self) -> InputOutputClass {
+        match self {
+            InputOutputClass::ArrayApplet => InputOutputClass::Applet,
+            InputOutputClass::ArrayBoolean => InputOutputClass::Boolean,
+            InputOutputClass::ArrayFile => InputOutputClass::File,
+            InputOutputClass::ArrayFloat => InputOutputClass::Float,
+            InputOutputClass::ArrayInt => InputOutputClass::Int,
+            InputOutputClass::ArrayRecord => InputOutputClass::Record,
+            InputOutputClass::ArrayString => InputOutputClass::String,
+            _ => self.clone(),
+        }
+    }
+
     pub fn wdl_class(&self) -> &'static str {
         match self {
             InputOutputClass::Applet => "Applet",
@@ -371,6 +482,43 @@ impl InputOutputClass {
             InputOutputClass::String => "String",
         }
     }
+
+    // The class for a WDL type, e.g., "Array[File]+" or "Map[String, Int]"
+    pub fn from_wdl(wdl_type: &str) -> Option<InputOutputClass> {
+        let wdl_type = wdl_type.trim_end_matches('+');
+        if let Some(item) = wdl_type
+            .strip_prefix("Array[")
+            .and_then(|t| t.strip_suffix(']'))
+        {
+            return match InputOutputClass::from_wdl(item)? {
+                InputOutputClass::Applet => Some(InputOutputClass::ArrayApplet),
+                InputOutputClass::Boolean => {
+                    Some(InputOutputClass::ArrayBoolean)
+                }
+                InputOutputClass::File => Some(InputOutputClass::ArrayFile),
+                InputOutputClass::Float => Some(InputOutputClass::ArrayFloat),
+                InputOutputClass::Int => Some(InputOutputClass::ArrayInt),
+                InputOutputClass::Record => Some(InputOutputClass::ArrayRecord),
+                InputOutputClass::String => Some(InputOutputClass::ArrayString),
+                _ => None,
+            };
+        }
+
+        match wdl_type {
+            "Applet" => Some(InputOutputClass::Applet),
+            "Boolean" => Some(InputOutputClass::Boolean),
+            "File" => Some(InputOutputClass::File),
+            "Float" => Some(InputOutputClass::Float),
+            "Int" => Some(InputOutputClass::Int),
+            "Record" => Some(InputOutputClass::Record),
+            "String" => Some(InputOutputClass::String),
+            "Hash" | "Object" => Some(InputOutputClass::Hash),
+            t if t.starts_with("Map[") || t.starts_with("Pair[") => {
+                Some(InputOutputClass::Hash)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for InputOutputClass {
@@ -395,6 +543,29 @@ impl fmt::Display for InputOutputClass {
     }
 }
 
+/// Which jobs of an app the platform may restart after a failure
+#[derive(Debug, Serialize, Deserialize, PartialEq, EnumString, Clone)]
+pub enum RestartableEntryPoints {
+    /// Only the job running "main"
+    #[strum(serialize = "master")]
+    #[serde(rename = "master")]
+    Master,
+
+    /// Any job, e.g., the subjobs of a scatter/gather app
+    #[strum(serialize = "all")]
+    #[serde(rename = "all")]
+    All,
+}
+
+impl fmt::Display for RestartableEntryPoints {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RestartableEntryPoints::Master => write!(f, "master"),
+            RestartableEntryPoints::All => write!(f, "all"),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, EnumString)]
 pub enum TimeoutUnit {
     #[strum(serialize = "minute", serialize = "m")]
@@ -531,7 +702,7 @@ pub struct RegionalOptions {
     pub system_requirements: HashMap<String, SystemRequirements>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct SystemRequirements {
     #[serde(rename = "instanceType")]
@@ -542,20 +713,26 @@ pub struct SystemRequirements {
     pub cluster_spec: Option<ClusterSpec>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct ClusterSpec {
     #[serde(rename = "type")]
     pub cluster_type: String,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub version: String,
 
     #[serde(rename = "initialInstanceCount")]
     pub initial_instance_count: u32,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub ports: String,
 
     #[serde(rename = "bootstrapScript")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub bootstrap_script: String,
 }
 
@@ -589,8 +766,19 @@ pub struct HttpsApp {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub ports: Vec<u32>,
 
-    #[serde(rename = "sharedAccess")]
+    /// Permission in the job's project that others need to open the app
+    #[serde(rename = "sharedAccess", alias = "shared_access")]
     pub shared_access: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns: Option<HttpsAppDns>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HttpsAppDns {
+    /// Prefix of the app's URL in place of the job ID
+    pub hostname: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -618,7 +806,7 @@ pub struct InputSpec {
 
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub choices: Vec<String>,
+    pub choices: Vec<serde_json::Value>,
 
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -691,7 +879,7 @@ pub struct RunSpec {
 
     #[serde(rename = "restartableEntryPoints")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restartable_entry_points: Option<String>,
+    pub restartable_entry_points: Option<RestartableEntryPoints>,
 
     #[serde(rename = "assetDepends")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -701,6 +889,12 @@ pub struct RunSpec {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub exec_depends: Vec<ExecDepends>,
 
+    /// Instance types by entry point, e.g., "main" or "*" for any other,
+    /// for every region that has none in "regionalOptions"
+    #[serde(default, rename = "systemRequirements")]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub system_requirements: HashMap<String, SystemRequirements>,
+
     #[serde(rename = "timeoutPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeout_policy: Option<HashMap<String, HashMap<TimeoutUnit, u32>>>,
@@ -718,11 +912,83 @@ pub struct ExecDepends {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 
+    /// Entry points that need the package, or all of them if empty
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub stages: Vec<String>,
 }
 
+impl DxApp {
+    /// Entry points named in system requirements or timeout policies,
+    /// always including "main"
+    pub fn entry_points(&self) -> BTreeSet<&str> {
+        let regional = self
+            .regional_options
+            .iter()
+            .flat_map(|opts| opts.values())
+            .flat_map(|opt| opt.system_requirements.keys());
+        let timeouts = self
+            .run_spec
+            .timeout_policy
+            .iter()
+            .flat_map(|policy| policy.keys());
+        self.run_spec
+            .system_requirements
+            .keys()
+            .chain(regional)
+            .chain(timeouts)
+            .map(|name| name.as_str())
+            .chain(["main"])
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintIssue {
+    pub severity: Severity,
+    pub path: String,
+    pub message: String,
+}
+
+impl LintIssue {
+    fn error(path: &str, message: String) -> Self {
+        LintIssue {
+            severity: Severity::Error,
+            path: path.to_string(),
+            message,
+        }
+    }
+
+    fn warning(path: &str, message: String) -> Self {
+        LintIssue {
+            severity: Severity::Warning,
+            path: path.to_string(),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}: {}", self.severity, self.path, self.message)
+    }
+}
+
 // --------------------------------------------------
 pub fn parse<T: for<'a> Deserialize<'a>>(filename: &str) -> Result<T> {
     match File::open(filename) {
@@ -778,6 +1044,12 @@ pub fn lint_app(app: &DxApp) -> Vec<String> {
         suggestions.push(s);
     }
 
+    suggestions.append(&mut lint_app_exec_depends_stages(app));
+
+    if let Some(https_app) = &app.https_app {
+        suggestions.append(&mut lint_app_https_app(https_app));
+    }
+
     if let Some(access_spec) = &app.access {
         let mut res = lint_app_access_spec(access_spec);
         if !res.is_empty() {
@@ -825,6 +1097,32 @@ fn lint_app_release_version(run_spec: &RunSpec) -> Option<String> {
     }
 }
 
+// --------------------------------------------------
+// A stage that is not an entry point is most likely misspelled, though
+// that cannot be known if "*" stands in for entry points not named
+fn lint_app_exec_depends_stages(app: &DxApp) -> Vec<String> {
+    let entry_points = app.entry_points();
+    if entry_points.contains("*") {
+        return vec![];
+    }
+
+    app.run_spec
+        .exec_depends
+        .iter()
+        .flat_map(|dep| {
+            dep.stages
+                .iter()
+                .filter(|stage| !entry_points.contains(stage.as_str()))
+                .map(|stage| {
+                    format!(
+                        r#"Package "{}" stage "{stage}" is not an entry point"#,
+                        dep.name
+                    )
+                })
+        })
+        .collect()
+}
+
 // --------------------------------------------------
 fn lint_app_regional_options(
     opts: &HashMap<String, RegionalOptions>,
@@ -877,8 +1175,7 @@ fn lint_app_cluster_spec(cluster_spec: &ClusterSpec) -> Vec<String> {
 
     let version = &cluster_spec.version;
     if !VALID_CLUSTER_SPEC_VERSION.contains(&version.as_str()) {
-        suggestions
-            .push(format!("Invalid cluster spec version \"{version}\""));
+        suggestions.push(format!("Invalid cluster spec version \"{version}\""));
     }
 
     let cluster_type = &cluster_spec.cluster_type;
@@ -902,8 +1199,41 @@ fn lint_app_access_spec(access: &AccessSpec) -> Vec<String> {
 
     if let Some(all_projects) = &access.all_projects {
         if !VALID_ACCESS_SPEC_OPTIONS.contains(&all_projects.as_str()) {
+            res.push(format!("Invalid allProjects access \"{all_projects}\""));
+        }
+    }
+
+    res
+}
+
+// --------------------------------------------------
+pub fn lint_app_https_app(https_app: &HttpsApp) -> Vec<String> {
+    let mut res = vec![];
+
+    if https_app.ports.is_empty() {
+        res.push("httpsApp needs at least one port".to_string());
+    }
+
+    for port in &https_app.ports {
+        if !VALID_HTTPS_APP_PORTS.contains(port) {
+            res.push(format!("Invalid port {port} (use 443, 8080, or 8081)"));
+        }
+    }
+
+    let shared_access = &https_app.shared_access;
+    if !VALID_HTTPS_APP_SHARED_ACCESS.contains(&shared_access.as_str()) {
+        res.push(format!(
+            r#"Invalid sharedAccess "{shared_access}" (use {})"#,
+            VALID_HTTPS_APP_SHARED_ACCESS.join(", ")
+        ));
+    }
+
+    if let Some(dns) = &https_app.dns {
+        let re = Regex::new(r"^[a-z0-9]([a-z0-9-]*[a-z0-9])?$").unwrap();
+        if !re.is_match(&dns.hostname) {
             res.push(format!(
-                "Invalid allProjects access \"{all_projects}\""
+                r#"Invalid hostname "{}" (use a-z, 0-9, and "-")"#,
+                dns.hostname
             ));
         }
     }
@@ -923,32 +1253,564 @@ pub fn lint_version(version: &Option<String>) -> Option<String> {
     })
 }
 
+// --------------------------------------------------
+// Validate the raw JSON of a dxapp.json against the app spec, reporting
+// each problem with the JSON path where it was found
+pub fn validate_app(app: &serde_json::Value) -> Vec<LintIssue> {
+    let mut issues = vec![];
+    let Some(obj) = app.as_object() else {
+        return vec![LintIssue::error(
+            "$",
+            "dxapp.json should be an object".to_string(),
+        )];
+    };
+
+    check_keys(obj, APP_KEYS, "$", &mut issues);
+    check_required(
+        obj,
+        &["name", "title", "inputSpec", "outputSpec"],
+        "$",
+        &mut issues,
+    );
+
+    match obj.get("runSpec") {
+        Some(run_spec) => validate_run_spec(run_spec, &mut issues),
+        _ => issues.push(LintIssue::error(
+            "$",
+            r#"Missing required key "runSpec""#.to_string(),
+        )),
+    }
+
+    if let Some(spec) = obj.get("inputSpec") {
+        validate_io_spec(spec, "$.inputSpec", INPUT_SPEC_KEYS, &mut issues);
+    }
+
+    if let Some(spec) = obj.get("outputSpec") {
+        validate_io_spec(spec, "$.outputSpec", OUTPUT_SPEC_KEYS, &mut issues);
+    }
+
+    if let Some(access) = obj.get("access") {
+        match access.as_object() {
+            Some(map) => check_keys(map, ACCESS_KEYS, "$.access", &mut issues),
+            _ => issues.push(LintIssue::error(
+                "$.access",
+                "Should be an object".to_string(),
+            )),
+        }
+    }
+
+    if let Some(https_app) = obj.get("httpsApp").and_then(|v| v.as_object()) {
+        check_keys(https_app, HTTPS_APP_KEYS, "$.httpsApp", &mut issues);
+        if let Some(dns) = https_app.get("dns").and_then(|v| v.as_object()) {
+            check_keys(dns, HTTPS_APP_DNS_KEYS, "$.httpsApp.dns", &mut issues);
+        }
+    }
+
+    if let Some(opts) = obj.get("regionalOptions") {
+        validate_regional_options(opts, &mut issues);
+    }
+
+    // Checks that are easier on the typed struct, once the shape is right
+    let has_errors = issues.iter().any(|i| i.severity == Severity::Error);
+    match serde_json::from_value::<DxApp>(app.clone()) {
+        Ok(dx_app) => {
+            if let Some(s) = lint_version(&dx_app.version) {
+                issues.push(LintIssue::warning("$.version", s));
+            }
+
+            if let Some(s) = lint_app_categories(&dx_app.categories) {
+                issues.push(LintIssue::warning("$.categories", s));
+            }
+
+            if let Some(s) = lint_app_release_version(&dx_app.run_spec) {
+                issues.push(LintIssue::error("$.runSpec.version", s));
+            }
+
+            for s in lint_app_exec_depends_stages(&dx_app) {
+                issues.push(LintIssue::warning("$.runSpec.execDepends", s));
+            }
+
+            if !dx_app.run_spec.system_requirements.is_empty()
+                && dx_app
+                    .regional_options
+                    .iter()
+                    .flatten()
+                    .any(|(_, opts)| !opts.system_requirements.is_empty())
+            {
+                issues.push(LintIssue::warning(
+                    "$.runSpec.systemRequirements",
+                    "Ignored in regions with their own in regionalOptions"
+                        .to_string(),
+                ));
+            }
+
+            if let Some(access) = &dx_app.access {
+                for s in lint_app_access_spec(access) {
+                    issues.push(LintIssue::error("$.access", s));
+                }
+            }
+
+            if let Some(https_app) = &dx_app.https_app {
+                for s in lint_app_https_app(https_app) {
+                    issues.push(LintIssue::error("$.httpsApp", s));
+                }
+            }
+        }
+        Err(e) if !has_errors => {
+            issues.push(LintIssue::error("$", e.to_string()))
+        }
+        _ => (),
+    }
+
+    issues
+}
+
+// --------------------------------------------------
+fn check_keys(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    known: &[&str],
+    path: &str,
+    issues: &mut Vec<LintIssue>,
+) {
+    for key in obj.keys() {
+        if !known.contains(&key.as_str()) {
+            issues.push(LintIssue::error(
+                &format!("{path}.{key}"),
+                format!(r#"Unknown field "{key}""#),
+            ));
+        }
+    }
+}
+
+// --------------------------------------------------
+fn check_required(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    required: &[&str],
+    path: &str,
+    issues: &mut Vec<LintIssue>,
+) {
+    for key in required {
+        if !obj.contains_key(*key) {
+            issues.push(LintIssue::error(
+                path,
+                format!(r#"Missing required key "{key}""#),
+            ));
+        }
+    }
+}
+
+// --------------------------------------------------
+fn validate_run_spec(
+    run_spec: &serde_json::Value,
+    issues: &mut Vec<LintIssue>,
+) {
+    let path = "$.runSpec";
+    let Some(obj) = run_spec.as_object() else {
+        issues.push(LintIssue::error(path, "Should be an object".to_string()));
+        return;
+    };
+
+    check_keys(obj, RUN_SPEC_KEYS, path, issues);
+    check_required(obj, &["distribution", "interpreter"], path, issues);
+
+    if !obj.contains_key("file") && !obj.contains_key("code") {
+        issues.push(LintIssue::error(
+            path,
+            r#"Missing required key "file" or "code""#.to_string(),
+        ));
+    }
+
+    let release = obj.get("release").and_then(|v| v.as_str());
+    if let Some(interpreter) = obj.get("interpreter") {
+        let interpreter_path = format!("{path}.interpreter");
+        match interpreter.as_str().map(Interpreter::from_str) {
+            Some(Ok(Interpreter::Python27)) if release != Some("16.04") => {
+                issues.push(LintIssue::error(
+                    &format!("{path}.release"),
+                    r#"Interpreter "python2.7" requires release "16.04""#
+                        .to_string(),
+                ))
+            }
+            Some(Ok(Interpreter::Python3))
+                if matches!(release, None | Some("14.04") | Some("16.04")) =>
+            {
+                issues.push(LintIssue::error(
+                    &format!("{path}.release"),
+                    r#"Interpreter "python3" requires release "20.04""#
+                        .to_string(),
+                ))
+            }
+            Some(Ok(_)) => (),
+            _ => issues.push(LintIssue::error(
+                &interpreter_path,
+                format!("Invalid interpreter {interpreter}"),
+            )),
+        }
+    }
+
+    if let Some(restartable) = obj.get("restartableEntryPoints") {
+        if restartable
+            .as_str()
+            .is_none_or(|v| RestartableEntryPoints::from_str(v).is_err())
+        {
+            issues.push(LintIssue::error(
+                &format!("{path}.restartableEntryPoints"),
+                format!(
+                    r#"Invalid value {restartable} (use "master" or "all")"#
+                ),
+            ));
+        }
+    }
+
+    if let Some(deps) = obj.get("execDepends").and_then(|v| v.as_array()) {
+        for (i, dep) in deps.iter().enumerate() {
+            let dep_path = format!("{path}.execDepends[{i}]");
+            if let Some(dep) = dep.as_object() {
+                check_keys(dep, EXEC_DEPENDS_KEYS, &dep_path, issues);
+                check_required(dep, &["name"], &dep_path, issues);
+
+                if let Some(stages) = dep.get("stages") {
+                    let is_names = stages.as_array().is_some_and(|vals| {
+                        vals.iter().all(|v| {
+                            v.as_str().is_some_and(|name| !name.is_empty())
+                        })
+                    });
+                    if !is_names {
+                        issues.push(LintIssue::error(
+                            &format!("{dep_path}.stages"),
+                            "Should be an array of entry point names"
+                                .to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(reqs) = obj.get("systemRequirements") {
+        validate_system_requirements(
+            reqs,
+            "*",
+            &format!("{path}.systemRequirements"),
+            issues,
+        );
+    }
+
+    if let Some(policy) = obj.get("timeoutPolicy") {
+        let policy_path = format!("{path}.timeoutPolicy");
+        let Some(entry_points) = policy.as_object() else {
+            issues.push(LintIssue::error(
+                &policy_path,
+                "Should be an object".to_string(),
+            ));
+            return;
+        };
+
+        for (entry_point, limits) in entry_points {
+            let entry_path = format!("{policy_path}.{entry_point}");
+            let Some(limits) = limits.as_object() else {
+                issues.push(LintIssue::error(
+                    &entry_path,
+                    "Should be an object".to_string(),
+                ));
+                continue;
+            };
+
+            for (unit, val) in limits {
+                let unit_path = format!("{entry_path}.{unit}");
+                if !VALID_TIMEOUT_UNIT.contains(&unit.as_str()) {
+                    issues.push(LintIssue::error(
+                        &unit_path,
+                        format!(
+                            r#"Invalid timeout unit "{unit}" (use {})"#,
+                            VALID_TIMEOUT_UNIT.join(", ")
+                        ),
+                    ));
+                } else if !val.is_u64() {
+                    issues.push(LintIssue::error(
+                        &unit_path,
+                        "Timeout should be a positive integer".to_string(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+fn validate_io_spec(
+    spec: &serde_json::Value,
+    path: &str,
+    known: &[&str],
+    issues: &mut Vec<LintIssue>,
+) {
+    let Some(items) = spec.as_array() else {
+        issues.push(LintIssue::error(path, "Should be an array".to_string()));
+        return;
+    };
+
+    for (i, item) in items.iter().enumerate() {
+        let item_path = format!("{path}[{i}]");
+        let Some(obj) = item.as_object() else {
+            issues.push(LintIssue::error(
+                &item_path,
+                "Should be an object".to_string(),
+            ));
+            continue;
+        };
+
+        check_keys(obj, known, &item_path, issues);
+        check_required(obj, &["name", "class"], &item_path, issues);
+
+        let class = match obj.get("class") {
+            Some(class) => {
+                match class.as_str().map(InputOutputClass::from_str) {
+                    Some(Ok(class)) => Some(class),
+                    _ => {
+                        issues.push(LintIssue::error(
+                            &format!("{item_path}.class"),
+                            format!("Invalid class {class}"),
+                        ));
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        // Nothing else can be checked without knowing the class
+        let Some(class) = class else { continue };
+
+        if obj.contains_key("patterns")
+            && !matches!(
+                class,
+                InputOutputClass::File | InputOutputClass::ArrayFile
+            )
+        {
+            issues.push(LintIssue::warning(
+                &format!("{item_path}.patterns"),
+                format!("Patterns only apply to files, not {class}"),
+            ));
+        }
+
+        if let Some(choices) = obj.get("choices") {
+            let choices_path = format!("{item_path}.choices");
+            match choices.as_array() {
+                Some(vals) => {
+                    let item_class = class.item_class();
+                    for (j, val) in vals.iter().enumerate() {
+                        if !value_matches_class(&item_class, val) {
+                            issues.push(LintIssue::error(
+                                &format!("{choices_path}[{j}]"),
+                                format!("Choice {val} is not {item_class}"),
+                            ));
+                        }
+                    }
+                }
+                _ => issues.push(LintIssue::error(
+                    &choices_path,
+                    "Should be an array".to_string(),
+                )),
+            }
+        }
+
+        if let Some(default) = obj.get("default") {
+            if !value_matches_class(&class, default) {
+                issues.push(LintIssue::error(
+                    &format!("{item_path}.default"),
+                    format!("Default {default} is not {class}"),
+                ));
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+fn validate_regional_options(
+    opts: &serde_json::Value,
+    issues: &mut Vec<LintIssue>,
+) {
+    let path = "$.regionalOptions";
+    let Some(regions) = opts.as_object() else {
+        issues.push(LintIssue::error(path, "Should be an object".to_string()));
+        return;
+    };
+
+    let mut entry_points: Option<(&str, Vec<String>)> = None;
+    for (region, opt) in regions {
+        let region_path = format!("{path}.{region}");
+        if !VALID_REGION.contains(&region.as_str()) {
+            issues.push(LintIssue::error(
+                &region_path,
+                format!(r#"Invalid region "{region}""#),
+            ));
+        }
+
+        let Some(opt) = opt.as_object() else {
+            issues.push(LintIssue::error(
+                &region_path,
+                "Should be an object".to_string(),
+            ));
+            continue;
+        };
+        check_keys(opt, REGIONAL_OPTIONS_KEYS, &region_path, issues);
+
+        let Some(reqs) = opt.get("systemRequirements") else {
+            continue;
+        };
+        let reqs_path = format!("{region_path}.systemRequirements");
+
+        if let Some(map) = reqs.as_object() {
+            let mut names: Vec<String> = map.keys().cloned().collect();
+            names.sort();
+            match &entry_points {
+                Some((first, expected)) if *expected != names => {
+                    issues.push(LintIssue::warning(
+                        &reqs_path,
+                        format!(
+                            r#"Entry points ({}) differ from "{first}" ({})"#,
+                            names.join(", "),
+                            expected.join(", "),
+                        ),
+                    ));
+                }
+                None => entry_points = Some((region, names)),
+                _ => (),
+            }
+        }
+
+        validate_system_requirements(reqs, region, &reqs_path, issues);
+    }
+}
+
+// --------------------------------------------------
+// Instance types and cluster specs by entry point, checked against the
+// region unless it is "*"
+fn validate_system_requirements(
+    reqs: &serde_json::Value,
+    region: &str,
+    path: &str,
+    issues: &mut Vec<LintIssue>,
+) {
+    let Some(reqs) = reqs.as_object() else {
+        issues.push(LintIssue::error(path, "Should be an object".to_string()));
+        return;
+    };
+
+    let is_azure = region.starts_with("azure:");
+    for (entry_point, req) in reqs {
+        let req_path = format!("{path}.{entry_point}");
+        let Some(req) = req.as_object() else {
+            issues.push(LintIssue::error(
+                &req_path,
+                "Should be an object".to_string(),
+            ));
+            continue;
+        };
+        check_keys(req, SYSTEM_REQUIREMENTS_KEYS, &req_path, issues);
+
+        if let Some(instance) = req.get("instanceType") {
+            let instance_path = format!("{req_path}.instanceType");
+            match instance.as_str() {
+                Some(name) if !VALID_INSTANCE_TYPE.contains(&name) => issues
+                    .push(LintIssue::error(
+                        &instance_path,
+                        format!(r#"Invalid instance type "{name}""#),
+                    )),
+                Some(name)
+                    if region != "*"
+                        && is_azure != name.starts_with("azure:") =>
+                {
+                    issues.push(LintIssue::error(
+                        &instance_path,
+                        format!(r#"Instance type "{name}" not in "{region}""#),
+                    ))
+                }
+                Some(_) => (),
+                _ => issues.push(LintIssue::error(
+                    &instance_path,
+                    "Should be a string".to_string(),
+                )),
+            }
+        }
+
+        if let Some(cluster_spec) = req.get("clusterSpec") {
+            let cluster_path = format!("{req_path}.clusterSpec");
+            match cluster_spec.as_object() {
+                Some(map) => {
+                    check_keys(map, CLUSTER_SPEC_KEYS, &cluster_path, issues)
+                }
+                _ => issues.push(LintIssue::error(
+                    &cluster_path,
+                    "Should be an object".to_string(),
+                )),
+            }
+
+            if let Ok(spec) =
+                serde_json::from_value::<ClusterSpec>(cluster_spec.clone())
+            {
+                for s in lint_app_cluster_spec(&spec) {
+                    issues.push(LintIssue::error(&cluster_path, s));
+                }
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+// Check a JSON value (e.g., an input default or choice) against a class
+pub fn value_matches_class(
+    class: &InputOutputClass,
+    val: &serde_json::Value,
+) -> bool {
+    let is_link = |prefix: &str| {
+        val.get("$dnanexus_link")
+            .and_then(|link| link.as_str().or_else(|| link["id"].as_str()))
+            .is_some_and(|id| id.starts_with(prefix))
+    };
+    let all = |check: &dyn Fn(&serde_json::Value) -> bool| {
+        val.as_array().is_some_and(|vals| vals.iter().all(check))
+    };
+
+    match class {
+        InputOutputClass::Applet => is_link("applet-"),
+        InputOutputClass::Boolean => val.is_boolean(),
+        InputOutputClass::File => is_link("file-"),
+        InputOutputClass::Float => val.is_number(),
+        InputOutputClass::Hash => val.is_object(),
+        InputOutputClass::Int => val.is_i64() || val.is_u64(),
+        InputOutputClass::Record => is_link("record-"),
+        InputOutputClass::String => val.is_string(),
+        _ => all(&|v| value_matches_class(&class.item_class(), v)),
+    }
+}
+
 // --------------------------------------------------
 #[cfg(test)]
 mod tests {
 
     use super::{
         lint_app_access_spec, lint_app_categories, lint_app_cluster_spec,
-        lint_app_regional_options, lint_app_release_version,
-        lint_app_system_requirements, parse, AccessSpec, ClusterSpec, DxApp,
-        DxAsset, Interpreter, LinuxDistribution, LinuxRelease, LinuxVersion,
-        RegionalOptions, RunSpec, SystemRequirements,
+        lint_app_https_app, lint_app_regional_options,
+        lint_app_release_version, lint_app_system_requirements, parse,
+        validate_app, AccessSpec, ClusterSpec, DxApp, DxAsset, HttpsApp,
+        HttpsAppDns, Interpreter, LinuxDistribution, LinuxRelease,
+        LinuxVersion, RegionalOptions, RestartableEntryPoints, RunSpec,
+        Severity, SystemRequirements,
     };
     use anyhow::{bail, Result};
     use std::collections::HashMap;
 
     #[test]
     fn test_lint_app_categories() {
-        assert!(
-            lint_app_categories(&["Annotation".to_string()]).is_none()
-        );
+        assert!(lint_app_categories(&["Annotation".to_string()]).is_none());
 
         let res = lint_app_categories(&["Bad".to_string()]);
         assert!(res.is_some());
         assert_eq!(res.unwrap(), "Invalid category: Bad");
 
-        let res = lint_app_categories(&["Bad1".to_string(),
-            "Bad2".to_string()]);
+        let res =
+            lint_app_categories(&["Bad1".to_string(), "Bad2".to_string()]);
         assert!(res.is_some());
         assert_eq!(res.unwrap(), "Invalid categories: Bad1, Bad2");
     }
@@ -966,6 +1828,7 @@ mod tests {
             restartable_entry_points: None,
             asset_depends: None,
             exec_depends: vec![],
+            system_requirements: HashMap::new(),
             timeout_policy: None,
         };
 
@@ -1140,6 +2003,163 @@ mod tests {
         assert_eq!(res, ["initialInstanceCount should be greater than zero"]);
     }
 
+    #[test]
+    fn test_cluster_spec_optional_fields() {
+        let spec: ClusterSpec = serde_json::from_value(serde_json::json!({
+            "type": "generic",
+            "initialInstanceCount": 3
+        }))
+        .unwrap();
+        assert!(lint_app_cluster_spec(&spec).is_empty());
+        assert_eq!(
+            serde_json::to_value(&spec).unwrap(),
+            serde_json::json!({
+                "type": "generic",
+                "initialInstanceCount": 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_app() {
+        let app = serde_json::json!({
+            "name": "my_app",
+            "title": "My App",
+            "version": "0.1.0",
+            "inputSpec": [
+                {
+                    "name": "reads",
+                    "class": "file",
+                    "patterns": ["*.fastq"]
+                },
+                {
+                    "name": "mode",
+                    "class": "string",
+                    "choices": ["fast", "slow"],
+                    "default": "fast"
+                }
+            ],
+            "outputSpec": [{ "name": "out", "class": "file" }],
+            "runSpec": {
+                "interpreter": "python3",
+                "file": "src/run.py",
+                "distribution": "Ubuntu",
+                "release": "20.04",
+                "version": "0",
+                "timeoutPolicy": { "*": { "hours": 48 } }
+            },
+            "regionalOptions": {
+                "aws:us-east-1": {
+                    "systemRequirements": {
+                        "*": { "instanceType": "mem1_ssd1_v2_x4" }
+                    }
+                }
+            }
+        });
+        assert!(validate_app(&app).is_empty());
+
+        let app = serde_json::json!({
+            "name": "my_app",
+            "title": "My App",
+            "version": "1",
+            "color": "blue",
+            "inputSpec": [
+                {
+                    "name": "threads",
+                    "class": "int",
+                    "patterns": ["*"],
+                    "choices": [1, "two"],
+                    "default": "4"
+                }
+            ],
+            "outputSpec": [],
+            "runSpec": {
+                "interpreter": "python3",
+                "distribution": "Ubuntu",
+                "release": "16.04",
+                "timeoutPolicy": { "main": { "weeks": 1 } }
+            },
+            "regionalOptions": {
+                "azure:westus": {
+                    "systemRequirements": {
+                        "*": { "instanceType": "mem1_ssd1_v2_x4" }
+                    }
+                }
+            }
+        });
+        let res: Vec<String> =
+            validate_app(&app).iter().map(|i| i.to_string()).collect();
+        assert_eq!(
+            res,
+            [
+                r#"error $.color: Unknown field "color""#,
+                r#"error $.runSpec: Missing required key "file" or "code""#,
+                r#"error $.runSpec.release: Interpreter "python3" requires release "20.04""#,
+                r#"error $.runSpec.timeoutPolicy.main.weeks: Invalid timeout unit "weeks" (use minutes, hours, days)"#,
+                "warning $.inputSpec[0].patterns: Patterns only apply to files, not int",
+                r#"error $.inputSpec[0].choices[1]: Choice "two" is not int"#,
+                r#"error $.inputSpec[0].default: Default "4" is not int"#,
+                r#"error $.regionalOptions.azure:westus.systemRequirements.*.instanceType: Instance type "mem1_ssd1_v2_x4" not in "azure:westus""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_app_typed_checks() {
+        let app = serde_json::json!({
+            "name": "my_app",
+            "title": "My App",
+            "version": "1",
+            "categories": ["Bad"],
+            "inputSpec": [],
+            "outputSpec": [],
+            "runSpec": {
+                "interpreter": "bash",
+                "file": "src/run.sh",
+                "distribution": "Ubuntu",
+                "release": "20.04",
+                "version": "1"
+            },
+            "regionalOptions": {
+                "aws:us-east-1": {
+                    "systemRequirements": {
+                        "main": { "instanceType": "mem1_ssd1_v2_x4" }
+                    }
+                },
+                "aws:eu-central-1": {
+                    "systemRequirements": {
+                        "*": { "instanceType": "mem1_ssd1_v2_x4" }
+                    }
+                }
+            }
+        });
+        let res = validate_app(&app);
+        let mut warnings: Vec<_> = res
+            .iter()
+            .filter(|i| i.severity == Severity::Warning)
+            .map(|i| i.path.as_str())
+            .collect();
+        warnings.sort();
+        assert_eq!(
+            warnings,
+            [
+                "$.categories",
+                "$.regionalOptions.aws:us-east-1.systemRequirements",
+                "$.version",
+            ]
+        );
+
+        let errors: Vec<_> = res
+            .iter()
+            .filter(|i| i.severity == Severity::Error)
+            .map(|i| i.to_string())
+            .collect();
+        assert_eq!(
+            errors,
+            ["error $.runSpec.version: runSpec.version should be 0 if release is 20.04"]
+        );
+    }
+
     #[test]
     fn parse_bad_app_fails() -> Result<()> {
         match parse::<DxApp>("./tests/inputs/json_app/bad.json") {
@@ -1304,4 +2324,107 @@ mod tests {
 
     //    Ok(())
     //}
+
+    #[test]
+    fn test_validate_app_entry_points() -> Result<()> {
+        let mut app = serde_json::json!({
+            "name": "scatter",
+            "title": "Scatter",
+            "inputSpec": [],
+            "outputSpec": [],
+            "runSpec": {
+                "interpreter": "bash",
+                "file": "src/run.sh",
+                "distribution": "Ubuntu",
+                "release": "20.04",
+                "version": "0",
+                "restartableEntryPoints": "all",
+                "execDepends": [
+                    { "name": "samtools", "stages": ["process"] },
+                    { "name": "bcftools", "stages": ["gathr"] }
+                ],
+                "systemRequirements": {
+                    "main": { "instanceType": "mem1_ssd1_v2_x2" },
+                    "process": { "instanceType": "mem1_ssd1_v2_x16" }
+                },
+                "timeoutPolicy": { "gather": { "hours": 1 } }
+            }
+        });
+
+        let dx_app: DxApp = serde_json::from_value(app.clone())?;
+        assert_eq!(
+            dx_app.run_spec.restartable_entry_points,
+            Some(RestartableEntryPoints::All)
+        );
+        assert_eq!(
+            dx_app.entry_points().into_iter().collect::<Vec<_>>(),
+            ["gather", "main", "process"]
+        );
+        assert_eq!(
+            validate_app(&app)
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>(),
+            [
+                r#"warning $.runSpec.execDepends: Package "bcftools" stage "gathr" is not an entry point"#
+            ]
+        );
+
+        app["runSpec"]["restartableEntryPoints"] = "some".into();
+        app["runSpec"]["execDepends"][0]["stages"] = "process".into();
+        app["runSpec"]["systemRequirements"]["*"] =
+            serde_json::json!({ "instanceType": "mem1_bogus" });
+        let mut paths: Vec<_> = validate_app(&app)
+            .into_iter()
+            .map(|i| format!("{} {}", i.severity, i.path))
+            .collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "error $.runSpec.execDepends[0].stages",
+                "error $.runSpec.restartableEntryPoints",
+                "error $.runSpec.systemRequirements.*.instanceType",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_app_https_app() -> Result<()> {
+        let https_app: HttpsApp = serde_json::from_value(serde_json::json!({
+            "ports": [443, 8080],
+            "shared_access": "VIEW",
+            "dns": { "hostname": "my-app" }
+        }))?;
+        assert_eq!(https_app.shared_access, "VIEW");
+        assert!(lint_app_https_app(&https_app).is_empty());
+
+        let https_app = HttpsApp {
+            ports: vec![80],
+            shared_access: "READ".to_string(),
+            dns: Some(HttpsAppDns {
+                hostname: "My_App".to_string(),
+            }),
+        };
+        assert_eq!(
+            lint_app_https_app(&https_app),
+            [
+                "Invalid port 80 (use 443, 8080, or 8081)",
+                r#"Invalid sharedAccess "READ" (use VIEW, CONTRIBUTE, ADMINISTER, NONE)"#,
+                r#"Invalid hostname "My_App" (use a-z, 0-9, and "-")"#,
+            ]
+        );
+
+        let https_app = HttpsApp {
+            ports: vec![],
+            shared_access: "NONE".to_string(),
+            dns: None,
+        };
+        assert_eq!(
+            lint_app_https_app(&https_app),
+            ["httpsApp needs at least one port"]
+        );
+        Ok(())
+    }
 }
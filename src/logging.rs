@@ -0,0 +1,142 @@
+//! Diagnostics written with the "log" macros, to STDERR or to the file
+//! from "--log-file", with tokens, passwords, and URL signatures
+//! redacted so that a log can be attached to a bug report
+use anyhow::{bail, Result};
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::{env, fs::OpenOptions, io::Write, path::Path, str::FromStr};
+
+/// JSON values of keys such as "auth_token" or "password"
+static SECRET_VALUE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r#"(?i)("[a-z_]*(?:token|password|secret|authorization)")"#,
+        r#"\s*:\s*"(?:[^"\\]|\\.)*""#
+    ))
+    .unwrap()
+});
+
+/// Credentials in an "Authorization" header
+static BEARER_TOKEN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(bearer)\s+[A-Za-z0-9._~+/=-]+").unwrap());
+
+/// Query strings, which carry the signature of a presigned URL
+static URL_QUERY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(https?://[^\s?"]+)\?[^\s"]+"#).unwrap());
+
+const REDACTED: &str = "[REDACTED]";
+
+// --------------------------------------------------
+/// Starts logging at a level such as "debug" or with "env_logger"
+/// directives such as "info,dxrs::api=trace," falling back to RUST_LOG
+/// and then to no logging at all
+pub fn init(level: Option<&str>, log_file: Option<&Path>) -> Result<()> {
+    let spec = match level {
+        Some(level) => filter_spec(level)?,
+        _ => env::var("RUST_LOG").unwrap_or("off".to_string()),
+    };
+
+    let mut builder = env_logger::Builder::new();
+    builder.parse_filters(&spec).format(|buf, record| {
+        writeln!(
+            buf,
+            "{} {:<5} {} {}",
+            Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ"),
+            record.level(),
+            record.target(),
+            redact(&record.args().to_string())
+        )
+    });
+
+    if let Some(path) = log_file {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        builder.target(env_logger::Target::Pipe(Box::new(file)));
+    }
+
+    builder.try_init()?;
+    Ok(())
+}
+
+// --------------------------------------------------
+// A bare level applies only to this crate's modules so that the HTTP
+// and TLS crates do not bury them, while directives are taken as given
+fn filter_spec(level: &str) -> Result<String> {
+    if level.contains(['=', ',']) {
+        return Ok(level.to_string());
+    }
+
+    match log::LevelFilter::from_str(level) {
+        Ok(filter) if filter <= log::LevelFilter::Warn => {
+            Ok(filter.to_string().to_lowercase())
+        }
+        Ok(filter) => {
+            Ok(format!("warn,dxrs={}", filter.to_string().to_lowercase()))
+        }
+        _ => bail!(crate::exit::CliError::Usage(format!(
+            r#"Invalid log level "{level}""#
+        ))),
+    }
+}
+
+// --------------------------------------------------
+/// Hides tokens, passwords, and URL signatures in a log message
+pub fn redact(text: &str) -> String {
+    let text = SECRET_VALUE.replace_all(text, format!(r#"$1: "{REDACTED}""#));
+    let text = URL_QUERY.replace_all(&text, format!("$1?{REDACTED}"));
+    BEARER_TOKEN
+        .replace_all(&text, format!("$1 {REDACTED}"))
+        .to_string()
+}
+
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::{filter_spec, redact};
+
+    #[test]
+    fn test_filter_spec() {
+        assert_eq!(filter_spec("off").unwrap(), "off");
+        assert_eq!(filter_spec("WARN").unwrap(), "warn");
+        assert_eq!(filter_spec("debug").unwrap(), "warn,dxrs=debug");
+        assert_eq!(
+            filter_spec("info,dxrs::api=trace").unwrap(),
+            "info,dxrs::api=trace"
+        );
+        assert!(filter_spec("loud").is_err());
+    }
+
+    #[test]
+    fn test_redact() {
+        assert_eq!(
+            redact(
+                r#"{"auth_token": "abc\"123", "auth_token_type": "Bearer"}"#
+            ),
+            r#"{"auth_token": "[REDACTED]", "auth_token_type": "Bearer"}"#
+        );
+        assert_eq!(
+            redact(r#"{"username": "kyclark", "password":"hunter2"}"#),
+            r#"{"username": "kyclark", "password": "[REDACTED]"}"#
+        );
+        assert_eq!(
+            redact(r#"headers={"authorization": "Bearer xyz.789"}"#),
+            r#"headers={"authorization": "[REDACTED]"}"#
+        );
+        assert_eq!(
+            redact("Authorization: Bearer xyz.789 sent"),
+            "Authorization: Bearer [REDACTED] sent"
+        );
+        assert_eq!(
+            redact(concat!(
+                "request PUT https://bucket.s3.amazonaws.com/part?",
+                "X-Amz-Credential=AKIA%2F20261018&X-Amz-Signature=f00d ",
+                "headers={}"
+            )),
+            "request PUT https://bucket.s3.amazonaws.com/part?[REDACTED] \
+            headers={}"
+        );
+        assert_eq!(
+            redact("POST /file-xxxx/describe 200"),
+            "POST /file-xxxx/describe 200"
+        );
+    }
+}
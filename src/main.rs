@@ -6,119 +6,36 @@ use dxrs::{Cli, Command};
 fn main() {
     if let Err(e) = run(Cli::parse()) {
         eprintln!("{e}");
-        std::process::exit(1);
+        std::process::exit(dxrs::exit::exit_code(&e));
     }
 }
 
 // --------------------------------------------------
 fn run(args: Cli) -> Result<()> {
-    env_logger::Builder::new()
-        .filter_level(if args.debug {
-            log::LevelFilter::Debug
-        } else {
-            log::LevelFilter::Off
-        })
-        .init();
+    let log_level = args
+        .log_level
+        .as_deref()
+        .or((args.debug || args.log_file.is_some()).then_some("debug"));
+    dxrs::logging::init(log_level, args.log_file.as_deref())?;
+
+    dxrs::set_output_format(args.output_format);
+    dxrs::dxenv::set_profile(args.profile.clone());
+    dxrs::cache::configure(!args.no_cache, args.cache_ttl);
+    dxrs::api::set_rate_limit(args.rate_limit);
+    dxrs::api::set_verbose_http(args.verbose_http);
+    dxrs::set_quiet(args.quiet);
+    dxrs::theme::configure(if args.no_color {
+        dxrs::ColorWhen::Never
+    } else {
+        args.color
+    });
+
+    if args.command.as_ref().is_some_and(Command::calls_api) {
+        dxrs::warn_token_expiry();
+    }
 
     match &args.command {
-        Some(Command::Build(args)) => {
-            dxrs::build(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Cd(args)) => {
-            dxrs::cd(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Describe(args)) => {
-            dxrs::describe(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Download(args)) => {
-            dxrs::download(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Env(args)) => {
-            dxrs::print_env(args.clone())?;
-            Ok(())
-        }
-        Some(Command::FindApps(args)) => {
-            dxrs::find_apps(args.clone())?;
-            Ok(())
-        }
-        Some(Command::FindData(args)) => {
-            dxrs::find_data(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Format(args)) => {
-            dxrs::format(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Lint(args)) => {
-            dxrs::lint(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Login(args)) => {
-            dxrs::login(args.clone())?;
-            println!("Login successful");
-            Ok(())
-        }
-        Some(Command::Logout {}) => {
-            dxrs::logout()?;
-            println!("Logout successful");
-            Ok(())
-        }
-        Some(Command::Ls(args)) => {
-            dxrs::ls(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Mkdir(args)) => {
-            dxrs::mkdir(args.clone())?;
-            Ok(())
-        }
-        Some(Command::NewProject(args)) => {
-            dxrs::new_project(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Pwd {}) => {
-            dxrs::pwd()?;
-            Ok(())
-        }
-        Some(Command::RmProject(args)) => {
-            dxrs::rm_project(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Rm(args)) => {
-            dxrs::rm(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Rmdir(args)) => {
-            dxrs::rmdir(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Select(args)) => {
-            dxrs::select_project(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Tree(args)) => {
-            dxrs::tree(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Upload(args)) => {
-            dxrs::upload(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Watch(args)) => {
-            dxrs::watch(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Whoami(args)) => {
-            dxrs::whoami(args.clone())?;
-            Ok(())
-        }
-        Some(Command::Wizard(args)) => {
-            dxrs::wizard(args.clone())?;
-            Ok(())
-        }
+        Some(command) => dxrs::dispatch(command),
         None => {
             bail!("Run with -h|--help for usage")
         }
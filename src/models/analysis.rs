@@ -0,0 +1,576 @@
+use crate::{AppSystemRequirement, Currency, KitchenSink, Workflow};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalysisDescribeOptions {
+    pub(crate) fields: HashMap<AnalysisDescribeField, bool>,
+}
+
+// TODO: totalEgress, subtotalPriceInfo, subtotalEgressInfo
+// https://documentation.dnanexus.com/developer/api/running-analyses/
+// workflows-and-analyses#api-method-analysis-xxxx-describe
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter)]
+pub enum AnalysisDescribeField {
+    #[serde(rename = "id")]
+    Id,
+
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "executable")]
+    Executable,
+
+    #[serde(rename = "executableName")]
+    ExecutableName,
+
+    #[serde(rename = "created")]
+    Created,
+
+    #[serde(rename = "modified")]
+    Modified,
+
+    #[serde(rename = "billTo")]
+    BillTo,
+
+    #[serde(rename = "project")]
+    Project,
+
+    #[serde(rename = "folder")]
+    Folder,
+
+    #[serde(rename = "rootExecution")]
+    RootExecution,
+
+    #[serde(rename = "parentJob")]
+    ParentJob,
+
+    #[serde(rename = "parentJobTry")]
+    ParentJobTry,
+
+    #[serde(rename = "parentAnalysis")]
+    ParentAnalysis,
+
+    #[serde(rename = "detachedFrom")]
+    DetachedFrom,
+
+    #[serde(rename = "detachedFromTry")]
+    DetachedFromTry,
+
+    #[serde(rename = "analysis")]
+    Analysis,
+
+    #[serde(rename = "stage")]
+    Stage,
+
+    #[serde(rename = "stages")]
+    Stages,
+
+    #[serde(rename = "state")]
+    State,
+
+    #[serde(rename = "workspace")]
+    Workspace,
+
+    #[serde(rename = "workflow")]
+    Workflow,
+
+    #[serde(rename = "priority")]
+    Priority,
+
+    #[serde(rename = "outputReusedFrom")]
+    OutputReusedFrom,
+
+    #[serde(rename = "workerReuseDeadlineRunTime")]
+    WorkerReuseDeadlineRunTime,
+
+    #[serde(rename = "dependsOn")]
+    DependsOn,
+
+    #[serde(rename = "launchedBy")]
+    LaunchedBy,
+
+    #[serde(rename = "tags")]
+    Tags,
+
+    #[serde(rename = "properties")]
+    Properties,
+
+    #[serde(rename = "details")]
+    Details,
+
+    #[serde(rename = "runInput")]
+    RunInput,
+
+    #[serde(rename = "originalInput")]
+    OriginalInput,
+
+    //#[serde(rename = "input")]
+    //Input,
+    #[serde(rename = "output")]
+    Output,
+
+    #[serde(rename = "delayWorkspaceDestruction")]
+    DelayWorkspaceDestruction,
+
+    #[serde(rename = "ignoreReuse")]
+    IgnoreReuse,
+
+    #[serde(rename = "preserveJobOutputs")]
+    PreserveJobOutputs,
+
+    #[serde(rename = "detailedJobMetrics")]
+    DetailedJobMetrics,
+
+    #[serde(rename = "costLimit")]
+    CostLimit,
+
+    #[serde(rename = "rank")]
+    Rank,
+
+    #[serde(rename = "selectedTreeTurnaroundTimeThreshold")]
+    SelectedTreeTurnaroundTimeThreshold,
+
+    #[serde(rename = "selectedTreeTurnaroundTimeThresholdFrom")]
+    SelectedTreeTurnaroundTimeThresholdFrom,
+
+    #[serde(rename = "treeTurnaroundTime")]
+    TreeTurnaroundTime,
+
+    #[serde(rename = "currency")]
+    Currency,
+
+    #[serde(rename = "totalPrice")]
+    TotalPrice,
+
+    #[serde(rename = "priceComputedAt")]
+    PriceComputedAt,
+
+    #[serde(rename = "egressComputedAt")]
+    EgressComputedAt,
+
+    #[serde(rename = "totalEgress")]
+    TotalEgress,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalysisDescribeResult {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) executable: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "executableName")]
+    pub(crate) executable_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "billTo")]
+    pub(crate) bill_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "rootExecution")]
+    pub(crate) root_execution: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "parentJob")]
+    pub(crate) parent_job: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "parentJobTry")]
+    parent_job_try: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "parentAnalysis")]
+    parent_analysis: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "detachedFrom")]
+    pub(crate) detached_from: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "detachedFromTry")]
+    pub(crate) detached_from_try: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analysis: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stage: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stages: Option<Vec<AnalysisStage>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) workspace: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) workflow: Option<Workflow>,
+
+    pub(crate) priority: Option<String>,
+
+    #[serde(rename = "outputReusedFrom")]
+    output_reused_from: Option<String>,
+
+    #[serde(rename = "workerReuseDeadlineRunTime")]
+    worker_reuse_deadline_run_time: Option<KitchenSink>,
+
+    #[serde(rename = "dependsOn")]
+    pub(crate) depends_on: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "launchedBy")]
+    pub(crate) launched_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) output: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<HashMap<String, KitchenSink>>,
+
+    //run_input: Option<HashMap<String, RunInputValue>>,
+    // TODO: cf analysis-GbxgbB8098YzQ0K3FBfFqyB2
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "runInput")]
+    pub(crate) run_input: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "originalInput")]
+    original_input: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "delayWorkspaceDestruction")]
+    delay_workspace_destruction: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ignoreReuse")]
+    ignore_reuse: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "preserveJobOutputs")]
+    pub(crate) preserve_job_outputs: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "detailedJobMetrics")]
+    pub(crate) detailed_job_metrics: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "costLimit")]
+    pub(crate) cost_limit: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) rank: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "selectedTreeTurnaroundTimeThreshold")]
+    selected_tree_turnaround_time_threshold: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "selectedTreeTurnaroundTimeThresholdFrom")]
+    selected_tree_turnaround_time_threshold_trom: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "treeTurnaroundTime")]
+    pub(crate) tree_turnaround_time: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) currency: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "totalPrice")]
+    pub(crate) total_price: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "priceComputedAt")]
+    #[serde(default)]
+    price_computed_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "egressComputedAt")]
+    #[serde(default)]
+    pub(crate) egress_computed_at: Option<DateTime<Utc>>,
+
+    #[serde(rename = "totalEgress")]
+    pub(crate) total_egress: Option<HashMap<String, u64>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalysisStage {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) execution: Option<AnalysisStageExecution>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalysisStageExecution {
+    id: String,
+
+    pub(crate) executable: Option<String>,
+
+    #[serde(rename = "executableName")]
+    executable_name: Option<String>,
+
+    class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "billTo")]
+    bill_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "costLimit")]
+    cost_limit: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "invoiceMetadata")]
+    invoice_metadata: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "parentJob")]
+    parent_job: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "originJob")]
+    pub(crate) origin_job: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "parentAnalysis")]
+    parent_analysis: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analysis: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stage: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "rootExecution")]
+    root_execution: Option<String>,
+
+    // TODO: enum?
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    function: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "launchedBy")]
+    launched_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "detachedFrom")]
+    detached_from: Option<String>,
+
+    // TODO: enum?
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<String>,
+
+    #[serde(rename = "outputReusedFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_reused_from: Option<String>,
+
+    #[serde(rename = "workerReuseDeadlineRunTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worker_reuse_deadline_run_time: Option<KitchenSink>,
+
+    #[serde(rename = "dependsOn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    depends_on: Option<Vec<String>>,
+
+    #[serde(rename = "singleContext")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    single_context: Option<bool>,
+
+    #[serde(rename = "failureCounts")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure_counts: Option<KitchenSink>,
+
+    #[serde(rename = "stateTransitions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state_transitions: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applet: Option<String>,
+
+    #[serde(rename = "ignoreReuse")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_reuse: Option<bool>,
+
+    #[serde(rename = "httpsApp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    https_app: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rank: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<HashMap<String, String>>,
+
+    #[serde(rename = "systemRequirements")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_requirements: Option<HashMap<String, AppSystemRequirement>>,
+
+    #[serde(rename = "executionPolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execution_policy: Option<KitchenSink>,
+
+    #[serde(rename = "instanceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance_type: Option<String>,
+
+    #[serde(rename = "trueInstanceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    true_instance_type: Option<String>,
+
+    #[serde(rename = "finalPriority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    final_priority: Option<String>,
+
+    #[serde(rename = "networkAccess")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network_access: Option<Vec<String>>,
+
+    #[serde(rename = "runInput")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    //run_input: Option<HashMap<String, FileInputValue>>,
+    run_input: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(rename = "originalInput")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_input: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "startedRunning")]
+    #[serde(default)]
+    started_running: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "stoppedRunning")]
+    #[serde(default)]
+    stopped_running: Option<DateTime<Utc>>,
+
+    #[serde(rename = "delayWorkspaceDestruction")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delay_workspace_destruction: Option<bool>,
+
+    #[serde(rename = "preserveJobOutputs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preserve_job_outputs: Option<bool>,
+
+    #[serde(rename = "detailedJobMetrics")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detailed_job_metrics: Option<bool>,
+
+    #[serde(rename = "isFree")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_free: Option<bool>,
+
+    #[serde(rename = "totalPrice")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_price: Option<f64>,
+
+    #[serde(rename = "totalEgress")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_egress: Option<HashMap<String, u64>>,
+
+    #[serde(rename = "egressReport")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    egress_report: Option<HashMap<String, u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "priceComputedAt")]
+    #[serde(default)]
+    price_computed_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "egressComputedAt")]
+    #[serde(default)]
+    egress_computed_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    currency: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<u64>,
+
+    #[serde(rename = "treeTurnaroundTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tree_turnaround_time: Option<u64>,
+}
@@ -0,0 +1,583 @@
+use crate::json_parser::{
+    InputOutputClass, Interpreter, LinuxDistribution, LinuxRelease,
+    LinuxVersion, TimeoutUnit,
+};
+use crate::{DxFileDescriptor, FindName, KitchenSink};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+use strum_macros::EnumIter;
+
+/// The lists of users on an app that its developers manage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AppUserList {
+    AuthorizedUsers,
+    Developers,
+}
+
+impl AppUserList {
+    /// The suffix of the "list", "add", and "remove" routes
+    pub fn route(&self) -> &'static str {
+        match self {
+            AppUserList::AuthorizedUsers => "AuthorizedUsers",
+            AppUserList::Developers => "Developers",
+        }
+    }
+
+    /// The key of the list in requests and responses
+    pub fn key(&self) -> &'static str {
+        match self {
+            AppUserList::AuthorizedUsers => "authorizedUsers",
+            AppUserList::Developers => "developers",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindAppsOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<FindName>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) category: Option<String>,
+
+    #[serde(rename = "allVersions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) all_versions: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) published: Option<bool>,
+
+    #[serde(rename = "billTo")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) bill_to: Vec<String>,
+
+    #[serde(rename = "createdBy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) created_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) developer: Option<String>,
+
+    #[serde(rename = "authorizedUser")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) authorized_user: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) installed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) starting: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) limit: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) describe: Option<FindAppsDescribe>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindAppsResponse {
+    pub(crate) results: Vec<FindAppsResult>,
+
+    pub(crate) next: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindAppsResult {
+    pub(crate) id: String,
+
+    pub(crate) describe: Option<FindAppsDescribeResult>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FindAppsDescribeResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) version: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) published: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) aliases: Option<Vec<String>>,
+
+    #[serde(rename = "billTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) bill_to: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FindAppsDescribe {
+    pub(crate) fields: HashMap<AppDescribeField, bool>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AppAccess {
+    #[serde(default)]
+    pub network: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    #[serde(rename = "allProjects")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_projects: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub developer: Option<bool>,
+
+    #[serde(rename = "projectCreation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_creation: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppInputSpec {
+    pub name: String,
+
+    pub class: InputOutputClass,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patterns: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub choices: Option<Vec<serde_json::Value>>,
+}
+
+impl fmt::Display for AppInputSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let is_optional = &self.optional.unwrap_or(false);
+        write!(
+            f,
+            "{}{} ({}){}",
+            if *is_optional { "[" } else { "" },
+            &self.name,
+            &self.class,
+            if *is_optional { "]" } else { "" },
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppOutputSpec {
+    pub name: String,
+
+    pub class: InputOutputClass,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patterns: Option<Vec<String>>,
+}
+
+impl fmt::Display for AppOutputSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let is_optional = &self.optional.unwrap_or(false);
+        write!(
+            f,
+            "{}{} ({}){}",
+            if *is_optional { "[" } else { "" },
+            &self.name,
+            &self.class,
+            if *is_optional { "]" } else { "" },
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppSystemRequirement {
+    #[serde(rename = "instanceType")]
+    pub(crate) instance_type: Option<String>,
+}
+
+impl fmt::Display for AppSystemRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{{\"instanceType\": \"{}\"}}",
+            &self.instance_type.clone().unwrap_or("NA".to_string())
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppBundledDepends {
+    pub id: DxFileDescriptor,
+
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stages: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppRunSpec {
+    pub interpreter: Interpreter,
+
+    pub distribution: LinuxDistribution,
+
+    pub release: LinuxRelease,
+
+    pub version: LinuxVersion,
+
+    #[serde(rename = "headJobOnDemand")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_job_on_demand: Option<bool>,
+
+    #[serde(rename = "inheritParentRestartOnPolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherit_parent_restart_on_policy: Option<bool>,
+
+    #[serde(rename = "bundledDepends")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundled_depends: Option<Vec<AppBundledDepends>>,
+
+    #[serde(rename = "execDepends")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec_depends: Option<Vec<serde_json::Value>>,
+
+    #[serde(rename = "bundledDependsByRegion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundled_depends_by_region:
+        Option<HashMap<String, Vec<HashMap<String, KitchenSink>>>>,
+
+    #[serde(default, rename = "systemRequirements")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_requirements: Option<HashMap<String, AppSystemRequirement>>,
+
+    #[serde(rename = "systemRequirementsByRegion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_requirements_by_region: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(default, rename = "executionPolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_policy: Option<KitchenSink>,
+
+    #[serde(rename = "timeoutPolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_policy: Option<HashMap<String, HashMap<TimeoutUnit, u32>>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppDescribeOptions {
+    pub fields: HashMap<AppDescribeField, bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, EnumIter)]
+pub enum AppDescribeField {
+    #[serde(rename = "id")]
+    Id,
+
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "billTo")]
+    BillTo,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "version")]
+    Version,
+
+    #[serde(rename = "aliases")]
+    Aliases,
+
+    #[serde(rename = "region")]
+    Region,
+
+    #[serde(rename = "applet")]
+    Applet,
+
+    #[serde(rename = "createdBy")]
+    CreatedBy,
+
+    #[serde(rename = "created")]
+    Created,
+
+    #[serde(rename = "modified")]
+    Modified,
+
+    #[serde(rename = "installed")]
+    Installed,
+
+    #[serde(rename = "openSource")]
+    OpenSource,
+
+    #[serde(rename = "ignoreReuse")]
+    IgnoreReuse,
+
+    #[serde(rename = "deleted")]
+    Deleted,
+
+    #[serde(rename = "installs")]
+    Installs,
+
+    #[serde(rename = "isDeveloperFor")]
+    IsDeveloperFor,
+
+    #[serde(rename = "authorizedUsers")]
+    AuthorizedUsers,
+
+    #[serde(rename = "regionalOptions")]
+    RegionalOptions,
+
+    #[serde(rename = "httpsApp")]
+    HttpsApp,
+
+    #[serde(rename = "published")]
+    Published,
+
+    #[serde(rename = "title")]
+    Title,
+
+    #[serde(rename = "summary")]
+    Summary,
+
+    #[serde(rename = "description")]
+    Description,
+
+    #[serde(rename = "details")]
+    Details,
+
+    #[serde(rename = "categories")]
+    Categories,
+
+    #[serde(rename = "lineItemPerTest")]
+    LineItemPerTest,
+
+    #[serde(rename = "access")]
+    Access,
+
+    #[serde(rename = "inputSpec")]
+    InputSpec,
+
+    #[serde(rename = "outputSpec")]
+    OutputSpec,
+
+    #[serde(rename = "dxapi")]
+    DxApi,
+
+    #[serde(rename = "runSpec")]
+    RunSpec,
+
+    #[serde(rename = "treeTurnaroundTimeThreshold")]
+    TreeTurnaroundTimeThreshold,
+
+    #[serde(rename = "resources")]
+    Resources,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppDescribeResult {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "billTo")]
+    pub(crate) bill_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) version: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aliases: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) region: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) applet: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    pub(crate) created_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[serde(with = "ts_milliseconds_option")]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[serde(with = "ts_milliseconds_option")]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[serde(with = "ts_milliseconds_option")]
+    pub(crate) published: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) installed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "openSource")]
+    pub(crate) open_source: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ignoreReuse")]
+    pub(crate) ignore_reuse: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) deleted: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) installs: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "isDeveloperFor")]
+    pub(crate) is_developer_for: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "authorizedUsers")]
+    pub(crate) authorized_users: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "regionalOptions")]
+    regional_options: Option<HashMap<String, AppRegionalOptions>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "httpsApp")]
+    https_app: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) summary: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) categories: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "lineItemPerTest")]
+    pub(crate) line_item_per_test: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) access: Option<AppAccess>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "inputSpec")]
+    pub(crate) input_spec: Option<Vec<AppInputSpec>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "outputSpec")]
+    pub output_spec: Option<Vec<AppOutputSpec>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dxapi")]
+    dx_api: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "runSpec")]
+    pub(crate) run_spec: Option<AppRunSpec>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "treeTurnaroundTimeThreshold")]
+    tree_turnaround_time_threshold: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "resources")]
+    pub(crate) resources: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppRegionalOptions {
+    pub applet: String,
+
+    pub resources: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "pricingPolicy")]
+    pub pricing_policy: Option<AppPricingPolicy>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppPricingPolicy {
+    pub unit: String,
+
+    #[serde(rename = "unitPrice")]
+    pub unit_price: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppHttpsApp {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ports: Option<Vec<u32>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_access: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl fmt::Display for AppHttpsApp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        //write!(
+        //    f,
+        //    "{{ports: {}, enabled: {}}}",
+        //    &self.ports.map_or("null".to_string(), |ports| ports
+        //        .iter()
+        //        .map(|v| v.to_string())
+        //        .collect::<Vec<_>>()
+        //        .join(", ")),
+        //    &self.enabled.map_or("null".to_string(), |v| v.to_string())
+        //)
+        write!(
+            f,
+            "{{enabled: {}}}",
+            &self.enabled.map_or("null".to_string(), |v| v.to_string())
+        )
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{
+    AppAccess, AppHttpsApp, AppInputSpec, AppOutputSpec, AppRunSpec, CreatedBy,
+    KitchenSink,
+};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppletDescribeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fields: Option<HashMap<AppletDescribeField, bool>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, EnumIter)]
+pub enum AppletDescribeField {
+    #[serde(rename = "id")]
+    Id,
+
+    #[serde(rename = "project")]
+    Project,
+
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "types")]
+    Types,
+
+    #[serde(rename = "created")]
+    Created,
+
+    #[serde(rename = "state")]
+    State,
+
+    #[serde(rename = "hidden")]
+    Hidden,
+
+    #[serde(rename = "links")]
+    Links,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "folder")]
+    Folder,
+
+    #[serde(rename = "sponsored")]
+    Sponsored,
+
+    #[serde(rename = "tags")]
+    Tags,
+
+    #[serde(rename = "modified")]
+    Modified,
+
+    #[serde(rename = "createdBy")]
+    CreatedBy,
+
+    #[serde(rename = "runSpec")]
+    RunSpec,
+
+    #[serde(rename = "dxapi")]
+    DxApi,
+
+    #[serde(rename = "access")]
+    Access,
+
+    #[serde(rename = "title")]
+    Title,
+
+    #[serde(rename = "summary")]
+    Summary,
+
+    #[serde(rename = "description")]
+    Description,
+
+    #[serde(rename = "developerNotes")]
+    DeveloperNotes,
+
+    #[serde(rename = "ignoreReuse")]
+    IgnoreReuse,
+
+    #[serde(rename = "httpsApps")]
+    HttpsApp,
+
+    #[serde(rename = "treeTurnaroundTimeThreshold")]
+    TreeTurnaroundTimeThreshold,
+
+    #[serde(rename = "inputSpec")]
+    InputSpec,
+
+    #[serde(rename = "outputSpec")]
+    OutputSpec,
+
+    #[serde(rename = "sponsoredUntil")]
+    SponsoredUntil,
+
+    #[serde(rename = "properties")]
+    Properties,
+
+    #[serde(rename = "details")]
+    Details,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppletDescribeResult {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) types: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hidden: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) links: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sponsored: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    pub(crate) created_by: Option<CreatedBy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dxapi")]
+    pub(crate) dx_api: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) summary: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "developerNotes")]
+    pub(crate) developer_notes: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ignoreReuse")]
+    pub(crate) ignore_reuse: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "treeTurnaroundTimeThreshold")]
+    tree_turnaround_time_threshold: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) access: Option<AppAccess>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "httpsApps")]
+    https_app: Option<AppHttpsApp>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "runSpec")]
+    pub(crate) run_spec: Option<AppRunSpec>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "inputSpec")]
+    pub(crate) input_spec: Option<Vec<AppInputSpec>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "outputSpec")]
+    pub(crate) output_spec: Option<Vec<AppOutputSpec>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "sponsoredUntil")]
+    sponsored_until: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) details: Option<HashMap<String, KitchenSink>>,
+}
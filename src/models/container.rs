@@ -0,0 +1,282 @@
+use crate::{AccessLevel, ArchivalState, ObjectState, ObjectType};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RmdirOptions {
+    pub(crate) folder: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) recurse: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) force: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) partial: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RmdirResult {
+    id: String,
+
+    pub(crate) completed: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListFolderObject {
+    pub(crate) id: String,
+
+    pub(crate) describe: Option<ListFolderObjectDescribe>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ListFolderObjectDescribe {
+    pub(crate) id: String,
+
+    project: String,
+
+    pub(crate) class: ObjectType,
+
+    sponsored: bool,
+
+    pub(crate) name: String,
+
+    types: Vec<String>,
+
+    pub(crate) state: ObjectState,
+
+    hidden: bool,
+
+    links: Vec<String>,
+
+    folder: String,
+
+    tags: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(rename = "createdBy")]
+    created_by: HashMap<String, String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    media: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "archivalState")]
+    archival_state: Option<ArchivalState>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) size: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "cloudAccount")]
+    cloud_account: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListFolderResult {
+    pub(crate) objects: Option<Vec<ListFolderObject>>,
+
+    pub(crate) folders: Option<Vec<(String, bool)>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListFolderOptions<'a> {
+    pub(crate) folder: &'a str,
+
+    // TODO: handle listing/bool
+    //describe: HashMap<ListFolderDescribeField, bool>,
+    pub(crate) describe: bool,
+
+    pub(crate) only: Option<ListFolderOptionOnlyValue>,
+
+    #[serde(rename = "includeHidden")]
+    pub(crate) include_hidden: bool,
+
+    #[serde(rename = "hasSubfolderFlags")]
+    pub(crate) has_subfolder_flags: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) enum ListFolderOptionOnlyValue {
+    #[serde(rename = "folders")]
+    Folders,
+
+    #[serde(rename = "objects")]
+    Objects,
+
+    #[serde(rename = "all")]
+    All,
+}
+
+#[derive(Debug, Hash, Serialize, Deserialize, PartialEq, Eq, EnumIter)]
+pub enum ListFolderDescribeField {
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "summary")]
+    Summary,
+
+    #[serde(rename = "description")]
+    Description,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MakeFolderOptions {
+    pub(crate) folder: String,
+
+    pub(crate) parents: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MakeFolderResult {
+    id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContainerDescribeOptions {
+    pub(crate) fields: Option<HashMap<ContainerDescribeField, bool>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter)]
+pub enum ContainerDescribeField {
+    #[serde(rename = "id")]
+    Id,
+
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "region")]
+    Region,
+
+    #[serde(rename = "billTo")]
+    BillTo,
+
+    #[serde(rename = "type")]
+    Type,
+
+    #[serde(rename = "created")]
+    Created,
+
+    #[serde(rename = "modified")]
+    Modified,
+
+    #[serde(rename = "level")]
+    Level,
+
+    #[serde(rename = "dataUsage")]
+    DataUsage,
+
+    #[serde(rename = "sponsoredDataUsage")]
+    SponsoredDataUsage,
+
+    #[serde(rename = "remoteDataUsage")]
+    RemoteDataUsage,
+
+    #[serde(rename = "project")]
+    Project,
+
+    #[serde(rename = "app")]
+    App,
+
+    #[serde(rename = "appName")]
+    AppName,
+
+    #[serde(rename = "destroyAt")]
+    DestroyAt,
+
+    #[serde(rename = "folders")]
+    Folders,
+
+    #[serde(rename = "cloudAccount")]
+    CloudAccount,
+    //https://documentation.dnanexus.com/developer/api/running-analyses/
+    //containers-for-execution#api-method-container-xxxx-describe
+    //#[serde(rename = "fileUploadParameters")]
+    //FileUploadParameters,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContainerDescribeResult {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) region: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "billTo")]
+    pub(crate) bill_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub(crate) container_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) level: Option<AccessLevel>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dataUsage")]
+    pub(crate) data_usage: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "sponsoredDataUsage")]
+    pub(crate) sponsored_data_usage: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "remoteDataUsage")]
+    pub(crate) remote_data_usage: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) app: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "appName")]
+    pub(crate) app_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "cloudAccount")]
+    pub(crate) cloud_account: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "destroyAt")]
+    destroy_at: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    folders: Option<Vec<String>>,
+}
@@ -0,0 +1,147 @@
+use crate::{CreatedBy, KitchenSink};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DatabaseDescribeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fields: Option<HashMap<DatabaseDescribeField, bool>>,
+
+    pub(crate) properties: bool,
+
+    pub(crate) details: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, EnumIter)]
+pub enum DatabaseDescribeField {
+    #[serde(rename = "id")]
+    Id,
+
+    #[serde(rename = "project")]
+    Project,
+
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "databaseName")]
+    DatabaseName,
+
+    #[serde(rename = "uniqueDatabaseName")]
+    UniqueDatabaseName,
+
+    #[serde(rename = "types")]
+    Types,
+
+    #[serde(rename = "created")]
+    Created,
+
+    #[serde(rename = "state")]
+    State,
+
+    #[serde(rename = "hidden")]
+    Hidden,
+
+    #[serde(rename = "links")]
+    Links,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "folder")]
+    Folder,
+
+    #[serde(rename = "sponsored")]
+    Sponsored,
+
+    #[serde(rename = "tags")]
+    Tags,
+
+    #[serde(rename = "modified")]
+    Modified,
+
+    #[serde(rename = "sponsoredUntil")]
+    SponsoredUntil,
+
+    #[serde(rename = "createdBy")]
+    CreatedBy,
+
+    #[serde(rename = "properties")]
+    Properties,
+
+    #[serde(rename = "details")]
+    Details,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DatabaseDescribeResult {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "databaseName")]
+    database_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "uniqueDatabaseName")]
+    pub(crate) unique_database_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    types: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hidden: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    links: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sponsored: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "sponsoredUntil")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    sponsored_until: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    created_by: Option<CreatedBy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<HashMap<String, KitchenSink>>,
+}
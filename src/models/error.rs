@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DxErrorResponse {
+    error: DxErrorPayload,
+}
+
+impl DxErrorResponse {
+    pub fn error_type(&self) -> &str {
+        &self.error.error_type
+    }
+}
+
+impl fmt::Display for DxErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.error.error_type, self.error.message)
+    }
+}
+
+impl std::error::Error for DxErrorResponse {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DxErrorPayload {
+    #[serde(rename = "type")]
+    error_type: String,
+
+    message: String,
+}
@@ -0,0 +1,296 @@
+use crate::{ArchivalState, CreatedBy, KitchenSink};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DownloadOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preauthenticated: Option<bool>,
+
+    #[serde(rename = "stickyIP")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticky_ip: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DownloadResponse {
+    pub(crate) url: String,
+
+    pub(crate) headers: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileCloseOptions {
+    pub(crate) id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileCloseResponse {
+    id: String,
+
+    detail: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileNewOptions {
+    pub(crate) project: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) tags: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) types: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hidden: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) details: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parents: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) media: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) nonce: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileNewResponse {
+    pub(crate) id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileUploadOptions {
+    pub(crate) size: usize,
+
+    pub(crate) md5: String,
+
+    pub(crate) index: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileUploadResponse {
+    pub(crate) url: String,
+
+    expires: u64,
+
+    pub(crate) headers: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileDescribeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<HashMap<FileDescribeField, bool>>,
+
+    pub properties: bool,
+
+    pub details: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, EnumIter)]
+pub enum FileDescribeField {
+    #[serde(rename = "id")]
+    Id,
+
+    #[serde(rename = "project")]
+    Project,
+
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "types")]
+    Types,
+
+    #[serde(rename = "created")]
+    Created,
+
+    #[serde(rename = "state")]
+    State,
+
+    #[serde(rename = "hidden")]
+    Hidden,
+
+    #[serde(rename = "links")]
+    Links,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "folder")]
+    Folder,
+
+    #[serde(rename = "sponsored")]
+    Sponsored,
+
+    #[serde(rename = "tags")]
+    Tags,
+
+    #[serde(rename = "modified")]
+    Modified,
+
+    #[serde(rename = "media")]
+    Media,
+
+    #[serde(rename = "size")]
+    Size,
+
+    #[serde(rename = "cloudAccount")]
+    CloudAccount,
+
+    #[serde(rename = "archivalState")]
+    ArchivalState,
+
+    #[serde(rename = "createdBy")]
+    CreatedBy,
+
+    #[serde(rename = "properties")]
+    Properties,
+
+    #[serde(rename = "details")]
+    Details,
+
+    #[serde(rename = "watermarkId")]
+    WatermarkId,
+
+    #[serde(rename = "watermarkVersion")]
+    WatermarkVersion,
+
+    #[serde(rename = "resolvedPolicies")]
+    ResolvedPolicies,
+
+    // Large files have many parts, so only ask for them explicitly
+    #[strum(disabled)]
+    #[serde(rename = "parts")]
+    Parts,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileDescribeResult {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) types: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hidden: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) links: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sponsored: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) media: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "cloudAccount")]
+    pub(crate) cloud_account: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "archivalState")]
+    pub(crate) archival_state: Option<ArchivalState>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    pub(crate) created_by: Option<CreatedBy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) size: Option<u64>,
+
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "sponsoredUntil")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    sponsored_until: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "watermarkId")]
+    watermark_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "watermarkVersion")]
+    watermark_version: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "resolvedPolicies")]
+    resolved_policies: Option<HashMap<String, bool>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parts: Option<HashMap<String, FilePart>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FilePart {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) md5: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) size: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+}
@@ -0,0 +1,1116 @@
+use crate::json_parser::SystemRequirements;
+use crate::{AppHttpsApp, AppSystemRequirement, Currency, SearchTime};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use clap::{builder::PossibleValue, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+use strum_macros::EnumIter;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WatchLevel {
+    Emerg,
+    Alert,
+    Critical,
+    Error,
+    Warning,
+    Notice,
+    Info,
+    Debug,
+    Stderr,
+    Stdout,
+    Metrics,
+}
+
+impl ValueEnum for WatchLevel {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            WatchLevel::Emerg,
+            WatchLevel::Alert,
+            WatchLevel::Critical,
+            WatchLevel::Error,
+            WatchLevel::Warning,
+            WatchLevel::Notice,
+            WatchLevel::Info,
+            WatchLevel::Debug,
+            WatchLevel::Stderr,
+            WatchLevel::Stdout,
+            WatchLevel::Metrics,
+        ]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            WatchLevel::Emerg => PossibleValue::new("EMERG"),
+            WatchLevel::Alert => PossibleValue::new("ALERT"),
+            WatchLevel::Critical => PossibleValue::new("CRITICAL"),
+            WatchLevel::Error => PossibleValue::new("ERROR"),
+            WatchLevel::Warning => PossibleValue::new("WARNING"),
+            WatchLevel::Notice => PossibleValue::new("NOTICE"),
+            WatchLevel::Info => PossibleValue::new("INFO"),
+            WatchLevel::Debug => PossibleValue::new("DEBUG"),
+            WatchLevel::Stderr => PossibleValue::new("STDERR"),
+            WatchLevel::Stdout => PossibleValue::new("STDOUT"),
+            WatchLevel::Metrics => PossibleValue::new("METRICS"),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WatchOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "numRecentMessages")]
+    pub(crate) num_recent_messages: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "recurseJobs")]
+    pub(crate) recurse_jobs: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tail: Option<bool>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) levels: Vec<WatchLevel>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WatchMessage {
+    #[serde(default)]
+    pub(crate) job: String,
+
+    #[serde(rename = "jobTry")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) job_try: Option<u32>,
+
+    #[serde(default)]
+    pub(crate) level: String,
+
+    #[serde(default)]
+    pub(crate) msg: String,
+
+    #[serde(default)]
+    pub(crate) source: String,
+
+    pub(crate) timestamp: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RunOptions {
+    pub(crate) input: serde_json::Value,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) tags: Vec<String>,
+
+    #[serde(rename = "systemRequirements")]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub(crate) system_requirements: HashMap<String, SystemRequirements>,
+
+    // Workflows only, keyed by stage ID and then entry point
+    #[serde(rename = "stageSystemRequirements")]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub(crate) stage_system_requirements:
+        HashMap<String, HashMap<String, SystemRequirements>>,
+
+    #[serde(rename = "allowSSH")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) allow_ssh: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RunResult {
+    pub(crate) id: String,
+
+    // The job of each stage when running a workflow
+    #[serde(default)]
+    pub(crate) stages: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindExecutionsOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(rename = "rootExecution")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) root_execution: Option<String>,
+
+    #[serde(rename = "includeSubjobs")]
+    pub(crate) include_subjobs: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) created: Option<SearchTime>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<Vec<String>>,
+
+    pub(crate) describe: serde_json::Value,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) starting: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindExecutionsResponse {
+    pub(crate) results: Vec<FindExecutionsResult>,
+
+    pub(crate) next: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindExecutionsResult {
+    id: String,
+
+    pub(crate) describe: Option<ExecutionCost>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExecutionCost {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(rename = "executableName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) executable_name: Option<String>,
+
+    #[serde(rename = "parentJob")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parent_job: Option<String>,
+
+    #[serde(rename = "parentAnalysis")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parent_analysis: Option<String>,
+
+    #[serde(rename = "rootExecution")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) root_execution: Option<String>,
+
+    #[serde(rename = "totalPrice")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) total_price: Option<f64>,
+
+    #[serde(rename = "totalEgress")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) total_egress: Option<HashMap<String, u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) currency: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<String>,
+
+    #[serde(rename = "instanceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) instance_type: Option<String>,
+
+    #[serde(rename = "startedRunning")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) started_running: Option<DateTime<Utc>>,
+}
+
+// States of a job that has finished
+#[cfg(feature = "cli")]
+pub(crate) const TERMINAL_JOB_STATES: &[&str] =
+    &["done", "failed", "terminated"];
+
+// States of a job that has not yet finished
+#[cfg(feature = "cli")]
+pub(crate) const ACTIVE_JOB_STATES: &[&str] = &[
+    "idle",
+    "waiting_on_input",
+    "runnable",
+    "running",
+    "waiting_on_output",
+    "debug_hold",
+    "restartable",
+    "terminating",
+];
+
+// Tried to get this to work but abandoned in favor of KitchenSink
+// but maybe revisit later to have more something specific?
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RunInputValue {
+    StringValue(String),
+
+    FileInputValue,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FileInputValue {
+    Single(FileDescriptor),
+
+    List(Vec<FileDescriptor>),
+}
+
+impl fmt::Display for FileInputValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FileInputValue::Single(val) => write!(f, "{}", val),
+            FileInputValue::List(vals) => {
+                write!(
+                    f,
+                    "[{}]",
+                    vals.iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum KitchenSink {
+    StringValue(String),
+
+    IntValue(i64),
+
+    FloatValue(f64),
+
+    BooleanValue(bool),
+
+    FileValue(FileDescriptor),
+
+    List(Vec<KitchenSink>),
+
+    Mapping(HashMap<String, KitchenSink>),
+}
+
+impl fmt::Display for KitchenSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KitchenSink::StringValue(val) => {
+                write!(f, "\"{val}\"")
+            }
+            KitchenSink::BooleanValue(val) => write!(f, "{val}"),
+            KitchenSink::IntValue(val) => write!(f, "{val}"),
+            KitchenSink::FloatValue(val) => write!(f, "{val}"),
+            KitchenSink::FileValue(val) => write!(f, "{val}"),
+            KitchenSink::List(vals) => write!(
+                f,
+                "[{}]",
+                vals.iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            KitchenSink::Mapping(val) => {
+                write!(
+                    f,
+                    "{{{}}}",
+                    val.iter()
+                        .map(|(k, v)| format!("\"{k}\": {v}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+        }
+    }
+}
+
+//#[derive(Debug, Serialize, Deserialize)]
+//#[serde(untagged)]
+//pub enum KitchenSinkValue {
+//    StringValue(String),
+
+//    BooleanValue(bool),
+
+//    IntValue(i64),
+
+//    FileValue(FileDescriptor),
+//}
+
+//impl fmt::Display for KitchenSinkValue {
+//    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//        match self {
+//            KitchenSinkValue::StringValue(val) => {
+//                write!(f, "\"{val}\"")
+//            }
+//            KitchenSinkValue::BooleanValue(val) => write!(f, "{val}"),
+//            KitchenSinkValue::IntValue(val) => write!(f, "{val}"),
+//            KitchenSinkValue::FileValue(val) => write!(f, "{val}"),
+//        }
+//    }
+//}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum FileDescriptor {
+    Simple(String),
+
+    Dx(DxFileDescriptor),
+}
+
+impl fmt::Display for FileDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FileDescriptor::Simple(val) => write!(f, "{val}"),
+            FileDescriptor::Dx(dx_file) => {
+                write!(f, "{}", dx_file)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DxFileDescriptor {
+    #[serde(rename = "$dnanexus_link")]
+    pub(crate) dnanexus_link: DxFileDescriptorValue,
+}
+
+impl fmt::Display for DxFileDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.dnanexus_link)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DxFileDescriptorComplex {
+    analysis: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stage: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<String>,
+
+    #[serde(rename = "wasInternal")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    was_internal: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DxFileDescriptorProject {
+    pub(crate) project: String,
+
+    pub(crate) id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum DxFileDescriptorValue {
+    FileId(String),
+
+    File(DxFileDescriptorProject),
+
+    Analysis(DxFileDescriptorComplex),
+}
+
+impl fmt::Display for DxFileDescriptorValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DxFileDescriptorValue::FileId(file_id) => {
+                write!(f, "{file_id}")
+            }
+            DxFileDescriptorValue::File(val) => {
+                write!(f, "{}:{}", val.project, val.id)
+            }
+            DxFileDescriptorValue::Analysis(val) => {
+                write!(f, "{}", val.analysis)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobDescribeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "defaultFields")]
+    pub default_fields: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<HashMap<JobDescribeField, bool>>,
+
+    #[serde(rename = "try")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub try_number: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, EnumIter)]
+pub enum JobDescribeField {
+    #[serde(rename = "try")]
+    Try,
+
+    #[serde(rename = "id")]
+    Id,
+
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "executableName")]
+    ExecutableName,
+
+    #[serde(rename = "created")]
+    Created,
+
+    #[serde(rename = "currency")]
+    Currency,
+
+    #[serde(rename = "tryCreated")]
+    TryCreated,
+
+    #[serde(rename = "modified")]
+    Modified,
+
+    #[serde(rename = "startedRunning")]
+    StartedRunning,
+
+    #[serde(rename = "stoppedRunning")]
+    StoppedRunning,
+
+    #[serde(rename = "egressReport")]
+    EgressReport,
+
+    #[serde(rename = "billTo")]
+    BillTo,
+
+    #[serde(rename = "project")]
+    Project,
+
+    #[serde(rename = "folder")]
+    Folder,
+
+    #[serde(rename = "rootExecution")]
+    RootExecution,
+
+    #[serde(rename = "parentJob")]
+    ParentJob,
+
+    #[serde(rename = "parentJobTry")]
+    ParentJobTry,
+
+    #[serde(rename = "originJob")]
+    OriginJob,
+
+    #[serde(rename = "detachedFrom")]
+    DetachedFrom,
+
+    #[serde(rename = "detachedFromTry")]
+    DetachedFromTry,
+
+    #[serde(rename = "parentAnalysis")]
+    ParentAnalysis,
+
+    #[serde(rename = "analysis")]
+    Analysis,
+
+    #[serde(rename = "stage")]
+    Stage,
+
+    #[serde(rename = "state")]
+    State,
+
+    #[serde(rename = "stateTransitions")]
+    StateTransitions,
+
+    #[serde(rename = "workspace")]
+    Workspace,
+
+    #[serde(rename = "launchedBy")]
+    LaunchedBy,
+
+    #[serde(rename = "function")]
+    Function,
+
+    #[serde(rename = "tags")]
+    Tags,
+
+    #[serde(rename = "properties")]
+    Properties,
+
+    #[serde(rename = "priority")]
+    Priority,
+
+    #[serde(rename = "finalPriority")]
+    FinalPriority,
+
+    #[serde(rename = "rank")]
+    Rank,
+
+    #[serde(rename = "details")]
+    Details,
+
+    #[serde(rename = "systemRequirements")]
+    SystemRequirements,
+
+    #[serde(rename = "executionPolicy")]
+    ExecutionPolicy,
+
+    #[serde(rename = "timeout")]
+    Timeout,
+
+    #[serde(rename = "instanceType")]
+    InstanceType,
+
+    #[serde(rename = "networkAccess")]
+    NetworkAccess,
+
+    #[serde(rename = "delayWorkspaceDestruction")]
+    DelayWorkspaceDestruction,
+
+    #[serde(rename = "dependsOn")]
+    DependsOn,
+
+    #[serde(rename = "failureReason")]
+    FailureReason,
+
+    #[serde(rename = "failureMessage")]
+    FailureMessage,
+
+    #[serde(rename = "failureFrom")]
+    FailureFrom,
+
+    #[serde(rename = "failureReports")]
+    FailureReports,
+
+    #[serde(rename = "failureCounts")]
+    FailureCounts,
+
+    #[serde(rename = "runInput")]
+    RunInput,
+
+    #[serde(rename = "originalInput")]
+    OriginalInput,
+
+    #[serde(rename = "input")]
+    Input,
+
+    #[serde(rename = "output")]
+    Output,
+
+    #[serde(rename = "region")]
+    Region,
+
+    #[serde(rename = "singleContext")]
+    SingleContext,
+
+    #[serde(rename = "ignoreReuse")]
+    IgnoreReuse,
+
+    #[serde(rename = "httpsApp")]
+    HttpsApp,
+
+    #[serde(rename = "preserveJobOutputs")]
+    PreserveJobOutputs,
+
+    #[serde(rename = "detailedJobMetrics")]
+    DetailedJobMetrics,
+
+    #[serde(rename = "clusterSpec")]
+    ClusterSpec,
+
+    #[serde(rename = "clusterID")]
+    ClusterID,
+
+    #[serde(rename = "costLimit")]
+    CostLimit,
+
+    #[serde(rename = "selectedTreeTurnaroundTimeThreshold")]
+    SelectedTreeTurnaroundTimeThreshold,
+
+    #[serde(rename = "selectedTreeTurnaroundTimeThresholdFrom")]
+    SelectedTreeTurnaroundTimeThresholdFrom,
+
+    #[serde(rename = "treeTurnaroundTime")]
+    TreeTurnaroundTime,
+
+    #[serde(rename = "debugOn")]
+    DebugOn,
+
+    #[serde(rename = "isFree")]
+    IsFree,
+
+    #[serde(rename = "totalPrice")]
+    TotalPrice,
+
+    #[serde(rename = "priceComputedAt")]
+    PriceComputedAt,
+
+    #[serde(rename = "totalEgress")]
+    TotalEgress,
+
+    #[serde(rename = "egressComputedAt")]
+    EgressComputedAt,
+
+    #[serde(rename = "allowSSH")]
+    AllowSSH,
+
+    #[serde(rename = "sshHostKey")]
+    SshHostKey,
+
+    #[serde(rename = "host")]
+    Host,
+
+    #[serde(rename = "sshPort")]
+    SshPort,
+
+    #[serde(rename = "clusterSlaves")]
+    ClusterSlaves,
+
+    #[serde(rename = "headJobOnDemand")]
+    HeadJobOnDemand,
+
+    #[serde(rename = "internetUsageIPs")]
+    InternetUsageIPs,
+
+    #[serde(rename = "subtotalEgressInfo")]
+    SubtotalEgressInfo,
+
+    #[serde(rename = "applet")]
+    Applet,
+
+    #[serde(rename = "app")]
+    App,
+
+    #[serde(rename = "resources")]
+    Resources,
+
+    #[serde(rename = "projectCache")]
+    ProjectCache,
+
+    #[serde(rename = "outputReusedFrom")]
+    OutputReusedFrom,
+
+    #[serde(rename = "workerReuseDeadlineRunTime")]
+    WorkerReuseDeadlineRunTime,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobDescribeResult {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "try")]
+    pub(crate) try_number: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) currency: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "executableName")]
+    pub(crate) executable_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "tryCreated")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) try_created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "startedRunning")]
+    #[serde(default)]
+    pub(crate) started_running: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(rename = "stoppedRunning")]
+    #[serde(default)]
+    pub(crate) stopped_running: Option<DateTime<Utc>>,
+
+    #[serde(rename = "egressReport")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    egress_report: Option<HashMap<String, u64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "billTo")]
+    pub(crate) bill_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "rootExecution")]
+    pub(crate) root_execution: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "parentJob")]
+    pub(crate) parent_job: Option<String>,
+
+    #[serde(rename = "parentJobTry")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_job_try: Option<u64>,
+
+    #[serde(rename = "originJob")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) origin_job: Option<String>,
+
+    #[serde(rename = "detachedFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) detached_from: Option<String>,
+
+    #[serde(rename = "detachedFromTry")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) detached_from_try: Option<u64>,
+
+    #[serde(rename = "parentAnalysis")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parent_analysis: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) analysis: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stage: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<String>,
+
+    #[serde(rename = "stateTransitions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state_transitions: Option<Vec<JobStateTransition>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspace: Option<String>,
+
+    #[serde(rename = "launchedBy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) launched_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) function: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) priority: Option<String>,
+
+    #[serde(rename = "finalPriority")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    final_priority: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) rank: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(rename = "systemRequirements")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) system_requirements:
+        Option<HashMap<String, AppSystemRequirement>>,
+
+    #[serde(rename = "executionPolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    //execution_policy: Option<HashMap<String, KitchenSink>>,
+    pub(crate) execution_policy: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<u64>,
+
+    #[serde(rename = "instanceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) instance_type: Option<String>,
+
+    #[serde(rename = "networkAccess")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network_access: Option<Vec<String>>,
+
+    #[serde(rename = "delayWorkspaceDestruction")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delay_workspace_destruction: Option<bool>,
+
+    #[serde(rename = "dependsOn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) depends_on: Option<Vec<String>>,
+
+    #[serde(rename = "failureReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) failure_reason: Option<String>,
+
+    #[serde(rename = "failureMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) failure_message: Option<String>,
+
+    #[serde(rename = "failureFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) failure_from: Option<JobFailureFrom>,
+
+    #[serde(rename = "failureReports")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) failure_reports: Option<Vec<JobFailureReport>>,
+
+    #[serde(rename = "failureCounts")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    //failure_counts: Option<HashMap<String, KitchenSinkValue>>,
+    pub(crate) failure_counts: Option<KitchenSink>,
+
+    #[serde(rename = "runInput")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    //run_input: Option<HashMap<String, FileInputValue>>,
+    pub(crate) run_input: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(rename = "originalInput")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_input: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    //input: Option<HashMap<String, FileInputValue>>,
+    input: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) output: Option<HashMap<String, KitchenSink>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) region: Option<String>,
+
+    #[serde(rename = "singleContext")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) single_context: Option<bool>,
+
+    #[serde(rename = "ignoreReuse")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) ignore_reuse: Option<bool>,
+
+    #[serde(rename = "httpsApp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) https_app: Option<AppHttpsApp>,
+
+    #[serde(rename = "preserveJobOutputs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) preserve_job_outputs: Option<String>,
+
+    #[serde(rename = "detailedJobMetrics")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detailed_job_metrics: Option<bool>,
+
+    // TODO: this will need work
+    #[serde(rename = "clusterSpec")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cluster_spec: Option<String>,
+
+    #[serde(rename = "clusterID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cluster_id: Option<String>,
+
+    #[serde(rename = "costLimit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cost_limit: Option<f64>,
+
+    #[serde(rename = "selectedTreeTurnaroundTimeThreshold")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected_tree_turnaround_time_threshold: Option<u64>,
+
+    #[serde(rename = "selectedTreeTurnaroundTimeThresholdFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected_tree_turnaround_time_threshold_from: Option<String>,
+
+    #[serde(rename = "treeTurnaroundTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tree_turnaround_time: Option<u64>,
+
+    #[serde(rename = "debugOn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug_on: Option<Vec<String>>,
+
+    #[serde(rename = "isFree")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_free: Option<bool>,
+
+    #[serde(rename = "totalPrice")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) total_price: Option<f64>,
+
+    #[serde(rename = "priceComputedAt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[serde(with = "ts_milliseconds_option")]
+    price_computed_at: Option<DateTime<Utc>>,
+
+    #[serde(rename = "totalEgress")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_egress: Option<HashMap<String, u64>>,
+
+    #[serde(rename = "egressComputedAt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[serde(with = "ts_milliseconds_option")]
+    egress_computed_at: Option<DateTime<Utc>>,
+
+    #[serde(rename = "allowSSH")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_ssh: Option<Vec<String>>,
+
+    #[serde(rename = "sshHostKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) ssh_host_key: Option<String>,
+
+    #[serde(rename = "host")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) host: Option<String>,
+
+    #[serde(rename = "sshPort")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) ssh_port: Option<String>,
+
+    #[serde(rename = "clusterSlaves")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cluster_slaves: Option<Vec<JobClusterSlave>>,
+
+    #[serde(rename = "headJobOnDemand")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    head_job_on_demand: Option<bool>,
+
+    #[serde(rename = "internetUsageIPs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    internet_usage_ips: Option<Vec<String>>,
+
+    #[serde(rename = "subtotalPriceInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subtotal_price_info: Option<JobSubtotalPriceInfo>,
+
+    #[serde(rename = "subtotalEgressInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subtotal_egress_info: Option<JobSubtotalEgressInfo>,
+
+    // Only if run by an applet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applet: Option<String>,
+
+    // Only if run by an app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) resources: Option<String>,
+
+    #[serde(rename = "projectCache")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project_cache: Option<String>,
+
+    #[serde(rename = "outputReusedFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) output_reused_from: Option<String>,
+
+    #[serde(rename = "workerReuseDeadlineRunTime")]
+    pub(crate) worker_reuse_deadline_run_time: Option<KitchenSink>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobStateTransition {
+    #[serde(rename = "newState")]
+    new_state: String,
+
+    #[serde(rename = "setAt")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    set_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobFailureFrom {
+    pub(crate) id: String,
+
+    #[serde(rename = "try")]
+    pub(crate) try_number: u64,
+
+    name: String,
+
+    executable: String,
+
+    #[serde(rename = "executableName")]
+    pub(crate) executable_name: String,
+
+    pub(crate) function: String,
+
+    #[serde(rename = "failureReason")]
+    pub(crate) failure_reason: String,
+
+    #[serde(rename = "failureMessage")]
+    pub(crate) failure_message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobFailureReport {
+    pub(crate) to: String,
+
+    pub(crate) by: String,
+
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobClusterSlave {
+    host: String,
+
+    #[serde(rename = "sshPort")]
+    ssh_port: String,
+
+    #[serde(rename = "internalIp")]
+    internal_ip: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobSubtotalPriceInfo {
+    #[serde(rename = "subtotalPrice")]
+    subtotal_price: f64,
+
+    #[serde(rename = "priceComputedAt")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    price_computed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobSubtotalEgressInfo {
+    #[serde(rename = "subtotalRegionLocalEgress")]
+    subtotal_region_local_egress: u64,
+
+    #[serde(rename = "subtotalInternetEgress")]
+    subtotal_internet_egress: u64,
+
+    #[serde(rename = "subtotalInterRegionEgress")]
+    subtotal_inter_region_egress: u64,
+
+    #[serde(rename = "egressComputedAt")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    egress_computed_at: Option<DateTime<Utc>>,
+}
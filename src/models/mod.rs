@@ -0,0 +1,31 @@
+//! Requests and responses of the DNAnexus API, grouped by the class of
+//! object they describe
+mod analysis;
+mod app;
+mod applet;
+mod container;
+mod database;
+mod error;
+mod file;
+mod job;
+mod object;
+mod project;
+mod record;
+mod search;
+mod user;
+mod workflow;
+
+pub use analysis::*;
+pub use app::*;
+pub use applet::*;
+pub use container::*;
+pub use database::*;
+pub use error::*;
+pub use file::*;
+pub use job::*;
+pub use object::*;
+pub use project::*;
+pub use record::*;
+pub use search::*;
+pub use user::*;
+pub use workflow::*;
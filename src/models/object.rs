@@ -0,0 +1,263 @@
+use crate::{
+    AnalysisDescribeResult, AppDescribeResult, AppletDescribeResult,
+    ContainerDescribeResult, DatabaseDescribeResult, FileDescribeResult,
+    JobDescribeResult, ProjectDescribeResult, RecordDescribeResult,
+};
+use clap::{builder::PossibleValue, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt, path::PathBuf};
+use strum_macros::EnumString;
+
+/// Any kind of object as returned by its describe method
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum Description {
+    Analysis(Box<AnalysisDescribeResult>),
+    App(Box<AppDescribeResult>),
+    Applet(Box<AppletDescribeResult>),
+    Container(Box<ContainerDescribeResult>),
+    Database(Box<DatabaseDescribeResult>),
+    File(Box<FileDescribeResult>),
+    Job(Box<JobDescribeResult>),
+    Project(Box<ProjectDescribeResult>),
+    Record(Box<RecordDescribeResult>),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddTagsOptions {
+    pub(crate) project: String,
+
+    pub(crate) tags: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RmOptions {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) objects: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) force: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RmResult {
+    id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ProjectPath {
+    pub project_id: String,
+
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Visibility {
+    #[serde(rename = "hidden")]
+    Hidden,
+
+    #[serde(rename = "visible")]
+    Visible,
+
+    #[serde(rename = "either")]
+    Either,
+}
+
+// TODO: More types
+#[derive(Debug, Serialize, Deserialize, EnumString, Clone, PartialEq)]
+pub enum ObjectType {
+    #[strum(serialize = "applet")]
+    #[serde(rename = "applet")]
+    Applet,
+
+    #[strum(serialize = "database")]
+    #[serde(rename = "database")]
+    Database,
+
+    #[strum(serialize = "file")]
+    #[serde(rename = "file")]
+    File,
+
+    #[strum(serialize = "record")]
+    #[serde(rename = "record")]
+    Record,
+
+    #[strum(serialize = "workflow")]
+    #[serde(rename = "workflow")]
+    Workflow,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ObjectState {
+    #[serde(rename = "opening")]
+    Opening,
+
+    #[serde(rename = "open")]
+    Open,
+
+    #[serde(rename = "closed")]
+    Closed,
+
+    #[serde(rename = "any")]
+    Any,
+}
+
+impl fmt::Display for ObjectState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ObjectState::Opening => write!(f, "opening"),
+            ObjectState::Open => write!(f, "open"),
+            ObjectState::Closed => write!(f, "closed"),
+            ObjectState::Any => write!(f, "any"),
+        }
+    }
+}
+
+// https://documentation.dnanexus.com/user/objects/archiving-files
+// #file-archival-states
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum ArchivalState {
+    #[serde(rename = "live")]
+    Live,
+
+    #[serde(rename = "archival")]
+    Archival,
+
+    #[serde(rename = "archived")]
+    Archived,
+
+    #[serde(rename = "unarchiving")]
+    Unarchiving,
+
+    #[serde(rename = "any")]
+    Any,
+}
+
+impl fmt::Display for ArchivalState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArchivalState::Live => write!(f, "live"),
+            ArchivalState::Archival => write!(f, "archival"),
+            ArchivalState::Archived => write!(f, "archived"),
+            ArchivalState::Unarchiving => write!(f, "unarchiving"),
+            ArchivalState::Any => write!(f, "any"),
+        }
+    }
+}
+
+impl ValueEnum for ArchivalState {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            ArchivalState::Live,
+            ArchivalState::Archival,
+            ArchivalState::Archived,
+            ArchivalState::Unarchiving,
+            ArchivalState::Any,
+        ]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            ArchivalState::Live => PossibleValue::new("live"),
+            ArchivalState::Archival => PossibleValue::new("archival"),
+            ArchivalState::Archived => PossibleValue::new("archived"),
+            ArchivalState::Unarchiving => PossibleValue::new("unarchiving"),
+            ArchivalState::Any => PossibleValue::new("any"),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CloneOptions {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) objects: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) folders: Vec<String>,
+
+    // The project to clone into
+    pub(crate) project: String,
+
+    pub(crate) destination: String,
+
+    pub(crate) parents: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CloneResult {
+    id: String,
+
+    project: String,
+
+    // Objects that were already in the destination project
+    #[serde(default)]
+    pub(crate) exists: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MoveOptions {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) objects: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) folders: Vec<String>,
+
+    pub(crate) destination: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MoveResult {
+    id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribeDataObjectsOptions {
+    pub(crate) objects: Vec<String>,
+
+    // Keyed on class, e.g., "file", or "*" for every class
+    #[serde(rename = "classDescribeOptions")]
+    pub(crate) class_describe_options: HashMap<String, DescribeFields>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribeFields {
+    pub(crate) fields: HashMap<String, bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribeDataObjectsResponse {
+    pub(crate) results: Vec<DescribeDataObjectsResult>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribeDataObjectsResult {
+    // Missing for objects that cannot be described
+    pub(crate) describe: Option<DataObjectName>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataObjectName {
+    pub(crate) id: String,
+
+    pub(crate) name: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DxPath {
+    pub(crate) path: String,
+
+    pub(crate) project_id: String,
+}
+
+impl fmt::Display for DxPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.project_id, self.path)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FileOrPath {
+    File { file_id: String, project_id: String },
+
+    Path { path: String, project_id: String },
+}
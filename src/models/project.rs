@@ -0,0 +1,502 @@
+use crate::FindName;
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use clap::{builder::PossibleValue, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+use strum_macros::EnumIter;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RmProjectOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "terminateJobs")]
+    pub(crate) terminate_jobs: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RmProjectResult {
+    pub(crate) id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindProjectsOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<FindName>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) id: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) level: Option<AccessLevel>,
+
+    #[serde(rename = "billTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) bill_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) starting: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) describe: Option<FindProjectsDescribe>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FindProjectsDescribe {
+    pub(crate) fields: HashMap<ProjectDescribeField, bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindProjectsResponse {
+    pub(crate) results: Vec<FindProjectsResult>,
+
+    pub(crate) next: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindProjectsResult {
+    pub(crate) id: String,
+
+    pub(crate) level: AccessLevel,
+
+    #[serde(rename = "permissionSources")]
+    permission_sources: Vec<String>,
+
+    public: bool,
+
+    pub(crate) describe: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AccessLevel {
+    #[serde(rename = "VIEW")]
+    View,
+
+    #[serde(rename = "UPLOAD")]
+    Upload,
+
+    #[serde(rename = "CONTRIBUTE")]
+    Contribute,
+
+    #[serde(rename = "ADMINISTER")]
+    Administer,
+}
+
+impl fmt::Display for AccessLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccessLevel::View => write!(f, "VIEW"),
+            AccessLevel::Upload => write!(f, "UPLOAD"),
+            AccessLevel::Contribute => write!(f, "CONTRIBUTE"),
+            AccessLevel::Administer => write!(f, "ADMINISTER"),
+        }
+    }
+}
+
+impl ValueEnum for AccessLevel {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            AccessLevel::View,
+            AccessLevel::Upload,
+            AccessLevel::Contribute,
+            AccessLevel::Administer,
+        ]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            AccessLevel::View => PossibleValue::new("VIEW"),
+            AccessLevel::Upload => PossibleValue::new("UPLOAD"),
+            AccessLevel::Contribute => PossibleValue::new("CONTRIBUTE"),
+            AccessLevel::Administer => PossibleValue::new("ADMINISTER"),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewProjectOptions {
+    pub(crate) name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) summary: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+
+    pub(crate) protected: bool,
+
+    pub(crate) restricted: bool,
+
+    #[serde(rename = "downloadRestricted")]
+    pub(crate) download_restricted: bool,
+
+    #[serde(rename = "externalUploadRestricted")]
+    pub(crate) external_upload_restricted: bool,
+
+    #[serde(rename = "databaseUIViewOnly")]
+    pub(crate) database_ui_view_only: bool,
+
+    #[serde(rename = "containsPHI")]
+    pub(crate) contains_phi: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<HashMap<String, String>>,
+
+    #[serde(rename = "billTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) bill_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) region: Option<String>,
+
+    #[serde(rename = "monthlyComputeLimit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) monthly_compute_limit: Option<u64>,
+
+    #[serde(rename = "monthlyEgressBytesLimit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) monthly_egress_bytes_limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewProjectResult {
+    pub(crate) id: String,
+}
+
+// TODO: Handle all fields at https://documentation.dnanexus.com/developer/
+// api/data-containers/projects#project-metadata ?
+#[derive(Debug, Serialize, Deserialize, Hash, PartialEq, Eq, EnumIter)]
+pub enum ProjectDescribeField {
+    #[serde(rename = "id")]
+    Id,
+
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "region")]
+    Region,
+
+    #[serde(rename = "summary")]
+    Summary,
+
+    #[serde(rename = "description")]
+    Description,
+
+    #[serde(rename = "version")]
+    Version,
+
+    #[serde(rename = "changes")]
+    Changes,
+
+    #[serde(rename = "tags")]
+    Tags,
+
+    #[serde(rename = "properties")]
+    Properties,
+
+    #[serde(rename = "cloudAccount")]
+    CloudAccount,
+
+    #[serde(rename = "remoteDataUsage")]
+    RemoteDataUsage,
+
+    #[serde(rename = "archivedDataUsage")]
+    ArchivedDataUsage,
+
+    #[serde(rename = "previewViewerRestricted")]
+    PreviewViewerRestricted,
+
+    #[serde(rename = "displayDataProtectionNotice")]
+    DisplayDataProtectionNotice,
+
+    #[serde(rename = "billTo")]
+    BillTo,
+
+    #[serde(rename = "with")]
+    With,
+
+    #[serde(rename = "protected")]
+    Protected,
+
+    #[serde(rename = "restricted")]
+    Restricted,
+
+    #[serde(rename = "downloadRestricted")]
+    DownloadRestricted,
+
+    #[serde(rename = "externalUploadRestricted")]
+    ExternalUploadRestricted,
+
+    #[serde(rename = "containsPHI")]
+    ContainsPHI,
+
+    #[serde(rename = "databaseUIViewOnly")]
+    DatabaseUIViewOnly,
+
+    #[serde(rename = "currency")]
+    Currency,
+
+    #[serde(rename = "created")]
+    Created,
+
+    #[serde(rename = "createdBy")]
+    CreatedBy,
+
+    #[serde(rename = "user")]
+    User,
+
+    #[serde(rename = "job")]
+    Job,
+
+    #[serde(rename = "executable")]
+    Executable,
+
+    #[serde(rename = "modified")]
+    Modified,
+
+    #[serde(rename = "level")]
+    Level,
+
+    #[serde(rename = "dataUsage")]
+    DataUsage,
+
+    #[serde(rename = "storageCost")]
+    StorageCost,
+
+    #[serde(rename = "defaultInstanceType")]
+    DefaultInstanceType,
+
+    #[serde(rename = "provider")]
+    Provider,
+
+    #[serde(rename = "sponsoredDataUsage")]
+    SponsoredDataUsage,
+
+    #[serde(rename = "sponsoredUntil")]
+    SponsoredUntil,
+
+    #[serde(rename = "pendingTransfer")]
+    PendingTransfer,
+
+    #[serde(rename = "totalSponsoredEgressBytes")]
+    TotalSponsoredEgressBytes,
+
+    #[serde(rename = "consumedSponsoredEgressBytes")]
+    ConsumedSponsoredEgressBytes,
+
+    #[serde(rename = "allowedExecutables")]
+    AllowedExecutables,
+
+    #[serde(rename = "atSpendingLimit")]
+    AtSpendingLimit,
+
+    #[serde(rename = "folders")]
+    Folders,
+
+    #[serde(rename = "objects")]
+    Objects,
+
+    #[serde(rename = "permissions")]
+    Permissions,
+
+    #[serde(rename = "appCaches")]
+    AppCaches,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectDescribeOptions {
+    pub(crate) fields: Option<HashMap<ProjectDescribeField, bool>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CreatedBy {
+    pub(crate) user: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    executable: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectDescribeResult {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) region: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) summary: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    changes: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "cloudAccount")]
+    pub(crate) cloud_account: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "remoteDataUsage")]
+    pub(crate) remote_data_usage: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "archivedDataUsage")]
+    pub(crate) archived_data_usage: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "previewViewerRestricted")]
+    pub(crate) preview_viewer_restricted: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "displayDataProtectionNotice")]
+    pub(crate) display_data_protection_notice: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "billTo")]
+    pub(crate) bill_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    with: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) protected: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) restricted: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "downloadRestricted")]
+    pub(crate) download_restricted: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "externalUploadRestricted")]
+    external_upload_restricted: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "containsPHI")]
+    pub(crate) contains_phi: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "databaseUIViewOnly")]
+    database_ui_view_only: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) currency: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    pub(crate) created_by: Option<CreatedBy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) level: Option<AccessLevel>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dataUsage")]
+    pub(crate) data_usage: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "storageCost")]
+    pub(crate) storage_cost: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "defaultInstanceType")]
+    pub(crate) default_instance_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) provider: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "sponsoredDataUsage")]
+    pub(crate) sponsored_data_usage: Option<f64>,
+
+    #[serde(rename = "sponsoredUntil")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    sponsored_until: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "pendingTransfer")]
+    pending_transfer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "totalSponsoredEgressBytes")]
+    pub(crate) total_sponsored_egress_bytes: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "consumedSponsoredEgressBytes")]
+    consumed_sponsored_egress_bytes: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "allowedExecutables")]
+    pub(crate) allowed_executables: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "atSpendingLimit")]
+    pub(crate) at_spending_limit: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folders: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    objects: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<HashMap<String, AccessLevel>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "appCaches")]
+    app_caches: Option<HashMap<String, String>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Currency {
+    #[serde(rename = "dxCode")]
+    dx_code: u64,
+
+    pub(crate) code: String,
+
+    pub(crate) symbol: String,
+
+    #[serde(rename = "symbolPosition")]
+    symbol_position: String,
+
+    #[serde(rename = "decimalSymbol")]
+    decimal_symbol: String,
+
+    #[serde(rename = "groupingSymbol")]
+    grouping_symbol: String,
+}
@@ -0,0 +1,242 @@
+use crate::{CreatedBy, KitchenSink};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecordNewOptions {
+    pub(crate) project: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parents: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) details: Option<serde_json::Value>,
+
+    pub(crate) close: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecordNewResponse {
+    pub(crate) id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecordDescribeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    pub(crate) fields: Option<HashMap<RecordDescribeField, bool>>,
+
+    pub(crate) properties: bool,
+
+    pub(crate) details: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, EnumIter)]
+pub enum RecordDescribeField {
+    #[serde(rename = "id")]
+    Id,
+
+    #[serde(rename = "project")]
+    Project,
+
+    #[serde(rename = "class")]
+    Class,
+
+    #[serde(rename = "size")]
+    Size,
+
+    #[serde(rename = "types")]
+    Types,
+
+    #[serde(rename = "created")]
+    Created,
+
+    #[serde(rename = "state")]
+    State,
+
+    #[serde(rename = "hidden")]
+    Hidden,
+
+    #[serde(rename = "links")]
+    Links,
+
+    #[serde(rename = "name")]
+    Name,
+
+    #[serde(rename = "folder")]
+    Folder,
+
+    #[serde(rename = "sponsored")]
+    Sponsored,
+
+    #[serde(rename = "tags")]
+    Tags,
+
+    #[serde(rename = "modified")]
+    Modified,
+
+    #[serde(rename = "createdBy")]
+    CreatedBy,
+
+    #[serde(rename = "properties")]
+    Properties,
+
+    #[serde(rename = "details")]
+    Details,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecordDescribeResult {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) size: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) types: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hidden: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) links: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sponsored: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    pub(crate) created_by: Option<CreatedBy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "sponsoredUntil")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    sponsored_until: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) details: Option<HashMap<String, KitchenSink>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecordVisualizeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(rename = "cohortBrowser")]
+    pub(crate) cohort_browser: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordVisualizeResult {
+    pub url: String,
+
+    // The underlying dataset, which is the record itself for a dataset
+    pub dataset: String,
+
+    #[serde(rename = "datasetVersion")]
+    pub dataset_version: Option<String>,
+
+    #[serde(rename = "datasetRecordProject")]
+    pub dataset_record_project: Option<String>,
+
+    #[serde(rename = "recordTypes")]
+    #[serde(default)]
+    pub record_types: Vec<String>,
+
+    #[serde(default)]
+    pub databases: Vec<String>,
+
+    // Only for cohorts
+    pub filters: Option<serde_json::Value>,
+
+    #[serde(rename = "baseSql")]
+    pub base_sql: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DatasetSummary {
+    #[serde(rename = "recordType")]
+    pub(crate) record_type: String,
+
+    pub(crate) dataset: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) version: Option<String>,
+
+    pub(crate) databases: Vec<String>,
+
+    #[serde(rename = "primaryKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) primary_key: Option<String>,
+
+    // Only for cohorts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) count: Option<usize>,
+
+    pub(crate) entities: Vec<DatasetEntity>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DatasetEntity {
+    pub(crate) name: String,
+
+    pub(crate) fields: Vec<DatasetField>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DatasetField {
+    pub(crate) name: String,
+
+    #[serde(rename = "type")]
+    pub(crate) field_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) table: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) column: Option<String>,
+}
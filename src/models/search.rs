@@ -0,0 +1,217 @@
+use crate::{
+    AccessLevel, ArchivalState, CreatedBy, KitchenSink, ObjectState,
+    ObjectType, Visibility,
+};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindDataResponse {
+    pub(crate) results: Vec<FindDataResult>,
+
+    pub(crate) next: Option<FindDataResult>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FindDataResult {
+    pub(crate) project: String,
+
+    pub(crate) id: String,
+
+    pub(crate) describe: Option<FindDataDescribe>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FindDataDescribe {
+    pub(crate) id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<ObjectState>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    class: Option<ObjectType>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    types: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    links: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) tags: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hidden: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) size: Option<u64>,
+
+    #[serde(rename = "archivalState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) archival_state: Option<ArchivalState>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sponsored: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    media: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "cloudAccount")]
+    cloud_account: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    pub(crate) created_by: Option<CreatedBy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) details: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindDataOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class: Option<ObjectType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<ObjectState>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<FindName>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) visibility: Option<Visibility>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) id: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub(crate) object_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) tags: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) region: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) link: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) scope: Option<FindDataScope>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "sortBy")]
+    pub(crate) sort_by: Option<FindDataSortBy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) level: Option<AccessLevel>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) modified: Option<SearchTime>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) created: Option<SearchTime>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) describe: Option<FindDescribe>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) starting: Option<FindDataResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) limit: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "archivalState")]
+    pub(crate) archival_state: Option<ArchivalState>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FindDescribe {
+    Boolean(bool),
+
+    Mapping(HashMap<String, bool>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchTime {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) after: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) before: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindDataSortBy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<SortByField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ordering: Option<SortOrdering>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SortByField {
+    #[serde(rename = "created")]
+    Created,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SortOrdering {
+    #[serde(rename = "ascending")]
+    Ascending,
+
+    #[serde(rename = "descending")]
+    Descending,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindDataScope {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) recurse: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) enum FindName {
+    #[serde(rename = "glob")]
+    Glob(String),
+
+    #[serde(rename = "regexp")]
+    Regexp(String),
+}
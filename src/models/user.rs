@@ -0,0 +1,147 @@
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Credentials {
+    pub(crate) username: String,
+
+    pub(crate) password: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewTokenOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) label: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) scope: Option<serde_json::Value>,
+
+    /// Milliseconds since the epoch
+    pub(crate) expires: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TokenDescribeResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) user: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) label: Option<String>,
+
+    /// e.g., {"full": {"level": "VIEW"}} or {"projects": {"project-xxxx":
+    /// "CONTRIBUTE"}}
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) scope: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[serde(with = "ts_milliseconds_option")]
+    pub(crate) created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    #[serde(with = "ts_milliseconds_option")]
+    pub(crate) expires: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "lastUsed")]
+    #[serde(default)]
+    #[serde(with = "ts_milliseconds_option")]
+    pub(crate) last_used: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthToken {
+    pub(crate) access_token: String,
+    token_signature: String,
+    token_type: String,
+    user_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WhoAmIOptions {
+    pub(crate) fields: Option<HashMap<WhoAmIOptionsFields, bool>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq)]
+pub enum WhoAmIOptionsFields {
+    #[serde(rename = "clientIp")]
+    ClientIp,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WhoAmIResult {
+    pub(crate) id: String,
+
+    #[serde(rename = "clientIp")]
+    pub(crate) client_ip: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UserDescribeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fields: Option<HashMap<String, bool>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserDescribeResult {
+    pub id: String,
+
+    pub handle: Option<String>,
+
+    pub first: Option<String>,
+
+    pub middle: Option<String>,
+
+    pub last: Option<String>,
+
+    pub email: Option<String>,
+
+    #[serde(rename = "billTo")]
+    pub bill_to: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindOrgsOptions {
+    // "MEMBER" or "ADMIN"
+    pub(crate) level: String,
+
+    pub(crate) describe: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) starting: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindOrgsResponse {
+    pub(crate) results: Vec<FindOrgsResult>,
+
+    pub(crate) next: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindOrgsResult {
+    pub id: String,
+
+    pub level: String,
+
+    pub describe: Option<OrgDescribe>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrgDescribe {
+    pub name: Option<String>,
+}
+
+/// The current user as shown by "whoami"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserInfo {
+    #[serde(flatten)]
+    pub user: UserDescribeResult,
+
+    pub orgs: Vec<FindOrgsResult>,
+
+    #[serde(rename = "clientIp")]
+    pub client_ip: Option<String>,
+}
@@ -0,0 +1,207 @@
+use crate::{CreatedBy, KitchenSink};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Workflow {
+    pub(crate) id: String,
+
+    project: String,
+
+    class: String,
+
+    name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    folder: Option<String>,
+
+    #[serde(rename = "outputFolder")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temporary: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sponsored: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hidden: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    types: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    links: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<WorkflowState>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "costLimit")]
+    cost_limit: Option<String>,
+
+    #[serde(rename = "stateTransitions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state_transitions: Option<Vec<HashMap<String, KitchenSink>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    created: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "ts_milliseconds_option")]
+    #[serde(default)]
+    modified: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    created_by: Option<CreatedBy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<WorkflowDetails>,
+
+    #[serde(rename = "editVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    edit_version: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stages: Option<Vec<WorkflowStage>>,
+
+    #[serde(rename = "inputSpec")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_spec: Option<Vec<WorkflowInputSpec>>,
+
+    #[serde(rename = "outputSpec")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_spec: Option<Vec<WorkflowOutputSpec>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkflowOutputSpec {
+    name: String,
+
+    class: String,
+
+    group: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    optional: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkflowInputSpec {
+    name: String,
+
+    class: String,
+
+    group: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    optional: Option<bool>,
+
+    //default: Option<HashMap<String, HashMap<String, String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default: Option<KitchenSink>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkflowStage {
+    id: String,
+
+    name: String,
+
+    executable: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    folder: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accessible: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "executionPolicy")]
+    execution_policy: Option<KitchenSink>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "systemRequirements")]
+    system_requirements: Option<KitchenSink>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkflowDetails {
+    #[serde(rename = "originalName")]
+    original_name: String,
+
+    #[serde(rename = "sourceCode")]
+    source_code: String,
+
+    version: String,
+
+    checksum: String,
+
+    #[serde(rename = "docContents")]
+    doc_contents: String,
+
+    #[serde(rename = "execTree")]
+    exec_tree: String,
+
+    #[serde(rename = "parseOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_options: Option<HashMap<String, String>>,
+
+    #[serde(rename = "staticInstanceTypeSelection")]
+    static_instance_type_selection: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum WorkflowState {
+    #[serde(rename = "in_progress")]
+    InProgress,
+
+    #[serde(rename = "partially_failed")]
+    PartiallyFailed,
+
+    #[serde(rename = "done")]
+    Done,
+
+    #[serde(rename = "failed")]
+    Failed,
+
+    #[serde(rename = "terminating")]
+    Terminating,
+
+    #[serde(rename = "terminated")]
+    Terminated,
+
+    #[serde(rename = "closed")]
+    Closed,
+}
+
+impl fmt::Display for WorkflowState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WorkflowState::InProgress => write!(f, "in_progress"),
+            WorkflowState::PartiallyFailed => write!(f, "paritially_failed"),
+            WorkflowState::Done => write!(f, "done"),
+            WorkflowState::Failed => write!(f, "failed"),
+            WorkflowState::Terminating => write!(f, "terminating"),
+            WorkflowState::Terminated => write!(f, "terminated"),
+            WorkflowState::Closed => write!(f, "closed"),
+        }
+    }
+}
@@ -0,0 +1,232 @@
+#[cfg(feature = "cli")]
+use crate::{
+    make_table, output_format, print_records, print_table, OutputFormat,
+};
+#[cfg(feature = "cli")]
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::{builder::PossibleValue, ValueEnum};
+use serde::Serialize;
+#[cfg(feature = "cli")]
+use size::Size;
+#[cfg(feature = "cli")]
+use tabular::Table;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListColumn {
+    Name,
+    Size,
+    Modified,
+    Id,
+    State,
+}
+
+impl ValueEnum for ListColumn {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            ListColumn::Name,
+            ListColumn::Size,
+            ListColumn::Modified,
+            ListColumn::Id,
+            ListColumn::State,
+        ]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            ListColumn::Name => PossibleValue::new("name"),
+            ListColumn::Size => PossibleValue::new("size"),
+            ListColumn::Modified => PossibleValue::new("modified"),
+            ListColumn::Id => PossibleValue::new("id"),
+            ListColumn::State => PossibleValue::new("state"),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListSortBy {
+    Name,
+    Size,
+    Modified,
+}
+
+impl ValueEnum for ListSortBy {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[ListSortBy::Name, ListSortBy::Size, ListSortBy::Modified]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            ListSortBy::Name => PossibleValue::new("name"),
+            ListSortBy::Size => PossibleValue::new("size"),
+            ListSortBy::Modified => PossibleValue::new("modified"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ListingRow {
+    pub name: String,
+
+    pub id: String,
+
+    pub state: String,
+
+    pub size: Option<u64>,
+
+    pub modified: Option<DateTime<Utc>>,
+}
+
+/// The objects matching one "ls" path, and the contents of the path
+/// when it is a folder
+#[derive(Debug, Serialize)]
+pub struct PathListing {
+    pub project_id: String,
+
+    pub path: String,
+
+    pub matches: Vec<ListingRow>,
+
+    pub contents: Option<Vec<ListingRow>>,
+}
+
+// --------------------------------------------------
+pub(crate) fn sort_listing(
+    rows: &mut [ListingRow],
+    sort_by: &Option<ListSortBy>,
+    reverse: bool,
+) {
+    match sort_by {
+        Some(ListSortBy::Name) => rows.sort_by(|a, b| a.name.cmp(&b.name)),
+        Some(ListSortBy::Size) => rows.sort_by_key(|row| row.size),
+        Some(ListSortBy::Modified) => rows.sort_by_key(|row| row.modified),
+        _ => (),
+    }
+
+    if reverse {
+        rows.reverse();
+    }
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn listing_rows(
+    rows: &[ListingRow],
+    columns: &[ListColumn],
+    human: bool,
+    header: bool,
+) -> Vec<Vec<String>> {
+    let mut table_rows = vec![];
+    if header {
+        table_rows.push(
+            columns
+                .iter()
+                .map(|col| {
+                    match col {
+                        ListColumn::Name => "Name",
+                        ListColumn::Size => "Size",
+                        ListColumn::Modified => "Modified",
+                        ListColumn::Id => "ID",
+                        ListColumn::State => "State",
+                    }
+                    .to_string()
+                })
+                .collect(),
+        );
+    }
+
+    for row in rows {
+        table_rows.push(
+            columns
+                .iter()
+                .map(|col| match col {
+                    ListColumn::Name => row.name.clone(),
+                    ListColumn::Size => {
+                        row.size.map_or("NA".to_string(), |s| {
+                            if human {
+                                Size::from_bytes(s).to_string()
+                            } else {
+                                s.to_string()
+                            }
+                        })
+                    }
+                    ListColumn::Modified => {
+                        row.modified.map_or("NA".to_string(), |ts| {
+                            ts.format("%Y-%m-%d %H:%M:%S").to_string()
+                        })
+                    }
+                    ListColumn::Id => row.id.clone(),
+                    ListColumn::State => row.state.clone(),
+                })
+                .collect(),
+        );
+    }
+
+    table_rows
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn listing_table(
+    rows: &[ListingRow],
+    columns: &[ListColumn],
+    human: bool,
+    header: bool,
+    wide: bool,
+) -> Table {
+    // Sizes line up on the right, everything else on the left
+    let fmt = columns
+        .iter()
+        .map(|col| match col {
+            ListColumn::Size => "{:>}",
+            _ => "{:<}",
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // Names are the first to give up space for a narrow terminal
+    let flex_column = columns
+        .iter()
+        .position(|col| col == &ListColumn::Name)
+        .unwrap_or(columns.len().saturating_sub(1));
+
+    make_table(
+        &fmt,
+        listing_rows(rows, columns, human, header),
+        flex_column,
+        wide,
+    )
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn print_listing(
+    rows: &[ListingRow],
+    columns: &[ListColumn],
+    human: bool,
+    header: bool,
+    wide: bool,
+) -> Result<()> {
+    match output_format() {
+        OutputFormat::Table => {
+            let table = listing_table(rows, columns, human, header, wide);
+            print_table(&table, wide);
+            Ok(())
+        }
+        _ => {
+            let names = column_names(columns);
+            let names: Vec<_> = names.iter().map(|n| n.as_str()).collect();
+            print_records(rows, &names)
+        }
+    }
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn column_names(columns: &[ListColumn]) -> Vec<String> {
+    columns
+        .iter()
+        .filter_map(|col| col.to_possible_value())
+        .map(|val| val.get_name().to_string())
+        .collect()
+}
@@ -0,0 +1,97 @@
+//! How results are rendered: tables, JSON, TSV, listings and progress
+mod listing;
+mod progress;
+#[cfg(feature = "cli")]
+mod table;
+
+pub use listing::*;
+pub use progress::*;
+#[cfg(feature = "cli")]
+pub(crate) use table::*;
+
+use anyhow::Result;
+use clap::{builder::PossibleValue, ValueEnum};
+use once_cell::sync::OnceCell;
+use std::{fs::File, io};
+
+static OUTPUT_FORMAT: OnceCell<OutputFormat> = OnceCell::new();
+
+static QUIET: OnceCell<bool> = OnceCell::new();
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorWhen {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ValueEnum for ColorWhen {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[ColorWhen::Auto, ColorWhen::Always, ColorWhen::Never]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            ColorWhen::Auto => PossibleValue::new("auto"),
+            ColorWhen::Always => PossibleValue::new("always"),
+            ColorWhen::Never => PossibleValue::new("never"),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Jsonl,
+    Tsv,
+}
+
+impl ValueEnum for OutputFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            OutputFormat::Table,
+            OutputFormat::Json,
+            OutputFormat::Jsonl,
+            OutputFormat::Tsv,
+        ]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            OutputFormat::Table => PossibleValue::new("table"),
+            OutputFormat::Json => PossibleValue::new("json"),
+            OutputFormat::Jsonl => PossibleValue::new("jsonl"),
+            OutputFormat::Tsv => PossibleValue::new("tsv"),
+        })
+    }
+}
+
+// --------------------------------------------------
+pub fn set_output_format(format: OutputFormat) {
+    let _ = OUTPUT_FORMAT.set(format);
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn output_format() -> OutputFormat {
+    OUTPUT_FORMAT.get().copied().unwrap_or(OutputFormat::Table)
+}
+
+// --------------------------------------------------
+pub fn set_quiet(quiet: bool) {
+    let _ = QUIET.set(quiet);
+}
+
+// --------------------------------------------------
+pub(crate) fn quiet() -> bool {
+    QUIET.get().copied().unwrap_or(false)
+}
+
+// --------------------------------------------------
+pub fn open_outfile(filename: &str) -> Result<Box<dyn io::Write>> {
+    match filename {
+        "-" => Ok(Box::new(io::stdout())),
+        out_name => Ok(Box::new(File::create(out_name)?)),
+    }
+}
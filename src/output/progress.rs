@@ -0,0 +1,198 @@
+#[cfg(feature = "cli")]
+use crate::quiet;
+use clap::{builder::PossibleValue, ValueEnum};
+#[cfg(feature = "cli")]
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProgressFormat {
+    Bar,
+    Json,
+    None_,
+}
+
+impl ValueEnum for ProgressFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            ProgressFormat::Bar,
+            ProgressFormat::Json,
+            ProgressFormat::None_,
+        ]
+    }
+
+    fn to_possible_value<'a>(&self) -> Option<PossibleValue> {
+        Some(match self {
+            ProgressFormat::Bar => PossibleValue::new("bar"),
+            ProgressFormat::Json => PossibleValue::new("json"),
+            ProgressFormat::None_ => PossibleValue::new("none"),
+        })
+    }
+}
+
+// Sizes are 0 for streams whose length is not known in advance
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum ProgressEvent<'a> {
+    Started {
+        file: &'a str,
+        size: u64,
+    },
+    Bytes {
+        file: &'a str,
+        bytes: u64,
+        total: u64,
+    },
+    Finished {
+        file: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<&'a str>,
+    },
+    Failed {
+        file: &'a str,
+        error: String,
+    },
+}
+
+#[cfg(feature = "cli")]
+pub struct UploadProgress {
+    bars: Option<MultiProgress>,
+
+    total: Option<ProgressBar>,
+
+    json: bool,
+}
+
+#[cfg(feature = "cli")]
+impl UploadProgress {
+    pub fn new(
+        format: ProgressFormat,
+        total_bytes: u64,
+        num_files: usize,
+    ) -> Self {
+        if format != ProgressFormat::Bar || quiet() {
+            return UploadProgress {
+                bars: None,
+                total: None,
+                json: format == ProgressFormat::Json,
+            };
+        }
+
+        let bars = MultiProgress::new();
+
+        // Only show an aggregate bar when there is more than one file
+        let total = (num_files > 1).then(|| {
+            let pb = bars.add(ProgressBar::new(total_bytes));
+            let template = "{msg}\n{spinner:.green} [{elapsed_precise}] \
+                [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} \
+                ({bytes_per_sec}, {eta})";
+            pb.set_style(
+                ProgressStyle::default_bar()
+                    .template(template)
+                    .expect("template")
+                    .progress_chars("#>-"),
+            );
+            pb.set_message(format!("Uploading {num_files} files"));
+            pb
+        });
+
+        UploadProgress {
+            bars: Some(bars),
+            total,
+            json: false,
+        }
+    }
+
+    pub(crate) fn file_bar(
+        &self,
+        filename: &str,
+        size: Option<u64>,
+    ) -> Option<ProgressBar> {
+        self.event(ProgressEvent::Started {
+            file: filename,
+            size: size.unwrap_or_default(),
+        });
+
+        self.bars.as_ref().map(|bars| {
+            let (pb, template) = match size {
+                Some(size) => (
+                    bars.add(ProgressBar::new(size)),
+                    "{msg} [{bar:30.cyan/blue}] {bytes}/{total_bytes} \
+                    ({bytes_per_sec}, {eta})",
+                ),
+                _ => (
+                    bars.add(ProgressBar::new_spinner()),
+                    "{msg} {spinner:.green} {bytes} ({bytes_per_sec})",
+                ),
+            };
+            pb.set_style(
+                ProgressStyle::default_bar()
+                    .template(template)
+                    .expect("template")
+                    .progress_chars("#>-"),
+            );
+            pb.set_message(filename.to_string());
+            pb
+        })
+    }
+
+    pub(crate) fn inc(&self, bar: &Option<ProgressBar>, bytes: u64) {
+        if let Some(pb) = bar {
+            pb.inc(bytes);
+        }
+        if let Some(pb) = &self.total {
+            pb.inc(bytes);
+        }
+    }
+
+    pub(crate) fn event(&self, event: ProgressEvent) {
+        if self.json {
+            emit_progress(&event);
+        }
+    }
+
+    pub(crate) fn println(&self, msg: String) {
+        match &self.bars {
+            Some(bars) => {
+                let _ = bars.println(msg);
+            }
+            _ => println!("{msg}"),
+        }
+    }
+
+    pub(crate) fn finish(&self) {
+        if let Some(pb) = &self.total {
+            pb.finish_with_message("Finished");
+        }
+    }
+}
+
+// --------------------------------------------------
+// Progress events are one JSON object per line on STDERR so that
+// wrappers can follow transfers while STDOUT stays parseable
+pub fn emit_progress(event: &ProgressEvent) {
+    if let Ok(line) = serde_json::to_string(event) {
+        eprintln!("{line}");
+    }
+}
+
+// --------------------------------------------------
+// Hidden under "--quiet" so that nothing is drawn on STDERR
+#[cfg(feature = "cli")]
+pub(crate) fn new_progress_bar(len: u64) -> ProgressBar {
+    if quiet() {
+        ProgressBar::hidden()
+    } else {
+        ProgressBar::new(len)
+    }
+}
+
+// --------------------------------------------------
+#[cfg(feature = "cli")]
+pub(crate) fn new_spinner() -> ProgressBar {
+    if quiet() {
+        ProgressBar::hidden()
+    } else {
+        ProgressBar::new_spinner()
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{output_format, Currency, OutputFormat};
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use tabular::{Row, Table};
+
+const MIN_CELL_WIDTH: usize = 12;
+
+// --------------------------------------------------
+pub(crate) fn csv_quote(val: &str) -> String {
+    if val.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", val.replace('"', "\"\""))
+    } else {
+        val.to_string()
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn format_price(
+    price: Option<f64>,
+    currency: &Option<Currency>,
+) -> String {
+    match price {
+        Some(val) => {
+            let symbol = match currency {
+                Some(c) => c.symbol.to_string(),
+                _ => "".to_string(),
+            };
+            format!("{symbol}{:.2}", val)
+        }
+        _ => "NA".to_string(),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn limit_rows<T>(
+    mut rows: Vec<T>,
+    max_rows: Option<usize>,
+) -> (Vec<T>, usize) {
+    match max_rows {
+        Some(max) if rows.len() > max => {
+            let hidden = rows.len() - max;
+            rows.truncate(max);
+            (rows, hidden)
+        }
+        _ => (rows, 0),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn more_rows(hidden: usize) -> String {
+    format!("… and {hidden} more (use --full)")
+}
+
+// --------------------------------------------------
+pub(crate) fn format_properties(
+    props: &HashMap<String, String>,
+    max_rows: Option<usize>,
+) -> String {
+    if props.is_empty() {
+        "-".to_string()
+    } else {
+        let mut pairs: Vec<String> =
+            props.iter().map(|(k, v)| format!("{k} = {v}")).collect();
+        pairs.sort();
+        let (mut pairs, hidden) = limit_rows(pairs, max_rows);
+        if hidden > 0 {
+            pairs.push(more_rows(hidden));
+        }
+        pairs.join(", ")
+    }
+}
+
+// --------------------------------------------------
+fn terminal_width(wide: bool) -> Option<usize> {
+    // Only constrain output that is going to an actual terminal
+    if wide {
+        None
+    } else {
+        console::Term::stdout()
+            .size_checked()
+            .map(|(_rows, cols)| cols as usize)
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn ellipsize(val: &str, max_width: usize) -> String {
+    if val.chars().count() <= max_width {
+        val.to_string()
+    } else if max_width == 0 {
+        "".to_string()
+    } else {
+        let mut short: String = val.chars().take(max_width - 1).collect();
+        short.push('…');
+        short
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn fit_column(
+    rows: &mut [Vec<String>],
+    column: usize,
+    width: usize,
+) {
+    // Shrink one column so that all the others fit in the given width
+    let num_cols = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let fixed: usize = (0..num_cols)
+        .filter(|&i| i != column)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|row| row.get(i).map(|c| c.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .sum();
+    let separators = num_cols.saturating_sub(1);
+    let budget = width.saturating_sub(fixed + separators).max(MIN_CELL_WIDTH);
+
+    for row in rows.iter_mut() {
+        if let Some(cell) = row.get_mut(column) {
+            *cell = ellipsize(cell, budget);
+        }
+    }
+}
+
+// --------------------------------------------------
+// Prints a single object such as a describe result
+pub(crate) fn print_record<T: Serialize>(record: &T) -> Result<()> {
+    match output_format() {
+        OutputFormat::Jsonl => {
+            println!("{}", serde_json::to_string(record)?)
+        }
+        OutputFormat::Tsv => {
+            if let serde_json::Value::Object(map) =
+                serde_json::to_value(record)?
+            {
+                for (key, val) in map {
+                    println!("{key}\t{}", tsv_value(&val));
+                }
+            }
+        }
+        _ => println!("{}", serde_json::to_string_pretty(record)?),
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+// Prints listing results in a machine-readable format, with the
+// given fields as the TSV columns
+pub(crate) fn print_records<T: Serialize>(
+    records: &[T],
+    fields: &[&str],
+) -> Result<()> {
+    match output_format() {
+        OutputFormat::Jsonl => {
+            for record in records {
+                println!("{}", serde_json::to_string(record)?);
+            }
+        }
+        OutputFormat::Tsv => {
+            println!("{}", fields.join("\t"));
+            for record in records {
+                let val = serde_json::to_value(record)?;
+                let cells: Vec<_> =
+                    fields.iter().map(|field| tsv_value(&val[field])).collect();
+                println!("{}", cells.join("\t"));
+            }
+        }
+        _ => println!("{}", serde_json::to_string_pretty(records)?),
+    }
+    Ok(())
+}
+
+// --------------------------------------------------
+pub(crate) fn tsv_value(val: &serde_json::Value) -> String {
+    match val {
+        serde_json::Value::Null => "".to_string(),
+        serde_json::Value::String(s) => s.replace(['\t', '\n'], " "),
+        other => other.to_string(),
+    }
+}
+
+// --------------------------------------------------
+pub(crate) fn make_table(
+    fmt: &str,
+    mut rows: Vec<Vec<String>>,
+    flex_column: usize,
+    wide: bool,
+) -> Table {
+    if let Some(width) = terminal_width(wide) {
+        fit_column(&mut rows, flex_column, width);
+    }
+
+    let mut table = Table::new(fmt);
+    for row in rows {
+        table.add_row(
+            row.into_iter()
+                .fold(Row::new(), |acc, cell| acc.with_cell(cell)),
+        );
+    }
+    table
+}
+
+// --------------------------------------------------
+pub(crate) fn print_table(table: &Table, wide: bool) {
+    match terminal_width(wide) {
+        Some(width) => {
+            for line in table.to_string().lines() {
+                println!("{}", ellipsize(line, width));
+            }
+            println!();
+        }
+        _ => println!("{table}"),
+    }
+}
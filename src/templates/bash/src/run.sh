@@ -0,0 +1,14 @@
+#!/usr/bin/env bash
+# {{name}}: {{summary}}
+
+set -exo pipefail
+
+main() {
+    dx download "$input_file" -o "$input_file_name"
+
+    # Add your code here
+    cp "$input_file_name" "${input_file_prefix}.out"
+
+    output_file_id=$(dx upload "${input_file_prefix}.out" --brief)
+    dx-jobutil-add-output output_file "$output_file_id" --class=file
+}
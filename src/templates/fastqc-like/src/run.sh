@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+# {{name}}: {{summary}}
+# Runs a QC tool over each FASTQ file and uploads one report per file
+
+set -exo pipefail
+
+main() {
+    mkdir -p reads reports
+
+    for i in "${!reads[@]}"; do
+        dx download "${reads[$i]}" -o "reads/${reads_name[$i]}"
+    done
+
+    for fastq in reads/*; do
+        name=$(basename "$fastq")
+        # Replace this with your QC tool, e.g.:
+        # fastqc $extra_options --outdir reports "$fastq"
+        {
+            echo "file: $name"
+            echo "reads: $(( $(zcat -f "$fastq" | wc -l) / 4 ))"
+        } > "reports/${name}.report.txt"
+    done
+
+    for report in reports/*.report.txt; do
+        report_id=$(dx upload "$report" --brief)
+        dx-jobutil-add-output reports "$report_id" --class="array:file"
+    done
+}
@@ -0,0 +1,26 @@
+#!/usr/bin/env python3
+"""
+{{name}}: {{summary}}
+"""
+
+import dxpy
+
+
+@dxpy.entry_point('main')
+def main(input_file):
+    """ Entry Point """
+
+    input_file = dxpy.DXFile(input_file)
+    name = input_file.describe()['name']
+    dxpy.download_dxfile(input_file.get_id(), name)
+
+    # Add your code here
+    out_name = f'{name}.out'
+    with open(name) as fh, open(out_name, 'w') as out:
+        out.write(fh.read())
+
+    output_file = dxpy.upload_local_file(out_name)
+    return {'output_file': dxpy.dxlink(output_file)}
+
+
+dxpy.run()
@@ -0,0 +1,3460 @@
+use crate::dxenv::DxEnvironment;
+use crate::exit::{exit_code, CliError, NOT_FOUND};
+use crate::{
+    app_region_problems, app_user_id, app_version_record,
+    applet_or_workflow_id, applet_to_app, archive_entry_path, bash_template,
+    batch_small_files, billing_csv, build_regions, cd_target, check_inputs,
+    check_instance_type, close_poll_delay, compiled_executable_id,
+    convert_source, cost_breakdown, dataset_csv_row, dataset_lines,
+    dataset_query, dataset_summary, diff_apps, diff_inventories,
+    dir_fingerprint, display_value, du_rows, dupe_rows, dx_link, ellipsize,
+    expand_stdin_args, fan_out, file_checksum, file_is_closed,
+    find_apps_record, find_duplicates, fit_column, fix_app, format_description,
+    format_json, format_properties, format_runtime, format_watch_message,
+    fuzzy_match, glob_to_regex, head_lines, in_trash, incomplete,
+    input_spec_record, input_template, is_formatted, is_up_to_date,
+    job_failure, job_notification,
+    json_parser::{DxApp, InputOutputClass, InputSpec, OutputSpec},
+    known_hosts_line, limit_rows, line_diff, linked_ids, list_templates,
+    listing_rows, local_job_outputs, local_md5s, local_source, normalize,
+    object_class, parse_batch_tsv, parse_duration, parse_instance_types,
+    parse_metrics, parse_project_path, parse_prompted_value, parse_properties,
+    parse_query_results, parse_run_inputs, parse_size,
+    parse_stage_instance_types, parse_time, parse_upload_manifest,
+    project_snapshot, property_filter, python_template, query_command,
+    query_page, read_input_file, read_part, remember_project, render_template,
+    rerun_input, resolve_local_inputs, restore_destination, search_json,
+    search_time, select_dataset_fields, select_fields, shell_quote,
+    snapshot_clones, sort_app_versions, sort_listing, split_glob_path,
+    stage_local_inputs, sync_local_files, sync_plan, tar_directory,
+    template_files, test_inputs_template, token_expiry_warning, token_scope,
+    top_lines, track_open_upload, trash_destination, tsv_value,
+    untrack_open_upload, upload_each, upload_manifest_results, wait_poll_delay,
+    wdl_template, wdl_to_app, AnalysisDescribeResult, AppDescribeResult,
+    AppDiff, AppInputSpec, AppletDescribeResult, BatchRow, BillingRow,
+    ChunkReader, ChunkWriter, ContainerDescribeResult, ConvertFrom, CostRow,
+    DatabaseDescribeResult, DatasetEntity, DatasetField, DatasetSummary, DuRow,
+    DupeCandidate, DxIgnore, ExecutionCost, FileDescribeResult, FindAppsResult,
+    FindDataDescribe, FindOrgsResult, InventoryObject, JobDescribeResult,
+    KitchenSink, ListColumn, ListSortBy, ListingRow, ManifestRow, OrgDescribe,
+    ProgressEvent, ProgressFormat, ProjectDescribeResult, ProjectInventory,
+    ProjectPath, ProjectSnapshot, RecordDescribeResult, RecordVisualizeResult,
+    RunResult, SearchTime, SyncAction, SyncOp, UploadProgress,
+    UserDescribeResult, UserInfo, WaitTarget, WatchMessage,
+    APPLET_IGNORED_FIELDS, EMBEDDED_TEMPLATES, MAX_RECENT_PROJECTS,
+    METRICS_CSV_HEADER, OPEN_UPLOADS,
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use flate2::{read::MultiGzDecoder, write::GzEncoder, Compression};
+use pretty_assertions::assert_eq;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
+};
+
+fn make_all_inputs() -> Vec<InputSpec> {
+    vec![
+        InputSpec {
+            name: "applet_input".to_string(),
+            label: Some("Applet Input".to_string()),
+            class: InputOutputClass::Applet,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("applet help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "boolean_input".to_string(),
+            label: Some("Boolean Input".to_string()),
+            class: InputOutputClass::Boolean,
+            optional: Some(false),
+            default: Some(serde_json::Value::Bool(true)),
+            patterns: vec![],
+            help: Some("Boolean help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "file_input".to_string(),
+            label: Some("File Input".to_string()),
+            class: InputOutputClass::File,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("file help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "float_input".to_string(),
+            label: Some("Float Input".to_string()),
+            class: InputOutputClass::Float,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("float help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "hash_input".to_string(),
+            label: Some("Hash Input".to_string()),
+            class: InputOutputClass::Hash,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("hash help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "int_input".to_string(),
+            label: Some("Int Input".to_string()),
+            class: InputOutputClass::Int,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("int help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "record_input".to_string(),
+            label: Some("Record Input".to_string()),
+            class: InputOutputClass::Record,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("record help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "string_input".to_string(),
+            label: Some("String Input".to_string()),
+            class: InputOutputClass::String,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("string help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "array_applet_input".to_string(),
+            label: Some("Array Applet Input".to_string()),
+            class: InputOutputClass::ArrayApplet,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("array applet help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "array_boolean_input".to_string(),
+            label: Some("Array Boolean Input".to_string()),
+            class: InputOutputClass::ArrayBoolean,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("array boolean help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "array_file_input".to_string(),
+            label: Some("Array File Input".to_string()),
+            class: InputOutputClass::ArrayFile,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("array file help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "array_float_input".to_string(),
+            label: Some("Array Float Input".to_string()),
+            class: InputOutputClass::ArrayFloat,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("array float help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "array_int_input".to_string(),
+            label: Some("Array Int Input".to_string()),
+            class: InputOutputClass::ArrayInt,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("array int help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "array_record_input".to_string(),
+            label: Some("Array Record Input".to_string()),
+            class: InputOutputClass::ArrayRecord,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("array record help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+        InputSpec {
+            name: "array_string_input".to_string(),
+            label: Some("Array String Input".to_string()),
+            class: InputOutputClass::ArrayString,
+            optional: Some(false),
+            default: None,
+            patterns: vec![],
+            help: Some("array string help".to_string()),
+            choices: vec![],
+            input_type: None,
+            group: None,
+            suggestions: vec![],
+        },
+    ]
+}
+
+fn make_all_outputs() -> Vec<OutputSpec> {
+    vec![
+        OutputSpec {
+            name: "applet_output".to_string(),
+            class: InputOutputClass::Applet,
+            label: Some("Applet Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "array_applet_output".to_string(),
+            class: InputOutputClass::ArrayApplet,
+            label: Some("Array File Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "array_boolean_output".to_string(),
+            class: InputOutputClass::ArrayBoolean,
+            label: Some("Array Boolean Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "array_file_output".to_string(),
+            class: InputOutputClass::ArrayFile,
+            label: Some("Array File Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "array_float_output".to_string(),
+            class: InputOutputClass::ArrayFloat,
+            label: Some("Array Float Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "array_int_output".to_string(),
+            class: InputOutputClass::ArrayInt,
+            label: Some("Array Int Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "array_record_output".to_string(),
+            class: InputOutputClass::ArrayRecord,
+            label: Some("Array Record Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "array_string_output".to_string(),
+            class: InputOutputClass::ArrayString,
+            label: Some("Array String Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "boolean_output".to_string(),
+            class: InputOutputClass::Boolean,
+            label: Some("Boolean Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "file_output".to_string(),
+            class: InputOutputClass::File,
+            label: Some("File Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "float_output".to_string(),
+            class: InputOutputClass::Float,
+            label: Some("Float Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "hash_output".to_string(),
+            class: InputOutputClass::Hash,
+            label: Some("Hash Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "int_output".to_string(),
+            class: InputOutputClass::Int,
+            label: Some("Int Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "record_output".to_string(),
+            class: InputOutputClass::Record,
+            label: Some("Record Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+        OutputSpec {
+            name: "string_output".to_string(),
+            class: InputOutputClass::String,
+            label: Some("String Output".to_string()),
+            help: Some("help".to_string()),
+            optional: Some(false),
+            patterns: vec![],
+        },
+    ]
+}
+
+#[test]
+fn test_wdl_template_none() -> Result<()> {
+    let expected =
+        fs::read_to_string("tests/expected/applets/wdl_template_none.txt")?;
+    let res = wdl_template("empty_wdl", &[], &[], None);
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap(), expected);
+    Ok(())
+}
+
+#[test]
+fn test_wdl_template_all() -> Result<()> {
+    let inputs = make_all_inputs();
+    let outputs = make_all_outputs();
+    let expected =
+        fs::read_to_string("tests/expected/applets/wdl_template_all.txt")?;
+    let res = wdl_template("empty_wdl", &inputs, &outputs, None);
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap(), expected);
+    Ok(())
+}
+
+#[test]
+fn test_wdl_template_all_commands() -> Result<()> {
+    let inputs = make_all_inputs();
+    let outputs = make_all_outputs();
+    let expected = fs::read_to_string(
+        "tests/expected/applets/wdl_template_all_commands.txt",
+    )?;
+    let path = Path::new("tests/expected/applets/commands.sh").to_path_buf();
+    let res = wdl_template("empty_wdl", &inputs, &outputs, Some(&path));
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap(), expected);
+    Ok(())
+}
+
+#[test]
+fn test_wdl_to_app() -> Result<()> {
+    // Round trip the inputs and outputs through the WDL template
+    let inputs = make_all_inputs();
+    let outputs = make_all_outputs();
+    let wdl = wdl_template("all_wdl", &inputs, &outputs, None)?;
+    let app = wdl_to_app(&wdl)?;
+    assert_eq!(app.name, Some("all_wdl".to_string()));
+    assert_eq!(app.input_spec.len(), inputs.len());
+    for (converted, input) in app.input_spec.iter().zip(&inputs) {
+        assert_eq!(converted.name, input.name);
+        assert_eq!(converted.class, input.class);
+        assert_eq!(
+            converted.optional.unwrap_or(false),
+            input.optional.unwrap_or(false)
+        );
+    }
+    assert_eq!(app.output_spec.len(), outputs.len());
+    for (converted, output) in app.output_spec.iter().zip(&outputs) {
+        assert_eq!(converted.name, output.name);
+        assert_eq!(converted.class, output.class);
+    }
+    assert!(app.regional_options.is_none());
+
+    let wdl = [
+        "version 1.0",
+        "task count {",
+        "    input {",
+        "        File reads",
+        r#"        Int? min = 4  # minimum"#,
+        "    }",
+        "    command <<<",
+        "        wc -l ~{reads}",
+        "    >>>",
+        "    runtime {",
+        r#"        dx_instance_type: "mem1_ssd1_v2_x4""#,
+        "    }",
+        "    parameter_meta {",
+        r#"        reads: { help: "Reads" }"#,
+        "    }",
+        "    output {",
+        "        Int lines = read_int(stdout())",
+        "    }",
+        "}",
+    ]
+    .join("\n");
+    let app = wdl_to_app(&wdl)?;
+    assert_eq!(app.input_spec.len(), 2);
+    assert_eq!(app.input_spec[1].default, Some(serde_json::json!(4)));
+    assert_eq!(app.input_spec[1].optional, Some(true));
+    assert_eq!(app.output_spec[0].class, InputOutputClass::Int);
+    assert_eq!(
+        app.run_spec.code,
+        Some("main() {\n    wc -l ${reads}\n}\n".to_string())
+    );
+    let regional_options = app.regional_options.unwrap();
+    assert_eq!(
+        regional_options["*"].system_requirements["*"].instance_type,
+        "mem1_ssd1_v2_x4"
+    );
+
+    assert!(wdl_to_app("version 1.0").is_err());
+    assert!(wdl_to_app("task bad {\n input {\n Directory d\n }\n}").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_convert_source() {
+    assert_eq!(convert_source("main.wdl", "{}"), ConvertFrom::Wdl);
+    assert_eq!(convert_source("a/dxapp.json", ""), ConvertFrom::Dxapp);
+    assert_eq!(convert_source("dxasset.json", ""), ConvertFrom::Dxasset);
+    assert_eq!(
+        convert_source("-", r#"{"runSpec": {}}"#),
+        ConvertFrom::Dxapp
+    );
+    assert_eq!(
+        convert_source("-", r#"{"name": "asset"}"#),
+        ConvertFrom::Dxasset
+    );
+    assert_eq!(convert_source("-", "version 1.0"), ConvertFrom::Wdl);
+}
+
+#[test]
+fn test_bash_template_none() -> Result<()> {
+    let expected =
+        fs::read_to_string("tests/expected/applets/bash_template_none.txt")?;
+    assert_eq!(bash_template("Empty Bash Applet", &[], &[]), expected);
+    Ok(())
+}
+
+#[test]
+fn test_bash_template_all() -> Result<()> {
+    let inputs = make_all_inputs();
+    let outputs = make_all_outputs();
+    let expected =
+        fs::read_to_string("tests/expected/applets/bash_template_all.txt")?;
+    assert_eq!(
+        bash_template("Bash Applet with All Args", &inputs, &outputs),
+        expected
+    );
+    Ok(())
+}
+
+#[test]
+fn test_python_template_none() -> Result<()> {
+    let expected =
+        fs::read_to_string("tests/expected/applets/python_template_none.txt")?;
+    assert_eq!(python_template("Empty Python Applet", &[], &[]), expected);
+    Ok(())
+}
+
+#[test]
+fn test_python_template_all() -> Result<()> {
+    let inputs = make_all_inputs();
+    let outputs = make_all_outputs();
+    let expected =
+        fs::read_to_string("tests/expected/applets/python_template_all.txt")?;
+    assert_eq!(
+        python_template("Python Applet with All Args", &inputs, &outputs),
+        expected
+    );
+    Ok(())
+}
+
+#[test]
+fn test_normalize() -> Result<()> {
+    let val = normalize("123foo".to_string());
+    assert!(val.is_err());
+    assert_eq!(
+        val.unwrap_err().to_string(),
+        "Value \"123foo\" must begin with a character"
+    );
+
+    let val = normalize("Foo Bar".to_string());
+    assert!(val.is_ok());
+    assert_eq!(val.unwrap(), "Foo_Bar".to_string());
+
+    let val = normalize("foo_bar~!@#$%^&*()+.123".to_string());
+    assert!(val.is_ok());
+    assert_eq!(val.unwrap(), "foo_bar.123".to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_run_inputs() -> Result<()> {
+    let inputs = parse_run_inputs(&[
+        "reads=file-GFfbj0Q054J4ypqJ8vQjF4V7".to_string(),
+        "ref=project-GbxZVz8071x9yvpXgxV4gVjK:file-GFfbj0Q054J4ypqJ8vQjF4V7"
+            .to_string(),
+        "threads=4".to_string(),
+        "verbose=true".to_string(),
+        "prefix=out".to_string(),
+        "tag=a".to_string(),
+        "tag=b".to_string(),
+    ])?;
+
+    assert_eq!(
+        inputs["reads"],
+        serde_json::json!({
+            "$dnanexus_link": "file-GFfbj0Q054J4ypqJ8vQjF4V7"
+        })
+    );
+    assert_eq!(
+        inputs["ref"],
+        serde_json::json!({
+            "$dnanexus_link": {
+                "project": "project-GbxZVz8071x9yvpXgxV4gVjK",
+                "id": "file-GFfbj0Q054J4ypqJ8vQjF4V7"
+            }
+        })
+    );
+    assert_eq!(inputs["threads"], serde_json::json!(4));
+    assert_eq!(inputs["verbose"], serde_json::json!(true));
+    assert_eq!(inputs["prefix"], serde_json::json!("out"));
+    assert_eq!(inputs["tag"], serde_json::json!(["a", "b"]));
+
+    let res = parse_run_inputs(&["foo".to_string()]);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        r#"Input "foo" must be NAME=VALUE"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_instance_types() -> Result<()> {
+    let reqs = parse_instance_types(&[
+        "mem1_ssd1_v2_x4".to_string(),
+        "main=mem2_ssd1_v2_x16".to_string(),
+        "scatter=mem1_ssd1_v2_x4".to_string(),
+    ])?;
+    assert_eq!(reqs.len(), 3);
+    assert_eq!(reqs["*"].instance_type, "mem1_ssd1_v2_x4");
+    assert_eq!(reqs["main"].instance_type, "mem2_ssd1_v2_x16");
+    assert_eq!(reqs["scatter"].instance_type, "mem1_ssd1_v2_x4");
+
+    let res = parse_instance_types(&["main=foo".to_string()]);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        r#"Invalid instance type "foo""#
+    );
+
+    let res = parse_instance_types(&["=mem1_ssd1_v2_x4".to_string()]);
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_limit_rows() {
+    assert_eq!(limit_rows(vec![1, 2, 3], None), (vec![1, 2, 3], 0));
+    assert_eq!(limit_rows(vec![1, 2, 3], Some(5)), (vec![1, 2, 3], 0));
+    assert_eq!(limit_rows(vec![1, 2, 3], Some(3)), (vec![1, 2, 3], 0));
+    assert_eq!(limit_rows(vec![1, 2, 3], Some(1)), (vec![1], 2));
+}
+
+#[test]
+fn test_format_properties() {
+    let props = std::collections::HashMap::from([
+        ("b".to_string(), "2".to_string()),
+        ("a".to_string(), "1".to_string()),
+        ("c".to_string(), "3".to_string()),
+    ]);
+    assert_eq!(format_properties(&props, None), "a = 1, b = 2, c = 3");
+    assert_eq!(
+        format_properties(&props, Some(1)),
+        "a = 1, … and 2 more (use --full)"
+    );
+    assert_eq!(
+        format_properties(&std::collections::HashMap::new(), None),
+        "-"
+    );
+}
+
+#[test]
+fn test_is_up_to_date() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("foo.txt");
+    assert!(!is_up_to_date(&path, Some(3), None));
+
+    fs::write(&path, "foo")?;
+    let past = chrono::Utc::now() - chrono::Duration::hours(1);
+    let future = chrono::Utc::now() + chrono::Duration::hours(1);
+    assert!(is_up_to_date(&path, Some(3), Some(past)));
+    assert!(!is_up_to_date(&path, Some(4), Some(past)));
+    assert!(!is_up_to_date(&path, Some(3), Some(future)));
+    assert!(!is_up_to_date(&path, None, Some(past)));
+    Ok(())
+}
+
+#[test]
+fn test_ellipsize() {
+    assert_eq!(ellipsize("foo", 5), "foo");
+    assert_eq!(ellipsize("foobar", 6), "foobar");
+    assert_eq!(ellipsize("foobarbaz", 6), "fooba…");
+    assert_eq!(ellipsize("foobarbaz", 1), "…");
+    assert_eq!(ellipsize("foobarbaz", 0), "");
+}
+
+#[test]
+fn test_fit_column() {
+    let mut rows = vec![
+        vec![
+            "closed".to_string(),
+            "a_very_long_file_name.txt".to_string(),
+        ],
+        vec!["open".to_string(), "short.txt".to_string()],
+    ];
+
+    // Plenty of room leaves everything alone
+    fit_column(&mut rows, 1, 80);
+    assert_eq!(rows[0][1], "a_very_long_file_name.txt");
+
+    // 6 for the first column, 1 separator, leaves 13
+    fit_column(&mut rows, 1, 20);
+    assert_eq!(rows[0][1], "a_very_long_…");
+    assert_eq!(rows[1][1], "short.txt");
+
+    // Never shrink below the minimum
+    fit_column(&mut rows, 1, 5);
+    assert_eq!(rows[0][1].chars().count(), 12);
+}
+
+#[test]
+fn test_parse_analysis_json1() -> Result<()> {
+    let file = "tests/inputs/desc-analysis-GFfkqz0054JJG8p1GBpv7qGX.json";
+    let json = fs::read_to_string(file)?;
+    let analysis: AnalysisDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(analysis.id, "analysis-GFfkqz0054JJG8p1GBpv7qGX".to_string());
+    Ok(())
+}
+
+#[test]
+fn test_parse_analysis_json2() -> Result<()> {
+    let file = "tests/inputs/desc-analysis-GbxgbB8098YzQ0K3FBfFqyB2.json";
+    let json = fs::read_to_string(file)?;
+    let analysis: AnalysisDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(analysis.id, "analysis-GbxgbB8098YzQ0K3FBfFqyB2".to_string());
+    Ok(())
+}
+
+#[test]
+fn test_parse_app_json() -> Result<()> {
+    let file = "tests/inputs/desc-app-GJzjbP00vyjyXPpkFv7bxf1F.json";
+    let json = fs::read_to_string(file)?;
+    let app: AppDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(app.id, "app-GJzjbP00vyjyXPpkFv7bxf1F".to_string());
+    Ok(())
+}
+
+#[test]
+fn test_parse_applet_json() -> Result<()> {
+    let file = "tests/inputs/desc-applet-GZ2BF8Q0jZ5qj3bQBX5BFjjZ.json";
+    let json = fs::read_to_string(file)?;
+    let applet: AppletDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(applet.id, "applet-GZ2BF8Q0jZ5qj3bQBX5BFjjZ".to_string());
+    Ok(())
+}
+
+#[test]
+fn test_parse_container_json() -> Result<()> {
+    let file = "tests/inputs/desc-container-GJzjbP008QGyXPpkFv7bxf1G.json";
+    let json = fs::read_to_string(file)?;
+    let container: ContainerDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(
+        container.id,
+        "container-GJzjbP008QGyXPpkFv7bxf1G".to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_database_json() -> Result<()> {
+    let file = "tests/inputs/desc-database-GZ6vP1801xf4fXjB3YVX011f.json";
+    let json = fs::read_to_string(file)?;
+    let db: DatabaseDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(db.id, "database-GZ6vP1801xf4fXjB3YVX011f".to_string());
+    Ok(())
+}
+
+#[test]
+fn test_parse_file_json() -> Result<()> {
+    let file = "tests/inputs/desc-file-GFfbj0Q054J4ypqJ8vQjF4V7.json";
+    let json = fs::read_to_string(file)?;
+    let file: FileDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(file.id, "file-GFfbj0Q054J4ypqJ8vQjF4V7".to_string());
+    Ok(())
+}
+
+#[test]
+fn test_parse_job_json() -> Result<()> {
+    let file = "tests/inputs/desc-job-GFfkqz0054JJG8p1GBpv7qGb.json";
+    let json = fs::read_to_string(file)?;
+    let job: JobDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(job.id, "job-GFfkqz0054JJG8p1GBpv7qGb".to_string());
+    Ok(())
+}
+
+#[test]
+fn test_parse_project_json() -> Result<()> {
+    let file = "tests/inputs/desc-project-GYgj4800jZ5YqgZ24ZzJpZvq.json";
+    let json = fs::read_to_string(file)?;
+    let project: ProjectDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(project.id, "project-GYgj4800jZ5YqgZ24ZzJpZvq".to_string());
+    Ok(())
+}
+
+#[test]
+fn test_parse_record_json() -> Result<()> {
+    let file = "tests/inputs/desc-record-GZ6vQPj0b5pJfbQ3XffQB1BJ.json";
+    let json = fs::read_to_string(file)?;
+    let record: RecordDescribeResult = serde_json::from_str(&json)?;
+    assert_eq!(record.id, "record-GZ6vQPj0b5pJfbQ3XffQB1BJ".to_string());
+    Ok(())
+}
+
+#[test]
+fn test_parse_project_path() -> Result<()> {
+    let dx_env = DxEnvironment {
+        apiserver_protocol: "https".to_string(),
+        username: "test_user".to_string(),
+        cli_wd: "/foo".to_string(),
+        apiserver_host: "api.dnanexus.com".to_string(),
+        project_context_id: "project-GXY0PK0071xJpG156BFyXpJF".to_string(),
+        project_context_name: "test".to_string(),
+        apiserver_port: 443,
+        auth_token_type: "Bearer".to_string(),
+        auth_token: "XXXX".to_string(),
+        plaintext_store: false,
+        recent_projects: vec![],
+        previous_wd: None,
+        auth_token_expires: None,
+    };
+
+    assert_eq!(
+        parse_project_path(&dx_env, &None),
+        ProjectPath {
+            project_id: "project-GXY0PK0071xJpG156BFyXpJF".to_string(),
+            path: PathBuf::from("/foo")
+        }
+    );
+
+    assert_eq!(
+        parse_project_path(&dx_env, &Some("/".to_string())),
+        ProjectPath {
+            project_id: "project-GXY0PK0071xJpG156BFyXpJF".to_string(),
+            path: PathBuf::from("/")
+        }
+    );
+
+    assert_eq!(
+        parse_project_path(
+            &dx_env,
+            &Some("project-GYgj4800jZ5YqgZ24ZzJpZvq".to_string())
+        ),
+        ProjectPath {
+            project_id: "project-GYgj4800jZ5YqgZ24ZzJpZvq".to_string(),
+            path: PathBuf::from("/")
+        }
+    );
+
+    assert_eq!(
+        parse_project_path(
+            &dx_env,
+            &Some("project-GYgj4800jZ5YqgZ24ZzJpZvq:/".to_string())
+        ),
+        ProjectPath {
+            project_id: "project-GYgj4800jZ5YqgZ24ZzJpZvq".to_string(),
+            path: PathBuf::from("/")
+        }
+    );
+
+    assert_eq!(
+        parse_project_path(
+            &dx_env,
+            &Some("project-GYgj4800jZ5YqgZ24ZzJpZvq:/bar".to_string())
+        ),
+        ProjectPath {
+            project_id: "project-GYgj4800jZ5YqgZ24ZzJpZvq".to_string(),
+            path: PathBuf::from("/bar")
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_split_glob_path() {
+    assert_eq!(
+        split_glob_path("/data/foo.bam"),
+        ("/data".to_string(), "foo.bam".to_string(), None)
+    );
+
+    assert_eq!(
+        split_glob_path("/data/*.bam"),
+        ("/data".to_string(), "*.bam".to_string(), None)
+    );
+
+    assert_eq!(
+        split_glob_path("/data/run_*/fastq/*"),
+        (
+            "/data".to_string(),
+            "*".to_string(),
+            Some("/data/run_*/fastq".to_string())
+        )
+    );
+
+    assert_eq!(
+        split_glob_path("/run_?/*.gz"),
+        (
+            "/".to_string(),
+            "*.gz".to_string(),
+            Some("/run_?".to_string())
+        )
+    );
+}
+
+#[test]
+fn test_glob_to_regex() -> Result<()> {
+    let re = glob_to_regex("/data/run_*/fastq")?;
+    assert!(re.is_match("/data/run_1/fastq"));
+    assert!(re.is_match("/data/run_/fastq"));
+    assert!(!re.is_match("/data/run_1/x/fastq"));
+    assert!(!re.is_match("/data/run_1/fastq/more"));
+
+    let re = glob_to_regex("sample?.[ch]")?;
+    assert!(re.is_match("sample1.c"));
+    assert!(!re.is_match("sample12.c"));
+    assert!(!re.is_match("sample1.o"));
+
+    let re = glob_to_regex("[!a]*.txt")?;
+    assert!(re.is_match("b.txt"));
+    assert!(!re.is_match("a.txt"));
+    assert!(!re.is_match("bxtxt"));
+
+    assert!(glob_to_regex("[z-a]").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_size() -> Result<()> {
+    assert_eq!(parse_size("100")?, 100);
+    assert_eq!(parse_size("64K")?, 64 * 1024);
+    assert_eq!(parse_size("64kb")?, 64 * 1024);
+    assert_eq!(parse_size("1M")?, 1024 * 1024);
+    assert_eq!(parse_size("2GiB")?, 2 * 1024 * 1024 * 1024);
+    assert!(parse_size("").is_err());
+    assert!(parse_size("1T").is_err());
+    assert!(parse_size("big").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_duration() -> Result<()> {
+    assert_eq!(parse_duration("90")?, 90);
+    assert_eq!(parse_duration("90s")?, 90);
+    assert_eq!(parse_duration("30m")?, 30 * 60);
+    assert_eq!(parse_duration("12H")?, 12 * 60 * 60);
+    assert_eq!(parse_duration("1d")?, 24 * 60 * 60);
+    assert_eq!(parse_duration("2w")?, 14 * 24 * 60 * 60);
+    assert!(parse_duration("").is_err());
+    assert!(parse_duration("1y").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_batch_small_files() -> Result<()> {
+    let src = tempfile::tempdir()?;
+    let out = tempfile::tempdir()?;
+    let dest = |folder: &str| ProjectPath {
+        project_id: "project-000".to_string(),
+        path: PathBuf::from(folder),
+    };
+    let mut files = vec![];
+    for (name, folder) in
+        [("a.txt", "/one"), ("b.txt", "/one"), ("c.txt", "/two")]
+    {
+        let path = src.path().join(name);
+        fs::write(&path, name)?;
+        files.push((path.display().to_string(), dest(folder)));
+    }
+
+    let bundles = batch_small_files(files, out.path())?;
+    assert_eq!(bundles.len(), 2);
+
+    assert_eq!(bundles[0].destination.path, PathBuf::from("/one"));
+    assert_eq!(bundles[0].name, "one_small_files.tar.gz");
+    assert_eq!(
+        bundles[0].properties.get("batch_manifest").unwrap(),
+        r#"["a.txt","b.txt"]"#
+    );
+    assert_eq!(bundles[0].properties.get("batch_file_count").unwrap(), "2");
+    assert!(Path::new(&bundles[0].filename).is_file());
+
+    assert_eq!(bundles[1].destination.path, PathBuf::from("/two"));
+    assert_eq!(bundles[1].name, "two_small_files.tar.gz");
+    assert_eq!(bundles[1].properties.get("batch_file_count").unwrap(), "1");
+
+    // Files with the same name cannot share a bundle
+    let other = src.path().join("other");
+    fs::create_dir(&other)?;
+    fs::write(other.join("a.txt"), "other")?;
+    let files = vec![
+        (src.path().join("a.txt").display().to_string(), dest("/one")),
+        (other.join("a.txt").display().to_string(), dest("/one")),
+    ];
+    assert!(batch_small_files(files, out.path()).is_err());
+    Ok(())
+}
+
+fn make_listing() -> Vec<ListingRow> {
+    vec![
+        ListingRow {
+            name: "b.txt".to_string(),
+            id: "file-b".to_string(),
+            state: "closed".to_string(),
+            size: Some(2048),
+            modified: None,
+        },
+        ListingRow {
+            name: "a.txt".to_string(),
+            id: "file-a".to_string(),
+            state: "open".to_string(),
+            size: Some(10),
+            modified: None,
+        },
+        ListingRow {
+            name: "c.txt".to_string(),
+            id: "file-c".to_string(),
+            state: "closed".to_string(),
+            size: None,
+            modified: None,
+        },
+    ]
+}
+
+#[test]
+fn test_sort_listing() {
+    let names = |rows: &[ListingRow]| {
+        rows.iter().map(|r| r.name.clone()).collect::<Vec<_>>()
+    };
+
+    let mut rows = make_listing();
+    sort_listing(&mut rows, &None, false);
+    assert_eq!(names(&rows), ["b.txt", "a.txt", "c.txt"]);
+
+    sort_listing(&mut rows, &Some(ListSortBy::Name), false);
+    assert_eq!(names(&rows), ["a.txt", "b.txt", "c.txt"]);
+
+    sort_listing(&mut rows, &Some(ListSortBy::Size), false);
+    assert_eq!(names(&rows), ["c.txt", "a.txt", "b.txt"]);
+
+    sort_listing(&mut rows, &Some(ListSortBy::Size), true);
+    assert_eq!(names(&rows), ["b.txt", "a.txt", "c.txt"]);
+}
+
+#[test]
+fn test_listing_rows() {
+    let rows = make_listing();
+    let columns = [ListColumn::Name, ListColumn::Size, ListColumn::Id];
+    let res = listing_rows(&rows[..2], &columns, false, true);
+    assert_eq!(
+        res,
+        [
+            ["Name", "Size", "ID"],
+            ["b.txt", "2048", "file-b"],
+            ["a.txt", "10", "file-a"],
+        ]
+    );
+
+    let columns = [ListColumn::Size, ListColumn::State];
+    let res = listing_rows(&rows[..1], &columns, true, false);
+    assert_eq!(res, [["2.00 KiB", "closed"]]);
+
+    let res = listing_rows(&rows[2..], &columns, true, false);
+    assert_eq!(res, [["NA", "closed"]]);
+}
+
+#[test]
+fn test_track_open_uploads() {
+    let is_open = |file_id: &str| {
+        OPEN_UPLOADS
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(_, id)| id == file_id)
+    };
+
+    track_open_upload("project-test", "file-track1");
+    track_open_upload("project-test", "file-track2");
+    assert!(is_open("file-track1"));
+    assert!(is_open("file-track2"));
+
+    untrack_open_upload("file-track1");
+    assert!(!is_open("file-track1"));
+    assert!(is_open("file-track2"));
+
+    untrack_open_upload("file-track2");
+    assert!(!is_open("file-track2"));
+}
+
+#[test]
+fn test_tsv_value() {
+    assert_eq!(tsv_value(&serde_json::json!(null)), "");
+    assert_eq!(tsv_value(&serde_json::json!("foo")), "foo");
+    assert_eq!(tsv_value(&serde_json::json!("a\tb\nc")), "a b c");
+    assert_eq!(tsv_value(&serde_json::json!(1024)), "1024");
+    assert_eq!(tsv_value(&serde_json::json!(true)), "true");
+    assert_eq!(tsv_value(&serde_json::json!(["a", 1])), r#"["a",1]"#);
+}
+
+#[test]
+fn test_progress_event() -> Result<()> {
+    let event = ProgressEvent::Started {
+        file: "a.txt",
+        size: 10,
+    };
+    assert_eq!(
+        serde_json::to_string(&event)?,
+        r#"{"event":"started","file":"a.txt","size":10}"#
+    );
+
+    let event = ProgressEvent::Bytes {
+        file: "a.txt",
+        bytes: 5,
+        total: 10,
+    };
+    assert_eq!(
+        serde_json::to_string(&event)?,
+        r#"{"event":"bytes","file":"a.txt","bytes":5,"total":10}"#
+    );
+
+    let event = ProgressEvent::Finished {
+        file: "a.txt",
+        id: Some("file-1"),
+    };
+    assert_eq!(
+        serde_json::to_string(&event)?,
+        r#"{"event":"finished","file":"a.txt","id":"file-1"}"#
+    );
+
+    let event = ProgressEvent::Finished {
+        file: "a.txt",
+        id: None,
+    };
+    assert_eq!(
+        serde_json::to_string(&event)?,
+        r#"{"event":"finished","file":"a.txt"}"#
+    );
+
+    let event = ProgressEvent::Failed {
+        file: "a.txt",
+        error: "boom".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_string(&event)?,
+        r#"{"event":"failed","file":"a.txt","error":"boom"}"#
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check_inputs() -> Result<()> {
+    let input = |name: &str, class, optional| AppInputSpec {
+        name: name.to_string(),
+        class,
+        label: None,
+        help: None,
+        optional: Some(optional),
+        default: None,
+        group: None,
+        patterns: None,
+        choices: None,
+    };
+    let spec = vec![
+        input("reads", InputOutputClass::File, false),
+        input("count", InputOutputClass::Int, false),
+        input("names", InputOutputClass::ArrayString, true),
+    ];
+
+    let inputs = parse_run_inputs(&[
+        "reads=file-GFfbj0Q054J4ypqJ8vQjF4V7".to_string(),
+        "count=10".to_string(),
+    ])?;
+    assert!(check_inputs(&spec, &inputs).is_empty());
+
+    let inputs =
+        parse_run_inputs(&["count=foo".to_string(), "extra=1".to_string()])?;
+    assert_eq!(
+        check_inputs(&spec, &inputs),
+        vec![
+            r#"Missing required input "reads" (file)"#.to_string(),
+            r#"Input "count" should be int"#.to_string(),
+            r#"Unknown input "extra""#.to_string(),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check_instance_type() {
+    assert_eq!(check_instance_type("aws:us-east-1", "mem1_ssd1_x2"), None);
+    assert_eq!(
+        check_instance_type("aws:us-east-1", "foo"),
+        Some(r#"Invalid instance type "foo""#.to_string())
+    );
+    assert!(check_instance_type("azure:westus", "mem1_ssd1_x2").is_some());
+}
+
+#[test]
+fn test_search_json() {
+    let details = serde_json::json!({
+        "batch": 3,
+        "samples": [
+            { "id": "NA12878", "tissue": "blood" },
+            { "id": "NA12891", "tissue": "Liver" }
+        ]
+    });
+    assert_eq!(
+        search_json(&details, "na12891", "details"),
+        Some("details.samples[1].id".to_string())
+    );
+    assert_eq!(
+        search_json(&details, "liver", "details"),
+        Some("details.samples[1].tissue".to_string())
+    );
+    assert_eq!(
+        search_json(&details, "batch", "details"),
+        Some("details.batch".to_string())
+    );
+    assert_eq!(
+        search_json(&details, "3", "details"),
+        Some("details.batch".to_string())
+    );
+    assert_eq!(search_json(&details, "kidney", "details"), None);
+}
+
+#[test]
+fn test_diff_inventories() {
+    let object = |size, md5: &str, tags: &[&str]| InventoryObject {
+        size: Some(size),
+        md5: Some(md5.to_string()),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+    };
+    let source = ProjectInventory {
+        folders: BTreeSet::from(["/".to_string(), "/data".to_string()]),
+        objects: BTreeMap::from([
+            ("/data/a.txt".to_string(), vec![object(10, "aa", &[])]),
+            ("/data/b.txt".to_string(), vec![object(20, "bb", &["x"])]),
+            ("/c.txt".to_string(), vec![object(30, "cc", &[])]),
+        ]),
+    };
+    let target = ProjectInventory {
+        folders: BTreeSet::from(["/".to_string(), "/tmp".to_string()]),
+        objects: BTreeMap::from([
+            ("/data/a.txt".to_string(), vec![object(10, "aa", &[])]),
+            ("/data/b.txt".to_string(), vec![object(21, "bb", &[])]),
+            ("/d.txt".to_string(), vec![object(40, "dd", &[])]),
+        ]),
+    };
+
+    assert!(diff_inventories(&source, &source).is_empty());
+
+    let summary: Vec<_> = diff_inventories(&source, &target)
+        .into_iter()
+        .map(|d| format!("{} {} {}", d.status, d.path, d.detail))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            "source-only /data folder",
+            "target-only /tmp folder",
+            "source-only /c.txt object",
+            "target-only /d.txt object",
+            "differs /data/b.txt size 20 != 21; tags [x] != []",
+        ]
+    );
+}
+
+#[test]
+fn test_head_lines() -> Result<()> {
+    let text = b"one\ntwo\nthree\nfour\n";
+    let chunks = || text.chunks(5).map(|c| Ok(c.to_vec()));
+    assert_eq!(head_lines(chunks(), 2, false)?, b"one\ntwo\n");
+    assert_eq!(head_lines(chunks(), 10, false)?, text);
+    assert_eq!(head_lines(chunks(), 0, false)?, b"");
+
+    // BGZF is a series of gzip members
+    let mut compressed = vec![];
+    for part in [&b"one\ntwo\n"[..], &b"three\nfour\n"[..]] {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(part)?;
+        compressed.extend(encoder.finish()?);
+    }
+    let chunks = compressed.chunks(7).map(|c| Ok(c.to_vec()));
+    assert_eq!(head_lines(chunks, 3, true)?, b"one\ntwo\nthree\n");
+
+    // Stops reading once there are enough lines
+    let mut read = 0;
+    let chunks = text.chunks(4).map(|c| {
+        read += 1;
+        Ok(c.to_vec())
+    });
+    head_lines(chunks, 1, false)?;
+    assert_eq!(read, 1);
+    Ok(())
+}
+
+#[test]
+fn test_compiled_executable_id() {
+    assert_eq!(compiled_executable_id(""), None);
+    assert_eq!(
+        compiled_executable_id(
+            "[warning] Project is unspecified\n\
+                workflow-GZ2BF8Q0jZ5qj3bQBX5BFjjZ\n"
+        ),
+        Some("workflow-GZ2BF8Q0jZ5qj3bQBX5BFjjZ".to_string())
+    );
+    assert_eq!(
+        compiled_executable_id("applet-GZ2BF8Q0jZ5qj3bQBX5BFjjZ"),
+        Some("applet-GZ2BF8Q0jZ5qj3bQBX5BFjjZ".to_string())
+    );
+    assert_eq!(
+        compiled_executable_id("file-GZ2BF8Q0jZ5qj3bQBX5BFjjZ"),
+        None
+    );
+}
+
+#[test]
+fn test_known_hosts_line() {
+    assert_eq!(
+        known_hosts_line("10.0.0.1", "22", "ssh-rsa AAAA\n"),
+        "10.0.0.1 ssh-rsa AAAA\n"
+    );
+    assert_eq!(
+        known_hosts_line("ec2.example.com", "2222", "ssh-ed25519 AAAA"),
+        "[ec2.example.com]:2222 ssh-ed25519 AAAA\n"
+    );
+}
+
+#[test]
+fn test_cost_breakdown() {
+    let execution =
+        |id: &str, name: &str, parent: Option<&str>, price| ExecutionCost {
+            id: id.to_string(),
+            name: Some(name.to_string()),
+            executable_name: Some(format!("{name}_app")),
+            parent_job: parent.map(|p| p.to_string()),
+            root_execution: Some(
+                parent.map_or(id.to_string(), |_| "job-1".to_string()),
+            ),
+            total_price: Some(price),
+            total_egress: Some(HashMap::from([(
+                "internetEgress".to_string(),
+                10,
+            )])),
+            ..Default::default()
+        };
+    let executions = vec![
+        execution("job-1", "root", None, 10.),
+        execution("job-2", "align", Some("job-1"), 6.),
+        execution("job-3", "align", Some("job-1"), 3.),
+        execution("job-4", "call", Some("job-1"), 1.),
+        execution("job-5", "align", Some("job-2"), 2.),
+        execution("job-6", "other", None, 4.),
+    ];
+
+    // A project sums its root executions by executable
+    assert_eq!(
+        cost_breakdown(&executions, None),
+        vec![
+            CostRow {
+                name: "root_app".to_string(),
+                executions: 1,
+                price: 10.,
+                egress: 10,
+            },
+            CostRow {
+                name: "other_app".to_string(),
+                executions: 1,
+                price: 4.,
+                egress: 10,
+            },
+        ]
+    );
+
+    // An execution tree is broken down by its stages
+    assert_eq!(
+        cost_breakdown(&executions, Some("job-1")),
+        vec![
+            CostRow {
+                name: "align".to_string(),
+                executions: 2,
+                price: 9.,
+                egress: 20,
+            },
+            CostRow {
+                name: "call".to_string(),
+                executions: 1,
+                price: 1.,
+                egress: 10,
+            },
+        ]
+    );
+
+    // Without children, the execution is its own breakdown
+    assert_eq!(cost_breakdown(&executions, Some("job-4")).len(), 1);
+}
+
+#[test]
+fn test_shell_quote() {
+    assert_eq!(shell_quote(""), "''");
+    assert_eq!(shell_quote("/my data"), "'/my data'");
+    assert_eq!(shell_quote("it's"), r#"'it'\''s'"#);
+}
+
+#[test]
+fn test_file_is_closed() {
+    assert!(file_is_closed(Some("closed")).unwrap());
+    assert!(!file_is_closed(Some("closing")).unwrap());
+    assert!(!file_is_closed(Some("open")).unwrap());
+    assert!(file_is_closed(Some("abandoned")).is_err());
+    assert!(file_is_closed(None).is_err());
+}
+
+#[test]
+fn test_close_poll_delay() {
+    assert_eq!(close_poll_delay(0), Duration::from_secs(1));
+    assert_eq!(close_poll_delay(2), Duration::from_secs(4));
+    assert_eq!(close_poll_delay(10), Duration::from_secs(10));
+    assert_eq!(close_poll_delay(100), Duration::from_secs(10));
+}
+
+#[test]
+fn test_dx_link() {
+    let link = dx_link(None, "file-GFfbj0Q054J4ypqJ8vQjF4V7".to_string());
+    assert_eq!(
+        serde_json::to_value(&link).unwrap(),
+        serde_json::json!({
+            "$dnanexus_link": "file-GFfbj0Q054J4ypqJ8vQjF4V7"
+        })
+    );
+
+    let link = dx_link(
+        Some("project-GFf8v9Q054J4ypqJ8vQjF4V4".to_string()),
+        "record-GFfbj0Q054J4ypqJ8vQjF4V7".to_string(),
+    );
+    assert_eq!(
+        serde_json::to_value(&link).unwrap(),
+        serde_json::json!({
+            "$dnanexus_link": {
+                "project": "project-GFf8v9Q054J4ypqJ8vQjF4V4",
+                "id": "record-GFfbj0Q054J4ypqJ8vQjF4V7"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_expand_stdin_args() -> Result<()> {
+    let input = "file-GFfbj0Q054J4ypqJ8vQjF4V7\n\n  /data/a.txt \n";
+    let args = vec!["/x.txt".to_string(), "-".to_string()];
+    assert_eq!(
+        expand_stdin_args(&args, false, input.as_bytes())?,
+        vec!["/x.txt", "file-GFfbj0Q054J4ypqJ8vQjF4V7", "/data/a.txt"]
+    );
+
+    let args = vec!["/x.txt".to_string()];
+    assert_eq!(
+        expand_stdin_args(&args, true, input.as_bytes())?,
+        vec!["/x.txt", "file-GFfbj0Q054J4ypqJ8vQjF4V7", "/data/a.txt"]
+    );
+
+    assert_eq!(
+        expand_stdin_args(&[], true, "".as_bytes())?,
+        Vec::<String>::new()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_time() -> Result<()> {
+    let now = DateTime::parse_from_rfc3339("2024-03-10T00:00:00Z")?
+        .with_timezone(&Utc);
+    let day = 24 * 60 * 60 * 1000;
+    assert_eq!(parse_time("-2d", now)?, now.timestamp_millis() - 2 * day);
+    assert_eq!(parse_time("-90", now)?, now.timestamp_millis() - 90_000);
+    assert_eq!(parse_time("2024-01-01", now)?, 1704067200000);
+    assert_eq!(parse_time("2024-01-01 00:00:01", now)?, 1704067201000);
+    assert_eq!(parse_time("2024-01-01T00:00:00Z", now)?, 1704067200000);
+    assert_eq!(parse_time("1704067200000", now)?, 1704067200000);
+    assert!(parse_time("yesterday", now).is_err());
+    assert!(parse_time("-2y", now).is_err());
+
+    assert_eq!(search_time(&None, &None, now)?, None);
+    assert_eq!(
+        search_time(&None, &Some("2024-01-01".to_string()), now)?,
+        Some(SearchTime {
+            after: None,
+            before: Some(1704067200000)
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_property_filter() -> Result<()> {
+    assert!(property_filter(&[])?.is_none());
+    let filter = property_filter(&[
+        "sample=NA12878".to_string(),
+        "reviewed".to_string(),
+    ])?;
+    assert_eq!(
+        serde_json::to_value(filter)?,
+        serde_json::json!({ "sample": "NA12878", "reviewed": true })
+    );
+    assert!(property_filter(&["=foo".to_string()]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_find_apps_record() -> Result<()> {
+    let app: FindAppsResult = serde_json::from_value(serde_json::json!({
+        "id": "app-GFfbj0Q054J4ypqJ8vQjF4V7",
+        "describe": {
+            "name": "bwa_mem",
+            "title": "BWA-MEM",
+            "version": "1.2.0",
+            "published": 1704067200000_i64,
+            "billTo": "org-dnanexus_apps"
+        }
+    }))?;
+    assert_eq!(
+        find_apps_record(app),
+        serde_json::json!({
+            "name": "bwa_mem",
+            "title": "BWA-MEM",
+            "version": "1.2.0",
+            "published": "2024-01-01 00:00:00",
+            "billTo": "org-dnanexus_apps",
+            "id": "app-GFfbj0Q054J4ypqJ8vQjF4V7",
+        })
+    );
+
+    let app: FindAppsResult = serde_json::from_value(
+        serde_json::json!({ "id": "app-GFfbj0Q054J4ypqJ8vQjF4V7" }),
+    )?;
+    assert_eq!(find_apps_record(app)["name"], serde_json::Value::Null);
+    Ok(())
+}
+
+#[test]
+fn test_test_inputs_template() {
+    assert_eq!(
+        test_inputs_template(&make_all_inputs()),
+        serde_json::json!({
+            "applet_input": "",
+            "file_input": "test/data/file_input.txt",
+            "float_input": 0.0,
+            "hash_input": {},
+            "int_input": 0,
+            "record_input": "",
+            "string_input": "",
+            "array_applet_input": [],
+            "array_boolean_input": [],
+            "array_file_input": ["test/data/array_file_input.txt"],
+            "array_float_input": [],
+            "array_int_input": [],
+            "array_record_input": [],
+            "array_string_input": [],
+        })
+    );
+}
+
+#[test]
+fn test_stage_local_inputs() -> Result<()> {
+    let names = [
+        "boolean_input",
+        "file_input",
+        "array_file_input",
+        "string_input",
+    ];
+    let spec: Vec<_> = make_all_inputs()
+        .into_iter()
+        .filter(|input| names.contains(&input.name.as_str()))
+        .collect();
+    let input = serde_json::json!({
+        "file_input": "data/reads.fq.gz",
+        "array_file_input": ["a.txt", "/tmp/b.txt"],
+        "string_input": "it's",
+    });
+    let input = input.as_object().unwrap();
+    let staged = stage_local_inputs(&spec, input, Path::new("app"))?;
+
+    assert_eq!(
+        staged.files,
+        vec![
+            (
+                PathBuf::from("app/data/reads.fq.gz"),
+                "in/file_input/reads.fq.gz".to_string()
+            ),
+            (
+                PathBuf::from("app/a.txt"),
+                "in/array_file_input/0/a.txt".to_string()
+            ),
+            (
+                PathBuf::from("/tmp/b.txt"),
+                "in/array_file_input/1/b.txt".to_string()
+            ),
+        ]
+    );
+    assert_eq!(
+        serde_json::Value::Object(staged.job_input),
+        serde_json::json!({
+            "boolean_input": true,
+            "file_input": {
+                "$dnanexus_link":
+                    "/home/dnanexus/in/file_input/reads.fq.gz"
+            },
+            "array_file_input": [
+                {
+                    "$dnanexus_link":
+                        "/home/dnanexus/in/array_file_input/0/a.txt"
+                },
+                {
+                    "$dnanexus_link":
+                        "/home/dnanexus/in/array_file_input/1/b.txt"
+                },
+            ],
+            "string_input": "it's",
+        })
+    );
+    assert_eq!(
+        staged.script.lines().collect::<Vec<_>>(),
+        [
+            "boolean_input='true'",
+            "file_input='/home/dnanexus/in/file_input/reads.fq.gz'",
+            "file_input_path='/home/dnanexus/in/file_input/reads.fq.gz'",
+            "file_input_name='reads.fq.gz'",
+            "file_input_prefix='reads'",
+            r#"string_input='it'\''s'"#,
+            "array_file_input=('/home/dnanexus/in/array_file_input/0/a.txt' \
+                '/home/dnanexus/in/array_file_input/1/b.txt')",
+            "array_file_input_path=('/home/dnanexus/in/array_file_input/0/a.txt' \
+                '/home/dnanexus/in/array_file_input/1/b.txt')",
+            "array_file_input_name=('a.txt' 'b.txt')",
+            "array_file_input_prefix=('a' 'b')",
+        ]
+    );
+
+    let missing = serde_json::Map::new();
+    let res = stage_local_inputs(&spec, &missing, Path::new("app"));
+    assert!(res.is_err());
+
+    let unknown = serde_json::json!({ "foo": 1 });
+    let res =
+        stage_local_inputs(&spec, unknown.as_object().unwrap(), Path::new("."));
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_local_job_outputs() -> Result<()> {
+    let tsv = [
+        "bam\tfile\t/home/dnanexus/out/x.bam",
+        "count\tint\t42",
+        "label\tstring\t42",
+        "bais\tarray:file\t/home/dnanexus/out/1.bai",
+        "bais\tarray:file\t/home/dnanexus/out/2.bai",
+        "",
+    ]
+    .join("\n");
+    assert_eq!(
+        serde_json::Value::Object(local_job_outputs(&tsv)?),
+        serde_json::json!({
+            "bam": { "$dnanexus_link": "/home/dnanexus/out/x.bam" },
+            "count": 42,
+            "label": "42",
+            "bais": [
+                { "$dnanexus_link": "/home/dnanexus/out/1.bai" },
+                { "$dnanexus_link": "/home/dnanexus/out/2.bai" },
+            ],
+        })
+    );
+    assert!(local_job_outputs("bam\tfile").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_render_template() {
+    let vars = BTreeMap::from([
+        ("name", "my-app".to_string()),
+        ("ident", "my_app".to_string()),
+    ]);
+    assert_eq!(
+        render_template("workflow {{ident}} # {{name}} {{other}}", &vars),
+        "workflow my_app # my-app {{other}}"
+    );
+}
+
+#[test]
+fn test_template_files() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let files = template_files("bash", dir.path())?;
+    let paths: Vec<_> = files.iter().map(|(path, _)| path).collect();
+    assert_eq!(paths, ["dxapp.json", "src/run.sh"]);
+    assert!(template_files("nope", dir.path()).is_err());
+
+    // User templates override the built-in ones
+    fs::create_dir_all(dir.path().join("bash/src"))?;
+    fs::write(dir.path().join("bash/src/code.sh"), "# {{name}}")?;
+    let files = template_files("bash", dir.path())?;
+    assert_eq!(
+        files,
+        [(PathBuf::from("src/code.sh"), "# {{name}}".to_string())]
+    );
+
+    let templates = list_templates(dir.path())?;
+    let names: Vec<_> = templates.iter().map(|(n, _)| n).collect();
+    assert_eq!(names, ["bash", "fastqc-like", "python", "wdl"]);
+    assert_eq!(
+        templates[0].1,
+        dir.path().join("bash").display().to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_embedded_templates_parse() {
+    let vars = BTreeMap::from([
+        ("name", "my-app".to_string()),
+        ("summary", "Does things".to_string()),
+        ("title", "My App".to_string()),
+        ("version", "0.1.0".to_string()),
+    ]);
+    for (name, files) in EMBEDDED_TEMPLATES {
+        for (path, contents) in files.iter() {
+            if *path == "dxapp.json" {
+                let app: DxApp =
+                    serde_json::from_str(&render_template(contents, &vars))
+                        .unwrap_or_else(|e| panic!("{name}: {e}"));
+                assert_eq!(app.name, Some("my-app".to_string()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fix_app() {
+    let mut app = serde_json::json!({
+        "name": "My App",
+        "runSpec": {
+            "release": "20.04",
+            "version": "1",
+            "execDepends": [{ "name": "jq", "packageManager": "apt" }]
+        }
+    });
+    let fixes = fix_app(&mut app);
+    assert_eq!(fixes.len(), 4);
+    assert_eq!(
+        app,
+        serde_json::json!({
+            "name": "My_App",
+            "dxapi": "1.0.0",
+            "runSpec": {
+                "release": "20.04",
+                "version": "0",
+                "execDepends": [
+                    { "name": "jq", "package_manager": "apt" }
+                ]
+            }
+        })
+    );
+
+    // Nothing left to fix
+    assert!(fix_app(&mut app).is_empty());
+
+    // Names that cannot be normalized are left alone
+    let mut app = serde_json::json!({ "name": "1app", "dxapi": "1.0.0" });
+    assert!(fix_app(&mut app).is_empty());
+    assert_eq!(app["name"], "1app");
+}
+
+#[test]
+fn test_line_diff() {
+    assert!(line_diff("a\nb\n", "a\nb").is_empty());
+    assert_eq!(line_diff("a\nb\nc", "a\nc\nd"), ["-b", "+d"]);
+    assert_eq!(line_diff("", "a"), ["+a"]);
+    assert_eq!(line_diff("a\nb", "b\na"), ["-a", "+a"]);
+}
+
+#[test]
+fn test_format_json() -> Result<()> {
+    let asset = r#"{"distribution": "Ubuntu", "title": "Asset"}"#;
+    let expected =
+        "{\n  \"title\": \"Asset\",\n  \"distribution\": \"Ubuntu\"\n}";
+    assert_eq!(format_json(asset, Some("dxasset.json"))?, expected);
+    assert_eq!(format_json(asset, None)?, expected);
+    assert!(format_json(asset, Some("dxapp.json")).is_err());
+    assert!(format_json("{}", None).is_err());
+
+    assert!(is_formatted(&format!("{expected}\n"), expected));
+    assert!(!is_formatted(asset, expected));
+    Ok(())
+}
+
+#[test]
+fn test_du_rows() {
+    let files = [
+        ("/".to_string(), 1),
+        ("/data".to_string(), 10),
+        ("/data/raw".to_string(), 100),
+        ("/results".to_string(), 1000),
+    ];
+    let row = |folder: &str, files, size| DuRow {
+        folder: folder.to_string(),
+        files,
+        size,
+    };
+
+    assert_eq!(du_rows(&files, "/", 0), [row("/", 4, 1111)]);
+    assert_eq!(
+        du_rows(&files, "/", 1),
+        [
+            row("/", 1, 1),
+            row("/data", 2, 110),
+            row("/results", 1, 1000)
+        ]
+    );
+    assert_eq!(
+        du_rows(&files, "/data", 1),
+        [row("/data", 1, 10), row("/data/raw", 1, 100)]
+    );
+}
+
+#[test]
+fn test_parse_properties() -> Result<()> {
+    assert!(parse_properties(&[])?.is_empty());
+    assert_eq!(
+        parse_properties(&["a=1".to_string(), "b=x=y".to_string()])?,
+        HashMap::from([
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "x=y".to_string()),
+        ])
+    );
+
+    let res = parse_properties(&["a".to_string()]);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        r#"Property "a" must be KEY=VALUE"#
+    );
+    assert!(parse_properties(&["=1".to_string()]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_read_part() -> Result<()> {
+    // Chained readers return short reads at the boundary
+    let mut reader = (&b"abc"[..]).chain(&b"defg"[..]);
+    let mut buffer = [0; 5];
+    assert_eq!(read_part(&mut reader, &mut buffer)?, 5);
+    assert_eq!(&buffer, b"abcde");
+    assert_eq!(read_part(&mut reader, &mut buffer)?, 2);
+    assert_eq!(&buffer[..2], b"fg");
+    assert_eq!(read_part(&mut reader, &mut buffer)?, 0);
+    Ok(())
+}
+
+#[test]
+fn test_local_md5s() -> Result<()> {
+    let md5 = |bytes: &[u8]| format!("{:x}", md5::compute(bytes));
+    let data = b"hello world";
+
+    let (whole, parts) = local_md5s(&data[..], &[])?;
+    assert_eq!(whole, md5(data));
+    assert!(parts.is_empty());
+
+    let (whole, parts) = local_md5s(&data[..], &[5, 6])?;
+    assert_eq!(whole, md5(data));
+    assert_eq!(parts, [md5(b"hello"), md5(b" world")]);
+
+    // Bytes beyond the parts still count toward the whole
+    let (whole, parts) = local_md5s(&data[..], &[5])?;
+    assert_eq!(whole, md5(data));
+    assert_eq!(parts, [md5(b"hello")]);
+    Ok(())
+}
+
+#[test]
+fn test_parse_metrics() {
+    let msg = WatchMessage {
+        job: "job-1".to_string(),
+        job_try: None,
+        level: "METRICS".to_string(),
+        msg: "CPU: 12% (4 cores) * Memory: 1024/15006MB * \
+                  Storage: 5/78GB * Net: 0↓/0.5↑MBps * Disk: 1r/2wMBps"
+            .to_string(),
+        source: "SYSTEM".to_string(),
+        timestamp: Some(1700000000000),
+    };
+    let sample = parse_metrics(&msg).unwrap();
+    assert_eq!(sample.cpu, 12.);
+    assert_eq!(sample.cores, Some(4));
+    assert_eq!(sample.mem_used_mb, Some(1024.));
+    assert_eq!(sample.storage_total_gb, Some(78.));
+    assert_eq!(sample.net_up_mbps, Some(0.5));
+    assert_eq!(sample.disk_write_mbps, Some(2.));
+    assert_eq!(
+        sample.csv_row(),
+        "1700000000000,job-1,12,4,1024,15006,5,78,0,0.5,1,2"
+    );
+    assert_eq!(
+        METRICS_CSV_HEADER.split(',').count(),
+        sample.csv_row().split(',').count()
+    );
+
+    // Missing fields are left empty
+    let msg = WatchMessage {
+        msg: "CPU: 3.5%".to_string(),
+        ..msg
+    };
+    let sample = parse_metrics(&msg).unwrap();
+    assert_eq!(sample.cores, None);
+    assert_eq!(sample.csv_row(), "1700000000000,job-1,3.5,,,,,,,,,");
+
+    let msg = WatchMessage {
+        msg: "Not a metrics line".to_string(),
+        ..msg
+    };
+    assert!(parse_metrics(&msg).is_none());
+}
+
+#[test]
+fn test_format_watch_message() {
+    let msg = WatchMessage {
+        job: "job-1".to_string(),
+        job_try: None,
+        level: "STDOUT".to_string(),
+        msg: "hello".to_string(),
+        source: "APP".to_string(),
+        timestamp: Some(0),
+    };
+    assert_eq!(
+        format_watch_message(&msg, true, true),
+        "1970-01-01 00:00:00 job-1 STDOUT hello"
+    );
+    assert_eq!(format_watch_message(&msg, false, false), "STDOUT hello");
+}
+
+#[test]
+fn test_rerun_input() -> Result<()> {
+    let run_input: HashMap<String, KitchenSink> = serde_json::from_str(
+        r#"{
+                "reads": {"$dnanexus_link": "file-GFfbj0Q054J4ypqJ8vQjF4V7"},
+                "min_qual": 20,
+                "ratio": 0.5,
+                "label": "first"
+            }"#,
+    )?;
+
+    // No overrides keeps the original inputs, including floats
+    let input = rerun_input(Some(run_input.clone()), &[])?;
+    assert_eq!(
+        serde_json::Value::Object(input),
+        serde_json::json!({
+            "reads": {"$dnanexus_link": "file-GFfbj0Q054J4ypqJ8vQjF4V7"},
+            "min_qual": 20,
+            "ratio": 0.5,
+            "label": "first"
+        })
+    );
+
+    let input = rerun_input(
+        Some(run_input),
+        &["min_qual=30".to_string(), "label=second".to_string()],
+    )?;
+    assert_eq!(input["min_qual"], serde_json::json!(30));
+    assert_eq!(input["label"], serde_json::json!("second"));
+    assert_eq!(input["ratio"], serde_json::json!(0.5));
+
+    assert!(rerun_input(None, &["bad".to_string()]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_batch_tsv() -> Result<()> {
+    let text = "batch ID\treads\treads\tmin_qual\n\
+            s1\t/data/s1_R1.fq\t/data/s1_R2.fq\t20\n\
+            \n\
+            s2\tfile-GFfbj0Q054J4ypqJ8vQjF4V7\t\t30\n";
+    let rows = parse_batch_tsv(text)?;
+    assert_eq!(rows.len(), 2);
+    assert_eq!(
+        rows[0],
+        BatchRow {
+            batch_id: "s1".to_string(),
+            inputs: vec![
+                ("reads".to_string(), "/data/s1_R1.fq".to_string()),
+                ("reads".to_string(), "/data/s1_R2.fq".to_string()),
+                ("min_qual".to_string(), "20".to_string()),
+            ],
+        }
+    );
+
+    // Empty cells are left out
+    assert_eq!(rows[1].batch_id, "s2");
+    assert_eq!(rows[1].inputs.len(), 2);
+
+    // Without a batch ID column, rows are numbered
+    let rows = parse_batch_tsv("reads\nfoo\nbar\n")?;
+    assert_eq!(rows[1].batch_id, "2");
+
+    assert!(parse_batch_tsv("").is_err());
+    assert!(parse_batch_tsv("reads\n").is_err());
+    assert!(parse_batch_tsv("reads\tqual\nfoo\n").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_resolve_local_inputs() -> Result<()> {
+    let dx_env = DxEnvironment::default();
+    let tmp = tempfile::NamedTempFile::new()?;
+    let local = tmp.path().display().to_string();
+    let file_id = "file-GFfbj0Q054J4ypqJ8vQjF4V7";
+
+    // Files already sent are not uploaded again
+    let mut uploaded =
+        HashMap::from([(tmp.path().canonicalize()?, file_id.to_string())]);
+    let inputs = [
+        format!("reads={local}"),
+        "min_qual=20".to_string(),
+        "ref=/remote/ref.fa".to_string(),
+        format!("mates={local}"),
+    ];
+    let resolved = resolve_local_inputs(&dx_env, "", &inputs, &mut uploaded)?;
+    assert_eq!(
+        resolved,
+        [
+            format!("reads={file_id}"),
+            "min_qual=20".to_string(),
+            "ref=/remote/ref.fa".to_string(),
+            format!("mates={file_id}"),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_trash_destination() {
+    let trash = "/.trash/20261017-120000";
+    assert_eq!(trash_destination(trash, "/a.txt"), trash);
+    assert_eq!(
+        trash_destination(trash, "/data/raw/a.txt"),
+        "/.trash/20261017-120000/data/raw"
+    );
+
+    assert_eq!(restore_destination(trash, trash), "/");
+    assert_eq!(
+        restore_destination(trash, "/.trash/20261017-120000/data/raw"),
+        "/data/raw"
+    );
+
+    assert!(in_trash("/.trash/20261017-120000/a.txt"));
+    assert!(!in_trash("/.trashy/a.txt"));
+    assert!(!in_trash("/data/a.txt"));
+}
+
+#[test]
+fn test_remember_project() {
+    let recent = remember_project(&[], "project-1");
+    assert_eq!(recent, ["project-1"]);
+
+    let recent = remember_project(&recent, "project-2");
+    assert_eq!(recent, ["project-2", "project-1"]);
+
+    // Selecting again moves it to the top without a duplicate
+    let recent = remember_project(&recent, "project-1");
+    assert_eq!(recent, ["project-1", "project-2"]);
+
+    let many: Vec<String> = (0..MAX_RECENT_PROJECTS)
+        .map(|i| format!("project-{i}"))
+        .collect();
+    assert_eq!(
+        remember_project(&many, "project-new").len(),
+        MAX_RECENT_PROJECTS
+    );
+}
+
+#[test]
+fn test_fuzzy_match() {
+    assert!(fuzzy_match("", "RNA-seq"));
+    assert!(fuzzy_match("rnsq", "RNA-seq (project-123) [ADMINISTER]"));
+    assert!(fuzzy_match("RNA seq", "RNA-seq"));
+    assert!(!fuzzy_match("qsnr", "RNA-seq"));
+    assert!(!fuzzy_match("rnax", "RNA-seq"));
+}
+
+#[test]
+fn test_user_info_json() -> Result<()> {
+    let user: UserDescribeResult = serde_json::from_str(
+        r#"{"id": "user-kyclark", "class": "user", "handle": "kyclark",
+            "first": "Ken", "last": "Youens-Clark",
+            "email": "kyclark@example.com", "billTo": "org-lab"}"#,
+    )?;
+    let info = UserInfo {
+        user,
+        orgs: vec![FindOrgsResult {
+            id: "org-lab".to_string(),
+            level: "ADMIN".to_string(),
+            describe: Some(OrgDescribe {
+                name: Some("Lab".to_string()),
+            }),
+        }],
+        client_ip: Some("10.0.0.1".to_string()),
+    };
+
+    let json = serde_json::to_value(&info)?;
+    assert_eq!(json["id"], "user-kyclark");
+    assert_eq!(json["billTo"], "org-lab");
+    assert_eq!(json["clientIp"], "10.0.0.1");
+    assert_eq!(json["orgs"][0]["describe"]["name"], "Lab");
+    Ok(())
+}
+
+#[test]
+fn test_input_template() -> Result<()> {
+    let spec: Vec<AppInputSpec> = serde_json::from_str(
+        r#"[
+                {"name": "reads", "class": "array:file"},
+                {"name": "ref", "class": "file"},
+                {"name": "mode", "class": "string", "choices": ["fast", "slow"]},
+                {"name": "threads", "class": "int", "default": 4},
+                {"name": "notes", "class": "string", "optional": true}
+            ]"#,
+    )?;
+
+    assert_eq!(
+        input_template(&spec),
+        serde_json::json!({
+            "reads": [{"$dnanexus_link": ""}],
+            "ref": {"$dnanexus_link": ""},
+            "mode": "fast",
+            "threads": 4,
+        })
+    );
+
+    let record = input_spec_record(&spec[2]);
+    assert_eq!(record["group"], "Common");
+    assert_eq!(record["optional"], false);
+    assert_eq!(record["choices"], serde_json::json!(["fast", "slow"]));
+    Ok(())
+}
+
+#[test]
+fn test_read_input_file() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let json = dir.path().join("inputs.json");
+    fs::write(
+        &json,
+        r#"{"reads": {"$dnanexus_link": "file-xxxx"}, "threads": 4,
+            "ratio": 0.5, "names": ["a", "b"]}"#,
+    )?;
+    let inputs = read_input_file(&json)?;
+    assert_eq!(inputs["reads"]["$dnanexus_link"], "file-xxxx");
+    assert_eq!(inputs["threads"], 4);
+    assert_eq!(inputs["ratio"], 0.5);
+    assert_eq!(inputs["names"], serde_json::json!(["a", "b"]));
+
+    let yaml = dir.path().join("inputs.yaml");
+    fs::write(&yaml, "threads: 4\nreads:\n  $dnanexus_link: file-xxxx\n")?;
+    let inputs = read_input_file(&yaml)?;
+    assert_eq!(inputs["threads"], 4);
+    assert_eq!(inputs["reads"]["$dnanexus_link"], "file-xxxx");
+
+    fs::write(&json, r#"{"threads": null}"#)?;
+    let res = read_input_file(&json);
+    assert!(res.is_err());
+    assert!(res.unwrap_err().to_string().contains("inputs.json"));
+    Ok(())
+}
+
+#[test]
+fn test_parse_stage_instance_types() -> Result<()> {
+    let reqs = parse_stage_instance_types(&[
+        "stage-1=mem1_ssd1_v2_x4".to_string(),
+        "stage-2=main=mem1_ssd1_v2_x2".to_string(),
+        "stage-2=finish=mem1_ssd1_v2_x4".to_string(),
+    ])?;
+    assert_eq!(reqs["stage-1"]["*"].instance_type, "mem1_ssd1_v2_x4");
+    assert_eq!(reqs["stage-2"]["main"].instance_type, "mem1_ssd1_v2_x2");
+    assert_eq!(reqs["stage-2"]["finish"].instance_type, "mem1_ssd1_v2_x4");
+
+    assert!(
+        parse_stage_instance_types(&["mem1_ssd1_v2_x4".to_string()]).is_err()
+    );
+    assert!(
+        parse_stage_instance_types(&["=mem1_ssd1_v2_x4".to_string()]).is_err()
+    );
+    assert!(parse_stage_instance_types(&["stage-1=bogus".to_string()]).is_err());
+
+    let res: RunResult = serde_json::from_str(
+        r#"{"id": "analysis-1", "stages": ["job-1", "job-2"]}"#,
+    )?;
+    assert_eq!(res.stages, ["job-1", "job-2"]);
+    let res: RunResult = serde_json::from_str(r#"{"id": "job-1"}"#)?;
+    assert!(res.stages.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_object_class() {
+    assert_eq!(object_class("applet-GFfkqz0054JJG8p1GBpv7qGX"), "applet");
+    assert_eq!(
+        object_class("workflow-GFfkqz0054JJG8p1GBpv7qGX"),
+        "workflow"
+    );
+    assert_eq!(object_class("app-bwa_mem"), "app");
+    assert_eq!(object_class("foo"), "foo");
+}
+
+#[test]
+fn test_cd_target() -> Result<()> {
+    let mut dx_env = DxEnvironment {
+        cli_wd: "/foo/bar".to_string(),
+        ..Default::default()
+    };
+
+    assert_eq!(cd_target(&dx_env, None)?, "/");
+    assert_eq!(cd_target(&dx_env, Some(".."))?, "/foo");
+    assert_eq!(cd_target(&dx_env, Some("baz/"))?, "/foo/bar/baz");
+    assert_eq!(cd_target(&dx_env, Some("/qux"))?, "/qux");
+    assert!(cd_target(&dx_env, Some("-")).is_err());
+
+    dx_env.previous_wd = Some("/qux".to_string());
+    assert_eq!(cd_target(&dx_env, Some("-"))?, "/qux");
+    Ok(())
+}
+
+#[test]
+fn test_display_value() -> Result<()> {
+    let values: HashMap<String, KitchenSink> = serde_json::from_str(
+        r#"{
+                "reads": {"$dnanexus_link": "file-1"},
+                "ref": {"$dnanexus_link": {"project": "project-1", "id": "file-2"}},
+                "more": [{"$dnanexus_link": "file-3"}, {"$dnanexus_link": "file-1"}],
+                "prev": {"$dnanexus_link": {"analysis": "analysis-1", "field": "out"}},
+                "n": 3
+            }"#,
+    )?;
+
+    let mut ids = vec![];
+    for val in values.values() {
+        linked_ids(val, &mut ids);
+    }
+    ids.sort();
+    assert_eq!(ids, ["file-1", "file-1", "file-2", "file-3"]);
+
+    let names = HashMap::from([
+        ("file-1".to_string(), "reads.fq".to_string()),
+        ("file-2".to_string(), "ref.fa".to_string()),
+    ]);
+    assert_eq!(display_value(&values["reads"], &names), "reads.fq (file-1)");
+    assert_eq!(display_value(&values["ref"], &names), "ref.fa (file-2)");
+    assert_eq!(
+        display_value(&values["more"], &names),
+        "[file-3, reads.fq (file-1)]"
+    );
+    assert_eq!(display_value(&values["prev"], &names), "analysis-1");
+    assert_eq!(display_value(&values["n"], &names), "3");
+    assert_eq!(display_value(&values["reads"], &HashMap::new()), "file-1");
+    Ok(())
+}
+
+#[test]
+fn test_watch_message_jsonl() -> Result<()> {
+    let line = r#"{"job":"job-1","jobTry":1,"level":"STDERR","msg":"oops","source":"APP","timestamp":1700000000000}"#;
+    let msg: WatchMessage = serde_json::from_str(line)?;
+    assert_eq!(msg.job_try, Some(1));
+    assert_eq!(serde_json::to_string(&msg)?, line);
+
+    // Messages from jobs that were never restarted have no try
+    let msg: WatchMessage =
+        serde_json::from_str(r#"{"job":"job-1","msg":"hi"}"#)?;
+    assert_eq!(msg.job_try, None);
+    assert!(!serde_json::to_string(&msg)?.contains("jobTry"));
+    Ok(())
+}
+
+#[test]
+fn test_billing_csv() {
+    let rows = [
+        BillingRow {
+            project: "project-1".to_string(),
+            name: "RNA-seq, batch 2".to_string(),
+            executions: 3,
+            compute: 12.345,
+            egress: 1024,
+        },
+        BillingRow {
+            project: "project-2".to_string(),
+            name: r#"The "big" one"#.to_string(),
+            executions: 0,
+            compute: 0.,
+            egress: 0,
+        },
+    ];
+    assert_eq!(
+        billing_csv(&rows),
+        "project,name,executions,compute,egress_bytes\n\
+            project-1,\"RNA-seq, batch 2\",3,12.35,1024\n\
+            project-2,\"The \"\"big\"\" one\",0,0.00,0\n"
+    );
+    assert_eq!(
+        billing_csv(&[]),
+        "project,name,executions,compute,egress_bytes\n"
+    );
+}
+
+#[test]
+fn test_build_regions() -> Result<()> {
+    let app: DxApp = serde_json::from_value(serde_json::json!({
+        "name": "foo",
+        "title": "Foo",
+        "summary": "Foo",
+        "dxapi": "1.0.0",
+        "version": "0.1.0",
+        "inputSpec": [],
+        "outputSpec": [],
+        "runSpec": {
+            "interpreter": "bash",
+            "file": "src/foo.sh",
+            "distribution": "Ubuntu",
+            "release": "20.04",
+            "version": "0"
+        },
+        "regionalOptions": {
+            "aws:us-east-1": {
+                "systemRequirements": {
+                    "*": { "instanceType": "mem1_ssd1_v2_x4" }
+                }
+            },
+            "azure:westus": {
+                "systemRequirements": {
+                    "*": { "instanceType": "mem1_ssd1_v2_x4" }
+                }
+            }
+        }
+    }))?;
+
+    assert_eq!(
+        build_regions(&[], &app, "aws:eu-central-1"),
+        ["aws:us-east-1", "azure:westus"]
+    );
+    assert_eq!(
+        build_regions(&["aws:eu-west-2".to_string()], &app, ""),
+        ["aws:eu-west-2"]
+    );
+
+    assert!(app_region_problems(&app, "aws:us-east-1").is_empty());
+    assert_eq!(
+        app_region_problems(&app, "azure:westus"),
+        [
+            r#"*: Instance type "mem1_ssd1_v2_x4" is not available in "azure:westus""#
+        ]
+    );
+    assert_eq!(
+        app_region_problems(&app, "mars:olympus-1"),
+        [r#"Invalid region "mars:olympus-1""#]
+    );
+
+    // Regions without their own options get none to check
+    assert!(app_region_problems(&app, "aws:eu-west-2").is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_diff_apps() -> Result<()> {
+    let source: DxApp = serde_json::from_str(
+        r#"{
+                "name": "app", "title": "App", "version": "1.0.0",
+                "inputSpec": [
+                    {"name": "reads", "class": "file", "optional": false},
+                    {"name": "min", "class": "int"}
+                ],
+                "outputSpec": [{"name": "out", "class": "file"}],
+                "runSpec": {
+                    "interpreter": "bash", "file": "src/run.sh",
+                    "distribution": "Ubuntu", "release": "20.04",
+                    "version": "0",
+                    "timeoutPolicy": {"*": {"hours": 1}}
+                },
+                "access": {"network": ["*"]}
+            }"#,
+    )?;
+    let target: DxApp = serde_json::from_str(
+        r#"{
+                "name": "app", "title": "App",
+                "inputSpec": [
+                    {"name": "reads", "class": "array:file"},
+                    {"name": "max", "class": "int", "optional": true}
+                ],
+                "outputSpec": [{"name": "out", "class": "file"}],
+                "runSpec": {
+                    "interpreter": "bash", "code": "main() { :; }",
+                    "distribution": "Ubuntu", "release": "20.04",
+                    "version": "0",
+                    "timeoutPolicy": {"*": {"hours": 2}}
+                }
+            }"#,
+    )?;
+
+    assert!(diff_apps(&source, &source, &[])?.is_empty());
+
+    let diffs = diff_apps(&source, &target, &[])?;
+    let diff = |status, path: &str, detail: &str| AppDiff {
+        status,
+        path: path.to_string(),
+        detail: detail.to_string(),
+    };
+    assert_eq!(
+        diffs,
+        [
+            diff("source-only", "access", r#"{"network":["*"]}"#),
+            diff("differs", "inputSpec.reads", "class file != array:file"),
+            diff("source-only", "inputSpec.min", "int"),
+            diff("target-only", "inputSpec.max", "int"),
+            diff("target-only", "runSpec.code", "main() { :; }"),
+            diff("source-only", "runSpec.file", "src/run.sh"),
+            diff("differs", "runSpec.timeoutPolicy.*.hours", "1 != 2"),
+            diff("source-only", "version", "1.0.0"),
+        ]
+    );
+
+    // A built applet keeps neither the version nor the run file
+    let diffs = diff_apps(&source, &target, APPLET_IGNORED_FIELDS)?;
+    assert_eq!(diffs.len(), 5);
+    assert!(diffs.iter().all(|diff| diff.path != "version"
+        && !diff.path.starts_with("runSpec.code")
+        && !diff.path.starts_with("runSpec.file")));
+    Ok(())
+}
+
+#[test]
+fn test_applet_to_app() -> Result<()> {
+    let applet: AppletDescribeResult = serde_json::from_str(
+        r#"{
+                "id": "applet-1", "name": "app", "title": "App",
+                "inputSpec": [{"name": "reads", "class": "file",
+                    "optional": false, "patterns": ["*.fq"]}],
+                "outputSpec": [{"name": "out", "class": "file",
+                    "help": "Counts"}],
+                "runSpec": {
+                    "interpreter": "bash", "distribution": "Ubuntu",
+                    "release": "20.04", "version": "0",
+                    "code": "main() { :; }",
+                    "execDepends": [{"name": "samtools"}],
+                    "systemRequirementsByRegion": {"aws:us-east-1":
+                        {"main": {"instanceType": "mem1_ssd1_v2_x4"}}}
+                },
+                "access": {"network": [], "project": "VIEW"}
+            }"#,
+    )?;
+    let app = applet_to_app(applet)?;
+    assert_eq!(app.name, Some("app".to_string()));
+    assert_eq!(app.input_spec[0].patterns, ["*.fq"]);
+    assert_eq!(app.output_spec[0].help, Some("Counts".to_string()));
+    assert_eq!(app.run_spec.exec_depends[0].name, "samtools");
+    assert_eq!(
+        app.access.and_then(|access| access.project),
+        Some("VIEW".to_string())
+    );
+    let regional_options = app.regional_options.unwrap();
+    assert_eq!(
+        regional_options["aws:us-east-1"].system_requirements["main"]
+            .instance_type,
+        "mem1_ssd1_v2_x4"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sort_app_versions() -> Result<()> {
+    let mut apps: Vec<FindAppsResult> = serde_json::from_str(
+        r#"[
+                {"id": "app-1", "describe": {"version": "1.2.0"}},
+                {"id": "app-2", "describe": {"version": "1.10.0"}},
+                {"id": "app-3", "describe": {"version": "2.0.0-rc1"}},
+                {"id": "app-4", "describe": {"version": "2.0.0+build.5",
+                    "aliases": ["default", "2.0.0"],
+                    "published": 1700000000000}},
+                {"id": "app-5", "describe": {"version": "1.9.9"}}
+            ]"#,
+    )?;
+    sort_app_versions(&mut apps);
+    let ids: Vec<_> = apps.iter().map(|app| app.id.as_str()).collect();
+    assert_eq!(ids, ["app-4", "app-3", "app-2", "app-5", "app-1"]);
+
+    let record = app_version_record(apps.remove(0));
+    assert_eq!(record["default"], true);
+    assert_eq!(record["published"], "2023-11-14 22:13:20");
+    assert_eq!(record["created"], serde_json::Value::Null);
+    let record = app_version_record(apps.remove(0));
+    assert_eq!(record["default"], false);
+    Ok(())
+}
+
+#[test]
+fn test_top_lines() -> Result<()> {
+    let now = DateTime::parse_from_rfc3339("2024-01-01T02:00:00Z")?
+        .with_timezone(&Utc);
+    let jobs: Vec<ExecutionCost> = serde_json::from_str(
+        r#"[
+                {"id": "job-1", "name": "align", "state": "running",
+                    "instanceType": "mem1_ssd1_v2_x4",
+                    "startedRunning": 1704067199000, "totalPrice": 1.5,
+                    "currency": {"dxCode": 0, "code": "USD",
+                        "symbol": "$", "symbolPosition": "left",
+                        "decimalSymbol": ".", "groupingSymbol": ","}},
+                {"id": "job-2", "name": "sort", "state": "runnable",
+                    "instanceType": "mem1_ssd1_v2_x4"},
+                {"id": "job-3", "name": "call", "state": "running",
+                    "instanceType": "mem2_ssd1_v2_x8",
+                    "startedRunning": 1704070800000, "totalPrice": 0.25}
+            ]"#,
+    )?;
+
+    let lines = top_lines(&jobs, Some(1), now, true);
+    assert_eq!(lines[0], "Jobs: 3 (1 runnable, 2 running)");
+    assert_eq!(
+        lines[1],
+        "Instance types: 2 mem1_ssd1_v2_x4, 1 mem2_ssd1_v2_x8"
+    );
+    assert_eq!(lines[2], "Cost so far: $1.75");
+    assert!(lines[4].contains("JOB"));
+    assert!(lines[5].contains("job-1") && lines[5].contains("2:00:01"));
+    assert!(lines[6].starts_with('>') && lines[6].contains("job-2"));
+    assert!(lines[7].contains("1:00:00") && lines[7].contains("0.25"));
+
+    let lines = top_lines(&[], None, now, true);
+    assert_eq!(lines[0], "Jobs: 0");
+    assert_eq!(lines[4], "No active jobs");
+
+    assert_eq!(format_runtime(0), "0:00:00");
+    assert_eq!(format_runtime(3 * 3600 + 62), "3:01:02");
+    assert_eq!(format_runtime(-5), "0:00:00");
+    Ok(())
+}
+
+#[test]
+fn test_job_notification() -> Result<()> {
+    let job: JobDescribeResult = serde_json::from_str(
+        r#"{"id": "job-1", "name": "align", "state": "failed",
+                "totalPrice": 0.42,
+                "currency": {"dxCode": 0, "code": "USD", "symbol": "$",
+                    "symbolPosition": "left", "decimalSymbol": ".",
+                    "groupingSymbol": ","},
+                "failureReason": "AppError",
+                "failureMessage": "Out of memory"}"#,
+    )?;
+    let notification = job_notification(&job);
+    assert_eq!(
+        serde_json::to_value(&notification)?,
+        serde_json::json!({
+            "job": "job-1",
+            "name": "align",
+            "state": "failed",
+            "cost": 0.42,
+            "currency": "USD",
+            "failureReason": "AppError",
+            "failureMessage": "Out of memory",
+        })
+    );
+
+    let job: JobDescribeResult =
+        serde_json::from_str(r#"{"id": "job-2", "state": "done"}"#)?;
+    assert_eq!(
+        serde_json::to_value(job_notification(&job))?,
+        serde_json::json!({"job": "job-2", "state": "done"})
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_upload_manifest() -> Result<()> {
+    let manifest = "# uploads\npath\tfolder\ttags\tproperties\n\
+            a.fq\t/reads\tfoo, bar\tsample=1,lane=2\n\
+            \n\
+            b.fq\t\t\t\n";
+    let rows = parse_upload_manifest(manifest)?;
+    assert_eq!(
+        rows,
+        vec![
+            ManifestRow {
+                path: "a.fq".to_string(),
+                folder: Some("/reads".to_string()),
+                tags: vec!["foo".to_string(), "bar".to_string()],
+                properties: HashMap::from([
+                    ("sample".to_string(), "1".to_string()),
+                    ("lane".to_string(), "2".to_string()),
+                ]),
+            },
+            ManifestRow {
+                path: "b.fq".to_string(),
+                folder: None,
+                tags: vec![],
+                properties: HashMap::new(),
+            },
+        ]
+    );
+
+    // Only the path is required
+    let rows = parse_upload_manifest("path\nc.fq\n")?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].folder, None);
+
+    let res = parse_upload_manifest("folder\n/reads\n");
+    assert_eq!(res.unwrap_err().to_string(), r#"Missing "path" column"#);
+
+    let res = parse_upload_manifest("path\tfolder\n\t/reads\n");
+    assert_eq!(res.unwrap_err().to_string(), "Line 2: missing path");
+
+    let res = parse_upload_manifest("path\tproperties\na.fq\tfoo\n");
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        r#"Line 2: Property "foo" must be KEY=VALUE"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_upload_manifest_results() -> Result<()> {
+    let rows = parse_upload_manifest(
+        "path\tfolder\ttags\tproperties\n\
+            a.fq\t/reads\tfoo,bar\tsample=1,lane=2\n\
+            b.fq\t\t\t\n",
+    )?;
+    let results = vec![
+        Ok("file-1".to_string()),
+        Err("File \"b.fq\" is empty\n".to_string()),
+    ];
+    assert_eq!(
+        upload_manifest_results(&rows, &results),
+        "path\tfolder\ttags\tproperties\tid\terror\n\
+            a.fq\t/reads\tfoo,bar\tlane=2,sample=1\tfile-1\t\n\
+            b.fq\t\t\t\t\tFile \"b.fq\" is empty "
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dataset_summary() -> Result<()> {
+    let viz: RecordVisualizeResult =
+        serde_json::from_value(serde_json::json!({
+            "url": "https://vizserver.dnanexus.com",
+            "dataset": "record-GZ6vQPj0b5pJfbQ3XffQB1BJ",
+            "datasetVersion": "3.0",
+            "datasetRecordProject": "project-GZ6vPq00b5pKvP7yFj0V6Y5K",
+            "recordTypes": ["DatabaseQuery", "CohortBrowser"],
+            "databases": ["database-GZ6vQ2Q0b5pFgvz4ZXXxVQYX"],
+            "filters": {"pheno_filters": {}},
+            "baseSql": "SELECT 1",
+        }))?;
+    let descriptor = serde_json::json!({
+        "version": "2.0",
+        "model": {
+            "global_primary_key": {
+                "entity": "participant",
+                "field": "eid",
+            },
+            "entities": {
+                "assay": {
+                    "fields": {
+                        "sample": {"type": "string"},
+                    },
+                },
+                "participant": {
+                    "fields": {
+                        "eid": {
+                            "type": "string",
+                            "title": "Participant ID",
+                            "mapping": {
+                                "table": "participant_0001",
+                                "column": "eid",
+                            },
+                        },
+                        "age": {"type": "integer", "title": "Age"},
+                    },
+                },
+            },
+        },
+    });
+
+    let summary = dataset_summary(&viz, &descriptor);
+    assert_eq!(summary.record_type, "Cohort");
+    assert_eq!(summary.version, Some("3.0".to_string()));
+    assert_eq!(summary.primary_key, Some("participant.eid".to_string()));
+    assert_eq!(
+        summary
+            .entities
+            .iter()
+            .map(|entity| entity.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["participant", "assay"]
+    );
+    assert_eq!(
+        summary.entities[0].fields[1],
+        DatasetField {
+            name: "eid".to_string(),
+            field_type: "string".to_string(),
+            title: Some("Participant ID".to_string()),
+            table: Some("participant_0001".to_string()),
+            column: Some("eid".to_string()),
+        }
+    );
+
+    let payload = dataset_query(&viz, &["participant.eid".to_string()]);
+    assert_eq!(
+        payload,
+        serde_json::json!({
+            "project_context": "project-GZ6vPq00b5pKvP7yFj0V6Y5K",
+            "fields": [{"participant.eid": "participant$eid"}],
+            "filters": {"pheno_filters": {}},
+            "base_sql": "SELECT 1",
+        })
+    );
+
+    let lines = dataset_lines(&summary, true);
+    assert!(lines[0].contains("Cohort"));
+    assert_eq!(lines[1], r#"Entity "participant" (2 fields)"#);
+    assert!(lines[2].contains("participant_0001.eid"));
+    Ok(())
+}
+
+#[test]
+fn test_select_dataset_fields() -> Result<()> {
+    let field = |name: &str, field_type: &str| DatasetField {
+        name: name.to_string(),
+        field_type: field_type.to_string(),
+        title: None,
+        table: None,
+        column: None,
+    };
+    let summary = DatasetSummary {
+        record_type: "Dataset".to_string(),
+        dataset: "record-GZ6vQPj0b5pJfbQ3XffQB1BJ".to_string(),
+        version: None,
+        databases: vec![],
+        primary_key: Some("participant.eid".to_string()),
+        count: None,
+        entities: vec![
+            DatasetEntity {
+                name: "participant".to_string(),
+                fields: vec![field("age", "integer"), field("eid", "string")],
+            },
+            DatasetEntity {
+                name: "sample".to_string(),
+                fields: vec![field("weight", "float")],
+            },
+        ],
+    };
+
+    let selected = select_dataset_fields(
+        &summary,
+        &["participant.eid".to_string()],
+        &["participant".to_string(), "sample".to_string()],
+    )?;
+    assert_eq!(
+        selected,
+        vec![
+            ("participant.eid".to_string(), "string".to_string()),
+            ("participant.age".to_string(), "integer".to_string()),
+            ("sample.weight".to_string(), "float".to_string()),
+        ]
+    );
+
+    let res = select_dataset_fields(&summary, &[], &[]);
+    assert!(res.is_err());
+
+    let res =
+        select_dataset_fields(&summary, &["participant".to_string()], &[]);
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        r#"Field "participant" must be ENTITY.FIELD"#
+    );
+
+    let res =
+        select_dataset_fields(&summary, &["participant.bmi".to_string()], &[]);
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        r#"Unknown field "participant.bmi""#
+    );
+
+    let res = select_dataset_fields(&summary, &[], &["visit".to_string()]);
+    assert_eq!(res.unwrap_err().to_string(), r#"Unknown entity "visit""#);
+
+    Ok(())
+}
+
+#[test]
+fn test_dataset_csv_row() {
+    let fields = vec![
+        "participant.eid".to_string(),
+        "participant.name".to_string(),
+        "participant.age".to_string(),
+        "participant.meds".to_string(),
+        "participant.bmi".to_string(),
+    ];
+    let row = serde_json::json!({
+        "participant.eid": "1",
+        "participant.name": "Smith, \"Jo\"",
+        "participant.age": 40,
+        "participant.meds": ["a", "b"],
+        "participant.bmi": null,
+    });
+    assert_eq!(
+        dataset_csv_row(&fields, &row),
+        r#"1,"Smith, ""Jo""",40,"[""a"",""b""]","#
+    );
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_write_dataset_parquet() -> Result<()> {
+    use super::write_dataset_parquet;
+    use indicatif::ProgressBar;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use std::fs::File;
+
+    let fields = vec![
+        ("participant.eid".to_string(), "string".to_string()),
+        ("participant.age".to_string(), "integer".to_string()),
+    ];
+    let rows = vec![
+        serde_json::json!({"participant.eid": "1", "participant.age": 40}),
+        serde_json::json!({"participant.eid": "2", "participant.age": null}),
+    ];
+    let dir = tempfile::tempdir()?;
+    let out = dir.path().join("out.parquet");
+    let filename = out.display().to_string();
+    write_dataset_parquet(&filename, &fields, &rows, &ProgressBar::hidden())?;
+
+    let reader = SerializedFileReader::new(File::open(&out)?)?;
+    let meta = reader.metadata().file_metadata();
+    assert_eq!(meta.num_rows(), 2);
+    assert_eq!(meta.schema_descr().num_columns(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_query_command() {
+    assert_eq!(
+        query_command(
+            "database_gz6vq2q0b5pfgvz4zxxxvqyx__my_db",
+            "SELECT * FROM t WHERE name = 'x'",
+            "project-GZ6vPq00b5pKvP7yFj0V6Y5K",
+        ),
+        "spark-sql --silent --hiveconf hive.cli.print.header=true \
+            --database 'database_gz6vq2q0b5pfgvz4zxxxvqyx__my_db' \
+            -e 'SELECT * FROM t WHERE name = '\\''x'\\''' > query.tsv && \
+            dx upload query.tsv --parents --brief \
+            --path \"project-GZ6vPq00b5pKvP7yFj0V6Y5K:/.query/$DX_JOB_ID.tsv\""
+    );
+}
+
+#[test]
+fn test_parse_query_results() {
+    let (header, rows) = parse_query_results("id\tname\n1\tfoo\n2\t\n\n");
+    assert_eq!(header, vec!["id", "name"]);
+    assert_eq!(rows, vec![vec!["1", "foo"], vec!["2", ""]]);
+
+    let (header, rows) = parse_query_results("");
+    assert!(header.is_empty());
+    assert!(rows.is_empty());
+}
+
+#[test]
+fn test_query_page() {
+    assert_eq!(query_page(250, 100, 1), (0, 100));
+    assert_eq!(query_page(250, 100, 3), (200, 250));
+    assert_eq!(query_page(250, 100, 4), (250, 250));
+    assert_eq!(query_page(250, 100, 0), (0, 100));
+    assert_eq!(query_page(0, 100, 1), (0, 0));
+}
+
+#[test]
+fn test_find_duplicates() {
+    let file =
+        |id: &str, size: u64, created: i64, md5: Option<&str>| DupeCandidate {
+            id: id.to_string(),
+            path: format!("/{id}.txt"),
+            size,
+            created: DateTime::from_timestamp(created, 0),
+            checksum: md5.map(str::to_string),
+        };
+    let groups = find_duplicates(vec![
+        file("file-3", 10, 3, Some("aaa")),
+        file("file-1", 10, 1, Some("aaa")),
+        file("file-2", 10, 2, Some("bbb")),
+        file("file-4", 10, 4, None),
+        file("file-5", 10, 5, None),
+        file("file-6", 0, 6, Some("d41d8cd98f00b204e9800998ecf8427e")),
+        file("file-7", 0, 7, Some("d41d8cd98f00b204e9800998ecf8427e")),
+        file("file-8", 50, 9, Some("ccc")),
+        file("file-9", 50, 8, Some("ccc")),
+    ]);
+
+    let ids: Vec<Vec<&str>> = groups
+        .iter()
+        .map(|group| group.iter().map(|f| f.id.as_str()).collect())
+        .collect();
+    assert_eq!(
+        ids,
+        vec![vec!["file-9", "file-8"], vec!["file-1", "file-3"]]
+    );
+
+    let rows = dupe_rows(&groups);
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows[0].group, 1);
+    assert_eq!(rows[0].status, "keep");
+    assert_eq!(rows[1].status, "duplicate");
+    assert_eq!(rows[3].group, 2);
+    assert_eq!(rows[3].path, "/file-3.txt");
+}
+
+#[test]
+fn test_file_checksum() -> Result<()> {
+    let desc: FileDescribeResult = serde_json::from_value(serde_json::json!({
+        "id": "file-1",
+        "parts": {
+            "2": {"md5": "bbb", "size": 5},
+            "1": {"md5": "aaa", "size": 10},
+        },
+    }))?;
+    assert_eq!(
+        file_checksum(&desc),
+        Some(format!("parts:{:x}", md5::compute("aaa,bbb")))
+    );
+
+    let desc: FileDescribeResult = serde_json::from_value(serde_json::json!({
+        "id": "file-1",
+        "properties": {"md5": "ccc"},
+        "parts": {"1": {"md5": "aaa"}},
+    }))?;
+    assert_eq!(file_checksum(&desc), Some("aaa".to_string()));
+
+    let desc: FileDescribeResult = serde_json::from_value(
+        serde_json::json!({"id": "file-1", "parts": {"1": {"size": 1}}}),
+    )?;
+    assert_eq!(file_checksum(&desc), None);
+    Ok(())
+}
+
+#[test]
+fn test_project_snapshot() -> Result<()> {
+    let objects: Vec<FindDataDescribe> =
+        serde_json::from_value(serde_json::json!([
+            {
+                "id": "file-GFfbj0Q054J4ypqJ8vQjF4V7",
+                "name": "reads.fq",
+                "folder": "/data",
+                "state": "closed",
+                "types": [],
+                "links": [],
+                "tags": ["raw"],
+                "properties": {"sample": "1"},
+            },
+            {
+                "id": "record-GZ6vQPj0b5pJfbQ3XffQB1BJ",
+                "name": "dataset",
+                "folder": "/",
+                "state": "closed",
+                "hidden": true,
+                "types": ["Dataset"],
+                "links": [],
+                "tags": [],
+            },
+            {
+                "id": "file-GZ6vPq00b5pKvP7yFj0V6Y5K",
+                "name": "upload.bam",
+                "folder": "/data",
+                "state": "open",
+                "types": [],
+                "links": [],
+                "tags": [],
+            },
+        ]))?;
+    let created = DateTime::from_timestamp(1700000000, 0).unwrap();
+    let snapshot = project_snapshot(
+        "project-GFfbj0Q054J4ypqJ8vQjF4V7",
+        Some("My project".to_string()),
+        vec!["/data".to_string(), "/".to_string(), "/data".to_string()],
+        objects,
+        created,
+    );
+
+    assert_eq!(snapshot.folders, vec!["/", "/data"]);
+    assert_eq!(
+        snapshot
+            .objects
+            .iter()
+            .map(|obj| obj.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["dataset", "reads.fq", "upload.bam"]
+    );
+    assert_eq!(snapshot.objects[0].class, "record");
+    assert!(snapshot.objects[0].hidden);
+    assert_eq!(snapshot.objects[1].tags, vec!["raw"]);
+    assert_eq!(snapshot.objects[1].properties["sample"], "1");
+
+    // The manifest reads back as it was written
+    let json = serde_json::to_string(&snapshot)?;
+    let read: ProjectSnapshot = serde_json::from_str(&json)?;
+    assert_eq!(read, snapshot);
+
+    let (clones, skipped) = snapshot_clones(&snapshot);
+    assert_eq!(
+        clones,
+        BTreeMap::from([
+            (
+                "/".to_string(),
+                vec!["record-GZ6vQPj0b5pJfbQ3XffQB1BJ".to_string()]
+            ),
+            (
+                "/data".to_string(),
+                vec!["file-GFfbj0Q054J4ypqJ8vQjF4V7".to_string()]
+            ),
+        ])
+    );
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].name, "upload.bam");
+    Ok(())
+}
+
+#[test]
+fn test_sync_plan() {
+    let source = BTreeMap::from([
+        ("a.txt".to_string(), 10),
+        ("b.txt".to_string(), 20),
+        ("dir/c.txt".to_string(), 30),
+        ("dir/d.txt".to_string(), 40),
+    ]);
+    let dest = BTreeMap::from([
+        ("b.txt".to_string(), 21),
+        ("dir/c.txt".to_string(), 30),
+        ("dir/d.txt".to_string(), 40),
+        ("old.txt".to_string(), 50),
+    ]);
+    let changed = BTreeSet::from(["dir/d.txt".to_string()]);
+    let action = |op, path: &str| SyncAction {
+        op,
+        path: path.to_string(),
+    };
+
+    assert_eq!(
+        sync_plan(&source, &dest, &changed, false),
+        vec![
+            action(SyncOp::Add, "a.txt"),
+            action(SyncOp::Update, "b.txt"),
+            action(SyncOp::Update, "dir/d.txt"),
+        ]
+    );
+
+    assert_eq!(
+        sync_plan(&source, &dest, &BTreeSet::new(), true),
+        vec![
+            action(SyncOp::Add, "a.txt"),
+            action(SyncOp::Update, "b.txt"),
+            action(SyncOp::Delete, "old.txt"),
+        ]
+    );
+
+    assert!(sync_plan(&source, &source, &BTreeSet::new(), true).is_empty());
+}
+
+#[test]
+fn test_sync_local_files() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::create_dir_all(dir.path().join("sub"))?;
+    fs::write(dir.path().join("top.txt"), "abc")?;
+    fs::write(dir.path().join("sub").join("nested.txt"), "abcdef")?;
+    fs::write(dir.path().join(".top.txt.dxrs-sync"), "partial")?;
+
+    assert_eq!(
+        sync_local_files(dir.path()),
+        BTreeMap::from([
+            ("sub/nested.txt".to_string(), 6),
+            ("top.txt".to_string(), 3),
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_format_description() -> Result<()> {
+    let val = serde_json::json!({
+        "id": "file-1234",
+        "name": "reads.fq",
+        "size": 42,
+        "folder": null,
+        "properties": { "sample": "NA12878" },
+        "tags": ["raw", "fastq"],
+    });
+
+    assert_eq!(
+        format_description(r"{id}\t{name}\t{size}", &val)?,
+        "file-1234\treads.fq\t42"
+    );
+    assert_eq!(
+        format_description("{properties.sample} {tags.1} {tags}", &val)?,
+        r#"NA12878 fastq ["raw","fastq"]"#
+    );
+    assert_eq!(format_description("{{{ folder }}}{missing}", &val)?, "{}");
+    assert!(format_description("{id", &val).is_err());
+    assert!(format_description("id}", &val).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_select_fields() {
+    let val = serde_json::json!({
+        "id": "job-1234",
+        "state": "done",
+        "runInput": { "reads": "file-1234" },
+    });
+    let fields = vec![
+        "state".to_string(),
+        "runInput.reads".to_string(),
+        "size".to_string(),
+    ];
+
+    assert_eq!(
+        serde_json::to_string(&select_fields(&val, &fields)).unwrap(),
+        r#"{"runInput.reads":"file-1234","size":null,"state":"done"}"#
+    );
+}
+
+#[test]
+fn test_fan_out() -> Result<()> {
+    let items: Vec<u64> = (0..50).collect();
+    let res = fan_out(&items, |n| {
+        // Later items finish first to shuffle the completion order
+        std::thread::sleep(Duration::from_millis(50 - n));
+        n * 2
+    })?;
+    assert_eq!(res, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+
+    let empty: Vec<u64> = vec![];
+    assert!(fan_out(&empty, |n| *n)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_incomplete() {
+    assert!(incomplete("Mkdir", vec![]).is_ok());
+
+    let errors = vec![
+        anyhow::Error::new(CliError::NotFound("No such file".to_string())),
+        anyhow::anyhow!("Other failure"),
+    ];
+    let err = incomplete("Download", errors).unwrap_err();
+    assert_eq!(err.to_string(), "Download incomplete, 2 errors");
+    assert_eq!(exit_code(&err), NOT_FOUND);
+}
+
+#[test]
+fn test_upload_each() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let good = dir.path().join("good.txt");
+    fs::write(&good, "data")?;
+    let empty = dir.path().join("empty.txt");
+    fs::write(&empty, "")?;
+
+    let uploads: Vec<_> = [&good, &empty]
+        .iter()
+        .map(|path| (path.display().to_string(), ()))
+        .collect();
+    let progress = UploadProgress::new(ProgressFormat::None_, 4, 2);
+    let err = upload_each(uploads, &progress, |file, _| {
+        local_source(file, None).map(|_| "file-xxxx".to_string())
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Upload incomplete, 1 error");
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!(r#"File "{}" is empty"#, empty.display())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_applet_or_workflow_id() {
+    let dx_env = DxEnvironment {
+        project_context_id: "project-GXY0PK0071xJpG156BFyXpJF".to_string(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        applet_or_workflow_id(&dx_env, "applet-GXY0PK0071xJpG156BFyXpJ1"),
+        Some((
+            "project-GXY0PK0071xJpG156BFyXpJF".to_string(),
+            "applet-GXY0PK0071xJpG156BFyXpJ1".to_string()
+        ))
+    );
+    assert_eq!(
+        applet_or_workflow_id(
+            &dx_env,
+            "project-GXY0PK0071xJpG156BFyXpJ2:\
+                workflow-GXY0PK0071xJpG156BFyXpJ3"
+        ),
+        Some((
+            "project-GXY0PK0071xJpG156BFyXpJ2".to_string(),
+            "workflow-GXY0PK0071xJpG156BFyXpJ3".to_string()
+        ))
+    );
+    assert_eq!(applet_or_workflow_id(&dx_env, "app-bwa_mem"), None);
+    assert_eq!(applet_or_workflow_id(&dx_env, "/data/applet"), None);
+}
+
+#[test]
+fn test_dir_fingerprint() -> Result<()> {
+    let make = |files: &[(&str, &str)]| -> Result<tempfile::TempDir> {
+        let dir = tempfile::tempdir()?;
+        for (name, contents) in files {
+            let path = dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(path, contents)?;
+        }
+        Ok(dir)
+    };
+
+    let files = [("usr/bin/tool", "#!/bin/sh"), ("ref.txt", "ACGT")];
+    let fingerprint = |dir: tempfile::TempDir, ignore: &DxIgnore| {
+        dir_fingerprint(dir.path(), Path::new("resources"), ignore)
+    };
+    let none = DxIgnore::default();
+    let first = fingerprint(make(&files)?, &none)?;
+    assert_eq!(first, fingerprint(make(&files)?, &none)?);
+
+    let changed = [("usr/bin/tool", "#!/bin/sh"), ("ref.txt", "ACGA")];
+    assert_ne!(first, fingerprint(make(&changed)?, &none)?);
+
+    let renamed = [("usr/bin/tool", "#!/bin/sh"), ("ref2.txt", "ACGT")];
+    assert_ne!(first, fingerprint(make(&renamed)?, &none)?);
+
+    let ignore = DxIgnore::parse("resources/venv/")?;
+    let venv = [
+        ("usr/bin/tool", "#!/bin/sh"),
+        ("ref.txt", "ACGT"),
+        ("venv/bin/python", "ELF"),
+    ];
+    assert_eq!(
+        fingerprint(make(&files)?, &ignore)?,
+        fingerprint(make(&venv)?, &ignore)?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dxignore() -> Result<()> {
+    let ignore = DxIgnore::parse(
+        "# Local only\n\n.git/\n*.pyc\n/test_data\n\
+             docs/**/*.pdf\nlogs/**\n!keep.pyc\n",
+    )?;
+    let ignored =
+        |path: &str, is_dir: bool| ignore.is_ignored(Path::new(path), is_dir);
+    assert!(ignored(".git", true));
+    assert!(ignored("resources/.git", true));
+    assert!(!ignored(".git", false));
+    assert!(ignored("src/main.pyc", false));
+    assert!(!ignored("src/keep.pyc", false));
+    assert!(ignored("test_data", true));
+    assert!(!ignored("src/test_data", true));
+    assert!(ignored("docs/manual.pdf", false));
+    assert!(ignored("docs/a/b/manual.pdf", false));
+    assert!(!ignored("docs/manual.txt", false));
+    assert!(ignored("logs/run/1.log", false));
+    assert!(!ignored("logs", true));
+    assert!(!ignored("src/main.py", false));
+    Ok(())
+}
+
+#[test]
+fn test_stream_tarball() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::create_dir_all(dir.path().join("bin"))?;
+    fs::write(dir.path().join("bin/tool"), "#!/bin/sh")?;
+    fs::write(dir.path().join("ref.txt"), "ACGT")?;
+    let ignore = DxIgnore::parse("*.txt")?;
+
+    let (sender, chunks) = mpsc::sync_channel(1);
+    let writer = ChunkWriter(sender);
+    let path = dir.path().to_path_buf();
+    let handle = std::thread::spawn(move || {
+        tar_directory(&path, Path::new(""), &DxIgnore::default(), writer)
+    });
+    let mut reader = ChunkReader {
+        chunks,
+        chunk: io::Cursor::new(vec![]),
+    };
+    let mut archive = vec![];
+    reader.read_to_end(&mut archive)?;
+    handle.join().unwrap()?;
+    let names = tar::Archive::new(MultiGzDecoder::new(&archive[..]))
+        .entries()?
+        .map(|entry| Ok(entry?.path()?.display().to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(names, ["bin", "bin/tool", "ref.txt"]);
+
+    let mut archive = vec![];
+    tar_directory(dir.path(), Path::new(""), &ignore, &mut archive)?;
+    let names = tar::Archive::new(MultiGzDecoder::new(&archive[..]))
+        .entries()?
+        .map(|entry| Ok(entry?.path()?.display().to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(names, ["bin", "bin/tool"]);
+
+    // An error from the writer is not mistaken for the end
+    let (sender, chunks) = mpsc::sync_channel(2);
+    sender.send(Ok(b"partial".to_vec()))?;
+    sender.send(Err(io::Error::other("Disk on fire")))?;
+    drop(sender);
+    let mut reader = ChunkReader {
+        chunks,
+        chunk: io::Cursor::new(vec![]),
+    };
+    let mut buffer = vec![];
+    assert!(reader.read_to_end(&mut buffer).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_prompted_value() {
+    use serde_json::json;
+    let parse = |class, text| parse_prompted_value(&class, text);
+    assert_eq!(parse(InputOutputClass::Int, "42"), Some(json!(42)));
+    assert_eq!(parse(InputOutputClass::Int, "4.2"), None);
+    assert_eq!(parse(InputOutputClass::Float, "4.2"), Some(json!(4.2)));
+    assert_eq!(parse(InputOutputClass::String, "42"), Some(json!("42")));
+    assert_eq!(parse(InputOutputClass::String, " "), None);
+    assert_eq!(
+        parse(InputOutputClass::Hash, r#"{"a": 1}"#),
+        Some(json!({"a": 1}))
+    );
+    assert_eq!(
+        parse(InputOutputClass::ArrayInt, "1, 2,3"),
+        Some(json!([1, 2, 3]))
+    );
+    assert_eq!(
+        parse(InputOutputClass::ArrayInt, "[1, 2]"),
+        Some(json!([1, 2]))
+    );
+    assert_eq!(
+        parse(InputOutputClass::ArrayString, "a, b"),
+        Some(json!(["a", "b"]))
+    );
+    assert_eq!(
+        parse(InputOutputClass::Record, "record-GZfj8Q804bBJ0gGJFq5Jbz4V"),
+        Some(json!({"$dnanexus_link": "record-GZfj8Q804bBJ0gGJFq5Jbz4V"}))
+    );
+    assert_eq!(parse(InputOutputClass::Record, "notes"), None);
+}
+
+#[test]
+fn test_job_failure() -> Result<()> {
+    let job: JobDescribeResult = serde_json::from_value(serde_json::json!({
+        "id": "job-GZfj8Q804bBJ0gGJFq5Jbz4V",
+        "state": "failed",
+        "failureReason": "AppError",
+        "failureMessage": "Subjob failed",
+        "failureFrom": {
+            "id": "job-GZfj8Q804bBJ0gGJFq5Jbz4W",
+            "try": 1,
+            "name": "process",
+            "executable": "applet-GZfj8Q804bBJ0gGJFq5Jbz4X",
+            "executableName": "scatter",
+            "function": "process",
+            "failureReason": "AppInternalError",
+            "failureMessage": "Out of memory"
+        },
+        "failureReports": [{
+            "to": "job-GZfj8Q804bBJ0gGJFq5Jbz4V",
+            "by": "job-GZfj8Q804bBJ0gGJFq5Jbz4W",
+            "at": 1700000000000_u64
+        }]
+    }))?;
+
+    let mut failure = job_failure(&job).unwrap();
+    assert_eq!(failure.origin_id, "job-GZfj8Q804bBJ0gGJFq5Jbz4W");
+    failure.stderr = vec!["Killed".to_string()];
+    assert_eq!(
+        failure.to_string(),
+        [
+            "job-GZfj8Q804bBJ0gGJFq5Jbz4V failed: AppInternalError: Out of memory",
+            "  Failure started in job-GZfj8Q804bBJ0gGJFq5Jbz4W (scatter:process, try 1)",
+            "  Reported to job-GZfj8Q804bBJ0gGJFq5Jbz4V by job-GZfj8Q804bBJ0gGJFq5Jbz4W at 2023-11-14 22:13:20",
+            "  Last lines of stderr from job-GZfj8Q804bBJ0gGJFq5Jbz4W:",
+            "    Killed",
+            "",
+        ]
+        .join("\n")
+    );
+
+    let done: JobDescribeResult = serde_json::from_value(
+        serde_json::json!({ "id": "job-GZfj8Q804bBJ0gGJFq5Jbz4V", "state": "done" }),
+    )?;
+    assert_eq!(job_failure(&done), None);
+    Ok(())
+}
+
+#[test]
+fn test_wait_target() {
+    let job = WaitTarget::Job("job-xxxx".to_string());
+    assert!(!job.is_settled("running").unwrap());
+    assert!(job.is_settled("done").unwrap());
+    assert!(!job.is_failed("done"));
+    assert!(job.is_failed("terminated"));
+
+    let file = WaitTarget::File("file-xxxx".to_string());
+    assert!(!file.is_settled("closing").unwrap());
+    assert!(file.is_settled("closed").unwrap());
+    assert!(file.is_settled("done").is_err());
+    assert!(!file.is_failed("closed"));
+
+    assert_eq!(wait_poll_delay(0), Duration::from_secs(1));
+    assert_eq!(wait_poll_delay(3), Duration::from_secs(8));
+    assert_eq!(wait_poll_delay(10), Duration::from_secs(30));
+}
+
+#[test]
+fn test_app_user_id() {
+    assert_eq!(app_user_id("kyclark"), "user-kyclark");
+    assert_eq!(app_user_id("user-kyclark"), "user-kyclark");
+    assert_eq!(app_user_id("org-dnanexus"), "org-dnanexus");
+    assert_eq!(app_user_id("PUBLIC"), "PUBLIC");
+}
+
+#[test]
+fn test_archive_entry_path() {
+    assert_eq!(
+        archive_entry_path("/results", "/results", "a.txt"),
+        PathBuf::from("results/a.txt")
+    );
+    assert_eq!(
+        archive_entry_path("/runs/1", "/runs/1/logs", "b.log"),
+        PathBuf::from("1/logs/b.log")
+    );
+    assert_eq!(
+        archive_entry_path("/", "/data", "c.bam"),
+        PathBuf::from("data/c.bam")
+    );
+}
+
+#[test]
+fn test_token_info() {
+    assert_eq!(
+        token_scope(&serde_json::json!({"full": {"level": "VIEW"}})),
+        "full (VIEW)"
+    );
+    assert_eq!(
+        token_scope(&serde_json::json!({"projects": {
+            "project-xxxx": "CONTRIBUTE",
+            "project-yyyy": "VIEW"
+        }})),
+        "project-xxxx (CONTRIBUTE), project-yyyy (VIEW)"
+    );
+
+    let now = Utc::now();
+    let warn =
+        |hours| token_expiry_warning(now + chrono::Duration::hours(hours), now);
+    assert_eq!(warn(24 * 30), None);
+    assert_eq!(warn(50).as_deref(), Some("expires in 2 days"));
+    assert_eq!(warn(5).as_deref(), Some("expires in 5 hours"));
+    assert_eq!(warn(-1).as_deref(), Some("expired"));
+}
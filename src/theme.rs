@@ -0,0 +1,249 @@
+use crate::{dxenv, ColorWhen};
+use ansi_term::{Colour, Style};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Colors are off until "configure" turns them on
+static THEME: OnceCell<Option<Theme>> = OnceCell::new();
+
+/// Colors for each kind of output, set by "colors" in "config.json" in
+/// the config directory, e.g., {"colors": {"folder": "bold blue"}}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// Project and folder titles above listings
+    pub heading: String,
+
+    pub folder: String,
+
+    pub id: String,
+
+    /// Lines only in the second of two things compared
+    pub added: String,
+
+    /// Lines only in the first of two things compared
+    pub removed: String,
+
+    /// Object and job states, e.g., "closed" or "failed," added to the
+    /// default state colors
+    #[serde(deserialize_with = "with_default_states")]
+    pub states: BTreeMap<String, String>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        let states = [
+            ("closed", "green"),
+            ("done", "green"),
+            ("open", "yellow"),
+            ("closing", "yellow"),
+            ("idle", "yellow"),
+            ("runnable", "yellow"),
+            ("running", "yellow"),
+            ("waiting_on_input", "yellow"),
+            ("waiting_on_output", "yellow"),
+            ("failed", "red"),
+            ("terminated", "red"),
+            ("terminating", "red"),
+        ];
+
+        Theme {
+            heading: "cyan".to_string(),
+            folder: "cyan".to_string(),
+            id: "".to_string(),
+            added: "green".to_string(),
+            removed: "red".to_string(),
+            states: states
+                .into_iter()
+                .map(|(state, color)| (state.to_string(), color.to_string()))
+                .collect(),
+        }
+    }
+}
+
+// --------------------------------------------------
+fn with_default_states<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let mut states = Theme::default().states;
+    states.extend(BTreeMap::<String, String>::deserialize(deserializer)?);
+    Ok(states)
+}
+
+// --------------------------------------------------
+/// Decides once whether to color the output and with which theme
+pub fn configure(when: ColorWhen) {
+    let enabled = colors_enabled(
+        when,
+        std::env::var("NO_COLOR").ok().as_deref(),
+        console::Term::stdout().is_term(),
+    );
+
+    // Progress bars and spinners come from "console," which has its own
+    // switch for colors
+    if when != ColorWhen::Auto || !enabled {
+        console::set_colors_enabled(enabled);
+        console::set_colors_enabled_stderr(enabled);
+    }
+
+    let theme = enabled.then(|| match dxenv::get_config() {
+        Ok(config) => {
+            for name in config.colors.names() {
+                if parse_style(name).is_none() {
+                    eprintln!(r#"Ignoring unknown color "{name}""#);
+                }
+            }
+            config.colors
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            Theme::default()
+        }
+    });
+    let _ = THEME.set(theme);
+}
+
+// --------------------------------------------------
+// "never" and "always" win over NO_COLOR, which wins over the terminal
+fn colors_enabled(
+    when: ColorWhen,
+    no_color: Option<&str>,
+    is_term: bool,
+) -> bool {
+    match when {
+        ColorWhen::Always => true,
+        ColorWhen::Never => false,
+        ColorWhen::Auto => no_color.is_none_or(|v| v.is_empty()) && is_term,
+    }
+}
+
+impl Theme {
+    fn names(&self) -> impl Iterator<Item = &String> {
+        [
+            &self.heading,
+            &self.folder,
+            &self.id,
+            &self.added,
+            &self.removed,
+        ]
+        .into_iter()
+        .chain(self.states.values())
+    }
+}
+
+// --------------------------------------------------
+// A color name, e.g., "red," optionally after "bold," "dimmed," or
+// "underline," or "" for plain text
+fn parse_style(name: &str) -> Option<Style> {
+    let mut style = Style::new();
+    for word in name.split_whitespace() {
+        style = match word.to_lowercase().as_str() {
+            "bold" => style.bold(),
+            "dimmed" => style.dimmed(),
+            "italic" => style.italic(),
+            "underline" => style.underline(),
+            "black" => style.fg(Colour::Black),
+            "red" => style.fg(Colour::Red),
+            "green" => style.fg(Colour::Green),
+            "yellow" => style.fg(Colour::Yellow),
+            "blue" => style.fg(Colour::Blue),
+            "purple" | "magenta" => style.fg(Colour::Purple),
+            "cyan" => style.fg(Colour::Cyan),
+            "white" => style.fg(Colour::White),
+            other => match other.parse::<u8>() {
+                Ok(num) => style.fg(Colour::Fixed(num)),
+                _ => return None,
+            },
+        };
+    }
+    Some(style)
+}
+
+// --------------------------------------------------
+fn paint(color: impl Fn(&Theme) -> &str, text: impl AsRef<str>) -> String {
+    let text = text.as_ref();
+    match THEME.get().and_then(|theme| theme.as_ref()) {
+        Some(theme) => match parse_style(color(theme)) {
+            Some(style) => style.paint(text).to_string(),
+            _ => text.to_string(),
+        },
+        _ => text.to_string(),
+    }
+}
+
+// --------------------------------------------------
+pub fn heading(text: impl AsRef<str>) -> String {
+    paint(|theme| &theme.heading, text)
+}
+
+// --------------------------------------------------
+pub fn folder(text: impl AsRef<str>) -> String {
+    paint(|theme| &theme.folder, text)
+}
+
+// --------------------------------------------------
+pub fn id(text: impl AsRef<str>) -> String {
+    paint(|theme| &theme.id, text)
+}
+
+// --------------------------------------------------
+pub fn added(text: impl AsRef<str>) -> String {
+    paint(|theme| &theme.added, text)
+}
+
+// --------------------------------------------------
+pub fn removed(text: impl AsRef<str>) -> String {
+    paint(|theme| &theme.removed, text)
+}
+
+// --------------------------------------------------
+pub fn state(text: impl AsRef<str>) -> String {
+    let text = text.as_ref();
+    paint(
+        |theme| theme.states.get(text).map_or("", |color| color),
+        text,
+    )
+}
+
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::{colors_enabled, parse_style, Theme};
+    use crate::ColorWhen;
+    use ansi_term::{Colour, Style};
+
+    #[test]
+    fn test_colors_enabled() {
+        assert!(colors_enabled(ColorWhen::Auto, None, true));
+        assert!(colors_enabled(ColorWhen::Auto, Some(""), true));
+        assert!(!colors_enabled(ColorWhen::Auto, Some("1"), true));
+        assert!(!colors_enabled(ColorWhen::Auto, None, false));
+        assert!(colors_enabled(ColorWhen::Always, Some("1"), false));
+        assert!(!colors_enabled(ColorWhen::Never, None, true));
+    }
+
+    #[test]
+    fn test_parse_style() {
+        assert_eq!(parse_style(""), Some(Style::new()));
+        assert_eq!(parse_style("cyan"), Some(Colour::Cyan.normal()));
+        assert_eq!(parse_style("Bold Blue"), Some(Colour::Blue.bold()));
+        assert_eq!(parse_style("208"), Some(Colour::Fixed(208).normal()));
+        assert_eq!(parse_style("sparkly"), None);
+    }
+
+    #[test]
+    fn test_theme_config() {
+        let theme: Theme = serde_json::from_str(
+            r#"{"folder": "bold blue", "states": {"failed": "purple"}}"#,
+        )
+        .unwrap();
+        assert_eq!(theme.folder, "bold blue");
+        assert_eq!(theme.heading, "cyan");
+        assert_eq!(theme.states.get("failed"), Some(&"purple".to_string()));
+        assert_eq!(theme.states.get("done"), Some(&"green".to_string()));
+    }
+}